@@ -0,0 +1,320 @@
+//!
+//! # Basic Rbac Policy
+//!
+//! Role based policy shared by SC and SPU. Roles are matched against scopes of X509 identity.
+//! Permission either applies to all instances of object type or only to instances
+//! whose name matches resource pattern.
+//!
+use std::fmt;
+use std::fs::read;
+use std::collections::HashMap;
+use std::path::PathBuf;
+use std::convert::TryFrom;
+use std::str::FromStr;
+
+use tracing::debug;
+use serde::{Serialize, Deserialize};
+
+use fluvio_controlplane_metadata::extended::ObjectType;
+
+use crate::{AuthError, TypeAction, InstanceAction, Identity};
+
+type Role = String;
+
+#[derive(Debug, Clone, PartialEq, Hash, Eq, Deserialize, Serialize)]
+pub enum Action {
+    Create,
+    Read,
+    Write,
+    Update,
+    Delete,
+    All,
+}
+
+impl From<TypeAction> for Action {
+    fn from(action: TypeAction) -> Self {
+        match action {
+            TypeAction::Create => Action::Create,
+            TypeAction::Read => Action::Read,
+        }
+    }
+}
+
+impl From<InstanceAction> for Action {
+    fn from(action: InstanceAction) -> Self {
+        match action {
+            InstanceAction::Create => Action::Create,
+            InstanceAction::Read => Action::Read,
+            InstanceAction::Write => Action::Write,
+            InstanceAction::Update => Action::Update,
+            InstanceAction::Delete => Action::Delete,
+        }
+    }
+}
+
+impl fmt::Display for Action {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Debug::fmt(self, f)
+    }
+}
+
+impl FromStr for Action {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "create" => Ok(Action::Create),
+            "read" => Ok(Action::Read),
+            "write" => Ok(Action::Write),
+            "update" => Ok(Action::Update),
+            "delete" => Ok(Action::Delete),
+            "all" => Ok(Action::All),
+            _ => Err(format!("unknown action: {}", s)),
+        }
+    }
+}
+
+/// Pattern of resource name
+#[derive(Debug, Clone, PartialEq, Hash, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ResourcePattern {
+    /// exact name
+    Literal(String),
+    /// name starting with prefix
+    Prefix(String),
+    /// name matching glob, `*` matches any sequence and `?` matches single character
+    Glob(String),
+}
+
+impl ResourcePattern {
+    pub fn matches(&self, name: &str) -> bool {
+        match self {
+            Self::Literal(literal) => literal == name,
+            Self::Prefix(prefix) => name.starts_with(prefix.as_str()),
+            Self::Glob(glob) => glob_match(glob, name),
+        }
+    }
+}
+
+impl fmt::Display for ResourcePattern {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Literal(literal) => write!(f, "literal:{}", literal),
+            Self::Prefix(prefix) => write!(f, "prefix:{}", prefix),
+            Self::Glob(glob) => write!(f, "glob:{}", glob),
+        }
+    }
+}
+
+/// match name against glob pattern
+fn glob_match(pattern: &str, name: &str) -> bool {
+    let pattern: Vec<char> = pattern.chars().collect();
+    let name: Vec<char> = name.chars().collect();
+
+    let (mut p, mut n) = (0, 0);
+    // position of last `*` in pattern and name position it was matched against
+    let mut backtrack: Option<(usize, usize)> = None;
+
+    while n < name.len() {
+        if p < pattern.len() && (pattern[p] == '?' || pattern[p] == name[n]) {
+            p += 1;
+            n += 1;
+        } else if p < pattern.len() && pattern[p] == '*' {
+            backtrack = Some((p, n));
+            p += 1;
+        } else if let Some((star, matched)) = backtrack {
+            p = star + 1;
+            n = matched + 1;
+            backtrack = Some((star, matched + 1));
+        } else {
+            return false;
+        }
+    }
+
+    pattern[p..].iter().all(|c| *c == '*')
+}
+
+/// Permission granted to role for object type.
+/// Plain action applies to every instance, otherwise only instances matching resource
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+#[serde(untagged)]
+pub enum Permission {
+    Action(Action),
+    Resource {
+        action: Action,
+        resource: ResourcePattern,
+    },
+}
+
+impl From<Action> for Permission {
+    fn from(action: Action) -> Self {
+        Self::Action(action)
+    }
+}
+
+impl Permission {
+    pub fn action(&self) -> &Action {
+        match self {
+            Self::Action(action) => action,
+            Self::Resource { action, .. } => action,
+        }
+    }
+
+    /// resource pattern, none if permission applies to all instances
+    pub fn resource(&self) -> Option<&ResourcePattern> {
+        match self {
+            Self::Action(_) => None,
+            Self::Resource { resource, .. } => Some(resource),
+        }
+    }
+
+    /// check if permission allows action.
+    /// if instance is not specified, permission restricted to resource still allows action
+    /// since it may apply to some instances
+    pub fn allows(&self, action: &Action, instance: Option<&str>) -> bool {
+        if self.action() != action && self.action() != &Action::All {
+            return false;
+        }
+
+        match (self.resource(), instance) {
+            (Some(resource), Some(name)) => resource.matches(name),
+            _ => true,
+        }
+    }
+}
+
+/// Permission of role for object type
+#[derive(Debug, Clone, PartialEq)]
+pub struct PolicyRule {
+    pub role: Role,
+    pub object_type: ObjectType,
+    pub permission: Permission,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct BasicRbacPolicy(pub HashMap<Role, HashMap<ObjectType, Vec<Permission>>>);
+
+impl From<HashMap<Role, HashMap<ObjectType, Vec<Permission>>>> for BasicRbacPolicy {
+    fn from(map: HashMap<Role, HashMap<ObjectType, Vec<Permission>>>) -> Self {
+        Self(map)
+    }
+}
+
+impl TryFrom<PathBuf> for BasicRbacPolicy {
+    type Error = std::io::Error;
+    fn try_from(path: PathBuf) -> Result<Self, Self::Error> {
+        debug!("reading basic policy: {:#?}", path);
+        let file = read(path)?;
+        let policy: BasicRbacPolicy = serde_json::from_slice(&file)?;
+        Ok(policy)
+    }
+}
+
+impl BasicRbacPolicy {
+    pub async fn evaluate(
+        &self,
+        action: Action,
+        object_type: ObjectType,
+        instance: Option<&str>,
+        identity: &dyn Identity,
+    ) -> Result<bool, AuthError> {
+        // For each scope provided in the identity,
+        // check if there is a permission which allows action on instance
+        let is_allowed = identity.scopes().iter().any(|scope| {
+            self.0
+                .get(scope)
+                .and_then(|objects| objects.get(&object_type))
+                .map(|permissions| {
+                    permissions
+                        .iter()
+                        .any(|permission| permission.allows(&action, instance))
+                })
+                .unwrap_or(false)
+        });
+
+        Ok(is_allowed)
+    }
+
+    /// all rules which apply to scopes, sorted by role and object type
+    pub fn rules(&self, scopes: &[String]) -> Vec<PolicyRule> {
+        let mut rules: Vec<PolicyRule> = scopes
+            .iter()
+            .filter_map(|scope| self.0.get(scope).map(|objects| (scope, objects)))
+            .flat_map(|(scope, objects)| {
+                objects.iter().flat_map(move |(object_type, permissions)| {
+                    permissions.iter().map(move |permission| PolicyRule {
+                        role: scope.clone(),
+                        object_type: object_type.clone(),
+                        permission: permission.clone(),
+                    })
+                })
+            })
+            .collect();
+
+        rules.sort_by(|a, b| {
+            (&a.role, format!("{:?}", a.object_type))
+                .cmp(&(&b.role, format!("{:?}", b.object_type)))
+        });
+        rules
+    }
+}
+
+impl Default for BasicRbacPolicy {
+    // default only allows the `Root` role to have full permissions;
+    fn default() -> Self {
+        let mut root_policy = HashMap::new();
+
+        root_policy.insert(ObjectType::Spu, vec![Action::All.into()]);
+        root_policy.insert(ObjectType::CustomSpu, vec![Action::All.into()]);
+        root_policy.insert(ObjectType::SpuGroup, vec![Action::All.into()]);
+        root_policy.insert(ObjectType::Topic, vec![Action::All.into()]);
+        root_policy.insert(ObjectType::Partition, vec![Action::All.into()]);
+        root_policy.insert(ObjectType::Quota, vec![Action::All.into()]);
+        root_policy.insert(ObjectType::Schema, vec![Action::All.into()]);
+        root_policy.insert(ObjectType::Transaction, vec![Action::All.into()]);
+
+        let mut policy = HashMap::new();
+
+        policy.insert(String::from("Root"), root_policy);
+
+        Self(policy)
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    use super::*;
+
+    #[test]
+    fn test_glob_match() {
+        assert!(glob_match("team-a-*", "team-a-orders"));
+        assert!(glob_match("team-a-*", "team-a-"));
+        assert!(!glob_match("team-a-*", "team-b-orders"));
+        assert!(glob_match("team-?-*", "team-b-orders"));
+        assert!(glob_match("*-orders", "team-a-orders"));
+        assert!(glob_match("*a*s", "team-a-orders"));
+        assert!(!glob_match("*a*x", "team-a-orders"));
+        assert!(glob_match("orders", "orders"));
+        assert!(!glob_match("orders", "orders-1"));
+        assert!(glob_match("*", ""));
+    }
+
+    #[test]
+    fn test_permission_deserialize() {
+        let permissions: Vec<Permission> = serde_json::from_str(
+            r#"["Read", {"action": "Delete", "resource": {"prefix": "team-a-"}}]"#,
+        )
+        .expect("parse");
+        assert_eq!(
+            permissions,
+            vec![
+                Permission::Action(Action::Read),
+                Permission::Resource {
+                    action: Action::Delete,
+                    resource: ResourcePattern::Prefix("team-a-".to_owned())
+                }
+            ]
+        );
+    }
+}
@@ -6,12 +6,17 @@ use thiserror::Error;
 pub enum AuthError {
     #[error("IoError")]
     IoError(#[from] IoError),
+    #[error("authentication failed: {0}")]
+    AuthenticationFailed(String),
 }
 
 impl From<AuthError> for IoError {
     fn from(e: AuthError) -> Self {
         match e {
             AuthError::IoError(source) => source,
+            AuthError::AuthenticationFailed(msg) => {
+                IoError::new(std::io::ErrorKind::PermissionDenied, msg)
+            }
         }
     }
 }
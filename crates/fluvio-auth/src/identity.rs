@@ -0,0 +1,8 @@
+use std::fmt::Debug;
+
+/// Authenticated principal and authorization scopes bound to it
+pub trait Identity: Debug + Send + Sync {
+    fn principal(&self) -> &str;
+
+    fn scopes(&self) -> &[String];
+}
@@ -1,7 +1,11 @@
 mod policy;
 mod error;
+mod identity;
 
 pub mod x509;
+pub mod basic;
+pub mod sasl;
 
 pub use policy::*;
 pub use error::AuthError;
+pub use identity::Identity;
@@ -14,7 +14,12 @@ pub enum TypeAction {
     Read,
 }
 
+#[derive(Debug, Clone, PartialEq)]
 pub enum InstanceAction {
+    Create,
+    Read,
+    Write,
+    Update,
     Delete,
 }
 
@@ -34,6 +39,11 @@ pub trait AuthContext {
         action: InstanceAction,
         key: &str,
     ) -> Result<bool, AuthError>;
+
+    /// principal of client, None if client is not authenticated
+    fn principal(&self) -> Option<&str> {
+        None
+    }
 }
 
 #[async_trait]
@@ -0,0 +1,399 @@
+//!
+//! # SASL Credentials
+//!
+//! Salted credentials of users for PLAIN and SCRAM-SHA-256 mechanisms.
+//! Passwords are never stored, only keys derived from them as described in RFC 5802.
+//!
+use std::fs::read;
+use std::collections::HashMap;
+use std::path::PathBuf;
+use std::convert::TryFrom;
+use std::io::{Error as IoError, ErrorKind};
+
+use once_cell::sync::Lazy;
+use tracing::debug;
+use serde::{Serialize, Deserialize};
+use rand::Rng;
+
+use dataplane::sasl::scram::{self, CLIENT_KEY, SERVER_KEY, MIN_ITERATIONS};
+
+use super::SaslIdentity;
+
+const NONCE_LEN: usize = 18;
+const INVALID_CREDENTIALS: &str = "invalid username or password";
+
+/// key for salts of unknown users, so they can't be told apart from existing users
+static MOCK_SALT_KEY: Lazy<[u8; 32]> = Lazy::new(|| rand::thread_rng().gen());
+
+/// Credential of user, keys are base64 encoded
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ScramCredential {
+    #[serde(default)]
+    pub scopes: Vec<String>,
+    pub salt: String,
+    pub iterations: u32,
+    pub stored_key: String,
+    pub server_key: String,
+}
+
+impl ScramCredential {
+    /// derive credential from password with random salt
+    pub fn generate(password: &str, scopes: Vec<String>) -> Self {
+        let salt: [u8; 16] = rand::thread_rng().gen();
+        Self::new(password, &salt, MIN_ITERATIONS, scopes)
+    }
+
+    pub fn new(password: &str, salt: &[u8], iterations: u32, scopes: Vec<String>) -> Self {
+        let salted_password = scram::hi(password.as_bytes(), salt, iterations);
+        let client_key = scram::hmac(&salted_password, CLIENT_KEY);
+        let server_key = scram::hmac(&salted_password, SERVER_KEY);
+
+        Self {
+            scopes,
+            salt: base64::encode(salt),
+            iterations,
+            stored_key: base64::encode(scram::h(&client_key)),
+            server_key: base64::encode(server_key),
+        }
+    }
+
+    /// credential for unknown user, salt is stable for username but no proof matches it
+    fn mock(username: &str) -> Self {
+        let salt = scram::hmac(&*MOCK_SALT_KEY, username.as_bytes());
+        let stored_key: [u8; 32] = rand::thread_rng().gen();
+        let server_key: [u8; 32] = rand::thread_rng().gen();
+        Self {
+            scopes: vec![],
+            salt: base64::encode(&salt[..16]),
+            iterations: MIN_ITERATIONS,
+            stored_key: base64::encode(stored_key),
+            server_key: base64::encode(server_key),
+        }
+    }
+
+    fn decode(value: &str) -> Result<Vec<u8>, String> {
+        base64::decode(value).map_err(|err| format!("invalid credential: {}", err))
+    }
+
+    fn verify_password(&self, password: &str) -> Result<bool, String> {
+        let salted_password = scram::hi(
+            password.as_bytes(),
+            &Self::decode(&self.salt)?,
+            self.iterations,
+        );
+        let client_key = scram::hmac(&salted_password, CLIENT_KEY);
+        Ok(scram::constant_time_eq(
+            &scram::h(&client_key),
+            &Self::decode(&self.stored_key)?,
+        ))
+    }
+}
+
+/// credentials by username
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct SaslCredentials(pub HashMap<String, ScramCredential>);
+
+impl TryFrom<PathBuf> for SaslCredentials {
+    type Error = std::io::Error;
+    fn try_from(path: PathBuf) -> Result<Self, Self::Error> {
+        debug!("reading sasl credentials: {:#?}", path);
+        let file = read(path)?;
+        let credentials: SaslCredentials = serde_json::from_slice(&file)?;
+        for (username, credential) in &credentials.0 {
+            scram::validate_iterations(credential.iterations).map_err(|msg| {
+                IoError::new(
+                    ErrorKind::InvalidData,
+                    format!("credential of {}: {}", username, msg),
+                )
+            })?;
+        }
+        Ok(credentials)
+    }
+}
+
+impl SaslCredentials {
+    fn get(&self, username: &str) -> Result<&ScramCredential, String> {
+        self.0
+            .get(username)
+            .ok_or_else(|| INVALID_CREDENTIALS.to_owned())
+    }
+
+    /// authenticate PLAIN message: `[authzid] NUL authcid NUL passwd`
+    pub(crate) fn authenticate_plain(&self, auth_bytes: &[u8]) -> Result<SaslIdentity, String> {
+        let message = std::str::from_utf8(auth_bytes).map_err(|_| "invalid plain message")?;
+        let mut parts = message.split('\0');
+        let (authzid, username, password) = match (parts.next(), parts.next(), parts.next()) {
+            (Some(authzid), Some(username), Some(password)) if parts.next().is_none() => {
+                (authzid, username, password)
+            }
+            _ => return Err("invalid plain message".to_owned()),
+        };
+
+        if !authzid.is_empty() && authzid != username {
+            return Err("authorization identity must match username".to_owned());
+        }
+
+        let credential = self.get(username)?;
+        if credential.verify_password(password)? {
+            Ok(SaslIdentity::new(username, credential.scopes.clone()))
+        } else {
+            Err(INVALID_CREDENTIALS.to_owned())
+        }
+    }
+}
+
+/// Server side of SCRAM-SHA-256 exchange, created from client first message
+#[derive(Debug)]
+pub(crate) struct ScramExchange {
+    username: String,
+    credential: ScramCredential,
+    nonce: String,
+    client_first_bare: String,
+    server_first: String,
+}
+
+impl ScramExchange {
+    /// process client first message and return server first message
+    pub(crate) fn start(
+        credentials: &SaslCredentials,
+        auth_bytes: &[u8],
+    ) -> Result<(Self, Vec<u8>), String> {
+        let message = std::str::from_utf8(auth_bytes).map_err(|_| "invalid scram message")?;
+
+        // gs2 header, channel binding is not supported
+        let client_first_bare = if let Some(bare) = message.strip_prefix("n,,") {
+            bare
+        } else if let Some(bare) = message.strip_prefix("y,,") {
+            bare
+        } else {
+            return Err("unsupported scram gs2 header".to_owned());
+        };
+
+        let mut username = None;
+        let mut client_nonce = None;
+        for attribute in client_first_bare.split(',') {
+            if let Some(value) = attribute.strip_prefix("n=") {
+                username = Some(value.replace("=2C", ",").replace("=3D", "="));
+            } else if let Some(value) = attribute.strip_prefix("r=") {
+                client_nonce = Some(value);
+            }
+        }
+
+        let username = username.ok_or("missing scram username")?;
+        let client_nonce = client_nonce.ok_or("missing scram nonce")?;
+        // unknown user gets challenge as well and fails at proof
+        let credential = match credentials.get(&username) {
+            Ok(credential) => credential.clone(),
+            Err(_) => ScramCredential::mock(&username),
+        };
+
+        let server_nonce: [u8; NONCE_LEN] = rand::thread_rng().gen();
+        let nonce = format!("{}{}", client_nonce, base64::encode(server_nonce));
+        let server_first = format!(
+            "r={},s={},i={}",
+            nonce, credential.salt, credential.iterations
+        );
+
+        let exchange = Self {
+            username,
+            credential,
+            nonce,
+            client_first_bare: client_first_bare.to_owned(),
+            server_first: server_first.clone(),
+        };
+
+        Ok((exchange, server_first.into_bytes()))
+    }
+
+    /// verify client proof and return server final message
+    pub(crate) fn finish(self, auth_bytes: &[u8]) -> Result<(SaslIdentity, Vec<u8>), String> {
+        let message = std::str::from_utf8(auth_bytes).map_err(|_| "invalid scram message")?;
+
+        let (without_proof, proof) = message
+            .rsplit_once(",p=")
+            .ok_or("missing scram client proof")?;
+
+        let nonce = without_proof
+            .split(',')
+            .find_map(|attribute| attribute.strip_prefix("r="))
+            .ok_or("missing scram nonce")?;
+        if nonce != self.nonce {
+            return Err("scram nonce mismatch".to_owned());
+        }
+
+        let auth_message = format!(
+            "{},{},{}",
+            self.client_first_bare, self.server_first, without_proof
+        );
+
+        let stored_key = ScramCredential::decode(&self.credential.stored_key)?;
+        let server_key = ScramCredential::decode(&self.credential.server_key)?;
+        let proof = base64::decode(proof).map_err(|_| "invalid scram client proof")?;
+
+        let client_signature = scram::hmac(&stored_key, auth_message.as_bytes());
+        if proof.len() != client_signature.len() {
+            return Err(INVALID_CREDENTIALS.to_owned());
+        }
+        let client_key = scram::xor(&proof, &client_signature);
+
+        if !scram::constant_time_eq(&scram::h(&client_key), &stored_key) {
+            return Err(INVALID_CREDENTIALS.to_owned());
+        }
+
+        let server_signature = scram::hmac(&server_key, auth_message.as_bytes());
+        let server_final = format!("v={}", base64::encode(server_signature));
+
+        Ok((
+            SaslIdentity::new(self.username, self.credential.scopes),
+            server_final.into_bytes(),
+        ))
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod test {
+
+    use super::*;
+
+    pub(crate) fn credentials() -> SaslCredentials {
+        let mut credentials = SaslCredentials::default();
+        credentials.0.insert(
+            "ci".to_owned(),
+            ScramCredential::new("secret", b"salt", 4096, vec!["Default".to_owned()]),
+        );
+        credentials
+    }
+
+    #[test]
+    fn test_plain() {
+        let credentials = credentials();
+
+        let identity = credentials
+            .authenticate_plain(b"\0ci\0secret")
+            .expect("authenticate");
+        assert_eq!(identity.principal, "ci");
+        assert_eq!(identity.scopes, vec!["Default".to_owned()]);
+
+        assert!(credentials.authenticate_plain(b"ci\0ci\0secret").is_ok());
+        assert!(credentials.authenticate_plain(b"\0ci\0wrong").is_err());
+        assert!(credentials
+            .authenticate_plain(b"\0unknown\0secret")
+            .is_err());
+        assert!(credentials
+            .authenticate_plain(b"other\0ci\0secret")
+            .is_err());
+        assert!(credentials.authenticate_plain(b"ci:secret").is_err());
+    }
+
+    /// client side of exchange
+    pub(crate) fn client_final(
+        password: &str,
+        client_first_bare: &str,
+        server_first: &str,
+    ) -> String {
+        let mut salt = None;
+        let mut iterations = None;
+        let mut nonce = None;
+        for attribute in server_first.split(',') {
+            if let Some(value) = attribute.strip_prefix("s=") {
+                salt = Some(base64::decode(value).unwrap());
+            } else if let Some(value) = attribute.strip_prefix("i=") {
+                iterations = Some(value.parse().unwrap());
+            } else if let Some(value) = attribute.strip_prefix("r=") {
+                nonce = Some(value.to_owned());
+            }
+        }
+
+        let salted_password = scram::hi(password.as_bytes(), &salt.unwrap(), iterations.unwrap());
+        let client_key = scram::hmac(&salted_password, CLIENT_KEY);
+        let stored_key = scram::h(&client_key);
+        let without_proof = format!("c=biws,r={}", nonce.unwrap());
+        let auth_message = format!("{},{},{}", client_first_bare, server_first, without_proof);
+        let client_signature = scram::hmac(&stored_key, auth_message.as_bytes());
+        let proof = scram::xor(&client_key, &client_signature);
+
+        format!("{},p={}", without_proof, base64::encode(proof))
+    }
+
+    #[test]
+    fn test_scram_exchange() {
+        let credentials = credentials();
+        let client_first_bare = "n=ci,r=rOprNGfwEbeRWgbNEkqO";
+
+        let (exchange, server_first) =
+            ScramExchange::start(&credentials, format!("n,,{}", client_first_bare).as_bytes())
+                .expect("start");
+        let server_first = String::from_utf8(server_first).unwrap();
+        assert!(server_first.starts_with("r=rOprNGfwEbeRWgbNEkqO"));
+
+        let client_final = client_final("secret", client_first_bare, &server_first);
+        let (identity, server_final) = exchange.finish(client_final.as_bytes()).expect("finish");
+        assert_eq!(identity.principal, "ci");
+        assert!(String::from_utf8(server_final).unwrap().starts_with("v="));
+
+        // wrong password
+        let (exchange, server_first) =
+            ScramExchange::start(&credentials, format!("n,,{}", client_first_bare).as_bytes())
+                .expect("start");
+        let server_first = String::from_utf8(server_first).unwrap();
+        let client_final = client_final("wrong", client_first_bare, &server_first);
+        assert!(exchange.finish(client_final.as_bytes()).is_err());
+    }
+
+    fn salt(server_first: &[u8]) -> String {
+        std::str::from_utf8(server_first)
+            .unwrap()
+            .split(',')
+            .find_map(|attribute| attribute.strip_prefix("s="))
+            .unwrap()
+            .to_owned()
+    }
+
+    #[test]
+    fn test_scram_unknown_user() {
+        let credentials = credentials();
+        let client_first_bare = "n=unknown,r=rOprNGfwEbeRWgbNEkqO";
+        let client_first = format!("n,,{}", client_first_bare);
+
+        // unknown user gets challenge with same salt on every attempt
+        let (exchange, server_first) =
+            ScramExchange::start(&credentials, client_first.as_bytes()).expect("start");
+        let (_, other_server_first) =
+            ScramExchange::start(&credentials, client_first.as_bytes()).expect("start");
+        assert_eq!(salt(&server_first), salt(&other_server_first));
+        assert_ne!(
+            salt(&server_first),
+            salt(
+                &ScramExchange::start(&credentials, b"n,,n=other,r=abc")
+                    .unwrap()
+                    .1
+            )
+        );
+
+        let server_first = String::from_utf8(server_first).unwrap();
+        assert!(server_first.ends_with(&format!(",i={}", MIN_ITERATIONS)));
+        let client_final = client_final("secret", client_first_bare, &server_first);
+        assert_eq!(
+            exchange.finish(client_final.as_bytes()).unwrap_err(),
+            INVALID_CREDENTIALS
+        );
+    }
+
+    #[test]
+    fn test_load_rejects_weak_iterations() {
+        let mut credentials = credentials();
+        credentials.0.insert(
+            "weak".to_owned(),
+            ScramCredential::new("secret", b"salt", 1, vec![]),
+        );
+
+        let path = std::env::temp_dir().join("fluvio-sasl-weak-credentials.json");
+        std::fs::write(&path, serde_json::to_vec(&credentials).unwrap()).unwrap();
+        let err = SaslCredentials::try_from(path.clone()).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::InvalidData);
+
+        credentials.0.remove("weak");
+        std::fs::write(&path, serde_json::to_vec(&credentials).unwrap()).unwrap();
+        assert_eq!(SaslCredentials::try_from(path).unwrap(), credentials);
+    }
+}
@@ -0,0 +1,361 @@
+//!
+//! # SASL Authentication
+//!
+//! Authenticate client with SASL handshake as alternative to X509 client certificate.
+//! Supports PLAIN and SCRAM-SHA-256 with credentials file and OAUTHBEARER with JWT.
+//!
+mod credentials;
+mod token;
+
+pub use credentials::*;
+pub use token::*;
+
+use std::io::{Error as IoError, ErrorKind};
+use std::path::Path;
+use std::convert::TryFrom;
+
+use futures_util::stream::StreamExt;
+use tracing::{debug, instrument};
+
+use dataplane::api::RequestMessage;
+use dataplane::sasl::{
+    SaslAuthenticateRequest, SaslAuthenticateResponse, PLAIN_MECHANISM, SCRAM_SHA_256_MECHANISM,
+    OAUTHBEARER_MECHANISM,
+};
+use fluvio_socket::{FluvioSocket, SocketError};
+
+use crate::{AuthError, Identity};
+use crate::x509::AuthorizationApiRequest;
+
+/// Identity established by SASL handshake
+#[derive(Debug, Clone, PartialEq)]
+pub struct SaslIdentity {
+    pub principal: String,
+    pub scopes: Vec<String>,
+}
+
+impl SaslIdentity {
+    pub fn new<S: Into<String>>(principal: S, scopes: Vec<String>) -> Self {
+        Self {
+            principal: principal.into(),
+            scopes,
+        }
+    }
+}
+
+impl Identity for SaslIdentity {
+    fn principal(&self) -> &str {
+        &self.principal
+    }
+
+    fn scopes(&self) -> &[String] {
+        &self.scopes
+    }
+}
+
+enum Step {
+    Continue(Vec<u8>),
+    Done(SaslIdentity, Vec<u8>),
+}
+
+/// default algorithm of bearer token
+pub const DEFAULT_TOKEN_ALGORITHM: &str = "RS256";
+
+/// Server side of SASL handshake
+#[derive(Debug, Default)]
+pub struct SaslAuthenticator {
+    credentials: Option<SaslCredentials>,
+    token_verifier: Option<TokenVerifier>,
+}
+
+impl SaslAuthenticator {
+    /// load credentials file and token public key, if any of them is specified.
+    /// if token audience or issuer is specified, token claims must match it
+    pub fn load(
+        credentials: Option<&Path>,
+        token_public_key: Option<&Path>,
+        token_algorithm: &str,
+        token_audience: Option<&str>,
+        token_issuer: Option<&str>,
+    ) -> Result<Option<Self>, IoError> {
+        let mut authenticator = Self::default();
+        if let Some(path) = credentials {
+            authenticator =
+                authenticator.with_credentials(SaslCredentials::try_from(path.to_owned())?);
+        }
+        if let Some(path) = token_public_key {
+            let verifier = TokenVerifier::load(path, token_algorithm)?
+                .with_audience(token_audience.map(|audience| audience.to_owned()))
+                .with_issuer(token_issuer.map(|issuer| issuer.to_owned()));
+            authenticator = authenticator.with_token_verifier(verifier);
+        }
+
+        if authenticator.is_enabled() {
+            Ok(Some(authenticator))
+        } else {
+            Ok(None)
+        }
+    }
+
+    /// enable PLAIN and SCRAM-SHA-256
+    pub fn with_credentials(mut self, credentials: SaslCredentials) -> Self {
+        self.credentials = Some(credentials);
+        self
+    }
+
+    /// enable OAUTHBEARER
+    pub fn with_token_verifier(mut self, token_verifier: TokenVerifier) -> Self {
+        self.token_verifier = Some(token_verifier);
+        self
+    }
+
+    pub fn is_enabled(&self) -> bool {
+        self.credentials.is_some() || self.token_verifier.is_some()
+    }
+
+    /// perform handshake with client, it must be first exchange of connection
+    #[instrument(level = "trace", skip(self, socket))]
+    pub async fn authenticate(&self, socket: &mut FluvioSocket) -> Result<SaslIdentity, AuthError> {
+        let mut scram: Option<ScramExchange> = None;
+
+        loop {
+            let req_msg = next_request(socket).await?;
+            let (header, request) = req_msg.get_header_request();
+            let mechanism = request.mechanism.clone();
+
+            let (response, outcome) = match self.step(&mut scram, request) {
+                Ok(Step::Continue(auth_bytes)) => {
+                    (SaslAuthenticateResponse::challenge(auth_bytes), None)
+                }
+                Ok(Step::Done(identity, auth_bytes)) => {
+                    debug!(principal = %identity.principal, %mechanism, "authenticated");
+                    (
+                        SaslAuthenticateResponse::success(auth_bytes),
+                        Some(Ok(identity)),
+                    )
+                }
+                Err(msg) => {
+                    debug!(%mechanism, %msg, "authentication failed");
+                    (
+                        SaslAuthenticateResponse::failure(msg.clone()),
+                        Some(Err(AuthError::AuthenticationFailed(msg))),
+                    )
+                }
+            };
+
+            let response_msg =
+                RequestMessage::<SaslAuthenticateRequest>::response_with_header(&header, response);
+            socket
+                .get_mut_sink()
+                .send_response(&response_msg, header.api_version())
+                .await
+                .map_err(|err| match err {
+                    SocketError::Io(source) => source,
+                    SocketError::SocketClosed => {
+                        IoError::new(ErrorKind::BrokenPipe, "connection closed")
+                    }
+                })?;
+
+            if let Some(outcome) = outcome {
+                return outcome;
+            }
+        }
+    }
+
+    fn step(
+        &self,
+        scram: &mut Option<ScramExchange>,
+        request: SaslAuthenticateRequest,
+    ) -> Result<Step, String> {
+        match request.mechanism.as_str() {
+            PLAIN_MECHANISM => {
+                let identity = self
+                    .credentials()?
+                    .authenticate_plain(&request.auth_bytes)?;
+                Ok(Step::Done(identity, vec![]))
+            }
+            SCRAM_SHA_256_MECHANISM => match scram.take() {
+                None => {
+                    let (exchange, server_first) =
+                        ScramExchange::start(self.credentials()?, &request.auth_bytes)?;
+                    *scram = Some(exchange);
+                    Ok(Step::Continue(server_first))
+                }
+                Some(exchange) => {
+                    let (identity, server_final) = exchange.finish(&request.auth_bytes)?;
+                    Ok(Step::Done(identity, server_final))
+                }
+            },
+            OAUTHBEARER_MECHANISM => {
+                let identity = self
+                    .token_verifier
+                    .as_ref()
+                    .ok_or_else(|| mechanism_disabled(OAUTHBEARER_MECHANISM))?
+                    .authenticate_oauthbearer(&request.auth_bytes)?;
+                Ok(Step::Done(identity, vec![]))
+            }
+            other => Err(format!("unsupported mechanism: {}", other)),
+        }
+    }
+
+    fn credentials(&self) -> Result<&SaslCredentials, String> {
+        self.credentials
+            .as_ref()
+            .ok_or_else(|| mechanism_disabled("PLAIN and SCRAM"))
+    }
+}
+
+fn mechanism_disabled(mechanism: &str) -> String {
+    format!("{} authentication is not enabled", mechanism)
+}
+
+async fn next_request(
+    socket: &mut FluvioSocket,
+) -> Result<RequestMessage<SaslAuthenticateRequest>, AuthError> {
+    let stream = socket.get_mut_stream();
+    let mut api_stream = stream.api_stream::<AuthorizationApiRequest, _>();
+
+    match api_stream.next().await {
+        Some(Ok(AuthorizationApiRequest::SaslAuthenticateRequest(req_msg))) => Ok(req_msg),
+        Some(Ok(_)) => Err(AuthError::AuthenticationFailed(
+            "expected sasl authenticate request".to_owned(),
+        )),
+        _ => {
+            tracing::trace!("client connect terminated");
+            Err(IoError::new(ErrorKind::Interrupted, "connection closed").into())
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    use std::time::Duration;
+
+    use futures_util::future::join;
+    use futures_util::stream::StreamExt;
+
+    use fluvio_future::net::TcpListener;
+    use fluvio_future::timer::sleep;
+    use dataplane::ErrorCode;
+
+    use super::*;
+    use super::credentials::test::{credentials, client_final};
+
+    async fn server(
+        addr: &str,
+        authenticator: &SaslAuthenticator,
+    ) -> Result<SaslIdentity, AuthError> {
+        let listener = TcpListener::bind(addr).await.expect("bind");
+        let mut incoming = listener.incoming();
+        let stream = incoming.next().await.expect("next").expect("stream");
+        let mut socket: FluvioSocket = stream.into();
+        authenticator.authenticate(&mut socket).await
+    }
+
+    async fn connect(addr: &str) -> FluvioSocket {
+        sleep(Duration::from_millis(50)).await;
+        FluvioSocket::connect(addr).await.expect("connect")
+    }
+
+    async fn send(
+        socket: &mut FluvioSocket,
+        mechanism: &str,
+        auth_bytes: Vec<u8>,
+    ) -> SaslAuthenticateResponse {
+        let req_msg =
+            RequestMessage::new_request(SaslAuthenticateRequest::new(mechanism, auth_bytes));
+        socket.send(&req_msg).await.expect("send").response
+    }
+
+    async fn plain_client(addr: &str, password: &str) -> SaslAuthenticateResponse {
+        let mut socket = connect(addr).await;
+        let message = format!("\0ci\0{}", password);
+        send(&mut socket, PLAIN_MECHANISM, message.into_bytes()).await
+    }
+
+    async fn scram_client(addr: &str, password: &str) -> SaslAuthenticateResponse {
+        let mut socket = connect(addr).await;
+        let client_first_bare = "n=ci,r=rOprNGfwEbeRWgbNEkqO";
+        let response = send(
+            &mut socket,
+            SCRAM_SHA_256_MECHANISM,
+            format!("n,,{}", client_first_bare).into_bytes(),
+        )
+        .await;
+        assert_eq!(response.error_code, ErrorCode::None);
+        assert!(!response.complete);
+
+        let server_first = String::from_utf8(response.auth_bytes).expect("utf8");
+        let client_final = client_final(password, client_first_bare, &server_first);
+        send(
+            &mut socket,
+            SCRAM_SHA_256_MECHANISM,
+            client_final.into_bytes(),
+        )
+        .await
+    }
+
+    fn authenticator() -> SaslAuthenticator {
+        SaslAuthenticator::default().with_credentials(credentials())
+    }
+
+    #[fluvio_future::test]
+    async fn test_authenticate_plain() {
+        let authenticator = authenticator();
+
+        let addr = "127.0.0.1:9101";
+        let (identity, response) =
+            join(server(addr, &authenticator), plain_client(addr, "secret")).await;
+        assert_eq!(identity.expect("identity").principal, "ci");
+        assert_eq!(response.error_code, ErrorCode::None);
+        assert!(response.complete);
+
+        let addr = "127.0.0.1:9102";
+        let (identity, response) =
+            join(server(addr, &authenticator), plain_client(addr, "wrong")).await;
+        assert!(matches!(identity, Err(AuthError::AuthenticationFailed(_))));
+        assert_eq!(response.error_code, ErrorCode::SaslAuthenticationFailed);
+        assert!(response.complete);
+    }
+
+    #[fluvio_future::test]
+    async fn test_authenticate_scram() {
+        let authenticator = authenticator();
+
+        let addr = "127.0.0.1:9103";
+        let (identity, response) =
+            join(server(addr, &authenticator), scram_client(addr, "secret")).await;
+        assert_eq!(identity.expect("identity").principal, "ci");
+        assert_eq!(response.error_code, ErrorCode::None);
+        assert!(response.complete);
+        // server proves it knows credential
+        assert!(response.auth_bytes.starts_with(b"v="));
+
+        let addr = "127.0.0.1:9104";
+        let (identity, response) =
+            join(server(addr, &authenticator), scram_client(addr, "wrong")).await;
+        assert!(matches!(identity, Err(AuthError::AuthenticationFailed(_))));
+        assert_eq!(response.error_code, ErrorCode::SaslAuthenticationFailed);
+        assert!(response.auth_bytes.is_empty());
+    }
+
+    #[fluvio_future::test]
+    async fn test_authenticate_disabled_mechanism() {
+        let authenticator = authenticator();
+
+        let addr = "127.0.0.1:9105";
+        let client = async {
+            let mut socket = connect(addr).await;
+            send(
+                &mut socket,
+                OAUTHBEARER_MECHANISM,
+                b"n,,\x01auth=Bearer abc\x01\x01".to_vec(),
+            )
+            .await
+        };
+        let (identity, response) = join(server(addr, &authenticator), client).await;
+        assert!(matches!(identity, Err(AuthError::AuthenticationFailed(_))));
+        assert_eq!(response.error_code, ErrorCode::SaslAuthenticationFailed);
+    }
+}
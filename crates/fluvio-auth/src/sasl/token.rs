@@ -0,0 +1,154 @@
+//!
+//! # Bearer Token
+//!
+//! JWT verified with local public key. Principal is taken from `sub` claim
+//! and authorization scopes from `scopes` claim. Token must have expiration.
+//! If audience or issuer is configured, `aud` or `iss` claim must match it.
+//!
+use std::fs::read;
+use std::path::Path;
+use std::io::{Error as IoError, ErrorKind};
+
+use serde::Deserialize;
+use jsonwebtoken::{decode, Algorithm, DecodingKey, Validation};
+
+use super::SaslIdentity;
+
+#[derive(Debug, Deserialize)]
+struct TokenClaims {
+    sub: String,
+    #[serde(default)]
+    scopes: Vec<String>,
+}
+
+#[derive(Debug)]
+pub struct TokenVerifier {
+    public_key: Vec<u8>,
+    algorithm: Algorithm,
+    audience: Option<String>,
+    issuer: Option<String>,
+}
+
+impl TokenVerifier {
+    /// load PEM encoded public key, algorithm is one of RS256, RS384, RS512, ES256 or ES384
+    pub fn load(public_key_path: &Path, algorithm: &str) -> Result<Self, IoError> {
+        let algorithm: Algorithm = algorithm
+            .parse()
+            .map_err(|_| IoError::new(ErrorKind::InvalidInput, "invalid token algorithm"))?;
+        let public_key = read(public_key_path)?;
+        let verifier = Self {
+            public_key,
+            algorithm,
+            audience: None,
+            issuer: None,
+        };
+        // validate key
+        verifier.decoding_key()?;
+        Ok(verifier)
+    }
+
+    /// token must have `aud` claim which contains audience
+    pub fn with_audience(mut self, audience: Option<String>) -> Self {
+        self.audience = audience;
+        self
+    }
+
+    /// token must have `iss` claim which is issuer
+    pub fn with_issuer(mut self, issuer: Option<String>) -> Self {
+        self.issuer = issuer;
+        self
+    }
+
+    fn validation(&self) -> Validation {
+        let mut validation = Validation::new(self.algorithm);
+        if let Some(audience) = &self.audience {
+            validation.set_audience(&[audience]);
+        }
+        validation.iss = self.issuer.clone();
+        validation
+    }
+
+    fn decoding_key(&self) -> Result<DecodingKey, IoError> {
+        match self.algorithm {
+            Algorithm::RS256 | Algorithm::RS384 | Algorithm::RS512 => {
+                DecodingKey::from_rsa_pem(&self.public_key)
+            }
+            Algorithm::ES256 | Algorithm::ES384 => DecodingKey::from_ec_pem(&self.public_key),
+            _ => {
+                return Err(IoError::new(
+                    ErrorKind::InvalidInput,
+                    "token algorithm must use public key",
+                ))
+            }
+        }
+        .map_err(|err| IoError::new(ErrorKind::InvalidData, err.to_string()))
+    }
+
+    pub(crate) fn verify(&self, token: &str) -> Result<SaslIdentity, String> {
+        let key = self.decoding_key().map_err(|err| err.to_string())?;
+        let data = decode::<TokenClaims>(token, &key, &self.validation())
+            .map_err(|err| format!("invalid token: {}", err))?;
+        Ok(SaslIdentity::new(data.claims.sub, data.claims.scopes))
+    }
+
+    /// authenticate OAUTHBEARER client initial response: `gs2-header ^A auth=Bearer <token> ^A^A`
+    pub(crate) fn authenticate_oauthbearer(
+        &self,
+        auth_bytes: &[u8],
+    ) -> Result<SaslIdentity, String> {
+        let token = bearer_token(auth_bytes)?;
+        self.verify(token)
+    }
+}
+
+fn bearer_token(auth_bytes: &[u8]) -> Result<&str, String> {
+    let message = std::str::from_utf8(auth_bytes).map_err(|_| "invalid oauthbearer message")?;
+    message
+        .split('\u{1}')
+        .find_map(|kv| kv.strip_prefix("auth="))
+        .and_then(|auth| {
+            auth.strip_prefix("Bearer ")
+                .or_else(|| auth.strip_prefix("bearer "))
+        })
+        .map(|token| token.trim())
+        .filter(|token| !token.is_empty())
+        .ok_or_else(|| "missing bearer token".to_owned())
+}
+
+#[cfg(test)]
+mod test {
+
+    use jsonwebtoken::Algorithm;
+
+    use super::{bearer_token, TokenVerifier};
+
+    #[test]
+    fn test_bearer_token() {
+        assert_eq!(
+            bearer_token(b"n,,\x01auth=Bearer abc.def.ghi\x01\x01").expect("token"),
+            "abc.def.ghi"
+        );
+        assert!(bearer_token(b"n,,\x01auth=Basic abc\x01\x01").is_err());
+        assert!(bearer_token(b"n,,\x01\x01").is_err());
+    }
+
+    #[test]
+    fn test_token_validation() {
+        let verifier = TokenVerifier {
+            public_key: vec![],
+            algorithm: Algorithm::RS256,
+            audience: None,
+            issuer: None,
+        };
+        let validation = verifier.validation();
+        assert!(validation.aud.is_none());
+        assert!(validation.iss.is_none());
+
+        let validation = verifier
+            .with_audience(Some("fluvio".to_owned()))
+            .with_issuer(Some("https://auth.example.com".to_owned()))
+            .validation();
+        assert!(validation.aud.expect("aud").contains("fluvio"));
+        assert_eq!(validation.iss.as_deref(), Some("https://auth.example.com"));
+    }
+}
@@ -47,6 +47,13 @@ impl X509Authenticator {
         }
     }
 
+    /// forward only principal of certificate, used to authenticate cluster peers
+    pub fn identity_only() -> Self {
+        Self {
+            scope_bindings: ScopeBindings(HashMap::new()),
+        }
+    }
+
     async fn send_authorization_request(
         tcp_stream: &TcpStream,
         authorization_request: AuthRequest,
@@ -95,7 +102,8 @@ impl X509Authenticator {
         Ok(principal)
     }
 
-    fn principal_from_raw_certificate(certificate_bytes: &[u8]) -> Result<String, IoError> {
+    /// common name of DER encoded certificate
+    pub fn principal_from_raw_certificate(certificate_bytes: &[u8]) -> Result<String, IoError> {
         parse_x509_certificate(certificate_bytes)
             .map_err(|err| IoError::new(IoErrorKind::InvalidData, err))
             .and_then(|(_, parsed_cert)| Self::common_name_from_parsed_certificate(parsed_cert))
@@ -1,10 +1,15 @@
+use std::io::{Error as IoError, ErrorKind};
+use std::net::{IpAddr, SocketAddr};
+
 use serde::{Serialize, Deserialize};
 
 use futures_util::stream::StreamExt;
 
 use fluvio_protocol::api::{ResponseMessage};
+use fluvio_types::SpuId;
 use fluvio_socket::FluvioSocket;
 
+use crate::Identity;
 use super::request::{AuthorizationScopes, AuthorizationApiRequest, AuthResponse};
 
 #[derive(Debug, Default, Clone, Deserialize, Serialize)]
@@ -22,6 +27,11 @@ impl X509Identity {
         &self.scopes
     }
 
+    /// check if identity belongs to SPU, common name must be `<id>` or `spu-<id>`
+    pub fn is_spu(&self, spu_id: SpuId) -> bool {
+        self.principal == spu_id.to_string() || self.principal == format!("spu-{}", spu_id)
+    }
+
     /// extract x509 identity from TCP Socket
     pub async fn create_from_connection(socket: &mut FluvioSocket) -> Result<Self, std::io::Error> {
         let identity = {
@@ -36,6 +46,12 @@ impl X509Identity {
                             scopes: req_msg.request.scopes,
                             principal: req_msg.request.principal,
                         },
+                        AuthorizationApiRequest::SaslAuthenticateRequest(_) => {
+                            return Err(std::io::Error::new(
+                                std::io::ErrorKind::InvalidData,
+                                "sasl authentication is not enabled",
+                            ))
+                        }
                     },
                     Err(_e) => {
                         return Err(std::io::Error::new(
@@ -74,3 +90,68 @@ impl X509Identity {
         }
     }
 }
+
+/// Identity is forwarded by TLS proxy without certificate,
+/// so non TLS endpoint behind proxy must only be reachable from local host
+pub fn validate_non_tls_endpoint(endpoint: &str) -> Result<(), IoError> {
+    let is_loopback = match endpoint.parse::<SocketAddr>() {
+        Ok(addr) => addr.ip().is_loopback(),
+        Err(_) => match endpoint.rsplit_once(':') {
+            Some((host, _port)) => {
+                host == "localhost"
+                    || host
+                        .parse::<IpAddr>()
+                        .map(|ip| ip.is_loopback())
+                        .unwrap_or(false)
+            }
+            None => false,
+        },
+    };
+
+    if is_loopback {
+        Ok(())
+    } else {
+        Err(IoError::new(
+            ErrorKind::InvalidInput,
+            format!("non tls addr: {} must be loopback address", endpoint),
+        ))
+    }
+}
+
+impl Identity for X509Identity {
+    fn principal(&self) -> &str {
+        &self.principal
+    }
+
+    fn scopes(&self) -> &[String] {
+        &self.scopes
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    use super::{X509Identity, validate_non_tls_endpoint};
+
+    #[test]
+    fn test_is_spu() {
+        let identity = |principal: &str| X509Identity::new(principal.to_owned(), vec![]);
+
+        assert!(identity("5001").is_spu(5001));
+        assert!(identity("spu-5001").is_spu(5001));
+        assert!(!identity("5002").is_spu(5001));
+        assert!(!identity("spu-50011").is_spu(5001));
+        assert!(!identity("root").is_spu(5001));
+    }
+
+    #[test]
+    fn test_validate_non_tls_endpoint() {
+        assert!(validate_non_tls_endpoint("127.0.0.1:9006").is_ok());
+        assert!(validate_non_tls_endpoint("[::1]:9006").is_ok());
+        assert!(validate_non_tls_endpoint("localhost:9006").is_ok());
+        assert!(validate_non_tls_endpoint("0.0.0.0:9006").is_err());
+        assert!(validate_non_tls_endpoint("10.0.0.1:9006").is_err());
+        assert!(validate_non_tls_endpoint("sc.fluvio:9006").is_err());
+        assert!(validate_non_tls_endpoint("localhost").is_err());
+    }
+}
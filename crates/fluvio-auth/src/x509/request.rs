@@ -5,6 +5,7 @@ use std::fmt::Debug;
 use dataplane::bytes::Buf;
 use dataplane::api::{api_decode, ApiMessage, Request, RequestHeader, RequestMessage};
 use dataplane::derive::{Encoder, Decoder};
+use dataplane::sasl::{SaslAuthenticateRequest, SASL_AUTHENTICATE_API_KEY};
 
 pub type AuthorizationScopes = Vec<String>;
 
@@ -36,6 +37,7 @@ pub struct AuthResponse {
 #[derive(Debug)]
 pub enum AuthorizationApiRequest {
     AuthRequest(RequestMessage<AuthRequest>),
+    SaslAuthenticateRequest(RequestMessage<SaslAuthenticateRequest>),
 }
 
 // Added to satisfy Encoder/Decoder traits
@@ -56,11 +58,17 @@ impl ApiMessage for AuthorizationApiRequest {
     {
         match header.api_key() {
             AUTH_REQUEST_API_KEY => api_decode!(AuthorizationApiRequest, AuthRequest, src, header),
+            SASL_AUTHENTICATE_API_KEY => api_decode!(
+                AuthorizationApiRequest,
+                SaslAuthenticateRequest,
+                src,
+                header
+            ),
             _ => Err(std::io::Error::new(
                 std::io::ErrorKind::InvalidInput,
                 format!(
-                    "api auth header key should be set to {:?}",
-                    AUTH_REQUEST_API_KEY
+                    "api auth header key should be set to {:?} or {:?}",
+                    AUTH_REQUEST_API_KEY, SASL_AUTHENTICATE_API_KEY
                 ),
             )),
         }
@@ -0,0 +1,132 @@
+//!
+//! # Consumer Lag
+//!
+//! CLI tree and processing to show lag of consumer streams
+//!
+
+use structopt::StructOpt;
+
+use fluvio::Fluvio;
+
+use crate::Result;
+use crate::common::output::Terminal;
+use crate::common::OutputFormat;
+
+/// Option for showing consumer lag
+#[derive(Debug, StructOpt)]
+pub struct ConsumerLagOpt {
+    /// The name of the Topic
+    #[structopt(value_name = "topic")]
+    topic: String,
+
+    #[structopt(flatten)]
+    output: OutputFormat,
+}
+
+impl ConsumerLagOpt {
+    /// perform actions
+    pub async fn process<O>(self, out: std::sync::Arc<O>, fluvio: &Fluvio) -> Result<()>
+    where
+        O: Terminal,
+    {
+        let output = self.output.format;
+        let partitions = fluvio.consumer_lag(&self.topic).await?;
+
+        display::format_consumer_lag_output(out, partitions, output)?;
+        Ok(())
+    }
+}
+
+mod display {
+
+    use prettytable::Row;
+    use prettytable::row;
+    use prettytable::cell;
+    use serde::Serialize;
+
+    use fluvio::consumer::PartitionLag;
+
+    use crate::common::output::{OutputType, OutputError, Terminal, TableOutputHandler};
+
+    #[derive(Serialize)]
+    #[serde(transparent)]
+    struct ListLag {
+        partitions: Vec<PartitionLag>,
+    }
+
+    /// Process lag based on output type
+    pub fn format_consumer_lag_output<O>(
+        out: std::sync::Arc<O>,
+        partitions: Vec<PartitionLag>,
+        output_type: OutputType,
+    ) -> Result<(), OutputError>
+    where
+        O: Terminal,
+    {
+        let list = ListLag { partitions };
+        out.render_list(&list, output_type)?;
+        Ok(())
+    }
+
+    fn offset(offset: Option<i64>) -> String {
+        offset
+            .map(|offset| offset.to_string())
+            .unwrap_or_else(|| "-".to_owned())
+    }
+
+    impl TableOutputHandler for ListLag {
+        /// table header implementation
+        fn header(&self) -> Row {
+            row![
+                "PARTITION",
+                "LEADER",
+                "HW",
+                "LEO",
+                "SESSION",
+                "CLIENT",
+                "DELIVERED",
+                "COMMITTED",
+                "LAG"
+            ]
+        }
+
+        /// return errors in string format
+        fn errors(&self) -> Vec<String> {
+            vec![]
+        }
+
+        /// one row per session, partitions without sessions are shown with empty session
+        fn content(&self) -> Vec<Row> {
+            let mut rows = vec![];
+            for partition in &self.partitions {
+                if partition.sessions.is_empty() {
+                    rows.push(row![
+                        l -> partition.partition,
+                        l -> partition.leader,
+                        l -> partition.high_watermark,
+                        l -> partition.leo,
+                        l -> "-",
+                        l -> "-",
+                        l -> "-",
+                        l -> "-",
+                        l -> "-"
+                    ]);
+                }
+                for session in &partition.sessions {
+                    rows.push(row![
+                        l -> partition.partition,
+                        l -> partition.leader,
+                        l -> partition.high_watermark,
+                        l -> partition.leo,
+                        l -> session.session_id,
+                        l -> session.client_id,
+                        l -> offset(session.delivered_offset),
+                        l -> offset(session.committed_offset),
+                        l -> offset(session.lag)
+                    ]);
+                }
+            }
+            rows
+        }
+    }
+}
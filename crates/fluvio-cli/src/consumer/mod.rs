@@ -0,0 +1,42 @@
+use std::sync::Arc;
+use structopt::StructOpt;
+use fluvio::Fluvio;
+
+mod lag;
+
+use crate::Result;
+use crate::common::output::Terminal;
+use crate::common::FluvioExtensionMetadata;
+use self::lag::ConsumerLagOpt;
+
+#[derive(Debug, StructOpt)]
+#[structopt(name = "consumer", about = "Consumer operations")]
+pub enum ConsumerCmd {
+    /// Show lag of active consumer streams of a Topic
+    #[structopt(
+        name = "lag",
+        template = crate::common::COMMAND_TEMPLATE,
+    )]
+    Lag(ConsumerLagOpt),
+}
+
+impl ConsumerCmd {
+    pub async fn process<O: Terminal>(self, out: Arc<O>, fluvio: &Fluvio) -> Result<()> {
+        match self {
+            Self::Lag(lag) => {
+                lag.process(out, fluvio).await?;
+            }
+        }
+
+        Ok(())
+    }
+
+    pub fn metadata() -> FluvioExtensionMetadata {
+        FluvioExtensionMetadata {
+            title: "consumer".into(),
+            package: Some("fluvio/fluvio".parse().unwrap()),
+            description: "Consumer Operations".into(),
+            version: semver::Version::parse(env!("CARGO_PKG_VERSION")).unwrap(),
+        }
+    }
+}
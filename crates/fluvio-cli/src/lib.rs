@@ -19,11 +19,15 @@ mod topic;
 mod consume;
 mod produce;
 mod partition;
+mod consumer;
+mod schema;
 
 use topic::TopicCmd;
 use consume::ConsumeOpt;
 use produce::ProduceOpt;
 use partition::PartitionCmd;
+use consumer::ConsumerCmd;
+use schema::SchemaCmd;
 use profile::ProfileOpt;
 use install::update::UpdateOpt;
 use install::plugins::InstallOpt;
@@ -210,6 +214,21 @@ pub enum FluvioCmd {
     /// total throughput of the Topic.
     #[structopt(name = "partition")]
     Partition(PartitionCmd),
+
+    /// Inspect consumers of Topics
+    ///
+    /// Shows how far behind the end of each partition active consumer
+    /// streams are, as reported by partition leaders.
+    #[structopt(name = "consumer")]
+    Consumer(ConsumerCmd),
+
+    /// Manage and view schemas of Topic values
+    ///
+    /// Schemas are registered as versions of a subject. A new version must be
+    /// compatible with the latest one, and values produced to the Topic with
+    /// the same name as the subject can be validated by SPUs.
+    #[structopt(name = "schema")]
+    Schema(SchemaCmd),
 }
 
 impl FluvioCmd {
@@ -231,6 +250,12 @@ impl FluvioCmd {
             Self::Partition(partition) => {
                 partition.process(out, &fluvio).await?;
             }
+            Self::Consumer(consumer) => {
+                consumer.process(out, &fluvio).await?;
+            }
+            Self::Schema(schema) => {
+                schema.process(out, &fluvio).await?;
+            }
         }
 
         Ok(())
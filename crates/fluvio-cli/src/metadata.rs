@@ -5,6 +5,8 @@ use structopt::StructOpt;
 use crate::Result;
 use crate::TopicCmd;
 use crate::PartitionCmd;
+use crate::ConsumerCmd;
+use crate::SchemaCmd;
 use crate::ConsumeOpt;
 use crate::ProduceOpt;
 use fluvio_command::CommandExt;
@@ -26,6 +28,8 @@ impl MetadataOpt {
         let mut metadata = vec![
             TopicCmd::metadata(),
             PartitionCmd::metadata(),
+            ConsumerCmd::metadata(),
+            SchemaCmd::metadata(),
             ProduceOpt::metadata(),
             ConsumeOpt::metadata(),
         ];
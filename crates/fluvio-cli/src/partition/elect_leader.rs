@@ -0,0 +1,59 @@
+//!
+//! # Elect Partition Leader
+//!
+//! CLI tree to move partition leadership to another replica
+//!
+
+use std::sync::Arc;
+use tracing::debug;
+use structopt::StructOpt;
+
+use fluvio::Fluvio;
+use crate::Result;
+use crate::common::output::Terminal;
+use crate::common::t_println;
+
+/// Option for electing Partition leader
+#[derive(Debug, StructOpt)]
+pub struct ElectLeaderOpt {
+    /// The name of the Topic
+    #[structopt(value_name = "topic")]
+    topic: String,
+
+    /// The Partition number
+    #[structopt(value_name = "partition")]
+    partition: i32,
+
+    /// SPU to become the new leader. Defaults to the preferred (first) replica
+    #[structopt(long = "spu", value_name = "id")]
+    spu: Option<i32>,
+}
+
+impl ElectLeaderOpt {
+    pub async fn process<O: Terminal>(self, out: Arc<O>, fluvio: &Fluvio) -> Result<()> {
+        debug!(
+            "electing leader for partition: {}-{}, spu: {:?}",
+            self.topic, self.partition, self.spu
+        );
+        let admin = fluvio.admin().await;
+        admin
+            .elect_leader(&self.topic, self.partition, self.spu)
+            .await?;
+        match self.spu {
+            Some(spu) => t_println!(
+                out,
+                "spu {} elected leader for partition \"{}-{}\"",
+                spu,
+                self.topic,
+                self.partition
+            ),
+            None => t_println!(
+                out,
+                "preferred replica elected leader for partition \"{}-{}\"",
+                self.topic,
+                self.partition
+            ),
+        }
+        Ok(())
+    }
+}
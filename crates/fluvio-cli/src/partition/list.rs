@@ -7,6 +7,7 @@
 use structopt::StructOpt;
 
 use fluvio::Fluvio;
+use fluvio::metadata::spu::SpuSpec;
 use fluvio_controlplane_metadata::partition::*;
 
 use crate::Result;
@@ -30,15 +31,22 @@ impl ListPartitionOpt {
         let admin = fluvio.admin().await;
 
         let partitions = admin.list::<PartitionSpec, _>(vec![]).await?;
+        let spu_racks = admin
+            .list::<SpuSpec, _>(vec![])
+            .await?
+            .into_iter()
+            .filter_map(|spu| spu.spec.rack.map(|rack| (spu.spec.id, rack)))
+            .collect();
 
         // format and dump to screen
-        display::format_partition_response_output(out, partitions, output)?;
+        display::format_partition_response_output(out, partitions, spu_racks, output)?;
         Ok(())
     }
 }
 
 mod display {
 
+    use std::collections::BTreeMap;
     use std::convert::TryInto;
 
     use prettytable::Row;
@@ -55,14 +63,33 @@ mod display {
     use crate::common::output::{OutputType, OutputError, Terminal, TableOutputHandler};
 
     #[derive(Serialize)]
-    struct ListSpus(Vec<Metadata<PartitionSpec>>);
+    #[serde(transparent)]
+    struct ListSpus {
+        partitions: Vec<Metadata<PartitionSpec>>,
+        #[serde(skip)]
+        spu_racks: BTreeMap<i32, String>,
+    }
 
     impl IntoIterator for ListSpus {
         type Item = Metadata<PartitionSpec>;
         type IntoIter = std::vec::IntoIter<Self::Item>;
 
         fn into_iter(self) -> Self::IntoIter {
-            self.0.into_iter()
+            self.partitions.into_iter()
+        }
+    }
+
+    impl ListSpus {
+        /// rack of each follower, same as replicas column, `-` if spu is not assigned to rack
+        fn follower_racks(&self, spec: &PartitionSpec) -> String {
+            spec.followers()
+                .iter()
+                .map(|spu| {
+                    let rack = self.spu_racks.get(spu).map(|r| r.as_str()).unwrap_or("-");
+                    format!("{}:{}", spu, rack)
+                })
+                .collect::<Vec<String>>()
+                .join(", ")
         }
     }
 
@@ -70,13 +97,17 @@ mod display {
     pub fn format_partition_response_output<O>(
         out: std::sync::Arc<O>,
         spus: Vec<Metadata<PartitionSpec>>,
+        spu_racks: BTreeMap<i32, String>,
         output_type: OutputType,
     ) -> Result<(), OutputError>
     where
         O: Terminal,
     {
         if !spus.is_empty() {
-            let meta_spus = ListSpus(spus);
+            let meta_spus = ListSpus {
+                partitions: spus,
+                spu_racks,
+            };
             out.render_list(&meta_spus, output_type)?;
         } else {
             t_println!(out, "No partitions found");
@@ -93,6 +124,7 @@ mod display {
                 "PARTITION",
                 "LEADER",
                 "REPLICAS",
+                "RACKS",
                 "RESOLUTION",
                 "HW",
                 "LEO",
@@ -107,7 +139,7 @@ mod display {
         }
 
         fn content(&self) -> Vec<Row> {
-            let mut metadata = self.0.clone();
+            let mut metadata = self.partitions.clone();
             metadata.sort_by(|a, b| a.name.cmp(&b.name));
             metadata
                 .iter()
@@ -128,6 +160,7 @@ mod display {
                         l -> partition.to_string(),
                         l -> spec.leader.to_string(),
                         l -> format!("{:?}",spec.followers()),
+                        l -> self.follower_racks(spec),
                         l -> format!("{:?}",status.resolution),
                         l -> status.leader.hw.to_string(),
                         l -> status.leader.leo.to_string(),
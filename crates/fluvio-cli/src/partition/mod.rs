@@ -3,11 +3,13 @@ use structopt::StructOpt;
 use fluvio::Fluvio;
 
 mod list;
+mod elect_leader;
 
 use crate::Result;
 use crate::common::output::Terminal;
 use crate::common::FluvioExtensionMetadata;
 use self::list::ListPartitionOpt;
+use self::elect_leader::ElectLeaderOpt;
 
 #[derive(Debug, StructOpt)]
 #[structopt(name = "partition", about = "Partition operations")]
@@ -18,6 +20,13 @@ pub enum PartitionCmd {
         template = crate::common::COMMAND_TEMPLATE,
     )]
     List(ListPartitionOpt),
+
+    /// Move leadership of a Partition to another in-sync replica
+    #[structopt(
+        name = "elect-leader",
+        template = crate::common::COMMAND_TEMPLATE,
+    )]
+    ElectLeader(ElectLeaderOpt),
 }
 
 impl PartitionCmd {
@@ -26,6 +35,9 @@ impl PartitionCmd {
             Self::List(list) => {
                 list.process(out, fluvio).await?;
             }
+            Self::ElectLeader(elect) => {
+                elect.process(out, fluvio).await?;
+            }
         }
 
         Ok(())
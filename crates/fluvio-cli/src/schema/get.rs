@@ -0,0 +1,55 @@
+//!
+//! # Get Schema CLI
+//!
+//! CLI tree and processing to show schema definition of subject
+//!
+
+use std::sync::Arc;
+use structopt::StructOpt;
+
+use fluvio::Fluvio;
+use fluvio::metadata::schema::SchemaSpec;
+
+use crate::{Result, CliError};
+use crate::common::output::Terminal;
+use crate::common::t_println;
+
+#[derive(Debug, StructOpt)]
+pub struct GetSchemaOpt {
+    /// The name of the subject
+    #[structopt(value_name = "subject")]
+    subject: String,
+
+    /// Version of schema, latest version if not specified
+    #[structopt(short = "v", long = "version", value_name = "integer")]
+    version: Option<u32>,
+}
+
+impl GetSchemaOpt {
+    pub async fn process<O: Terminal>(self, out: Arc<O>, fluvio: &Fluvio) -> Result<()> {
+        let admin = fluvio.admin().await;
+        let subject = admin
+            .list::<SchemaSpec, _>(vec![self.subject.clone()])
+            .await?
+            .into_iter()
+            .find(|subject| subject.name == self.subject)
+            .ok_or_else(|| {
+                CliError::invalid_arg(format!("schema subject '{}' not found", self.subject))
+            })?;
+
+        let schema = match self.version {
+            Some(version) => subject.spec.version(version),
+            None => subject.spec.latest(),
+        }
+        .ok_or_else(|| CliError::invalid_arg("schema version not found"))?;
+
+        t_println!(out, "subject: {}", subject.name);
+        t_println!(out, "version: {}", schema.version);
+        t_println!(out, "id: {}", schema.id);
+        t_println!(out, "type: {}", schema.schema_type);
+        t_println!(out, "compatibility: {}", subject.spec.compatibility);
+        t_println!(out, "validate: {}", subject.spec.validate);
+        t_println!(out, "{}", schema.definition);
+        Ok(())
+    }
+}
@@ -0,0 +1,110 @@
+//!
+//! # List Schemas CLI
+//!
+//! CLI tree and processing to list schema subjects
+//!
+
+use std::sync::Arc;
+use structopt::StructOpt;
+
+use fluvio::Fluvio;
+use fluvio::metadata::schema::SchemaSpec;
+
+use crate::Result;
+use crate::common::output::Terminal;
+use crate::common::OutputFormat;
+
+#[derive(Debug, StructOpt)]
+pub struct ListSchemasOpt {
+    #[structopt(flatten)]
+    output: OutputFormat,
+}
+
+impl ListSchemasOpt {
+    pub async fn process<O: Terminal>(self, out: Arc<O>, fluvio: &Fluvio) -> Result<()> {
+        let output_type = self.output.format;
+        let admin = fluvio.admin().await;
+
+        let schemas = admin.list::<SchemaSpec, _>(vec![]).await?;
+        display::format_response_output(out, schemas, output_type)?;
+        Ok(())
+    }
+}
+
+mod display {
+
+    use prettytable::*;
+    use serde::Serialize;
+
+    use fluvio::metadata::objects::Metadata;
+    use fluvio::metadata::schema::SchemaSpec;
+
+    use crate::common::output::{OutputType, TableOutputHandler, Terminal, OutputError};
+    use crate::common::t_println;
+
+    #[derive(Serialize)]
+    struct ListSchemas(Vec<Metadata<SchemaSpec>>);
+
+    /// Process server based on output type
+    pub fn format_response_output<O>(
+        out: std::sync::Arc<O>,
+        list_schemas: Vec<Metadata<SchemaSpec>>,
+        output_type: OutputType,
+    ) -> Result<(), OutputError>
+    where
+        O: Terminal,
+    {
+        if !list_schemas.is_empty() {
+            let table_list = ListSchemas(list_schemas);
+            out.render_list(&table_list, output_type)
+        } else {
+            t_println!(out, "No schemas found");
+            Ok(())
+        }
+    }
+
+    impl TableOutputHandler for ListSchemas {
+        /// table header implementation
+        fn header(&self) -> Row {
+            row![
+                "SUBJECT",
+                "TYPE",
+                "VERSION",
+                "ID",
+                "COMPATIBILITY",
+                "VALIDATE"
+            ]
+        }
+
+        /// return errors in string format
+        fn errors(&self) -> Vec<String> {
+            vec![]
+        }
+
+        /// one row per subject, with latest version
+        fn content(&self) -> Vec<Row> {
+            self.0
+                .iter()
+                .map(|metadata| {
+                    let spec = &metadata.spec;
+                    let (schema_type, version, id) = match spec.latest() {
+                        Some(latest) => (
+                            latest.schema_type.to_string(),
+                            latest.version.to_string(),
+                            latest.id.to_string(),
+                        ),
+                        None => ("-".to_owned(), "-".to_owned(), "-".to_owned()),
+                    };
+                    row![
+                        l -> metadata.name,
+                        c -> schema_type,
+                        c -> version,
+                        c -> id,
+                        c -> spec.compatibility,
+                        c -> spec.validate
+                    ]
+                })
+                .collect()
+        }
+    }
+}
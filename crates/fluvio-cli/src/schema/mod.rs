@@ -0,0 +1,66 @@
+use std::sync::Arc;
+use structopt::StructOpt;
+use fluvio::Fluvio;
+
+mod register;
+mod list;
+mod get;
+
+use crate::Result;
+use crate::common::output::Terminal;
+use crate::common::FluvioExtensionMetadata;
+use self::register::RegisterSchemaOpt;
+use self::list::ListSchemasOpt;
+use self::get::GetSchemaOpt;
+
+#[derive(Debug, StructOpt)]
+#[structopt(name = "schema", about = "Schema operations")]
+pub enum SchemaCmd {
+    /// Register schema as new version of subject
+    #[structopt(
+        name = "register",
+        template = crate::common::COMMAND_TEMPLATE,
+    )]
+    Register(RegisterSchemaOpt),
+
+    /// List all schema subjects
+    #[structopt(
+        name = "list",
+        template = crate::common::COMMAND_TEMPLATE,
+    )]
+    List(ListSchemasOpt),
+
+    /// Show schema definition of subject
+    #[structopt(
+        name = "get",
+        template = crate::common::COMMAND_TEMPLATE,
+    )]
+    Get(GetSchemaOpt),
+}
+
+impl SchemaCmd {
+    pub async fn process<O: Terminal>(self, out: Arc<O>, fluvio: &Fluvio) -> Result<()> {
+        match self {
+            Self::Register(register) => {
+                register.process(out, fluvio).await?;
+            }
+            Self::List(list) => {
+                list.process(out, fluvio).await?;
+            }
+            Self::Get(get) => {
+                get.process(out, fluvio).await?;
+            }
+        }
+
+        Ok(())
+    }
+
+    pub fn metadata() -> FluvioExtensionMetadata {
+        FluvioExtensionMetadata {
+            title: "schema".into(),
+            package: Some("fluvio/fluvio".parse().unwrap()),
+            description: "Schema Operations".into(),
+            version: semver::Version::parse(env!("CARGO_PKG_VERSION")).unwrap(),
+        }
+    }
+}
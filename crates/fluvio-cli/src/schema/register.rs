@@ -0,0 +1,93 @@
+//!
+//! # Register Schema CLI
+//!
+//! CLI tree and processing to register schema under subject
+//!
+
+use std::path::PathBuf;
+use std::sync::Arc;
+
+use structopt::StructOpt;
+use tracing::debug;
+
+use fluvio::Fluvio;
+use fluvio::metadata::schema::{RegisterSchemaRequest, SchemaCompatibility, SchemaType};
+
+use crate::Result;
+use crate::common::output::Terminal;
+use crate::common::t_println;
+
+fn parse_compatibility(value: &str) -> std::result::Result<SchemaCompatibility, String> {
+    value.parse()
+}
+
+fn parse_schema_type(value: &str) -> std::result::Result<SchemaType, String> {
+    value.parse()
+}
+
+#[derive(Debug, StructOpt)]
+pub struct RegisterSchemaOpt {
+    /// The name of the subject, values of Topic with same name are checked against subject
+    #[structopt(value_name = "subject")]
+    subject: String,
+
+    /// File with schema definition
+    #[structopt(short = "f", long = "file", value_name = "file", parse(from_os_str))]
+    file: PathBuf,
+
+    /// Type of schema: avro or json
+    #[structopt(
+        short = "t",
+        long = "type",
+        value_name = "type",
+        default_value = "avro",
+        parse(try_from_str = parse_schema_type)
+    )]
+    schema_type: SchemaType,
+
+    /// Set compatibility of subject: none, backward, forward or full
+    #[structopt(
+        short = "c",
+        long = "compatibility",
+        value_name = "mode",
+        parse(try_from_str = parse_compatibility)
+    )]
+    compatibility: Option<SchemaCompatibility>,
+
+    /// Reject values produced to Topic which don't match schema of subject
+    #[structopt(long = "validate", conflicts_with = "no_validate")]
+    validate: bool,
+
+    /// Stop validating values produced to Topic
+    #[structopt(long = "no-validate")]
+    no_validate: bool,
+}
+
+impl RegisterSchemaOpt {
+    pub async fn process<O: Terminal>(self, out: Arc<O>, fluvio: &Fluvio) -> Result<()> {
+        let definition = std::fs::read_to_string(&self.file)?;
+        let validate = match (self.validate, self.no_validate) {
+            (true, _) => Some(true),
+            (_, true) => Some(false),
+            _ => None,
+        };
+
+        let mut request = RegisterSchemaRequest::new(self.subject, self.schema_type, definition);
+        request.compatibility = self.compatibility;
+        request.validate = validate;
+        debug!(subject = %request.subject, "registering schema");
+
+        let subject = request.subject.clone();
+        let admin = fluvio.admin().await;
+        let (id, version) = admin.register_schema(request).await?;
+
+        t_println!(
+            out,
+            "schema registered: {} version {} (id {})",
+            subject,
+            version,
+            id
+        );
+        Ok(())
+    }
+}
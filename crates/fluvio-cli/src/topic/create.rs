@@ -78,6 +78,29 @@ pub struct CreateTopicOpt {
     )]
     replica_assignment: Option<PathBuf>,
 
+    /// Allow an out of sync replica to become leader, which may lose records
+    #[structopt(long = "unclean-leader-election")]
+    unclean_leader_election: bool,
+
+    /// Only allow a replica which has caught up with the leader to become leader
+    #[structopt(
+        long = "strict-leader-election",
+        conflicts_with = "unclean-leader-election"
+    )]
+    strict_leader_election: bool,
+
+    /// Minimum number of in sync replicas required to accept records
+    #[structopt(
+        long = "min-in-sync-replicas",
+        value_name = "integer",
+        default_value = "1"
+    )]
+    min_in_sync_replicas: u16,
+
+    /// Override storage configuration for the Topic, e.g. segment.max.bytes=1000000
+    #[structopt(long = "config", value_name = "key=value", number_of_values = 1)]
+    config: Vec<String>,
+
     /// Validates configuration, does not provision
     #[structopt(short = "d", long)]
     dry_run: bool,
@@ -100,9 +123,11 @@ impl CreateTopicOpt {
     fn validate(self) -> Result<(String, TopicSpec)> {
         use fluvio::metadata::topic::PartitionMaps;
         use fluvio::metadata::topic::TopicReplicaParam;
+        use fluvio::metadata::partition::ReplicationPolicy;
+        use fluvio::metadata::topic::TopicConfig;
         use load::PartitionLoad;
 
-        let topic = if let Some(replica_assign_file) = &self.replica_assignment {
+        let mut topic = if let Some(replica_assign_file) = &self.replica_assignment {
             TopicSpec::Assigned(
                 PartitionMaps::file_decode(replica_assign_file).map_err(|err| {
                     IoError::new(
@@ -119,8 +144,29 @@ impl CreateTopicOpt {
                 partitions: self.partitions,
                 replication_factor: self.replication as i32,
                 ignore_rack_assignment: self.ignore_rack_assigment,
+                ..Default::default()
             })
         };
+        topic.set_replication_policy(ReplicationPolicy {
+            unclean_leader_election: self.unclean_leader_election,
+            strict_leader_election: self.strict_leader_election,
+            min_in_sync_replicas: self.min_in_sync_replicas,
+        });
+
+        let mut config = TopicConfig::default();
+        for key_value in &self.config {
+            let (key, value) = key_value.split_once('=').ok_or_else(|| {
+                CliError::InvalidArg(format!(
+                    "config must be in the form of key=value: {}",
+                    key_value
+                ))
+            })?;
+            config.insert(key.trim(), value.trim());
+        }
+        config
+            .validate()
+            .map_err(|err| CliError::InvalidArg(err.to_string()))?;
+        topic.set_config(config);
 
         let is_valid = valid_topic_name(&self.topic);
         if !is_valid {
@@ -50,7 +50,7 @@ mod display {
     use serde::Serialize;
 
     use fluvio::metadata::objects::Metadata;
-    use fluvio::metadata::topic::TopicSpec;
+    use fluvio::metadata::topic::{TopicSpec, TOPIC_CONFIG_KEYS};
 
     use crate::common::output::{
         OutputType, OutputError, DescribeObjectHandler, KeyValOutputHandler, TableOutputHandler,
@@ -144,6 +144,32 @@ mod display {
                 }
             }
 
+            let policy = spec.replication_policy();
+            key_values.push((
+                "Unclean Leader Election".to_owned(),
+                Some(policy.unclean_leader_election.to_string()),
+            ));
+            key_values.push((
+                "Strict Leader Election".to_owned(),
+                Some(policy.strict_leader_election.to_string()),
+            ));
+            key_values.push((
+                "Min In Sync Replicas".to_owned(),
+                Some(policy.min_in_sync_replicas.to_string()),
+            ));
+
+            // show storage overrides, keys not overridden use SPU configuration
+            // which is shown as built-in default, SPU may be started with other values
+            let config = spec.config();
+            for key in TOPIC_CONFIG_KEYS.iter() {
+                let value = match config.value_or_default(key) {
+                    Some((value, true)) => value.to_string(),
+                    Some((value, false)) => format!("{} (built-in default)", value),
+                    None => String::new(),
+                };
+                key_values.push((key.to_string(), Some(value)));
+            }
+
             key_values.push((
                 "Status".to_owned(),
                 Some(status.resolution.resolution_label().to_string()),
@@ -0,0 +1,73 @@
+//!
+//! # Test ACL
+//!
+//! CLI tree to test if principal is allowed to perform action
+//!
+
+use std::sync::Arc;
+use structopt::StructOpt;
+use tracing::debug;
+
+use fluvio::Fluvio;
+use fluvio::metadata::acl::AclCheck;
+
+use crate::cli::ClusterCliError;
+use crate::cli::common::output::Terminal;
+use crate::cli::common::t_println;
+use super::PrincipalOpt;
+
+#[derive(Debug, StructOpt)]
+pub struct TestAclOpt {
+    #[structopt(flatten)]
+    principal: PrincipalOpt,
+
+    /// Object type: topic, partition, spu, custom-spu, spu-group, connector
+    #[structopt(value_name = "object")]
+    object: String,
+
+    /// Action: create, read, write, update, delete
+    #[structopt(
+        value_name = "action",
+        possible_values = &["create", "read", "write", "update", "delete"],
+        case_insensitive = true
+    )]
+    action: String,
+
+    /// Name of resource, if not specified action is tested on object type
+    #[structopt(value_name = "resource")]
+    resource: Option<String>,
+}
+
+impl TestAclOpt {
+    pub async fn process<O: Terminal>(
+        self,
+        out: Arc<O>,
+        fluvio: &Fluvio,
+    ) -> Result<(), ClusterCliError> {
+        let PrincipalOpt { principal, scopes } = self.principal;
+        let check = AclCheck {
+            object: self.object,
+            action: self.action,
+            resource: self.resource,
+        };
+        debug!(%principal, ?scopes, ?check, "testing acl");
+
+        let admin = fluvio.admin().await;
+        let (_, allowed) = admin
+            .describe_acl(principal.clone(), scopes, Some(check.clone()))
+            .await?;
+
+        let target = match &check.resource {
+            Some(resource) => format!("{} \"{}\"", check.object, resource),
+            None => check.object.clone(),
+        };
+
+        if allowed.unwrap_or(false) {
+            t_println!(out, "allowed: {} {} {}", principal, check.action, target);
+        } else {
+            t_println!(out, "denied: {} {} {}", principal, check.action, target);
+        }
+
+        Ok(())
+    }
+}
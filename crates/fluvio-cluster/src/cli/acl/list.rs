@@ -0,0 +1,53 @@
+//!
+//! # List ACL
+//!
+//! CLI tree to list policy rules which apply to principal
+//!
+
+use std::sync::Arc;
+use structopt::StructOpt;
+use tracing::debug;
+
+use fluvio::Fluvio;
+
+use crate::cli::ClusterCliError;
+use crate::cli::common::output::Terminal;
+use crate::cli::common::t_println;
+use super::PrincipalOpt;
+
+#[derive(Debug, StructOpt)]
+pub struct ListAclOpt {
+    #[structopt(flatten)]
+    principal: PrincipalOpt,
+}
+
+impl ListAclOpt {
+    pub async fn process<O: Terminal>(
+        self,
+        out: Arc<O>,
+        fluvio: &Fluvio,
+    ) -> Result<(), ClusterCliError> {
+        let PrincipalOpt { principal, scopes } = self.principal;
+        debug!(%principal, ?scopes, "listing acl");
+
+        let admin = fluvio.admin().await;
+        let (rules, _) = admin.describe_acl(principal.clone(), scopes, None).await?;
+
+        if rules.is_empty() {
+            t_println!(out, "principal \"{}\" has no permissions", principal);
+        }
+
+        for rule in &rules {
+            t_println!(
+                out,
+                "role: {}, object: {}, action: {}, resource: {}",
+                rule.role,
+                rule.object,
+                rule.action,
+                rule.resource.as_deref().unwrap_or("*")
+            );
+        }
+
+        Ok(())
+    }
+}
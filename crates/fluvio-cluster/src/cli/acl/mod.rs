@@ -0,0 +1,60 @@
+use std::sync::Arc;
+use structopt::StructOpt;
+
+mod list;
+mod check;
+
+use fluvio::Fluvio;
+
+use crate::cli::ClusterCliError;
+use super::common::COMMAND_TEMPLATE;
+use super::common::output::Terminal;
+use list::ListAclOpt;
+use check::TestAclOpt;
+
+#[derive(Debug, StructOpt)]
+pub enum AclCmd {
+    /// List permissions of principal in authorization policy of the cluster
+    #[structopt(
+        name = "list",
+        template = COMMAND_TEMPLATE,
+    )]
+    List(ListAclOpt),
+
+    /// Test if principal is allowed to perform action
+    #[structopt(
+        name = "test",
+        template = COMMAND_TEMPLATE,
+    )]
+    Test(TestAclOpt),
+}
+
+impl AclCmd {
+    pub async fn process<O: Terminal>(
+        self,
+        out: Arc<O>,
+        fluvio: &Fluvio,
+    ) -> Result<(), ClusterCliError> {
+        match self {
+            Self::List(list) => {
+                list.process(out, fluvio).await?;
+            }
+            Self::Test(test) => {
+                test.process(out, fluvio).await?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Principal whose permissions are evaluated
+#[derive(Debug, StructOpt)]
+pub struct PrincipalOpt {
+    /// Name of principal
+    #[structopt(long, value_name = "name")]
+    principal: String,
+
+    /// Role of principal, can be repeated
+    #[structopt(long = "scope", value_name = "role", required = true)]
+    scopes: Vec<String>,
+}
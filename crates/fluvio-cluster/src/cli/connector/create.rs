@@ -12,7 +12,7 @@ use fluvio::metadata::topic::{TopicSpec, TopicReplicaParam};
 use fluvio_controlplane_metadata::connector::ManagedConnectorSpec;
 
 use crate::cli::ClusterCliError;
-use super::ConnectorConfig;
+use super::{ConnectorConfig, SecretStore};
 
 // -----------------------------------
 // CLI Options
@@ -23,6 +23,10 @@ pub struct CreateManagedConnectorOpt {
     /// The name for the new Managed Connector
     #[structopt(short = "c", long = "config", value_name = "config")]
     pub config: String,
+
+    /// Kubernetes namespace of cluster, secrets of connector are stored there
+    #[structopt(long, default_value = "default")]
+    pub namespace: String,
 }
 
 impl CreateManagedConnectorOpt {
@@ -33,6 +37,8 @@ impl CreateManagedConnectorOpt {
 
         debug!("creating managed_connector: {}, spec: {:#?}", name, spec);
 
+        config.upload_secrets(&SecretStore::k8(self.namespace))?;
+
         let admin = fluvio.admin().await;
         if config.create_topic {
             let topic_spec = TopicSpec::Computed(TopicReplicaParam::new(1, 1, false));
@@ -9,6 +9,7 @@ use fluvio::Fluvio;
 use fluvio_controlplane_metadata::connector::ManagedConnectorSpec;
 
 use crate::cli::ClusterCliError;
+use super::SecretStore;
 
 // -----------------------------------
 // CLI Options
@@ -19,12 +20,26 @@ pub struct DeleteManagedConnectorOpt {
     /// The name of the connector to delete
     #[structopt(value_name = "name")]
     name: String,
+
+    /// Kubernetes namespace of cluster, secrets of connector are removed from there
+    #[structopt(long, default_value = "default")]
+    namespace: String,
 }
 
 impl DeleteManagedConnectorOpt {
     pub async fn process(self, fluvio: &Fluvio) -> Result<(), ClusterCliError> {
         let admin = fluvio.admin().await;
+        let has_secrets = admin
+            .list::<ManagedConnectorSpec, _>(vec![self.name.clone()])
+            .await?
+            .iter()
+            .any(|connector| connector.name == self.name && !connector.spec.secrets.is_empty());
         admin.delete::<ManagedConnectorSpec, _>(&self.name).await?;
+
+        if has_secrets {
+            SecretStore::k8(self.namespace)
+                .delete(&ManagedConnectorSpec::secret_object_name(&self.name))?;
+        }
         Ok(())
     }
 }
@@ -2,23 +2,26 @@ use std::sync::Arc;
 use structopt::StructOpt;
 
 use serde::Deserialize;
+use tracing::warn;
 use std::collections::BTreeMap;
 use std::path::PathBuf;
 use std::fs::File;
 use std::io::Read;
 
 use fluvio::Fluvio;
-use fluvio_controlplane_metadata::connector::ManagedConnectorSpec;
+use fluvio_controlplane_metadata::connector::{ManagedConnectorSpec, ManagedConnectorSecret};
 use fluvio_extension_common::Terminal;
 use fluvio_extension_common::COMMAND_TEMPLATE;
 
 mod create;
 mod delete;
 mod list;
+mod secret;
 
 use create::CreateManagedConnectorOpt;
 use delete::DeleteManagedConnectorOpt;
 use list::ListManagedConnectorsOpt;
+pub use secret::SecretStore;
 use crate::cli::ClusterCliError;
 
 #[derive(Debug, StructOpt)]
@@ -77,7 +80,55 @@ pub struct ConnectorConfig {
     #[serde(default)]
     parameters: BTreeMap<String, String>,
     #[serde(default)]
-    secrets: BTreeMap<String, String>,
+    secrets: BTreeMap<String, SecretSource>,
+}
+
+/// Secret of connector in config
+#[derive(Debug, Deserialize, Clone)]
+#[serde(untagged)]
+pub enum SecretSource {
+    /// where value is read from, value itself is not part of config
+    Reference(SecretReference),
+    /// value inline in config, accepted for configs of previous versions
+    Inline(String),
+}
+
+/// Where value of secret is read from
+#[derive(Debug, Deserialize, Clone)]
+#[serde(rename_all = "snake_case")]
+pub enum SecretReference {
+    /// content of file
+    File(PathBuf),
+    /// environment variable
+    Env(String),
+}
+
+impl SecretSource {
+    fn read(&self, parameter: &str) -> Result<String, ClusterCliError> {
+        match self {
+            Self::Reference(SecretReference::File(path)) => {
+                let mut value = std::fs::read_to_string(path)?;
+                // trailing new line is usually not part of secret
+                if value.ends_with('\n') {
+                    value.pop();
+                }
+                Ok(value)
+            }
+            Self::Reference(SecretReference::Env(var)) => std::env::var(var).map_err(|_| {
+                ClusterCliError::Other(format!(
+                    "secret {}: environment variable {} is not set",
+                    parameter, var
+                ))
+            }),
+            Self::Inline(value) => {
+                warn!(
+                    parameter,
+                    "inline secret value in connector config is deprecated, use file or env"
+                );
+                Ok(value.clone())
+            }
+        }
+    }
 }
 
 impl ConnectorConfig {
@@ -88,23 +139,81 @@ impl ConnectorConfig {
         let connector_config: ConnectorConfig = serde_yaml::from_str(&contents)?;
         Ok(connector_config)
     }
+
+    /// Uploads secrets of connector to secret store, replacing previous values.
+    pub(crate) fn upload_secrets(&self, store: &SecretStore) -> Result<(), ClusterCliError> {
+        if self.secrets.is_empty() {
+            return Ok(());
+        }
+
+        let mut values = BTreeMap::new();
+        for (parameter, source) in &self.secrets {
+            secret::validate_secret_key(parameter)?;
+            values.insert(parameter.clone(), source.read(parameter)?);
+        }
+
+        store.upload(
+            &ManagedConnectorSpec::secret_object_name(&self.name),
+            &values,
+        )
+    }
 }
 
 impl From<ConnectorConfig> for ManagedConnectorSpec {
     fn from(config: ConnectorConfig) -> ManagedConnectorSpec {
+        let object_name = ManagedConnectorSpec::secret_object_name(&config.name);
+        let secrets = config
+            .secrets
+            .keys()
+            .map(|parameter| {
+                let secret = ManagedConnectorSecret::new(&object_name, parameter);
+                (parameter.clone(), secret)
+            })
+            .collect();
         ManagedConnectorSpec {
             name: config.name,
             type_: config.type_,
             topic: config.topic,
             parameters: config.parameters,
-            secrets: config.secrets,
+            secrets,
         }
     }
 }
 
 #[test]
 fn config_test() {
-    let _: ManagedConnectorSpec = ConnectorConfig::from_file("test-data/test-config.yaml")
+    let spec: ManagedConnectorSpec = ConnectorConfig::from_file("test-data/test-config.yaml")
         .expect("Failed to load test config")
         .into();
+    assert_eq!(
+        spec.secrets.get("foo"),
+        Some(&ManagedConnectorSecret::new(
+            "fluvio-connector-my-test-mqtt",
+            "foo"
+        ))
+    );
+}
+
+#[test]
+fn legacy_config_test() {
+    let config: ConnectorConfig = serde_yaml::from_str(
+        r#"
+version: v1
+name: my-test-mqtt
+type: mqtt
+topic: my-mqtt
+secrets:
+  foo: bar
+"#,
+    )
+    .expect("Failed to parse legacy config");
+    assert_eq!(
+        config
+            .secrets
+            .get("foo")
+            .expect("secret")
+            .read("foo")
+            .expect("value"),
+        "bar"
+    );
 }
@@ -0,0 +1,99 @@
+//!
+//! # Connector Secret Store
+//!
+//! Secrets of connectors are kept out of connector spec, they are stored
+//! in Kubernetes secret objects which are mounted into connector.
+//!
+use std::collections::BTreeMap;
+use std::fs;
+use std::process::Command;
+
+use tracing::debug;
+
+use fluvio_command::CommandExt;
+
+use crate::cli::ClusterCliError;
+
+/// Secret objects of connectors in Kubernetes namespace
+#[derive(Debug)]
+pub struct SecretStore {
+    namespace: String,
+}
+
+impl SecretStore {
+    pub fn k8(namespace: impl Into<String>) -> Self {
+        Self {
+            namespace: namespace.into(),
+        }
+    }
+
+    /// store secret object, replacing previous values
+    pub fn upload(
+        &self,
+        object_name: &str,
+        values: &BTreeMap<String, String>,
+    ) -> Result<(), ClusterCliError> {
+        // values are written to temporary files so they don't appear in process arguments,
+        // key is used as file name
+        let dir = tempfile::tempdir()?;
+        for (key, value) in values {
+            validate_secret_key(key)?;
+            fs::write(dir.path().join(key), value)?;
+        }
+
+        self.delete(object_name)?;
+        Command::new("kubectl")
+            .args(&["create", "secret", "generic", object_name])
+            .arg(format!("--from-file={}", dir.path().display()))
+            .args(&["--namespace", &self.namespace])
+            .result()
+            .map_err(|err| ClusterCliError::Other(err.to_string()))?;
+        debug!(object_name, "stored secret");
+        Ok(())
+    }
+
+    /// remove secret object, if it exists
+    pub fn delete(&self, object_name: &str) -> Result<(), ClusterCliError> {
+        Command::new("kubectl")
+            .args(&["delete", "secret", object_name, "--ignore-not-found=true"])
+            .args(&["--namespace", &self.namespace])
+            .result()
+            .map_err(|err| ClusterCliError::Other(err.to_string()))?;
+        Ok(())
+    }
+}
+
+/// key of Kubernetes secret must consist of alphanumeric characters, `-`, `_` or `.`
+pub(crate) fn validate_secret_key(key: &str) -> Result<(), ClusterCliError> {
+    let valid = !key.is_empty()
+        && key != "."
+        && key != ".."
+        && key
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_' || c == '.');
+    if valid {
+        Ok(())
+    } else {
+        Err(ClusterCliError::Other(format!(
+            "invalid secret name: {}",
+            key
+        )))
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    use super::validate_secret_key;
+
+    #[test]
+    fn test_validate_secret_key() {
+        assert!(validate_secret_key("token").is_ok());
+        assert!(validate_secret_key("api.key-1_a").is_ok());
+        assert!(validate_secret_key("").is_err());
+        assert!(validate_secret_key(".").is_err());
+        assert!(validate_secret_key("..").is_err());
+        assert!(validate_secret_key("../token").is_err());
+        assert!(validate_secret_key("/etc/passwd").is_err());
+    }
+}
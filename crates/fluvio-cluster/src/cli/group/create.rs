@@ -34,6 +34,15 @@ pub struct CreateManagedSpuGroupOpt {
     #[structopt(long, value_name = "string")]
     pub rack: Option<String>,
 
+    /// Rack name for each SPU in the group, assigned round robin
+    #[structopt(
+        long,
+        value_name = "string",
+        use_delimiter = true,
+        conflicts_with = "rack"
+    )]
+    pub racks: Vec<String>,
+
     /// The amount of storage to assign to this SPG
     #[structopt(long, value_name = "string")]
     pub storage_size: Option<String>,
@@ -60,6 +69,7 @@ impl CreateManagedSpuGroupOpt {
         let spu_config = SpuConfig {
             storage,
             rack: self.rack,
+            racks: self.racks,
             ..Default::default()
         };
 
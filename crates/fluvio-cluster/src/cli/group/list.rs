@@ -101,14 +101,16 @@ mod output {
                 .map(|r| {
                     let spec = &r.spec;
                     let storage_config = spec.spu_config.real_storage_config();
+                    let rack = if spec.spu_config.racks.is_empty() {
+                        spec.spu_config.rack.clone().unwrap_or_default()
+                    } else {
+                        spec.spu_config.racks.join(",")
+                    };
                     Row::new(vec![
                         Cell::new_align(&r.name, Alignment::RIGHT),
                         Cell::new_align(&spec.replicas.to_string(), Alignment::CENTER),
                         Cell::new_align(&r.spec.min_id.to_string(), Alignment::RIGHT),
-                        Cell::new_align(
-                            &spec.spu_config.rack.clone().unwrap_or_default(),
-                            Alignment::RIGHT,
-                        ),
+                        Cell::new_align(&rack, Alignment::RIGHT),
                         Cell::new_align(&storage_config.size, Alignment::RIGHT),
                         Cell::new_align(&r.status.to_string(), Alignment::RIGHT),
                     ])
@@ -12,6 +12,8 @@ mod check;
 mod error;
 mod diagnostics;
 mod connector;
+mod acl;
+mod quota;
 
 use start::StartOpt;
 use start::UpgradeOpt;
@@ -20,9 +22,12 @@ use check::CheckOpt;
 use group::SpuGroupCmd;
 use spu::SpuCmd;
 use connector::ManagedConnectorCmd;
+use acl::AclCmd;
+use quota::QuotaCmd;
 use diagnostics::DiagnosticsOpt;
 
 pub use self::error::ClusterCliError;
+pub use connector::SecretStore;
 
 use fluvio_extension_common as common;
 use common::target::ClusterTarget;
@@ -65,6 +70,18 @@ pub enum ClusterCmd {
     #[structopt(name = "connector")]
     ManagedConnector(ManagedConnectorCmd),
 
+    /// List and test permissions of principals
+    ///
+    /// Permissions are evaluated by the SC against its authorization policy.
+    #[structopt(name = "acl")]
+    Acl(AclCmd),
+
+    /// Manage and view produce and fetch throughput quotas
+    ///
+    /// Clients exceeding their quota are throttled by the SPUs.
+    #[structopt(name = "quota")]
+    Quota(QuotaCmd),
+
     /// Collect anonymous diagnostic information to help with debugging
     #[structopt(name = "diagnostics")]
     Diagnostics(DiagnosticsOpt),
@@ -103,6 +120,14 @@ impl ClusterCmd {
                 let fluvio = target.connect().await?;
                 group.process(out, &fluvio).await?;
             }
+            Self::Acl(acl) => {
+                let fluvio = target.connect().await?;
+                acl.process(out, &fluvio).await?;
+            }
+            Self::Quota(quota) => {
+                let fluvio = target.connect().await?;
+                quota.process(out, &fluvio).await?;
+            }
             Self::Diagnostics(opt) => {
                 opt.process().await?;
             }
@@ -0,0 +1,67 @@
+//!
+//! # Create a Quota
+//!
+//! CLI tree to generate Create a Quota
+//!
+
+use structopt::StructOpt;
+use tracing::debug;
+
+use fluvio::Fluvio;
+use fluvio::metadata::quota::QuotaSpec;
+
+use crate::cli::ClusterCliError;
+
+// -----------------------------------
+// CLI Options
+// -----------------------------------
+
+#[derive(Debug, StructOpt, Default)]
+pub struct CreateQuotaOpt {
+    /// The name for the new Quota
+    #[structopt(value_name = "name")]
+    pub name: String,
+
+    /// Principal the quota applies to, all principals if not set
+    #[structopt(long, value_name = "principal")]
+    pub principal: Option<String>,
+
+    /// Client id the quota applies to, all client ids if not set
+    #[structopt(long, value_name = "client id")]
+    pub client_id: Option<String>,
+
+    /// Max bytes per second produced by matching clients
+    #[structopt(long, value_name = "bytes")]
+    pub produce_byte_rate: Option<u64>,
+
+    /// Max bytes per second fetched by matching clients
+    #[structopt(long, value_name = "bytes")]
+    pub fetch_byte_rate: Option<u64>,
+
+    /// Max produce and fetch requests per second of matching clients
+    #[structopt(long, value_name = "requests")]
+    pub request_rate: Option<u32>,
+
+    /// Validate quota without creating it
+    #[structopt(long)]
+    pub dry_run: bool,
+}
+
+impl CreateQuotaOpt {
+    pub async fn process(self, fluvio: &Fluvio) -> Result<(), ClusterCliError> {
+        let spec = QuotaSpec {
+            principal: self.principal,
+            client_id: self.client_id,
+            produce_byte_rate: self.produce_byte_rate,
+            fetch_byte_rate: self.fetch_byte_rate,
+            request_rate: self.request_rate,
+        };
+
+        debug!("creating quota: {}, spec: {:#?}", self.name, spec);
+
+        let admin = fluvio.admin().await;
+        admin.create(self.name, self.dry_run, spec).await?;
+
+        Ok(())
+    }
+}
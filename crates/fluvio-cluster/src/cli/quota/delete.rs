@@ -0,0 +1,30 @@
+//!
+//! # Delete Quotas
+//!
+//! CLI tree to generate Delete Quotas
+//!
+use structopt::StructOpt;
+
+use fluvio::Fluvio;
+use fluvio::metadata::quota::QuotaSpec;
+
+use crate::cli::ClusterCliError;
+
+// -----------------------------------
+// CLI Options
+// -----------------------------------
+
+#[derive(Debug, StructOpt)]
+pub struct DeleteQuotaOpt {
+    /// The name of the quota to delete
+    #[structopt(value_name = "name")]
+    name: String,
+}
+
+impl DeleteQuotaOpt {
+    pub async fn process(self, fluvio: &Fluvio) -> Result<(), ClusterCliError> {
+        let admin = fluvio.admin().await;
+        admin.delete::<QuotaSpec, _>(&self.name).await?;
+        Ok(())
+    }
+}
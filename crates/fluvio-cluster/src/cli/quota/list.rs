@@ -0,0 +1,131 @@
+//! # List Quotas CLI
+//!
+//! CLI tree and processing to list Quotas
+//!
+
+use std::sync::Arc;
+use structopt::StructOpt;
+
+use fluvio::Fluvio;
+use fluvio::metadata::quota::QuotaSpec;
+
+use fluvio_extension_common::Terminal;
+use fluvio_extension_common::OutputFormat;
+use crate::cli::ClusterCliError;
+
+#[derive(Debug, StructOpt)]
+pub struct ListQuotasOpt {
+    #[structopt(flatten)]
+    output: OutputFormat,
+}
+
+impl ListQuotasOpt {
+    /// Process list quotas cli request
+    pub async fn process<O: Terminal>(
+        self,
+        out: Arc<O>,
+        fluvio: &Fluvio,
+    ) -> Result<(), ClusterCliError> {
+        let admin = fluvio.admin().await;
+        let lists = admin.list::<QuotaSpec, _>(vec![]).await?;
+
+        output::quotas_response_to_output(out, lists, self.output.format)
+    }
+}
+
+mod output {
+
+    //!
+    //! # Fluvio SC - output processing
+    //!
+    //! Format Quotas response based on output type
+
+    use prettytable::Row;
+    use prettytable::row;
+    use prettytable::Cell;
+    use prettytable::cell;
+    use prettytable::format::Alignment;
+    use tracing::debug;
+    use serde::Serialize;
+    use fluvio_extension_common::output::OutputType;
+    use fluvio_extension_common::Terminal;
+
+    use fluvio::metadata::objects::Metadata;
+    use fluvio::metadata::quota::QuotaSpec;
+
+    use crate::cli::error::ClusterCliError;
+    use fluvio_extension_common::output::TableOutputHandler;
+    use fluvio_extension_common::t_println;
+
+    #[derive(Serialize)]
+    struct ListQuotas(Vec<Metadata<QuotaSpec>>);
+
+    // -----------------------------------
+    // Format Output
+    // -----------------------------------
+
+    /// Format Quotas based on output type
+    pub fn quotas_response_to_output<O: Terminal>(
+        out: std::sync::Arc<O>,
+        list_quotas: Vec<Metadata<QuotaSpec>>,
+        output_type: OutputType,
+    ) -> Result<(), ClusterCliError> {
+        debug!("quotas: {:#?}", list_quotas);
+
+        if !list_quotas.is_empty() {
+            let quotas = ListQuotas(list_quotas);
+            out.render_list(&quotas, output_type)?;
+            Ok(())
+        } else {
+            t_println!(out, "no quotas");
+            Ok(())
+        }
+    }
+
+    fn display<T: ToString>(value: &Option<T>) -> String {
+        value
+            .as_ref()
+            .map(|value| value.to_string())
+            .unwrap_or_else(|| "*".to_owned())
+    }
+
+    // -----------------------------------
+    // Output Handlers
+    // -----------------------------------
+    impl TableOutputHandler for ListQuotas {
+        /// table header implementation
+        fn header(&self) -> Row {
+            row![
+                "NAME",
+                "PRINCIPAL",
+                "CLIENT-ID",
+                "PRODUCE-BYTE-RATE",
+                "FETCH-BYTE-RATE",
+                "REQUEST-RATE",
+            ]
+        }
+
+        /// return errors in string format
+        fn errors(&self) -> Vec<String> {
+            self.0.iter().map(|_g| "".to_owned()).collect()
+        }
+
+        /// table content implementation
+        fn content(&self) -> Vec<Row> {
+            self.0
+                .iter()
+                .map(|r| {
+                    let spec = &r.spec;
+                    Row::new(vec![
+                        Cell::new_align(&r.name, Alignment::RIGHT),
+                        Cell::new_align(&display(&spec.principal), Alignment::RIGHT),
+                        Cell::new_align(&display(&spec.client_id), Alignment::RIGHT),
+                        Cell::new_align(&display(&spec.produce_byte_rate), Alignment::RIGHT),
+                        Cell::new_align(&display(&spec.fetch_byte_rate), Alignment::RIGHT),
+                        Cell::new_align(&display(&spec.request_rate), Alignment::RIGHT),
+                    ])
+                })
+                .collect()
+        }
+    }
+}
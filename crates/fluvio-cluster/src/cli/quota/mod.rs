@@ -0,0 +1,60 @@
+use std::sync::Arc;
+use structopt::StructOpt;
+
+mod create;
+mod delete;
+mod list;
+
+use fluvio::Fluvio;
+use fluvio_extension_common::Terminal;
+use fluvio_extension_common::COMMAND_TEMPLATE;
+
+use create::CreateQuotaOpt;
+use delete::DeleteQuotaOpt;
+use list::ListQuotasOpt;
+use crate::cli::ClusterCliError;
+
+#[derive(Debug, StructOpt)]
+pub enum QuotaCmd {
+    /// Create a new Quota
+    #[structopt(
+        name = "create",
+        template = COMMAND_TEMPLATE,
+    )]
+    Create(CreateQuotaOpt),
+
+    /// Delete a Quota
+    #[structopt(
+        name = "delete",
+        template = COMMAND_TEMPLATE,
+    )]
+    Delete(DeleteQuotaOpt),
+
+    /// List all Quotas
+    #[structopt(
+        name = "list",
+        template = COMMAND_TEMPLATE,
+    )]
+    List(ListQuotasOpt),
+}
+
+impl QuotaCmd {
+    pub async fn process<O: Terminal>(
+        self,
+        out: Arc<O>,
+        fluvio: &Fluvio,
+    ) -> Result<(), ClusterCliError> {
+        match self {
+            Self::Create(create) => {
+                create.process(fluvio).await?;
+            }
+            Self::Delete(delete) => {
+                delete.process(fluvio).await?;
+            }
+            Self::List(list) => {
+                list.process(out, fluvio).await?;
+            }
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,91 @@
+//!
+//! # Drain SPU
+//!
+//! CLI tree to move replicas and leaderships out of SPU before it is removed,
+//! or to stop draining it
+//!
+
+use std::sync::Arc;
+use structopt::StructOpt;
+use tracing::debug;
+
+use fluvio::Fluvio;
+
+use crate::cli::ClusterCliError;
+use crate::cli::common::output::Terminal;
+use crate::cli::common::t_println;
+
+#[derive(Debug, StructOpt)]
+pub struct DrainSpuOpt {
+    /// SPU id
+    #[structopt(value_name = "id")]
+    id: i32,
+
+    /// Only show planned replica moves, SPU is not drained
+    #[structopt(long)]
+    dry_run: bool,
+}
+
+impl DrainSpuOpt {
+    pub async fn process<O: Terminal>(
+        self,
+        out: Arc<O>,
+        fluvio: &Fluvio,
+    ) -> Result<(), ClusterCliError> {
+        debug!("draining spu: {}, dry run: {}", self.id, self.dry_run);
+        let admin = fluvio.admin().await;
+        let moves = admin.drain_spu(self.id, self.dry_run).await?;
+
+        if moves.is_empty() {
+            t_println!(out, "spu {} has no replicas to move", self.id);
+        }
+
+        for replica_move in &moves {
+            t_println!(
+                out,
+                "partition \"{}\": replica spu {} -> {}, leader: {}",
+                replica_move.partition,
+                replica_move.from,
+                replica_move.to,
+                replica_move.leader
+            );
+        }
+
+        if self.dry_run {
+            t_println!(out, "dry run, spu {} was not drained", self.id);
+        } else {
+            t_println!(
+                out,
+                "spu {} is draining, it can be removed once it holds no replicas",
+                self.id
+            );
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(Debug, StructOpt)]
+pub struct UndrainSpuOpt {
+    /// SPU id
+    #[structopt(value_name = "id")]
+    id: i32,
+}
+
+impl UndrainSpuOpt {
+    pub async fn process<O: Terminal>(
+        self,
+        out: Arc<O>,
+        fluvio: &Fluvio,
+    ) -> Result<(), ClusterCliError> {
+        debug!("undraining spu: {}", self.id);
+        let admin = fluvio.admin().await;
+        admin.undrain_spu(self.id).await?;
+        t_println!(
+            out,
+            "spu {} is no longer draining, replicas already moved are not moved back",
+            self.id
+        );
+        Ok(())
+    }
+}
@@ -5,6 +5,7 @@ mod list;
 mod display;
 mod register;
 mod unregister;
+mod drain;
 
 use fluvio::Fluvio;
 // pub use display::*;
@@ -15,6 +16,7 @@ use super::common::output::Terminal;
 use list::ListSpusOpt;
 use register::RegisterCustomSpuOpt;
 use unregister::UnregisterCustomSpuOpt;
+use drain::{DrainSpuOpt, UndrainSpuOpt};
 
 #[derive(Debug, StructOpt)]
 pub enum SpuCmd {
@@ -38,6 +40,20 @@ pub enum SpuCmd {
         template = COMMAND_TEMPLATE,
     )]
     List(ListSpusOpt),
+
+    /// Move all replicas and leaderships out of SPU so it can be removed
+    #[structopt(
+        name = "drain",
+        template = COMMAND_TEMPLATE,
+    )]
+    Drain(DrainSpuOpt),
+
+    /// Stop draining SPU so it can receive replicas again
+    #[structopt(
+        name = "undrain",
+        template = COMMAND_TEMPLATE,
+    )]
+    Undrain(UndrainSpuOpt),
 }
 
 impl SpuCmd {
@@ -56,6 +72,12 @@ impl SpuCmd {
             Self::List(list) => {
                 list.process(out, fluvio).await?;
             }
+            Self::Drain(drain) => {
+                drain.process(out, fluvio).await?;
+            }
+            Self::Undrain(undrain) => {
+                undrain.process(out, fluvio).await?;
+            }
         }
         Ok(())
     }
@@ -15,6 +15,11 @@ pub async fn process_k8(
     upgrade: bool,
     skip_sys: bool,
 ) -> Result<(), ClusterCliError> {
+    if opt.tls.spu_cert_dir.is_some() {
+        return Err(ClusterCliError::Other(
+            "SPU certificates are only supported by local cluster".to_string(),
+        ));
+    }
     let (client, server): (TlsPolicy, TlsPolicy) = opt.tls.try_into()?;
 
     let mut builder = ClusterConfig::builder(platform_version);
@@ -31,6 +31,10 @@ pub async fn process_local(
         builder.rust_log(rust_log);
     }
 
+    if let Some(spu_cert_dir) = &opt.tls.spu_cert_dir {
+        builder.spu_cert_dir(spu_cert_dir);
+    }
+
     if opt.tls.tls {
         let (client, server): (TlsPolicy, TlsPolicy) = opt.tls.try_into()?;
         builder.tls(client, server);
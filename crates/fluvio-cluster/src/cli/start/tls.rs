@@ -37,6 +37,11 @@ pub struct TlsOpt {
     /// TLS: path to server private key
     #[structopt(long, parse(from_os_str))]
     pub server_key: Option<PathBuf>,
+
+    /// TLS: directory with SPU certificates `spu-<id>.crt` and `spu-<id>.key`,
+    /// enables mutual TLS between SC and SPUs (local cluster only)
+    #[structopt(long, parse(from_os_str), requires = "tls")]
+    pub spu_cert_dir: Option<PathBuf>,
 }
 
 impl TryFrom<TlsOpt> for (TlsPolicy, TlsPolicy) {
@@ -1,5 +1,5 @@
 use std::{
-    fs::File,
+    fs::{File, create_dir_all},
     path::PathBuf,
     process::{Command, Stdio},
 };
@@ -10,8 +10,12 @@ use super::{FluvioLocalProcess, LocalRuntimeError};
 
 pub struct ScProcess {
     pub log_dir: PathBuf,
+    /// transaction state is stored here
+    pub data_dir: PathBuf,
     pub launcher: Option<PathBuf>,
     pub tls_policy: TlsPolicy,
+    /// require SPUs to connect with client cert
+    pub private_tls: bool,
     pub rust_log: String,
 }
 
@@ -29,8 +33,18 @@ impl ScProcess {
             cmd.arg("run").arg("sc").arg("--local");
             cmd
         };
+        create_dir_all(&self.data_dir)?;
+        binary
+            .arg("--transaction-state-file")
+            .arg(self.data_dir.join("sc-transactions.json"));
         if let TlsPolicy::Verified(tls) = &self.tls_policy {
             self.set_server_tls(&mut binary, tls, 9005)?;
+            if self.private_tls {
+                binary
+                    .arg("--enable-private-tls")
+                    .arg("--bind-non-tls-private")
+                    .arg("127.0.0.1:9006");
+            }
         }
         binary.env("RUST_LOG", &self.rust_log);
 
@@ -24,6 +24,8 @@ pub struct LocalSpuProcess {
     pub rust_log: String,
     pub data_dir: PathBuf,
     pub tls_policy: TlsPolicy,
+    /// directory with `spu-<id>.crt` and `spu-<id>.key`, enables private tls
+    pub spu_cert_dir: Option<PathBuf>,
 }
 
 impl FluvioLocalProcess for LocalSpuProcess {}
@@ -44,6 +46,16 @@ impl SpuTarget for LocalSpuProcess {
 
         if let TlsPolicy::Verified(tls) = &self.tls_policy {
             self.set_server_tls(&mut binary, tls, self.spec.private_endpoint.port + 1)?;
+            if let Some(cert_dir) = &self.spu_cert_dir {
+                binary
+                    .arg("--enable-private-tls")
+                    .arg("--private-cert")
+                    .arg(cert_dir.join(format!("spu-{}.crt", self.id)))
+                    .arg("--private-key")
+                    .arg(cert_dir.join(format!("spu-{}.key", self.id)))
+                    .arg("--bind-non-tls-private")
+                    .arg(format!("127.0.0.1:{}", self.spec.private_endpoint.port + 2));
+            }
         }
         binary.env("RUST_LOG", &self.rust_log);
         let cmd = binary
@@ -88,6 +100,7 @@ pub struct LocalSpuProcessClusterManager {
     pub rust_log: String,
     pub data_dir: PathBuf,
     pub tls_policy: TlsPolicy,
+    pub spu_cert_dir: Option<PathBuf>,
 }
 
 impl SpuClusterManager for LocalSpuProcessClusterManager {
@@ -127,6 +140,7 @@ impl SpuClusterManager for LocalSpuProcessClusterManager {
             rust_log: self.rust_log.clone(),
             launcher: self.launcher.clone(),
             tls_policy: self.tls_policy.clone(),
+            spu_cert_dir: self.spu_cert_dir.clone(),
             data_dir: self.data_dir.clone(),
         })
     }
@@ -126,6 +126,23 @@ pub struct LocalConfig {
     /// The TLS policy for the client
     #[builder(private, default = "DEFAULT_TLS_POLICY")]
     client_tls_policy: TlsPolicy,
+    /// Directory with certificates of SPUs, named `spu-<id>.crt` and `spu-<id>.key`.
+    ///
+    /// If set with verified TLS policy, SC and SPUs connect to each other with mutual TLS.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use fluvio_cluster::{ClusterError, LocalConfigBuilder};
+    /// # fn example(builder: &mut LocalConfigBuilder) -> Result<(), ClusterError> {
+    /// let config = builder
+    ///     .spu_cert_dir("/tmp/certs/spu")
+    ///     .build()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[builder(setter(into, strip_option), default)]
+    spu_cert_dir: Option<PathBuf>,
     /// The version of the Fluvio system chart to install
     ///
     /// This is the only required field that does not have a default value.
@@ -234,6 +251,7 @@ impl LocalConfig {
             rust_log: self.rust_log.clone(),
             launcher: self.launcher.clone(),
             tls_policy: self.server_tls_policy.clone(),
+            spu_cert_dir: self.spu_cert_dir.clone(),
             data_dir: self.data_dir.clone(),
         }
     }
@@ -497,8 +515,10 @@ impl LocalInstaller {
 
         let sc_process = ScProcess {
             log_dir: self.config.log_dir.clone(),
+            data_dir: self.config.data_dir.clone(),
             launcher: self.config.launcher.clone(),
             tls_policy: self.config.server_tls_policy.clone(),
+            private_tls: self.config.spu_cert_dir.is_some(),
             rust_log: self.config.rust_log.clone(),
         };
 
@@ -3,10 +3,11 @@
 //!
 //! Interface to the Managed Connector metadata spec in K8 key value store
 //!
-use serde::Deserialize;
+use serde::{Deserialize, Deserializer};
 use serde::Serialize;
+use tracing::warn;
 
-use super::super::ManagedConnectorStatus;
+use super::super::{ManagedConnectorStatus, ManagedConnectorSecret};
 use crate::k8_types::{Spec, Crd, DefaultHeader};
 use std::collections::BTreeMap;
 
@@ -42,7 +43,40 @@ pub struct K8ManagedConnectorSpec {
     pub type_: String, // syslog, github star, slack
     pub topic: String,
     pub parameters: BTreeMap<String, String>,
-    pub secrets: BTreeMap<String, String>,
+    #[serde(deserialize_with = "deserialize_secrets")]
+    pub secrets: BTreeMap<String, ManagedConnectorSecret>,
+}
+
+/// Secret entry as stored by previous versions, which kept values inline
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum SecretEntry {
+    Reference(ManagedConnectorSecret),
+    Inline(String),
+}
+
+/// Inline secret values were never passed to connectors, so they are dropped.
+/// Connector must be recreated to store them in a secret object.
+fn deserialize_secrets<'de, D>(
+    deserializer: D,
+) -> Result<BTreeMap<String, ManagedConnectorSecret>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let entries: BTreeMap<String, SecretEntry> = BTreeMap::deserialize(deserializer)?;
+    Ok(entries
+        .into_iter()
+        .filter_map(|(parameter, entry)| match entry {
+            SecretEntry::Reference(secret) => Some((parameter, secret)),
+            SecretEntry::Inline(_) => {
+                warn!(
+                    %parameter,
+                    "ignoring inline connector secret, recreate connector to store it in secret object"
+                );
+                None
+            }
+        })
+        .collect())
 }
 mod convert {
 
@@ -74,3 +108,33 @@ mod convert {
         }
     }
 }
+
+#[cfg(test)]
+mod test {
+
+    use super::*;
+
+    #[test]
+    fn test_legacy_inline_secrets() {
+        let spec: K8ManagedConnectorSpec = serde_json::from_str(
+            r#"{
+                "name": "github",
+                "type": "github-stars",
+                "topic": "stars",
+                "secrets": {
+                    "token": "plain",
+                    "key": { "name": "fluvio-connector-github", "key": "key" }
+                }
+            }"#,
+        )
+        .expect("parse");
+        assert_eq!(spec.secrets.len(), 1);
+        assert_eq!(
+            spec.secrets.get("key"),
+            Some(&ManagedConnectorSecret::new(
+                "fluvio-connector-github",
+                "key"
+            ))
+        );
+    }
+}
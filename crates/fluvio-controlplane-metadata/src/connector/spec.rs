@@ -17,5 +17,36 @@ pub struct ManagedConnectorSpec {
 
     pub topic: String,
     pub parameters: BTreeMap<String, String>,
-    pub secrets: BTreeMap<String, String>,
+    /// parameters whose values are read from secret objects, values are never stored in spec
+    pub secrets: BTreeMap<String, ManagedConnectorSecret>,
+}
+
+/// Reference to value in secret object
+#[derive(Encoder, Decoder, Default, Debug, PartialEq, Clone)]
+#[cfg_attr(
+    feature = "use_serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "camelCase")
+)]
+pub struct ManagedConnectorSecret {
+    /// name of secret object
+    pub name: String,
+    /// key of value in secret object
+    pub key: String,
+}
+
+impl ManagedConnectorSecret {
+    pub fn new(name: impl Into<String>, key: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            key: key.into(),
+        }
+    }
+}
+
+impl ManagedConnectorSpec {
+    /// name of secret object which holds secrets of connector created by CLI
+    pub fn secret_object_name(connector: &str) -> String {
+        format!("fluvio-connector-{}", connector)
+    }
 }
@@ -4,6 +4,8 @@ pub mod partition;
 pub mod spg;
 pub mod message;
 pub mod connector;
+pub mod quota;
+pub mod schema;
 
 pub use fluvio_stream_model::core;
 
@@ -27,6 +29,30 @@ pub mod extended {
         Topic,
         Partition,
         ManagedConnector,
+        Quota,
+        Schema,
+        Transaction,
+    }
+
+    impl std::str::FromStr for ObjectType {
+        type Err = String;
+
+        fn from_str(s: &str) -> Result<Self, Self::Err> {
+            match s.to_lowercase().replace('_', "-").as_str() {
+                "spu" => Ok(Self::Spu),
+                "custom-spu" | "customspu" => Ok(Self::CustomSpu),
+                "spu-group" | "spugroup" | "spg" => Ok(Self::SpuGroup),
+                "topic" => Ok(Self::Topic),
+                "partition" => Ok(Self::Partition),
+                "managed-connector" | "managedconnector" | "connector" => {
+                    Ok(Self::ManagedConnector)
+                }
+                "quota" => Ok(Self::Quota),
+                "schema" => Ok(Self::Schema),
+                "transaction" | "transactional-id" => Ok(Self::Transaction),
+                _ => Err(format!("unknown object type: {}", s)),
+            }
+        }
     }
 
     pub trait SpecExt: Spec {
@@ -30,7 +30,7 @@ impl ::std::default::Default for MsgType {
 #[derive(Decoder, Encoder, Debug, PartialEq, Clone, Default)]
 pub struct Message<C>
 where
-    C: Encoder + Decoder + Debug,
+    C: Encoder + Decoder + Default + Debug,
 {
     pub header: MsgType,
     pub content: C,
@@ -38,7 +38,7 @@ where
 
 impl<C> fmt::Display for Message<C>
 where
-    C: Encoder + Decoder + Debug + Display,
+    C: Encoder + Decoder + Default + Debug + Display,
 {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "{:#?} {}", self.header, self.content)
@@ -47,7 +47,7 @@ where
 
 impl<C> Message<C>
 where
-    C: Encoder + Decoder + Debug,
+    C: Encoder + Decoder + Default + Debug,
 {
     pub fn new(typ: MsgType, content: C) -> Self {
         Message {
@@ -81,7 +81,7 @@ where
     S: Spec,
     S::Status: PartialEq,
     C: MetadataItem,
-    D: Encoder + Decoder + Debug,
+    D: Encoder + Decoder + Default + Debug,
     D: From<MetadataStoreObject<S, C>>,
 {
     fn from(change: LSChange<S, C>) -> Self {
@@ -1,5 +1,40 @@
+use dataplane::core::{Encoder, Decoder};
+
 use super::ReplicaStatus;
 
+/// Replication settings of a topic, copied into each of its partitions
+#[derive(Decoder, Encoder, Debug, Clone, PartialEq)]
+#[cfg_attr(
+    feature = "use_serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "camelCase", default)
+)]
+pub struct ReplicationPolicy {
+    /// allow out of sync follower to become leader, at the cost of losing records
+    pub unclean_leader_election: bool,
+    /// only follower which has caught up with leader can become leader,
+    /// otherwise follower lagging by a few records is also eligible
+    pub strict_leader_election: bool,
+    /// minimum number of in sync replicas (including leader) required to accept records
+    pub min_in_sync_replicas: u16,
+}
+
+impl Default for ReplicationPolicy {
+    fn default() -> Self {
+        Self {
+            unclean_leader_election: false,
+            strict_leader_election: false,
+            min_in_sync_replicas: 1,
+        }
+    }
+}
+
+impl ReplicationPolicy {
+    pub fn is_default(&self) -> bool {
+        self == &Self::default()
+    }
+}
+
 pub enum ElectionScoring {
     NotSuitable,
     Score(u16), // 0 is perfect
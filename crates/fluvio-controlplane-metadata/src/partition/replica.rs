@@ -8,6 +8,8 @@ use crate::partition::ReplicaKey;
 use crate::core::{MetadataItem};
 use crate::store::MetadataStoreObject;
 use crate::partition::PartitionSpec;
+use crate::partition::ReplicationPolicy;
+use crate::topic::TopicConfig;
 use super::store::*;
 
 /// Metadata about Replica send from SC
@@ -17,6 +19,10 @@ pub struct Replica {
     pub leader: SpuId,
     pub replicas: Vec<SpuId>,
     pub is_being_deleted: bool,
+    #[fluvio(min_version = 1)]
+    pub replication_policy: ReplicationPolicy,
+    #[fluvio(min_version = 2)]
+    pub config: TopicConfig,
 }
 
 impl Replica {
@@ -35,6 +41,8 @@ impl Replica {
             leader,
             replicas,
             is_being_deleted,
+            replication_policy: ReplicationPolicy::default(),
+            config: TopicConfig::default(),
         }
     }
 }
@@ -53,6 +61,8 @@ where
             leader: inner.spec.leader,
             replicas: inner.spec.replicas,
             is_being_deleted,
+            replication_policy: inner.spec.replication_policy,
+            config: inner.spec.config,
         }
     }
 }
@@ -7,6 +7,10 @@
 use fluvio_types::SpuId;
 use dataplane::core::{Encoder, Decoder};
 
+use crate::topic::TopicConfig;
+
+use super::ReplicationPolicy;
+
 /// Spec for Partition
 /// Each partition has replicas spread among SPU
 /// one of replica is leader which is duplicated in the leader field
@@ -19,6 +23,18 @@ use dataplane::core::{Encoder, Decoder};
 pub struct PartitionSpec {
     pub leader: SpuId,
     pub replicas: Vec<SpuId>,
+    #[fluvio(min_version = 2)]
+    #[cfg_attr(
+        feature = "use_serde",
+        serde(default, skip_serializing_if = "ReplicationPolicy::is_default")
+    )]
+    pub replication_policy: ReplicationPolicy,
+    #[fluvio(min_version = 3)]
+    #[cfg_attr(
+        feature = "use_serde",
+        serde(default, skip_serializing_if = "TopicConfig::is_empty")
+    )]
+    pub config: TopicConfig,
 }
 
 impl std::default::Default for PartitionSpec {
@@ -26,19 +42,46 @@ impl std::default::Default for PartitionSpec {
         PartitionSpec {
             leader: 0,
             replicas: Vec::default(),
+            replication_policy: ReplicationPolicy::default(),
+            config: TopicConfig::default(),
         }
     }
 }
 
 impl PartitionSpec {
     pub fn new(leader: SpuId, replicas: Vec<SpuId>) -> Self {
-        Self { leader, replicas }
+        Self {
+            leader,
+            replicas,
+            replication_policy: ReplicationPolicy::default(),
+            config: TopicConfig::default(),
+        }
+    }
+
+    pub fn set_replication_policy(mut self, policy: ReplicationPolicy) -> Self {
+        self.replication_policy = policy;
+        self
+    }
+
+    pub fn set_config(mut self, config: TopicConfig) -> Self {
+        self.config = config;
+        self
     }
 
     pub fn has_spu(&self, spu: &SpuId) -> bool {
         self.replicas.contains(spu)
     }
 
+    /// preferred leader, which is first replica in the replica map
+    pub fn preferred_leader(&self) -> Option<SpuId> {
+        self.replicas.first().copied()
+    }
+
+    /// check if current leader is preferred leader
+    pub fn is_preferred_leader(&self) -> bool {
+        self.preferred_leader() == Some(self.leader)
+    }
+
     /// follower replicas
     pub fn followers(&self) -> Vec<SpuId> {
         self.replicas
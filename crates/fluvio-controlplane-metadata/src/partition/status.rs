@@ -106,6 +106,24 @@ impl PartitionStatus {
         !self.replicas.is_empty()
     }
 
+    /// find replica status for spu, including leader
+    pub fn replica_status(&self, spu: SpuId) -> Option<&ReplicaStatus> {
+        if self.leader.spu == spu {
+            Some(&self.leader)
+        } else {
+            self.replicas.iter().find(|status| status.spu == spu)
+        }
+    }
+
+    /// check if replica has caught up with leader.
+    /// this is same criteria used to compute lrs
+    pub fn is_in_sync(&self, spu: SpuId) -> bool {
+        match self.replica_status(spu) {
+            Some(status) => status.leo != -1 && status.leo == self.leader.leo,
+            None => false,
+        }
+    }
+
     /// set to being deleted
     pub fn set_to_delete(mut self) -> Self {
         self.is_being_deleted = true;
@@ -376,6 +394,24 @@ mod test {
         assert!(status.candidate_leader(&online_spu, &policy).is_none());
     }
 
+    #[test]
+    fn test_replica_in_sync() {
+        let status = PartitionStatus::new(
+            (5000, 100, 110),
+            vec![
+                (5001, 100, 110).into(), // caught up with leader
+                (5002, 100, 105).into(), // behind leader
+                (5003, -1, -1).into(),   // unknown
+            ],
+        );
+
+        assert!(status.is_in_sync(5000));
+        assert!(status.is_in_sync(5001));
+        assert!(!status.is_in_sync(5002));
+        assert!(!status.is_in_sync(5003));
+        assert!(!status.is_in_sync(5004));
+    }
+
     #[test]
     fn test_merge_initial() {
         let mut target = PartitionStatus::default();
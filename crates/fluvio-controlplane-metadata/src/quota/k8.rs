@@ -0,0 +1,24 @@
+use crate::k8_types::{Crd, GROUP, V1, CrdNames, Spec, Status, DefaultHeader};
+
+use super::QuotaStatus;
+use super::QuotaSpec;
+
+const QUOTA_API: Crd = Crd {
+    group: GROUP,
+    version: V1,
+    names: CrdNames {
+        kind: "Quota",
+        plural: "quotas",
+        singular: "quota",
+    },
+};
+
+impl Spec for QuotaSpec {
+    type Header = DefaultHeader;
+    type Status = QuotaStatus;
+    fn metadata() -> &'static Crd {
+        &QUOTA_API
+    }
+}
+
+impl Status for QuotaStatus {}
@@ -0,0 +1,93 @@
+#![allow(clippy::assign_op_pattern)]
+
+//!
+//! # Quota Spec
+//!
+//! Throughput limits of clients, enforced by SPUs on produce and fetch.
+//!
+use dataplane::core::{Encoder, Decoder};
+
+/// Quota applies to clients which match both principal and client id.
+///
+/// If only principal or only client id is set, every matching client shares the quota.
+/// If neither is set, quota is default for every principal and client id pair
+/// which doesn't match any other quota.
+#[derive(Encoder, Decoder, Default, Debug, Clone, PartialEq)]
+#[cfg_attr(
+    feature = "use_serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "camelCase", default)
+)]
+pub struct QuotaSpec {
+    /// principal of authenticated client, none matches every principal
+    pub principal: Option<String>,
+    /// client id of request header, none matches every client id
+    pub client_id: Option<String>,
+    /// max bytes per second of produced records
+    pub produce_byte_rate: Option<u64>,
+    /// max bytes per second of fetched records
+    pub fetch_byte_rate: Option<u64>,
+    /// max produce and fetch requests per second
+    pub request_rate: Option<u32>,
+}
+
+impl QuotaSpec {
+    /// true if quota applies to principal and client id
+    pub fn matches(&self, principal: Option<&str>, client_id: &str) -> bool {
+        let principal_match = match &self.principal {
+            Some(quota_principal) => principal == Some(quota_principal.as_str()),
+            None => true,
+        };
+        let client_match = match &self.client_id {
+            Some(quota_client_id) => quota_client_id == client_id,
+            None => true,
+        };
+        principal_match && client_match
+    }
+
+    /// more specific quota wins, principal is more specific than client id
+    pub fn precedence(&self) -> u8 {
+        match (&self.principal, &self.client_id) {
+            (Some(_), Some(_)) => 3,
+            (Some(_), None) => 2,
+            (None, Some(_)) => 1,
+            (None, None) => 0,
+        }
+    }
+
+    /// default quota is applied to each client separately
+    pub fn is_default(&self) -> bool {
+        self.principal.is_none() && self.client_id.is_none()
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    use super::QuotaSpec;
+
+    #[test]
+    fn test_quota_match() {
+        let alice = QuotaSpec {
+            principal: Some("alice".to_owned()),
+            ..Default::default()
+        };
+        assert!(alice.matches(Some("alice"), "producer"));
+        assert!(!alice.matches(Some("bob"), "producer"));
+        assert!(!alice.matches(None, "producer"));
+
+        let batch = QuotaSpec {
+            principal: Some("alice".to_owned()),
+            client_id: Some("batch".to_owned()),
+            ..Default::default()
+        };
+        assert!(batch.matches(Some("alice"), "batch"));
+        assert!(!batch.matches(Some("alice"), "producer"));
+        assert!(batch.precedence() > alice.precedence());
+
+        let default = QuotaSpec::default();
+        assert!(default.is_default());
+        assert!(default.matches(None, "any"));
+        assert_eq!(default.precedence(), 0);
+    }
+}
@@ -0,0 +1,16 @@
+#![allow(clippy::assign_op_pattern)]
+
+use std::fmt;
+
+use dataplane::core::{Encoder, Decoder};
+
+/// Quota has no status, it is enforced by SPUs as soon as it is created
+#[derive(Encoder, Decoder, Default, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "use_serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct QuotaStatus {}
+
+impl fmt::Display for QuotaStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Active")
+    }
+}
@@ -0,0 +1,567 @@
+//!
+//! # Avro schema
+//!
+//! Parsed Avro schema, schema resolution rules used for compatibility
+//! and validation of values in Avro binary encoding.
+//!
+use std::collections::HashMap;
+use std::convert::TryFrom;
+
+use serde_json::{Map, Value};
+
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum Avro {
+    Null,
+    Boolean,
+    Int,
+    Long,
+    Float,
+    Double,
+    Bytes,
+    String,
+    Record {
+        name: String,
+        fields: Vec<Field>,
+    },
+    Enum {
+        name: String,
+        symbols: Vec<String>,
+    },
+    Array(Box<Avro>),
+    Map(Box<Avro>),
+    Union(Vec<Avro>),
+    Fixed {
+        name: String,
+        size: usize,
+    },
+    /// reference to named type
+    Named(String),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct Field {
+    name: String,
+    schema: Avro,
+    has_default: bool,
+}
+
+/// Schema with named types, so references can be resolved
+#[derive(Debug, Default)]
+pub(crate) struct AvroSchema {
+    root: Option<Avro>,
+    named: HashMap<String, Avro>,
+}
+
+impl AvroSchema {
+    pub(crate) fn parse(value: &Value) -> Result<Self, String> {
+        let mut schema = Self::default();
+        let root = schema.parse_type(value)?;
+        schema.root = Some(root);
+        Ok(schema)
+    }
+
+    fn root(&self) -> &Avro {
+        self.root.as_ref().expect("parsed")
+    }
+
+    fn parse_type(&mut self, value: &Value) -> Result<Avro, String> {
+        match value {
+            Value::String(name) => Ok(self.parse_name(name)),
+            Value::Array(branches) => {
+                let branches = branches
+                    .iter()
+                    .map(|branch| self.parse_type(branch))
+                    .collect::<Result<Vec<_>, _>>()?;
+                Ok(Avro::Union(branches))
+            }
+            Value::Object(object) => self.parse_object(object),
+            _ => Err(format!("invalid avro type: {}", value)),
+        }
+    }
+
+    fn parse_name(&self, name: &str) -> Avro {
+        match name {
+            "null" => Avro::Null,
+            "boolean" => Avro::Boolean,
+            "int" => Avro::Int,
+            "long" => Avro::Long,
+            "float" => Avro::Float,
+            "double" => Avro::Double,
+            "bytes" => Avro::Bytes,
+            "string" => Avro::String,
+            other => Avro::Named(other.to_owned()),
+        }
+    }
+
+    fn parse_object(&mut self, object: &Map<String, Value>) -> Result<Avro, String> {
+        let type_value = object
+            .get("type")
+            .ok_or_else(|| "avro type is missing 'type'".to_owned())?;
+        let type_name = match type_value {
+            Value::String(type_name) => type_name.as_str(),
+            // nested type definition
+            other => return self.parse_type(other),
+        };
+
+        let avro = match type_name {
+            "record" | "error" => {
+                let name = required_name(object)?;
+                let fields = object
+                    .get("fields")
+                    .and_then(|fields| fields.as_array())
+                    .ok_or_else(|| format!("record '{}' is missing fields", name))?;
+                let mut parsed = vec![];
+                for field in fields {
+                    let field_name = field
+                        .get("name")
+                        .and_then(|name| name.as_str())
+                        .ok_or_else(|| format!("field of record '{}' is missing name", name))?;
+                    let field_type = field.get("type").ok_or_else(|| {
+                        format!(
+                            "field '{}' of record '{}' is missing type",
+                            field_name, name
+                        )
+                    })?;
+                    parsed.push(Field {
+                        name: field_name.to_owned(),
+                        schema: self.parse_type(field_type)?,
+                        has_default: field.get("default").is_some(),
+                    });
+                }
+                Avro::Record {
+                    name,
+                    fields: parsed,
+                }
+            }
+            "enum" => {
+                let name = required_name(object)?;
+                let symbols = object
+                    .get("symbols")
+                    .and_then(|symbols| symbols.as_array())
+                    .ok_or_else(|| format!("enum '{}' is missing symbols", name))?
+                    .iter()
+                    .map(|symbol| {
+                        symbol
+                            .as_str()
+                            .map(|symbol| symbol.to_owned())
+                            .ok_or_else(|| format!("invalid symbol of enum '{}'", name))
+                    })
+                    .collect::<Result<Vec<_>, _>>()?;
+                Avro::Enum { name, symbols }
+            }
+            "array" => {
+                let items = object
+                    .get("items")
+                    .ok_or_else(|| "array is missing items".to_owned())?;
+                Avro::Array(Box::new(self.parse_type(items)?))
+            }
+            "map" => {
+                let values = object
+                    .get("values")
+                    .ok_or_else(|| "map is missing values".to_owned())?;
+                Avro::Map(Box::new(self.parse_type(values)?))
+            }
+            "fixed" => {
+                let name = required_name(object)?;
+                let size = object
+                    .get("size")
+                    .and_then(|size| size.as_u64())
+                    .ok_or_else(|| format!("fixed '{}' is missing size", name))?;
+                Avro::Fixed {
+                    name,
+                    size: size as usize,
+                }
+            }
+            // primitive with attributes such as logicalType
+            other => return Ok(self.parse_name(other)),
+        };
+
+        if let Some(name) = avro.name() {
+            self.named.insert(name.to_owned(), avro.clone());
+        }
+        Ok(avro)
+    }
+
+    /// check every reference is defined
+    pub(crate) fn check_references(&self) -> Result<(), String> {
+        fn check(schema: &AvroSchema, avro: &Avro) -> Result<(), String> {
+            match avro {
+                Avro::Named(name) if schema.lookup(name).is_none() => {
+                    Err(format!("unknown avro type: {}", name))
+                }
+                Avro::Record { fields, .. } => fields
+                    .iter()
+                    .try_for_each(|field| check(schema, &field.schema)),
+                Avro::Array(items) => check(schema, items),
+                Avro::Map(values) => check(schema, values),
+                Avro::Union(branches) => {
+                    branches.iter().try_for_each(|branch| check(schema, branch))
+                }
+                _ => Ok(()),
+            }
+        }
+        check(self, self.root())
+    }
+
+    /// named type by full name, or by name within namespace
+    fn lookup(&self, name: &str) -> Option<&Avro> {
+        self.named.get(name).or_else(|| {
+            let suffix = format!(".{}", name);
+            self.named
+                .iter()
+                .find(|(full_name, _)| full_name.ends_with(&suffix))
+                .map(|(_, avro)| avro)
+        })
+    }
+
+    fn resolve<'a>(&'a self, avro: &'a Avro) -> &'a Avro {
+        match avro {
+            Avro::Named(name) => self.lookup(name).unwrap_or(avro),
+            _ => avro,
+        }
+    }
+
+    /// true if data written with writer schema can be read with this schema
+    pub(crate) fn can_read(&self, writer: &AvroSchema) -> Result<(), String> {
+        let mut visited = vec![];
+        self.can_read_type(self.root(), writer, writer.root(), &mut visited)
+    }
+
+    fn can_read_type(
+        &self,
+        reader: &Avro,
+        writer_schema: &AvroSchema,
+        writer: &Avro,
+        visited: &mut Vec<String>,
+    ) -> Result<(), String> {
+        // recursive records are compared once
+        if let (Avro::Named(reader_name), Avro::Named(writer_name)) = (reader, writer) {
+            if reader_name == writer_name && visited.contains(reader_name) {
+                return Ok(());
+            }
+        }
+        let reader = self.resolve(reader);
+        let writer = writer_schema.resolve(writer);
+
+        match (reader, writer) {
+            (_, Avro::Union(writer_branches)) => writer_branches
+                .iter()
+                .try_for_each(|branch| self.can_read_type(reader, writer_schema, branch, visited)),
+            (Avro::Union(reader_branches), _) => {
+                if reader_branches.iter().any(|branch| {
+                    self.can_read_type(branch, writer_schema, writer, &mut visited.clone())
+                        .is_ok()
+                }) {
+                    Ok(())
+                } else {
+                    Err(format!(
+                        "union {} can't read {}",
+                        reader.describe(),
+                        writer.describe()
+                    ))
+                }
+            }
+            (
+                Avro::Record {
+                    name: reader_name,
+                    fields: reader_fields,
+                },
+                Avro::Record {
+                    name: writer_name,
+                    fields: writer_fields,
+                },
+            ) => {
+                if reader_name != writer_name {
+                    return Err(format!(
+                        "record name changed from '{}' to '{}'",
+                        writer_name, reader_name
+                    ));
+                }
+                visited.push(reader_name.clone());
+                for reader_field in reader_fields {
+                    match writer_fields
+                        .iter()
+                        .find(|field| field.name == reader_field.name)
+                    {
+                        Some(writer_field) => self
+                            .can_read_type(
+                                &reader_field.schema,
+                                writer_schema,
+                                &writer_field.schema,
+                                visited,
+                            )
+                            .map_err(|err| {
+                                format!("field '{}.{}': {}", reader_name, reader_field.name, err)
+                            })?,
+                        None if reader_field.has_default => {}
+                        None => {
+                            return Err(format!(
+                                "field '{}.{}' is missing in written data and has no default",
+                                reader_name, reader_field.name
+                            ))
+                        }
+                    }
+                }
+                Ok(())
+            }
+            (
+                Avro::Enum {
+                    name: reader_name,
+                    symbols: reader_symbols,
+                },
+                Avro::Enum {
+                    name: writer_name,
+                    symbols: writer_symbols,
+                },
+            ) => {
+                if reader_name != writer_name {
+                    return Err(format!(
+                        "enum name changed from '{}' to '{}'",
+                        writer_name, reader_name
+                    ));
+                }
+                match writer_symbols
+                    .iter()
+                    .find(|symbol| !reader_symbols.contains(symbol))
+                {
+                    Some(symbol) => Err(format!(
+                        "symbol '{}' of enum '{}' can't be read",
+                        symbol, reader_name
+                    )),
+                    None => Ok(()),
+                }
+            }
+            (Avro::Array(reader_items), Avro::Array(writer_items)) => {
+                self.can_read_type(reader_items, writer_schema, writer_items, visited)
+            }
+            (Avro::Map(reader_values), Avro::Map(writer_values)) => {
+                self.can_read_type(reader_values, writer_schema, writer_values, visited)
+            }
+            (
+                Avro::Fixed {
+                    name: reader_name,
+                    size: reader_size,
+                },
+                Avro::Fixed {
+                    name: writer_name,
+                    size: writer_size,
+                },
+            ) if reader_name == writer_name && reader_size == writer_size => Ok(()),
+            (reader, writer) if reader == writer => Ok(()),
+            (Avro::Long, Avro::Int)
+            | (Avro::Float, Avro::Int)
+            | (Avro::Float, Avro::Long)
+            | (Avro::Double, Avro::Int)
+            | (Avro::Double, Avro::Long)
+            | (Avro::Double, Avro::Float)
+            | (Avro::String, Avro::Bytes)
+            | (Avro::Bytes, Avro::String) => Ok(()),
+            (reader, writer) => Err(format!(
+                "{} can't be read as {}",
+                writer.describe(),
+                reader.describe()
+            )),
+        }
+    }
+
+    /// check value is complete Avro binary encoding of schema
+    pub(crate) fn validate_binary(&self, value: &[u8]) -> Result<(), String> {
+        let mut reader = BinaryReader { buf: value };
+        self.read_value(self.root(), &mut reader, 0)?;
+        if reader.buf.is_empty() {
+            Ok(())
+        } else {
+            Err(format!("{} bytes left after value", reader.buf.len()))
+        }
+    }
+
+    fn read_value(
+        &self,
+        avro: &Avro,
+        reader: &mut BinaryReader,
+        depth: usize,
+    ) -> Result<(), String> {
+        if depth > MAX_DEPTH {
+            return Err("value is nested too deep".to_owned());
+        }
+        match self.resolve(avro) {
+            Avro::Null => Ok(()),
+            Avro::Boolean => match reader.byte()? {
+                0 | 1 => Ok(()),
+                other => Err(format!("invalid boolean: {}", other)),
+            },
+            Avro::Int => {
+                let value = reader.long()?;
+                if value < i32::MIN as i64 || value > i32::MAX as i64 {
+                    return Err(format!("int out of range: {}", value));
+                }
+                Ok(())
+            }
+            Avro::Long => reader.long().map(|_| ()),
+            Avro::Float => reader.skip(4),
+            Avro::Double => reader.skip(8),
+            Avro::Bytes => reader.bytes().map(|_| ()),
+            Avro::String => {
+                let bytes = reader.bytes()?;
+                std::str::from_utf8(bytes)
+                    .map(|_| ())
+                    .map_err(|err| format!("invalid string: {}", err))
+            }
+            Avro::Record { fields, .. } => fields
+                .iter()
+                .try_for_each(|field| self.read_value(&field.schema, reader, depth + 1)),
+            Avro::Enum { name, symbols } => {
+                let index = reader.long()?;
+                if index < 0 || index as usize >= symbols.len() {
+                    return Err(format!("invalid symbol index {} of enum '{}'", index, name));
+                }
+                Ok(())
+            }
+            Avro::Array(items) => self.read_blocks(reader, |schema, reader| {
+                schema.read_value(items, reader, depth + 1)
+            }),
+            Avro::Map(values) => self.read_blocks(reader, |schema, reader| {
+                reader.bytes()?;
+                schema.read_value(values, reader, depth + 1)
+            }),
+            Avro::Union(branches) => {
+                let index = reader.long()?;
+                let branch = usize::try_from(index)
+                    .ok()
+                    .and_then(|index| branches.get(index))
+                    .ok_or_else(|| format!("invalid union index {}", index))?;
+                self.read_value(branch, reader, depth + 1)
+            }
+            Avro::Fixed { size, .. } => reader.skip(*size),
+            Avro::Named(name) => Err(format!("unknown avro type: {}", name)),
+        }
+    }
+
+    fn read_blocks<F>(&self, reader: &mut BinaryReader, mut read_item: F) -> Result<(), String>
+    where
+        F: FnMut(&Self, &mut BinaryReader) -> Result<(), String>,
+    {
+        // every item takes at least one byte unless it's empty, so more items than bytes
+        // left in value can't be valid and would only keep reader busy
+        let mut remaining_items = reader.buf.len() as i64;
+        loop {
+            let mut count = reader.long()?;
+            if count == 0 {
+                return Ok(());
+            }
+            if count < 0 {
+                // negative count is followed by size of block
+                count = count
+                    .checked_neg()
+                    .ok_or_else(|| format!("invalid block count: {}", count))?;
+                reader.long()?;
+            }
+            if count > remaining_items {
+                return Err(format!("block count {} exceeds size of value", count));
+            }
+            remaining_items -= count;
+            for _ in 0..count {
+                read_item(self, reader)?;
+            }
+        }
+    }
+}
+
+const MAX_DEPTH: usize = 64;
+
+impl Avro {
+    fn name(&self) -> Option<&str> {
+        match self {
+            Self::Record { name, .. } | Self::Enum { name, .. } | Self::Fixed { name, .. } => {
+                Some(name)
+            }
+            _ => None,
+        }
+    }
+
+    fn describe(&self) -> String {
+        match self {
+            Self::Null => "null".to_owned(),
+            Self::Boolean => "boolean".to_owned(),
+            Self::Int => "int".to_owned(),
+            Self::Long => "long".to_owned(),
+            Self::Float => "float".to_owned(),
+            Self::Double => "double".to_owned(),
+            Self::Bytes => "bytes".to_owned(),
+            Self::String => "string".to_owned(),
+            Self::Record { name, .. } => format!("record '{}'", name),
+            Self::Enum { name, .. } => format!("enum '{}'", name),
+            Self::Array(_) => "array".to_owned(),
+            Self::Map(_) => "map".to_owned(),
+            Self::Union(_) => "union".to_owned(),
+            Self::Fixed { name, .. } => format!("fixed '{}'", name),
+            Self::Named(name) => name.clone(),
+        }
+    }
+}
+
+fn required_name(object: &Map<String, Value>) -> Result<String, String> {
+    let name = object
+        .get("name")
+        .and_then(|name| name.as_str())
+        .ok_or_else(|| "named avro type is missing name".to_owned())?;
+    // references may use full name
+    match object
+        .get("namespace")
+        .and_then(|namespace| namespace.as_str())
+    {
+        Some(namespace) if !name.contains('.') && !namespace.is_empty() => {
+            Ok(format!("{}.{}", namespace, name))
+        }
+        _ => Ok(name.to_owned()),
+    }
+}
+
+struct BinaryReader<'a> {
+    buf: &'a [u8],
+}
+
+impl<'a> BinaryReader<'a> {
+    fn byte(&mut self) -> Result<u8, String> {
+        let (first, rest) = self
+            .buf
+            .split_first()
+            .ok_or_else(|| "unexpected end of value".to_owned())?;
+        self.buf = rest;
+        Ok(*first)
+    }
+
+    fn skip(&mut self, len: usize) -> Result<(), String> {
+        self.take(len).map(|_| ())
+    }
+
+    fn take(&mut self, len: usize) -> Result<&'a [u8], String> {
+        if self.buf.len() < len {
+            return Err("unexpected end of value".to_owned());
+        }
+        let (bytes, rest) = self.buf.split_at(len);
+        self.buf = rest;
+        Ok(bytes)
+    }
+
+    /// zig-zag encoded variable length long
+    fn long(&mut self) -> Result<i64, String> {
+        let mut value: u64 = 0;
+        for shift in (0..64).step_by(7) {
+            let byte = self.byte()?;
+            value |= ((byte & 0x7f) as u64) << shift;
+            if byte & 0x80 == 0 {
+                return Ok((value >> 1) as i64 ^ -((value & 1) as i64));
+            }
+        }
+        Err("invalid variable length long".to_owned())
+    }
+
+    fn bytes(&mut self) -> Result<&'a [u8], String> {
+        let len = self.long()?;
+        if len < 0 {
+            return Err(format!("invalid length: {}", len));
+        }
+        self.take(len as usize)
+    }
+}
@@ -0,0 +1,397 @@
+//!
+//! # Schema compatibility and validation
+//!
+//! Registered versions are checked against latest version of subject,
+//! produced values are checked against registered versions.
+//!
+//! Values are framed with magic byte `0` and big endian schema id,
+//! followed by payload encoded with schema.
+//!
+use std::convert::TryInto;
+
+use serde_json::Value;
+
+use super::avro::AvroSchema;
+use super::json;
+use super::{SchemaCompatibility, SchemaSpec, SchemaType};
+
+/// first byte of value framed with schema id
+pub const SCHEMA_MAGIC_BYTE: u8 = 0;
+
+/// length of magic byte and schema id
+pub const SCHEMA_HEADER_LEN: usize = 5;
+
+/// prefix payload with schema id
+pub fn encode_schema_id(id: u32, payload: &[u8]) -> Vec<u8> {
+    let mut value = Vec::with_capacity(SCHEMA_HEADER_LEN + payload.len());
+    value.push(SCHEMA_MAGIC_BYTE);
+    value.extend_from_slice(&id.to_be_bytes());
+    value.extend_from_slice(payload);
+    value
+}
+
+/// schema id and payload of framed value, none if value is not framed
+pub fn decode_schema_id(value: &[u8]) -> Option<(u32, &[u8])> {
+    if value.len() < SCHEMA_HEADER_LEN || value[0] != SCHEMA_MAGIC_BYTE {
+        return None;
+    }
+    let id = u32::from_be_bytes(value[1..SCHEMA_HEADER_LEN].try_into().ok()?);
+    Some((id, &value[SCHEMA_HEADER_LEN..]))
+}
+
+#[derive(Debug)]
+enum ParsedSchema {
+    Avro(AvroSchema),
+    Json(Value),
+}
+
+impl ParsedSchema {
+    fn parse(schema_type: SchemaType, definition: &str) -> Result<Self, String> {
+        let value: Value = serde_json::from_str(definition)
+            .map_err(|err| format!("schema is not valid json: {}", err))?;
+        match schema_type {
+            SchemaType::Avro => {
+                let schema = AvroSchema::parse(&value)?;
+                schema.check_references()?;
+                Ok(Self::Avro(schema))
+            }
+            SchemaType::JsonSchema => {
+                json::validate_schema(&value)?;
+                Ok(Self::Json(value))
+            }
+        }
+    }
+
+    fn can_read(&self, writer: &ParsedSchema) -> Result<(), String> {
+        match (self, writer) {
+            (Self::Avro(reader), Self::Avro(writer)) => reader.can_read(writer),
+            (Self::Json(reader), Self::Json(writer)) => json::can_read(reader, writer),
+            _ => Err("schema type can't be changed".to_owned()),
+        }
+    }
+}
+
+/// check definition is valid schema of type
+pub fn validate_definition(schema_type: SchemaType, definition: &str) -> Result<(), String> {
+    ParsedSchema::parse(schema_type, definition).map(|_| ())
+}
+
+/// check new definition against latest version of subject
+pub fn check_compatibility(
+    spec: &SchemaSpec,
+    compatibility: SchemaCompatibility,
+    schema_type: SchemaType,
+    definition: &str,
+) -> Result<(), String> {
+    let new = ParsedSchema::parse(schema_type, definition)?;
+    let latest = match spec.latest() {
+        Some(latest) => latest,
+        None => return Ok(()),
+    };
+    if compatibility == SchemaCompatibility::None {
+        return Ok(());
+    }
+    if latest.schema_type != schema_type {
+        return Err(format!(
+            "schema type can't be changed from {} to {}",
+            latest.schema_type, schema_type
+        ));
+    }
+
+    let latest_schema = ParsedSchema::parse(latest.schema_type, &latest.definition)?;
+    if matches!(
+        compatibility,
+        SchemaCompatibility::Backward | SchemaCompatibility::Full
+    ) {
+        new.can_read(&latest_schema).map_err(|err| {
+            format!(
+                "not backward compatible with version {}: {}",
+                latest.version, err
+            )
+        })?;
+    }
+    if matches!(
+        compatibility,
+        SchemaCompatibility::Forward | SchemaCompatibility::Full
+    ) {
+        latest_schema.can_read(&new).map_err(|err| {
+            format!(
+                "not forward compatible with version {}: {}",
+                latest.version, err
+            )
+        })?;
+    }
+    Ok(())
+}
+
+/// check value is framed with schema id of subject and payload matches schema
+pub fn validate_value(spec: &SchemaSpec, value: &[u8]) -> Result<(), String> {
+    ValueValidator::new(spec)?.validate(value)
+}
+
+/// Schemas of all versions of subject, parsed once to validate many values
+#[derive(Debug)]
+pub struct ValueValidator {
+    schemas: Vec<(u32, ParsedSchema)>,
+}
+
+impl ValueValidator {
+    pub fn new(spec: &SchemaSpec) -> Result<Self, String> {
+        let schemas = spec
+            .versions
+            .iter()
+            .map(|version| {
+                ParsedSchema::parse(version.schema_type, &version.definition)
+                    .map(|schema| (version.id, schema))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(Self { schemas })
+    }
+
+    /// check value is framed with schema id of subject and payload matches schema
+    pub fn validate(&self, value: &[u8]) -> Result<(), String> {
+        let (id, payload) = decode_schema_id(value)
+            .ok_or_else(|| "value is not framed with schema id".to_owned())?;
+        let schema = self
+            .schemas
+            .iter()
+            .find(|(schema_id, _)| *schema_id == id)
+            .map(|(_, schema)| schema)
+            .ok_or_else(|| format!("schema id {} is not registered to subject", id))?;
+        match schema {
+            ParsedSchema::Avro(schema) => schema.validate_binary(payload),
+            ParsedSchema::Json(schema) => {
+                let document: Value = serde_json::from_slice(payload)
+                    .map_err(|err| format!("value is not valid json: {}", err))?;
+                json::validate_document(schema, &document)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    use super::super::{SchemaCompatibility, SchemaSpec, SchemaType, SchemaVersion};
+    use super::{check_compatibility, decode_schema_id, encode_schema_id, validate_value};
+
+    const USER_V1: &str = r#"{
+        "type": "record",
+        "name": "User",
+        "fields": [
+            {"name": "name", "type": "string"},
+            {"name": "age", "type": "int"}
+        ]
+    }"#;
+
+    fn spec(schema_type: SchemaType, definition: &str) -> SchemaSpec {
+        SchemaSpec {
+            versions: vec![SchemaVersion {
+                version: 1,
+                id: 7,
+                schema_type,
+                definition: definition.to_owned(),
+            }],
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_avro_compatibility() {
+        let spec = spec(SchemaType::Avro, USER_V1);
+
+        // new field with default, int promoted to long
+        let with_default = r#"{
+            "type": "record",
+            "name": "User",
+            "fields": [
+                {"name": "name", "type": "string"},
+                {"name": "age", "type": "long"},
+                {"name": "email", "type": ["null", "string"], "default": null}
+            ]
+        }"#;
+        assert!(check_compatibility(
+            &spec,
+            SchemaCompatibility::Backward,
+            SchemaType::Avro,
+            with_default
+        )
+        .is_ok());
+        // old reader can't read long as int
+        assert!(check_compatibility(
+            &spec,
+            SchemaCompatibility::Full,
+            SchemaType::Avro,
+            with_default
+        )
+        .is_err());
+
+        // new field without default
+        let without_default = r#"{
+            "type": "record",
+            "name": "User",
+            "fields": [
+                {"name": "name", "type": "string"},
+                {"name": "age", "type": "int"},
+                {"name": "email", "type": "string"}
+            ]
+        }"#;
+        assert!(check_compatibility(
+            &spec,
+            SchemaCompatibility::Backward,
+            SchemaType::Avro,
+            without_default
+        )
+        .is_err());
+        assert!(check_compatibility(
+            &spec,
+            SchemaCompatibility::Forward,
+            SchemaType::Avro,
+            without_default
+        )
+        .is_ok());
+        assert!(check_compatibility(
+            &spec,
+            SchemaCompatibility::None,
+            SchemaType::Avro,
+            without_default
+        )
+        .is_ok());
+
+        assert!(check_compatibility(
+            &spec,
+            SchemaCompatibility::None,
+            SchemaType::Avro,
+            r#"{"type": "record", "name": "User"}"#
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn test_json_compatibility() {
+        let spec = spec(
+            SchemaType::JsonSchema,
+            r#"{
+                "type": "object",
+                "properties": {"name": {"type": "string"}, "age": {"type": "integer"}},
+                "required": ["name"]
+            }"#,
+        );
+
+        let optional_field = r#"{
+            "type": "object",
+            "properties": {
+                "name": {"type": "string"},
+                "age": {"type": "number"},
+                "email": {"type": "string"}
+            },
+            "required": ["name"]
+        }"#;
+        assert!(check_compatibility(
+            &spec,
+            SchemaCompatibility::Backward,
+            SchemaType::JsonSchema,
+            optional_field
+        )
+        .is_ok());
+
+        let required_field = r#"{
+            "type": "object",
+            "properties": {"name": {"type": "string"}, "email": {"type": "string"}},
+            "required": ["name", "email"]
+        }"#;
+        assert!(check_compatibility(
+            &spec,
+            SchemaCompatibility::Backward,
+            SchemaType::JsonSchema,
+            required_field
+        )
+        .is_err());
+        assert!(check_compatibility(
+            &spec,
+            SchemaCompatibility::Backward,
+            SchemaType::Avro,
+            USER_V1
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn test_validate_value() {
+        let avro = spec(SchemaType::Avro, USER_V1);
+        // "bob" and 21, zig-zag encoded
+        let payload = [6, b'b', b'o', b'b', 42];
+        assert!(validate_value(&avro, &encode_schema_id(7, &payload)).is_ok());
+        assert!(validate_value(&avro, &encode_schema_id(8, &payload)).is_err());
+        assert!(validate_value(&avro, &encode_schema_id(7, &payload[..4])).is_err());
+        assert!(validate_value(&avro, &payload).is_err());
+
+        let json = spec(
+            SchemaType::JsonSchema,
+            r#"{"type": "object", "required": ["name"]}"#,
+        );
+        assert!(validate_value(&json, &encode_schema_id(7, br#"{"name": "bob"}"#)).is_ok());
+        assert!(validate_value(&json, &encode_schema_id(7, br#"{"age": 1}"#)).is_err());
+
+        let framed = encode_schema_id(258, b"data");
+        assert_eq!(decode_schema_id(&framed), Some((258, &b"data"[..])));
+    }
+
+    #[test]
+    fn test_avro_block_count() {
+        let nulls = spec(SchemaType::Avro, r#"{"type": "array", "items": "null"}"#);
+        // two nulls, then end of array
+        assert!(validate_value(&nulls, &encode_schema_id(7, &[4, 0])).is_ok());
+        // i64::MAX items, zig-zag encoded
+        let huge = [
+            0xfe, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0x01, 0,
+        ];
+        assert!(validate_value(&nulls, &encode_schema_id(7, &huge)).is_err());
+        // i64::MIN items, negative count followed by block size
+        let min = [
+            0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0x01, 0, 0,
+        ];
+        assert!(validate_value(&nulls, &encode_schema_id(7, &min)).is_err());
+    }
+
+    #[test]
+    fn test_json_unsupported_keywords() {
+        let spec = spec(
+            SchemaType::JsonSchema,
+            r#"{"type": "object", "properties": {"age": {"type": "integer"}}}"#,
+        );
+
+        let annotated = r#"{
+            "$schema": "http://json-schema.org/draft-07/schema#",
+            "title": "User",
+            "type": "object",
+            "properties": {"age": {"type": "integer", "description": "age in years"}}
+        }"#;
+        assert!(check_compatibility(
+            &spec,
+            SchemaCompatibility::Backward,
+            SchemaType::JsonSchema,
+            annotated
+        )
+        .is_ok());
+
+        let minimum = r#"{
+            "type": "object",
+            "properties": {"age": {"type": "integer", "minimum": 18}}
+        }"#;
+        assert!(check_compatibility(
+            &spec,
+            SchemaCompatibility::None,
+            SchemaType::JsonSchema,
+            minimum
+        )
+        .is_err());
+        assert!(check_compatibility(
+            &spec,
+            SchemaCompatibility::None,
+            SchemaType::JsonSchema,
+            r#"{"oneOf": [{"type": "string"}, {"type": "integer"}]}"#
+        )
+        .is_err());
+    }
+}
@@ -0,0 +1,263 @@
+//!
+//! # JSON Schema
+//!
+//! Subset of JSON Schema used for compatibility and validation:
+//! `type`, `properties`, `required`, `additionalProperties`, `items` and `enum`.
+//! Schemas with other validation keywords are rejected, since they would be ignored.
+//!
+use serde_json::{Map, Value};
+
+const TYPES: &[&str] = &[
+    "null", "boolean", "object", "array", "number", "integer", "string",
+];
+
+const KEYWORDS: &[&str] = &[
+    "type",
+    "properties",
+    "required",
+    "additionalProperties",
+    "items",
+    "enum",
+];
+
+/// keywords which don't affect validation
+const ANNOTATIONS: &[&str] = &[
+    "$schema",
+    "$id",
+    "$comment",
+    "title",
+    "description",
+    "default",
+    "examples",
+];
+
+/// check keywords used for compatibility have valid values
+pub(crate) fn validate_schema(schema: &Value) -> Result<(), String> {
+    let object = match schema {
+        Value::Bool(_) => return Ok(()),
+        Value::Object(object) => object,
+        other => return Err(format!("invalid json schema: {}", other)),
+    };
+
+    if let Some(keyword) = object
+        .keys()
+        .find(|key| !KEYWORDS.contains(&key.as_str()) && !ANNOTATIONS.contains(&key.as_str()))
+    {
+        return Err(format!("unsupported keyword: {}", keyword));
+    }
+
+    if let Some(types) = object.get("type") {
+        let types = match types {
+            Value::String(_) => vec![types],
+            Value::Array(types) => types.iter().collect(),
+            other => return Err(format!("invalid type: {}", other)),
+        };
+        for schema_type in types {
+            match schema_type.as_str() {
+                Some(name) if TYPES.contains(&name) => {}
+                _ => return Err(format!("invalid type: {}", schema_type)),
+            }
+        }
+    }
+
+    if let Some(properties) = object.get("properties") {
+        let properties = properties
+            .as_object()
+            .ok_or_else(|| "properties must be object".to_owned())?;
+        for (name, property) in properties {
+            validate_schema(property).map_err(|err| format!("property '{}': {}", name, err))?;
+        }
+    }
+
+    if let Some(required) = object.get("required") {
+        let valid = required
+            .as_array()
+            .map(|required| required.iter().all(|name| name.is_string()))
+            .unwrap_or(false);
+        if !valid {
+            return Err("required must be array of property names".to_owned());
+        }
+    }
+
+    if let Some(values) = object.get("enum") {
+        if !values.is_array() {
+            return Err("enum must be array of values".to_owned());
+        }
+    }
+
+    if let Some(items) = object.get("items") {
+        validate_schema(items).map_err(|err| format!("items: {}", err))?;
+    }
+
+    if let Some(additional) = object.get("additionalProperties") {
+        validate_schema(additional).map_err(|err| format!("additionalProperties: {}", err))?;
+    }
+
+    Ok(())
+}
+
+/// check every document valid under writer schema is valid under reader schema
+pub(crate) fn can_read(reader: &Value, writer: &Value) -> Result<(), String> {
+    let reader = match reader {
+        Value::Bool(true) => return Ok(()),
+        Value::Bool(false) => return Err("schema rejects every document".to_owned()),
+        Value::Object(reader) => reader,
+        _ => return Ok(()),
+    };
+    let any = Map::new();
+    let writer = match writer {
+        Value::Bool(false) => return Ok(()),
+        Value::Object(writer) => writer,
+        _ => &any,
+    };
+
+    if let Some(reader_types) = types(reader) {
+        match types(writer) {
+            Some(writer_types) => {
+                for writer_type in writer_types {
+                    let readable = reader_types.contains(&writer_type)
+                        || (writer_type == "integer" && reader_types.contains(&"number"));
+                    if !readable {
+                        return Err(format!("type '{}' is no longer accepted", writer_type));
+                    }
+                }
+            }
+            None => return Err("type is restricted".to_owned()),
+        }
+    }
+
+    if let Some(reader_enum) = reader.get("enum").and_then(|values| values.as_array()) {
+        match writer.get("enum").and_then(|values| values.as_array()) {
+            Some(writer_enum) => {
+                if let Some(value) = writer_enum
+                    .iter()
+                    .find(|value| !reader_enum.contains(value))
+                {
+                    return Err(format!("enum value {} is no longer accepted", value));
+                }
+            }
+            None => return Err("values are restricted by enum".to_owned()),
+        }
+    }
+
+    let writer_required = required(writer);
+    if let Some(name) = required(reader)
+        .into_iter()
+        .find(|name| !writer_required.contains(name))
+    {
+        return Err(format!("property '{}' is required", name));
+    }
+
+    let reader_properties = properties(reader);
+    let writer_properties = properties(writer);
+    for (name, writer_property) in &writer_properties {
+        match reader_properties.get(name) {
+            Some(reader_property) => can_read(reader_property, writer_property)
+                .map_err(|err| format!("property '{}': {}", name, err))?,
+            None => can_read(additional_properties(reader), writer_property)
+                .map_err(|err| format!("property '{}' is not accepted: {}", name, err))?,
+        }
+    }
+
+    if let (Some(reader_items), Some(writer_items)) = (reader.get("items"), writer.get("items")) {
+        can_read(reader_items, writer_items).map_err(|err| format!("items: {}", err))?;
+    }
+
+    Ok(())
+}
+
+/// check document against schema
+pub(crate) fn validate_document(schema: &Value, document: &Value) -> Result<(), String> {
+    let schema = match schema {
+        Value::Bool(true) => return Ok(()),
+        Value::Bool(false) => return Err("no document is accepted".to_owned()),
+        Value::Object(schema) => schema,
+        _ => return Ok(()),
+    };
+
+    if let Some(schema_types) = types(schema) {
+        let document_type = type_of(document);
+        let valid = schema_types.contains(&document_type)
+            || (document_type == "integer" && schema_types.contains(&"number"));
+        if !valid {
+            return Err(format!(
+                "expected {:?}, found {}",
+                schema_types, document_type
+            ));
+        }
+    }
+
+    if let Some(values) = schema.get("enum").and_then(|values| values.as_array()) {
+        if !values.contains(document) {
+            return Err(format!("{} is not one of enum values", document));
+        }
+    }
+
+    if let Value::Object(document) = document {
+        if let Some(name) = required(schema)
+            .into_iter()
+            .find(|name| !document.contains_key(*name))
+        {
+            return Err(format!("missing required property '{}'", name));
+        }
+        let schema_properties = properties(schema);
+        for (name, value) in document {
+            let property_schema = schema_properties
+                .get(name)
+                .unwrap_or_else(|| additional_properties(schema));
+            validate_document(property_schema, value)
+                .map_err(|err| format!("property '{}': {}", name, err))?;
+        }
+    }
+
+    if let (Value::Array(items), Some(items_schema)) = (document, schema.get("items")) {
+        for (index, item) in items.iter().enumerate() {
+            validate_document(items_schema, item)
+                .map_err(|err| format!("item {}: {}", index, err))?;
+        }
+    }
+
+    Ok(())
+}
+
+static ANY: Value = Value::Bool(true);
+
+fn types(schema: &Map<String, Value>) -> Option<Vec<&str>> {
+    match schema.get("type")? {
+        Value::String(name) => Some(vec![name.as_str()]),
+        Value::Array(names) => Some(names.iter().filter_map(|name| name.as_str()).collect()),
+        _ => None,
+    }
+}
+
+fn required(schema: &Map<String, Value>) -> Vec<&str> {
+    schema
+        .get("required")
+        .and_then(|required| required.as_array())
+        .map(|required| required.iter().filter_map(|name| name.as_str()).collect())
+        .unwrap_or_default()
+}
+
+fn properties(schema: &Map<String, Value>) -> Map<String, Value> {
+    schema
+        .get("properties")
+        .and_then(|properties| properties.as_object())
+        .cloned()
+        .unwrap_or_default()
+}
+
+fn additional_properties(schema: &Map<String, Value>) -> &Value {
+    schema.get("additionalProperties").unwrap_or(&ANY)
+}
+
+fn type_of(value: &Value) -> &'static str {
+    match value {
+        Value::Null => "null",
+        Value::Bool(_) => "boolean",
+        Value::Number(number) if number.is_i64() || number.is_u64() => "integer",
+        Value::Number(_) => "number",
+        Value::String(_) => "string",
+        Value::Array(_) => "array",
+        Value::Object(_) => "object",
+    }
+}
@@ -0,0 +1,24 @@
+use crate::k8_types::{Crd, GROUP, V1, CrdNames, Spec, Status, DefaultHeader};
+
+use super::SchemaStatus;
+use super::SchemaSpec;
+
+const SCHEMA_API: Crd = Crd {
+    group: GROUP,
+    version: V1,
+    names: CrdNames {
+        kind: "Schema",
+        plural: "schemas",
+        singular: "schema",
+    },
+};
+
+impl Spec for SchemaSpec {
+    type Header = DefaultHeader;
+    type Status = SchemaStatus;
+    fn metadata() -> &'static Crd {
+        &SCHEMA_API
+    }
+}
+
+impl Status for SchemaStatus {}
@@ -0,0 +1,65 @@
+mod spec;
+mod status;
+mod avro;
+mod json;
+mod compat;
+
+pub use spec::*;
+pub use status::*;
+pub use compat::*;
+
+#[cfg(feature = "k8")]
+mod k8;
+
+mod convert {
+
+    use crate::core::{Spec, Status, Removable, Creatable};
+    use crate::extended::{ObjectType, SpecExt};
+    use super::*;
+
+    impl Spec for SchemaSpec {
+        const LABEL: &'static str = "Schema";
+
+        type Status = SchemaStatus;
+
+        type Owner = Self;
+        type IndexKey = String;
+    }
+
+    impl SpecExt for SchemaSpec {
+        const OBJECT_TYPE: ObjectType = ObjectType::Schema;
+    }
+
+    impl Removable for SchemaSpec {
+        type DeleteKey = String;
+    }
+
+    impl Creatable for SchemaSpec {}
+
+    impl Status for SchemaStatus {}
+
+    #[cfg(feature = "k8")]
+    mod extended {
+
+        use crate::store::k8::K8ExtendedSpec;
+        use crate::store::k8::K8ConvertError;
+        use crate::store::k8::K8MetaItem;
+        use crate::store::MetadataStoreObject;
+        use crate::k8_types::K8Obj;
+        use crate::store::k8::default_convert_from_k8;
+
+        use super::SchemaSpec;
+
+        impl K8ExtendedSpec for SchemaSpec {
+            type K8Spec = Self;
+            type K8Status = Self::Status;
+
+            fn convert_from_k8(
+                k8_obj: K8Obj<Self::K8Spec>,
+            ) -> Result<MetadataStoreObject<Self, K8MetaItem>, K8ConvertError<Self::K8Spec>>
+            {
+                default_convert_from_k8(k8_obj)
+            }
+        }
+    }
+}
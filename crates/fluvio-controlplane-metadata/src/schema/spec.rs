@@ -0,0 +1,149 @@
+#![allow(clippy::assign_op_pattern)]
+
+//!
+//! # Schema Spec
+//!
+//! Versioned schemas of record values, registered under subject.
+//! Subject with same name as topic applies to values of that topic.
+//!
+use std::fmt;
+use std::str::FromStr;
+
+use dataplane::core::{Encoder, Decoder};
+
+/// Versions of subject, ordered from oldest to latest.
+/// New version must be compatible with latest version according to compatibility mode
+#[derive(Encoder, Decoder, Default, Debug, Clone, PartialEq)]
+#[cfg_attr(
+    feature = "use_serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "camelCase", default)
+)]
+pub struct SchemaSpec {
+    pub compatibility: SchemaCompatibility,
+    /// SPU rejects produced values of topic which are not encoded with schema of subject
+    pub validate: bool,
+    pub versions: Vec<SchemaVersion>,
+}
+
+impl SchemaSpec {
+    pub fn latest(&self) -> Option<&SchemaVersion> {
+        self.versions.last()
+    }
+
+    pub fn version(&self, version: u32) -> Option<&SchemaVersion> {
+        self.versions
+            .iter()
+            .find(|schema| schema.version == version)
+    }
+
+    /// version with schema id
+    pub fn find_id(&self, id: u32) -> Option<&SchemaVersion> {
+        self.versions.iter().find(|schema| schema.id == id)
+    }
+
+    /// version with same type and definition
+    pub fn find_definition(
+        &self,
+        schema_type: SchemaType,
+        definition: &str,
+    ) -> Option<&SchemaVersion> {
+        self.versions
+            .iter()
+            .find(|schema| schema.schema_type == schema_type && schema.definition == definition)
+    }
+}
+
+#[derive(Encoder, Decoder, Default, Debug, Clone, PartialEq)]
+#[cfg_attr(
+    feature = "use_serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "camelCase")
+)]
+pub struct SchemaVersion {
+    /// version within subject, starting from 1
+    pub version: u32,
+    /// id unique in cluster, embedded in records
+    pub id: u32,
+    pub schema_type: SchemaType,
+    pub definition: String,
+}
+
+#[derive(Encoder, Decoder, Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "use_serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum SchemaType {
+    Avro,
+    JsonSchema,
+}
+
+impl Default for SchemaType {
+    fn default() -> Self {
+        Self::Avro
+    }
+}
+
+impl fmt::Display for SchemaType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Avro => write!(f, "avro"),
+            Self::JsonSchema => write!(f, "json"),
+        }
+    }
+}
+
+impl FromStr for SchemaType {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "avro" => Ok(Self::Avro),
+            "json" | "json-schema" | "jsonschema" => Ok(Self::JsonSchema),
+            _ => Err(format!("unknown schema type: {}", s)),
+        }
+    }
+}
+
+/// Compatibility of new version with latest version of subject
+#[derive(Encoder, Decoder, Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "use_serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum SchemaCompatibility {
+    /// any schema is accepted
+    None,
+    /// consumers using new schema can read data written with latest schema
+    Backward,
+    /// consumers using latest schema can read data written with new schema
+    Forward,
+    /// both backward and forward
+    Full,
+}
+
+impl Default for SchemaCompatibility {
+    fn default() -> Self {
+        Self::Backward
+    }
+}
+
+impl fmt::Display for SchemaCompatibility {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::None => write!(f, "none"),
+            Self::Backward => write!(f, "backward"),
+            Self::Forward => write!(f, "forward"),
+            Self::Full => write!(f, "full"),
+        }
+    }
+}
+
+impl FromStr for SchemaCompatibility {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "none" => Ok(Self::None),
+            "backward" => Ok(Self::Backward),
+            "forward" => Ok(Self::Forward),
+            "full" => Ok(Self::Full),
+            _ => Err(format!("unknown compatibility: {}", s)),
+        }
+    }
+}
@@ -0,0 +1,16 @@
+#![allow(clippy::assign_op_pattern)]
+
+use std::fmt;
+
+use dataplane::core::{Encoder, Decoder};
+
+/// Schema has no status, versions are validated when they are registered
+#[derive(Encoder, Decoder, Default, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "use_serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SchemaStatus {}
+
+impl fmt::Display for SchemaStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Active")
+    }
+}
@@ -51,6 +51,8 @@ pub struct K8SpuGroupSpec {
 pub struct SpuTemplate {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub rack: Option<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub racks: Vec<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub public_endpoint: Option<SpuEndpointTemplate>,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -151,6 +153,7 @@ mod convert {
                 replication: template.replication.map(|r| r.into()),
                 storage: template.storage.map(|s| s.into()),
                 env: vec![], // doesn't really matter
+                racks: template.racks,
             }
         }
     }
@@ -172,6 +175,7 @@ mod convert {
         fn from(config: SpuConfig) -> Self {
             Self {
                 rack: config.rack,
+                racks: config.racks,
                 storage: config.storage.map(|s| s.into()),
                 replication: config.replication.map(|s| s.into()),
                 ..Default::default()
@@ -32,9 +32,24 @@ pub struct SpuConfig {
     pub replication: Option<ReplicationConfig>,
     pub storage: Option<StorageConfig>,
     pub env: Vec<EnvVar>,
+    /// rack for each SPU in the group, assigned round robin by SPU index.
+    /// if not empty, this takes precedence over rack.
+    /// racks are not read from node `topology.kubernetes.io/zone` labels
+    #[fluvio(min_version = 4)]
+    #[cfg_attr(feature = "use_serde", serde(default))]
+    pub racks: Vec<String>,
 }
 
 impl SpuConfig {
+    /// rack of SPU at given index in the group
+    pub fn rack_for_replica(&self, index: u16) -> Option<String> {
+        if self.racks.is_empty() {
+            self.rack.clone()
+        } else {
+            Some(self.racks[index as usize % self.racks.len()].clone())
+        }
+    }
+
     pub fn real_storage_config(&self) -> RealStorageConfig {
         if let Some(config) = &self.storage {
             config.real_config()
@@ -95,3 +110,23 @@ pub struct EnvVar {
     pub name: String,
     pub value: String,
 }
+
+#[cfg(test)]
+mod test {
+
+    use super::*;
+
+    #[test]
+    fn test_rack_for_replica() {
+        let mut config = SpuConfig::default();
+        assert_eq!(config.rack_for_replica(0), None);
+
+        config.rack = Some("r0".to_owned());
+        assert_eq!(config.rack_for_replica(3), Some("r0".to_owned()));
+
+        config.racks = vec!["r1".to_owned(), "r2".to_owned()];
+        assert_eq!(config.rack_for_replica(0), Some("r1".to_owned()));
+        assert_eq!(config.rack_for_replica(1), Some("r2".to_owned()));
+        assert_eq!(config.rack_for_replica(2), Some("r1".to_owned()));
+    }
+}
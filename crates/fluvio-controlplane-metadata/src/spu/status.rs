@@ -13,11 +13,19 @@ use dataplane::core::{Encoder, Decoder};
 #[cfg_attr(feature = "use_serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct SpuStatus {
     pub resolution: SpuStatusResolution,
+    /// SPU is being drained, no new replicas are assigned to it
+    #[cfg_attr(feature = "use_serde", serde(default))]
+    #[fluvio(min_version = 5)]
+    pub draining: bool,
 }
 
 impl fmt::Display for SpuStatus {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "{:#?}", self.resolution)
+        if self.draining {
+            write!(f, "{:#?} (draining)", self.resolution)
+        } else {
+            write!(f, "{:#?}", self.resolution)
+        }
     }
 }
 
@@ -25,6 +33,7 @@ impl Default for SpuStatus {
     fn default() -> Self {
         SpuStatus {
             resolution: SpuStatusResolution::default(),
+            draining: false,
         }
     }
 }
@@ -34,6 +43,7 @@ impl SpuStatus {
     pub fn offline() -> Self {
         Self {
             resolution: SpuStatusResolution::Offline,
+            draining: false,
         }
     }
     /// Resolution to string label
@@ -67,6 +77,15 @@ impl SpuStatus {
     pub fn set_offline(&mut self) {
         self.resolution = SpuStatusResolution::Offline;
     }
+
+    pub fn is_draining(&self) -> bool {
+        self.draining
+    }
+
+    /// Mark spu as draining
+    pub fn set_draining(&mut self, draining: bool) {
+        self.draining = draining;
+    }
 }
 
 #[derive(Decoder, Encoder, Debug, Clone, PartialEq)]
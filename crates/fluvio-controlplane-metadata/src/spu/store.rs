@@ -60,6 +60,8 @@ where
 
     async fn spu_used_for_replica(&self) -> i32;
 
+    async fn spu_ids_for_replica(&self) -> Vec<i32>;
+
     async fn online_spu_ids(&self) -> Vec<i32>;
 
     async fn spu_ids(&self) -> Vec<i32>;
@@ -82,6 +84,8 @@ where
 
     async fn online_spu_rack_map(&self) -> BTreeMap<String, Vec<i32>>;
 
+    async fn spu_rack_map(&self) -> BTreeMap<SpuId, String>;
+
     fn online_spus_in_rack(rack_map: &[(String, Vec<i32>)]) -> Vec<i32>;
 
     async fn all_spus_to_spu_msgs(&self) -> Vec<SpuMsg>;
@@ -122,7 +126,24 @@ where
 
     /// count spus that can be used for replica
     async fn spu_used_for_replica(&self) -> i32 {
-        self.count().await
+        self.read()
+            .await
+            .values()
+            .filter(|spu| !spu.status.is_draining())
+            .count() as i32
+    }
+
+    /// sorted ids of spus that can be used for replica, draining spus are excluded
+    async fn spu_ids_for_replica(&self) -> Vec<i32> {
+        let mut ids: Vec<i32> = self
+            .read()
+            .await
+            .values()
+            .filter(|spu| !spu.status.is_draining())
+            .map(|spu| spu.spec.id)
+            .collect();
+        ids.sort_unstable();
+        ids
     }
 
     // retrieve SPU ids.
@@ -271,6 +292,9 @@ where
         let mut rack_spus: BTreeMap<String, Vec<i32>> = BTreeMap::new();
 
         for spu in self.read().await.values() {
+            if spu.status.is_draining() {
+                continue;
+            }
             if let Some(rack) = &spu.spec.rack {
                 let mut ids: Vec<i32>;
                 let mut ids_in_map = rack_spus.remove(rack);
@@ -288,6 +312,15 @@ where
         rack_spus
     }
 
+    // Return rack of spus which have been assigned to rack {0:"r1", 1:"r1", 3:"r2"}
+    async fn spu_rack_map(&self) -> BTreeMap<SpuId, String> {
+        self.read()
+            .await
+            .values()
+            .filter_map(|spu| spu.spec.rack.clone().map(|rack| (spu.spec.id, rack)))
+            .collect()
+    }
+
     // Returns a list of rack inter-leaved spus [0, 4, 5, 1, 3, 2]
     fn online_spus_in_rack(rack_map: &[(String, Vec<i32>)]) -> Vec<i32> {
         let mut spus = vec![];
@@ -360,6 +393,24 @@ pub mod test {
         assert_eq!(spus.online_spu_count().await, 1);
     }
 
+    #[fluvio_future::test]
+    async fn test_spu_draining_not_used_for_replica() {
+        let spu_0 = DefaultSpuMd::quick(("spu-0", 0, true, Some("r1".to_owned())));
+        let mut spu_1 = DefaultSpuMd::quick(("spu-1", 1, true, Some("r1".to_owned())));
+        let spu_2 = DefaultSpuMd::quick(("spu-2", 2, true, Some("r2".to_owned())));
+        spu_1.status.set_draining(true);
+
+        let spus = DefaultSpuStore::bulk_new(vec![spu_0, spu_1, spu_2]);
+
+        assert_eq!(spus.count().await, 3);
+        assert_eq!(spus.spu_used_for_replica().await, 2);
+        assert_eq!(spus.spu_ids_for_replica().await, vec![0, 2]);
+
+        let rack_map = spus.online_spu_rack_map().await;
+        assert_eq!(rack_map.get("r1"), Some(&vec![0]));
+        assert_eq!(rack_map.get("r2"), Some(&vec![2]));
+    }
+
     #[test]
     fn test_spu_status_updates_online_offline() {
         let mut test_spu = DefaultSpuMd::quick(("spu", 10, false, None));
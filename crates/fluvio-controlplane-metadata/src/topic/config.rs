@@ -0,0 +1,233 @@
+#![allow(clippy::assign_op_pattern)]
+
+//!
+//! # Topic Config
+//!
+//! Per topic overrides of SPU storage configuration
+//!
+use std::collections::BTreeMap;
+use std::io::{Error, ErrorKind};
+
+use dataplane::core::{Encoder, Decoder};
+use fluvio_types::defaults::{
+    SPU_LOG_SEGMENT_MAX_BYTES, SPU_LOG_INDEX_MAX_BYTES, SPU_LOG_INDEX_MAX_INTERVAL_BYTES,
+    SPU_LOG_FLUSH_WRITE_COUNT, SPU_LOG_FLUSH_IDLE_MSEC, SPU_LOG_MAX_BATCH_SIZE,
+};
+
+pub const SEGMENT_MAX_BYTES: &str = "segment.max.bytes";
+pub const INDEX_MAX_BYTES: &str = "index.max.bytes";
+pub const INDEX_MAX_INTERVAL_BYTES: &str = "index.max.interval.bytes";
+pub const FLUSH_WRITE_COUNT: &str = "flush.write.count";
+pub const FLUSH_IDLE_MSEC: &str = "flush.idle.msec";
+pub const MAX_BATCH_SIZE: &str = "max.batch.size";
+
+/// all configuration keys which can be overridden by topic
+pub const TOPIC_CONFIG_KEYS: [&str; 6] = [
+    SEGMENT_MAX_BYTES,
+    INDEX_MAX_BYTES,
+    INDEX_MAX_INTERVAL_BYTES,
+    FLUSH_WRITE_COUNT,
+    FLUSH_IDLE_MSEC,
+    MAX_BATCH_SIZE,
+];
+
+/// keys whose value must be greater than 0
+const NON_ZERO_KEYS: [&str; 4] = [
+    SEGMENT_MAX_BYTES,
+    INDEX_MAX_BYTES,
+    INDEX_MAX_INTERVAL_BYTES,
+    MAX_BATCH_SIZE,
+];
+
+/// Storage configuration overrides for a topic.
+/// Keys which are not set use SPU configuration
+#[derive(Decoder, Encoder, Default, Debug, Clone, PartialEq)]
+#[cfg_attr(
+    feature = "use_serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(transparent)
+)]
+pub struct TopicConfig {
+    configs: BTreeMap<String, String>,
+}
+
+impl From<BTreeMap<String, String>> for TopicConfig {
+    fn from(configs: BTreeMap<String, String>) -> Self {
+        Self { configs }
+    }
+}
+
+impl std::iter::FromIterator<(String, String)> for TopicConfig {
+    fn from_iter<I: IntoIterator<Item = (String, String)>>(iter: I) -> Self {
+        Self {
+            configs: iter.into_iter().collect(),
+        }
+    }
+}
+
+impl TopicConfig {
+    pub fn is_empty(&self) -> bool {
+        self.configs.is_empty()
+    }
+
+    pub fn insert<K, V>(&mut self, key: K, value: V) -> Option<String>
+    where
+        K: Into<String>,
+        V: Into<String>,
+    {
+        self.configs.insert(key.into(), value.into())
+    }
+
+    pub fn get(&self, key: &str) -> Option<&str> {
+        self.configs.get(key).map(|value| value.as_str())
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&String, &String)> {
+        self.configs.iter()
+    }
+
+    pub fn segment_max_bytes(&self) -> Option<u32> {
+        self.get_u32(SEGMENT_MAX_BYTES)
+    }
+
+    pub fn index_max_bytes(&self) -> Option<u32> {
+        self.get_u32(INDEX_MAX_BYTES)
+    }
+
+    pub fn index_max_interval_bytes(&self) -> Option<u32> {
+        self.get_u32(INDEX_MAX_INTERVAL_BYTES)
+    }
+
+    pub fn flush_write_count(&self) -> Option<u32> {
+        self.get_u32(FLUSH_WRITE_COUNT)
+    }
+
+    pub fn flush_idle_msec(&self) -> Option<u32> {
+        self.get_u32(FLUSH_IDLE_MSEC)
+    }
+
+    pub fn max_batch_size(&self) -> Option<u32> {
+        self.get_u32(MAX_BATCH_SIZE)
+    }
+
+    /// built-in SPU storage default for key,
+    /// SPU started with other `--log-*` values uses those instead
+    pub fn default_value(key: &str) -> Option<u32> {
+        match key {
+            SEGMENT_MAX_BYTES => Some(SPU_LOG_SEGMENT_MAX_BYTES),
+            INDEX_MAX_BYTES => Some(SPU_LOG_INDEX_MAX_BYTES),
+            INDEX_MAX_INTERVAL_BYTES => Some(SPU_LOG_INDEX_MAX_INTERVAL_BYTES),
+            FLUSH_WRITE_COUNT => Some(SPU_LOG_FLUSH_WRITE_COUNT),
+            FLUSH_IDLE_MSEC => Some(SPU_LOG_FLUSH_IDLE_MSEC),
+            MAX_BATCH_SIZE => Some(SPU_LOG_MAX_BATCH_SIZE),
+            _ => None,
+        }
+    }
+
+    /// topic override or built-in default value.
+    /// returns flag indicating if value is overridden
+    pub fn value_or_default(&self, key: &str) -> Option<(u32, bool)> {
+        match self.get_u32(key) {
+            Some(value) => Some((value, true)),
+            None => Self::default_value(key).map(|value| (value, false)),
+        }
+    }
+
+    fn get_u32(&self, key: &str) -> Option<u32> {
+        self.get(key).and_then(|value| value.parse().ok())
+    }
+
+    /// Validate keys and values
+    pub fn validate(&self) -> Result<(), Error> {
+        for (key, value) in &self.configs {
+            if !TOPIC_CONFIG_KEYS.contains(&key.as_str()) {
+                return Err(Error::new(
+                    ErrorKind::InvalidInput,
+                    format!("unknown topic config: {}", key),
+                ));
+            }
+
+            let number: u32 = value.parse().map_err(|_| {
+                Error::new(
+                    ErrorKind::InvalidInput,
+                    format!(
+                        "topic config: {} must be an unsigned integer: {}",
+                        key, value
+                    ),
+                )
+            })?;
+
+            if number == 0 && NON_ZERO_KEYS.contains(&key.as_str()) {
+                return Err(Error::new(
+                    ErrorKind::InvalidInput,
+                    format!("topic config: {} must be greater than 0", key),
+                ));
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_topic_config_validate() {
+        let mut config = TopicConfig::default();
+        assert!(config.validate().is_ok());
+
+        config.insert(SEGMENT_MAX_BYTES, "1000");
+        config.insert(FLUSH_IDLE_MSEC, "0");
+        assert!(config.validate().is_ok());
+        assert_eq!(config.segment_max_bytes(), Some(1000));
+        assert_eq!(config.flush_idle_msec(), Some(0));
+        assert_eq!(config.max_batch_size(), None);
+
+        let mut unknown = config.clone();
+        unknown.insert("retention.ms", "1000");
+        assert_eq!(
+            format!("{}", unknown.validate().unwrap_err()),
+            "unknown topic config: retention.ms"
+        );
+
+        let mut invalid = config.clone();
+        invalid.insert(MAX_BATCH_SIZE, "big");
+        assert_eq!(
+            format!("{}", invalid.validate().unwrap_err()),
+            "topic config: max.batch.size must be an unsigned integer: big"
+        );
+
+        let mut zero = config;
+        zero.insert(SEGMENT_MAX_BYTES, "0");
+        assert_eq!(
+            format!("{}", zero.validate().unwrap_err()),
+            "topic config: segment.max.bytes must be greater than 0"
+        );
+    }
+
+    #[test]
+    fn test_topic_config_value_or_default() {
+        let mut config = TopicConfig::default();
+        config.insert(SEGMENT_MAX_BYTES, "1000");
+
+        assert_eq!(
+            config.value_or_default(SEGMENT_MAX_BYTES),
+            Some((1000, true))
+        );
+        assert_eq!(
+            config.value_or_default(INDEX_MAX_BYTES),
+            Some((SPU_LOG_INDEX_MAX_BYTES, false))
+        );
+        assert_eq!(
+            config.value_or_default(MAX_BATCH_SIZE),
+            Some((SPU_LOG_MAX_BATCH_SIZE, false))
+        );
+        assert_eq!(config.value_or_default("retention.ms"), None);
+
+        for key in TOPIC_CONFIG_KEYS.iter() {
+            assert!(TopicConfig::default_value(key).is_some());
+        }
+    }
+}
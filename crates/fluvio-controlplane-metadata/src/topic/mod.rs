@@ -1,9 +1,11 @@
 mod spec;
 mod status;
+mod config;
 pub mod store;
 
 pub use self::spec::*;
 pub use self::status::*;
+pub use self::config::*;
 
 pub const PENDING_REASON: &str = "waiting for live spus";
 
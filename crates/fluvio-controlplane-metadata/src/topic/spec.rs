@@ -8,7 +8,7 @@
 //!  * Computed
 //!
 use std::io::{Error, ErrorKind};
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, BTreeSet};
 
 use tracing::trace;
 use fluvio_types::{ReplicaMap, SpuId};
@@ -18,6 +18,10 @@ use dataplane::core::Version;
 use dataplane::bytes::{Buf, BufMut};
 use dataplane::core::{Encoder, Decoder};
 
+use crate::partition::ReplicationPolicy;
+
+use super::TopicConfig;
+
 #[derive(Debug, Clone, PartialEq)]
 #[cfg_attr(
     feature = "use_serde",
@@ -91,6 +95,34 @@ impl TopicSpec {
         }
     }
 
+    pub fn replication_policy(&self) -> &ReplicationPolicy {
+        match self {
+            TopicSpec::Computed(param) => &param.replication_policy,
+            TopicSpec::Assigned(partition_map) => partition_map.replication_policy(),
+        }
+    }
+
+    pub fn set_replication_policy(&mut self, policy: ReplicationPolicy) {
+        match self {
+            TopicSpec::Computed(param) => param.replication_policy = policy,
+            TopicSpec::Assigned(partition_map) => partition_map.replication_policy = policy,
+        }
+    }
+
+    pub fn config(&self) -> &TopicConfig {
+        match self {
+            TopicSpec::Computed(param) => &param.config,
+            TopicSpec::Assigned(partition_map) => partition_map.config(),
+        }
+    }
+
+    pub fn set_config(&mut self, config: TopicConfig) {
+        match self {
+            TopicSpec::Computed(param) => param.config = config,
+            TopicSpec::Assigned(partition_map) => partition_map.config = config,
+        }
+    }
+
     pub fn type_label(&self) -> &'static str {
         match self {
             Self::Computed(_) => "computed",
@@ -173,6 +205,31 @@ impl TopicSpec {
 
         Ok(())
     }
+
+    /// Validate min in sync replicas against replication factor
+    pub fn valid_min_in_sync_replicas(
+        policy: &ReplicationPolicy,
+        replication: &ReplicationFactor,
+    ) -> Result<(), Error> {
+        if policy.min_in_sync_replicas == 0 {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "min in sync replicas must be greater than 0",
+            ));
+        }
+
+        if i32::from(policy.min_in_sync_replicas) > *replication {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                format!(
+                    "min in sync replicas: {} cannot be greater than replication factor: {}",
+                    policy.min_in_sync_replicas, replication
+                ),
+            ));
+        }
+
+        Ok(())
+    }
 }
 
 impl Decoder for TopicSpec {
@@ -273,6 +330,18 @@ pub struct TopicReplicaParam {
     pub replication_factor: ReplicationFactor,
     #[cfg_attr(feature = "use_serde", serde(skip_serializing_if = "bool::clone"))]
     pub ignore_rack_assignment: IgnoreRackAssignment,
+    #[fluvio(min_version = 2)]
+    #[cfg_attr(
+        feature = "use_serde",
+        serde(default, skip_serializing_if = "ReplicationPolicy::is_default")
+    )]
+    pub replication_policy: ReplicationPolicy,
+    #[fluvio(min_version = 3)]
+    #[cfg_attr(
+        feature = "use_serde",
+        serde(default, skip_serializing_if = "TopicConfig::is_empty")
+    )]
+    pub config: TopicConfig,
 }
 
 #[allow(dead_code)]
@@ -290,6 +359,8 @@ impl TopicReplicaParam {
             partitions,
             replication_factor,
             ignore_rack_assignment,
+            replication_policy: ReplicationPolicy::default(),
+            config: TopicConfig::default(),
         }
     }
 }
@@ -316,11 +387,27 @@ impl std::fmt::Display for TopicReplicaParam {
 #[cfg_attr(feature = "use_serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct PartitionMaps {
     maps: Vec<PartitionMap>,
+    #[fluvio(min_version = 2)]
+    #[cfg_attr(
+        feature = "use_serde",
+        serde(default, skip_serializing_if = "ReplicationPolicy::is_default")
+    )]
+    replication_policy: ReplicationPolicy,
+    #[fluvio(min_version = 3)]
+    #[cfg_attr(
+        feature = "use_serde",
+        serde(default, skip_serializing_if = "TopicConfig::is_empty")
+    )]
+    config: TopicConfig,
 }
 
 impl From<Vec<PartitionMap>> for PartitionMaps {
     fn from(maps: Vec<PartitionMap>) -> Self {
-        Self { maps }
+        Self {
+            maps,
+            replication_policy: ReplicationPolicy::default(),
+            config: TopicConfig::default(),
+        }
     }
 }
 
@@ -349,11 +436,19 @@ impl PartitionMaps {
         self.maps
     }
 
+    pub fn replication_policy(&self) -> &ReplicationPolicy {
+        &self.replication_policy
+    }
+
+    pub fn config(&self) -> &TopicConfig {
+        &self.config
+    }
+
     fn partition_count(&self) -> PartitionCount {
         self.maps.len() as PartitionCount
     }
 
-    fn replication_factor(&self) -> Option<ReplicationFactor> {
+    pub fn replication_factor(&self) -> Option<ReplicationFactor> {
         // compute replication form replica map
         if !self.maps.is_empty() {
             Some(self.maps[0].replicas.len() as i32)
@@ -502,6 +597,43 @@ impl PartitionMaps {
     }
 }
 
+impl PartitionMaps {
+    /// Validate replicas of each assigned partition are not concentrated in one rack.
+    /// Only applies if SPUs have been assigned to more than one rack
+    pub fn valid_rack_assignment(&self, spu_racks: &BTreeMap<SpuId, String>) -> Result<(), Error> {
+        let rack_count = spu_racks.values().collect::<BTreeSet<_>>().len();
+        if rack_count < 2 {
+            return Ok(());
+        }
+
+        for partition in &self.maps {
+            if partition.replicas.len() < 2 {
+                continue;
+            }
+
+            // skip partition which has replica without rack
+            let racks: Option<BTreeSet<&String>> = partition
+                .replicas
+                .iter()
+                .map(|spu| spu_racks.get(spu))
+                .collect();
+            if let Some(racks) = racks {
+                if let (1, Some(rack)) = (racks.len(), racks.iter().next()) {
+                    return Err(Error::new(
+                        ErrorKind::InvalidInput,
+                        format!(
+                            "replicas of assigned partition with id: {} are all in rack: {}",
+                            partition.id, rack
+                        ),
+                    ));
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
 impl From<(PartitionCount, ReplicationFactor, IgnoreRackAssignment)> for TopicSpec {
     fn from(spec: (PartitionCount, ReplicationFactor, IgnoreRackAssignment)) -> Self {
         let (count, factor, rack) = spec;
@@ -792,4 +924,86 @@ pub mod test {
         let spec2 = TopicSpec::new_assigned(p2);
         assert_eq!(spec2.partition_map_str(), Some("".to_string()));
     }
+
+    #[test]
+    fn test_valid_min_in_sync_replicas() {
+        let policy = ReplicationPolicy {
+            min_in_sync_replicas: 0,
+            ..Default::default()
+        };
+        let r1 = TopicSpec::valid_min_in_sync_replicas(&policy, &3);
+        assert_eq!(
+            format!("{}", r1.unwrap_err()),
+            "min in sync replicas must be greater than 0"
+        );
+
+        let policy = ReplicationPolicy {
+            min_in_sync_replicas: 4,
+            ..Default::default()
+        };
+        let r2 = TopicSpec::valid_min_in_sync_replicas(&policy, &3);
+        assert_eq!(
+            format!("{}", r2.unwrap_err()),
+            "min in sync replicas: 4 cannot be greater than replication factor: 3"
+        );
+
+        let policy = ReplicationPolicy {
+            min_in_sync_replicas: 2,
+            ..Default::default()
+        };
+        assert!(TopicSpec::valid_min_in_sync_replicas(&policy, &3).is_ok());
+    }
+
+    #[test]
+    fn test_encode_decode_replication_policy() {
+        let mut topic_spec: TopicSpec = (2, 3).into();
+        topic_spec.set_replication_policy(ReplicationPolicy {
+            unclean_leader_election: true,
+            min_in_sync_replicas: 2,
+            ..Default::default()
+        });
+
+        // policy is only encoded from version 2
+        let mut dest = vec![];
+        topic_spec.encode(&mut dest, 1).expect("encode");
+        let mut decoded = TopicSpec::default();
+        decoded.decode(&mut Cursor::new(&dest), 1).expect("decode");
+        assert_eq!(decoded.replication_policy(), &ReplicationPolicy::default());
+
+        let mut dest = vec![];
+        topic_spec.encode(&mut dest, 2).expect("encode");
+        let mut decoded = TopicSpec::default();
+        decoded.decode(&mut Cursor::new(&dest), 2).expect("decode");
+        assert_eq!(decoded, topic_spec);
+    }
+
+    #[test]
+    fn test_valid_rack_assignment() {
+        let racks: BTreeMap<SpuId, String> = vec![
+            (0, "r1".to_owned()),
+            (1, "r1".to_owned()),
+            (2, "r2".to_owned()),
+        ]
+        .into_iter()
+        .collect();
+
+        let p1: PartitionMaps = vec![(0, vec![0, 2]), (1, vec![1, 2])].into();
+        assert!(p1.valid_rack_assignment(&racks).is_ok());
+
+        let p2: PartitionMaps = vec![(0, vec![0, 2]), (1, vec![0, 1])].into();
+        assert_eq!(
+            format!("{}", p2.valid_rack_assignment(&racks).unwrap_err()),
+            "replicas of assigned partition with id: 1 are all in rack: r1"
+        );
+
+        // spu without rack can't be validated
+        let p3: PartitionMaps = vec![(0, vec![0, 5])].into();
+        assert!(p3.valid_rack_assignment(&racks).is_ok());
+
+        // single rack in cluster
+        let single: BTreeMap<SpuId, String> = vec![(0, "r1".to_owned()), (1, "r1".to_owned())]
+            .into_iter()
+            .collect();
+        assert!(p2.valid_rack_assignment(&single).is_ok());
+    }
 }
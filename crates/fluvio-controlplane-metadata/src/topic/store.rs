@@ -36,8 +36,11 @@ where
             let replica_key = ReplicaKey::new(self.key(), *idx);
             debug!("Topic: {} creating partition: {}", self.key(), replica_key);
             if !partition_store.contains_key(&replica_key).await {
+                let spec = PartitionSpec::from(replicas.clone())
+                    .set_replication_policy(self.spec.replication_policy().clone())
+                    .set_config(self.spec.config().clone());
                 partitions.push(
-                    MetadataStoreObject::with_spec(replica_key, replicas.clone().into())
+                    MetadataStoreObject::with_spec(replica_key, spec)
                         .with_context(self.ctx.create_child()),
                 )
             }
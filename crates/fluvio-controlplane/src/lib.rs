@@ -12,6 +12,10 @@ pub use self::requests::update_replica::*;
 pub use self::requests::register_spu::*;
 pub use self::requests::update_lrs::*;
 pub use self::requests::remove::*;
+pub use self::requests::update_auth_policy::*;
+pub use self::requests::update_quota::*;
+pub use self::requests::update_schema::*;
+pub use self::requests::txn_markers::*;
 
 use dataplane::api::RequestMessage;
 
@@ -3,3 +3,7 @@ pub mod update_replica;
 pub mod register_spu;
 pub mod update_lrs;
 pub mod remove;
+pub mod update_auth_policy;
+pub mod update_quota;
+pub mod update_schema;
+pub mod txn_markers;
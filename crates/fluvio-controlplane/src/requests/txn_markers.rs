@@ -0,0 +1,79 @@
+#![allow(clippy::assign_op_pattern)]
+
+use dataplane::api::Request;
+use dataplane::derive::Decoder;
+use dataplane::derive::Encoder;
+use fluvio_controlplane_metadata::partition::ReplicaKey;
+
+use crate::InternalScKey;
+use crate::InternalSpuApi;
+
+/// Markers of all prepared transactions, SPU writes markers to replicas it leads.
+/// Same markers are sent until SPU confirms they are written
+#[derive(Decoder, Encoder, Debug, Default, Clone, PartialEq)]
+pub struct WriteTxnMarkersRequest {
+    pub markers: Vec<TxnMarker>,
+}
+
+impl WriteTxnMarkersRequest {
+    pub fn new(markers: Vec<TxnMarker>) -> Self {
+        Self { markers }
+    }
+}
+
+impl Request for WriteTxnMarkersRequest {
+    const API_KEY: u16 = InternalSpuApi::WriteTxnMarkers as u16;
+    type Response = WriteTxnMarkersResponse;
+}
+
+#[derive(Decoder, Encoder, Default, Debug)]
+pub struct WriteTxnMarkersResponse {}
+
+/// Markers written by SPU, sent to SC after markers are written
+#[derive(Decoder, Encoder, Debug, Default, Clone, PartialEq)]
+pub struct TxnMarkersWrittenRequest {
+    pub markers: Vec<TxnMarker>,
+}
+
+impl TxnMarkersWrittenRequest {
+    pub fn new(markers: Vec<TxnMarker>) -> Self {
+        Self { markers }
+    }
+}
+
+impl Request for TxnMarkersWrittenRequest {
+    const API_KEY: u16 = InternalScKey::TxnMarkersWritten as u16;
+    type Response = TxnMarkersWrittenResponse;
+}
+
+#[derive(Decoder, Encoder, Default, Debug)]
+pub struct TxnMarkersWrittenResponse {}
+
+/// Marker which ends transaction of producer in replica
+#[derive(Decoder, Encoder, Debug, Default, Clone, PartialEq, Eq, Hash)]
+pub struct TxnMarker {
+    pub replica: ReplicaKey,
+    pub producer_id: i64,
+    pub producer_epoch: i16,
+    /// sequence of transaction of producer, increased for every transaction
+    pub txn_sequence: i32,
+    pub commit: bool,
+}
+
+impl TxnMarker {
+    pub fn new(
+        replica: ReplicaKey,
+        producer_id: i64,
+        producer_epoch: i16,
+        txn_sequence: i32,
+        commit: bool,
+    ) -> Self {
+        Self {
+            replica,
+            producer_id,
+            producer_epoch,
+            txn_sequence,
+            commit,
+        }
+    }
+}
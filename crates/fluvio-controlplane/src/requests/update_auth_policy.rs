@@ -0,0 +1,30 @@
+#![allow(clippy::assign_op_pattern)]
+
+use dataplane::api::Request;
+use dataplane::derive::Decoder;
+use dataplane::derive::Encoder;
+
+use crate::InternalSpuApi;
+
+/// Authorization policy used by SPU to authorize produce and consume.
+/// Policy is in json, same format as SC authorization policy file.
+/// If there is no policy, every request is allowed.
+/// Until SPU has received this request, every request is denied
+#[derive(Decoder, Encoder, Debug, Default, Clone, PartialEq)]
+pub struct UpdateAuthPolicyRequest {
+    pub policy: Option<String>,
+}
+
+impl UpdateAuthPolicyRequest {
+    pub fn new(policy: Option<String>) -> Self {
+        Self { policy }
+    }
+}
+
+impl Request for UpdateAuthPolicyRequest {
+    const API_KEY: u16 = InternalSpuApi::UpdateAuthPolicy as u16;
+    type Response = UpdateAuthPolicyResponse;
+}
+
+#[derive(Decoder, Encoder, Default, Debug)]
+pub struct UpdateAuthPolicyResponse {}
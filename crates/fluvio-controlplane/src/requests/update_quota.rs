@@ -0,0 +1,41 @@
+#![allow(clippy::assign_op_pattern)]
+
+use dataplane::api::Request;
+use dataplane::derive::Decoder;
+use dataplane::derive::Encoder;
+use fluvio_controlplane_metadata::quota::QuotaSpec;
+
+use crate::InternalSpuApi;
+
+/// All quotas of cluster, replaces quotas enforced by SPU.
+/// If there are no quotas, throughput is not limited
+#[derive(Decoder, Encoder, Debug, Default, Clone, PartialEq)]
+pub struct UpdateQuotaRequest {
+    pub quotas: Vec<Quota>,
+}
+
+impl UpdateQuotaRequest {
+    pub fn new(quotas: Vec<Quota>) -> Self {
+        Self { quotas }
+    }
+}
+
+impl Request for UpdateQuotaRequest {
+    const API_KEY: u16 = InternalSpuApi::UpdateQuota as u16;
+    type Response = UpdateQuotaResponse;
+}
+
+#[derive(Decoder, Encoder, Default, Debug)]
+pub struct UpdateQuotaResponse {}
+
+#[derive(Decoder, Encoder, Debug, Default, Clone, PartialEq)]
+pub struct Quota {
+    pub name: String,
+    pub spec: QuotaSpec,
+}
+
+impl Quota {
+    pub fn new(name: String, spec: QuotaSpec) -> Self {
+        Self { name, spec }
+    }
+}
@@ -0,0 +1,41 @@
+#![allow(clippy::assign_op_pattern)]
+
+use dataplane::api::Request;
+use dataplane::derive::Decoder;
+use dataplane::derive::Encoder;
+use fluvio_controlplane_metadata::schema::SchemaSpec;
+
+use crate::InternalSpuApi;
+
+/// Schema subjects which are validated on produce, replaces subjects known by SPU.
+/// Subject applies to topic with same name
+#[derive(Decoder, Encoder, Debug, Default, Clone, PartialEq)]
+pub struct UpdateSchemaRequest {
+    pub subjects: Vec<SchemaSubject>,
+}
+
+impl UpdateSchemaRequest {
+    pub fn new(subjects: Vec<SchemaSubject>) -> Self {
+        Self { subjects }
+    }
+}
+
+impl Request for UpdateSchemaRequest {
+    const API_KEY: u16 = InternalSpuApi::UpdateSchema as u16;
+    type Response = UpdateSchemaResponse;
+}
+
+#[derive(Decoder, Encoder, Default, Debug)]
+pub struct UpdateSchemaResponse {}
+
+#[derive(Decoder, Encoder, Debug, Default, Clone, PartialEq)]
+pub struct SchemaSubject {
+    pub name: String,
+    pub spec: SchemaSpec,
+}
+
+impl SchemaSubject {
+    pub fn new(name: String, spec: SchemaSpec) -> Self {
+        Self { name, spec }
+    }
+}
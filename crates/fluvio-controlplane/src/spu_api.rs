@@ -11,6 +11,10 @@ use dataplane::derive::Decoder;
 
 use super::UpdateSpuRequest;
 use super::UpdateReplicaRequest;
+use super::UpdateAuthPolicyRequest;
+use super::UpdateQuotaRequest;
+use super::UpdateSchemaRequest;
+use super::WriteTxnMarkersRequest;
 
 #[repr(u16)]
 #[derive(PartialEq, Debug, Encoder, Decoder, Clone, Copy)]
@@ -18,6 +22,10 @@ use super::UpdateReplicaRequest;
 pub enum InternalSpuApi {
     UpdateSpu = 1001,
     UpdateReplica = 1002,
+    UpdateAuthPolicy = 1003,
+    UpdateQuota = 1004,
+    UpdateSchema = 1005,
+    WriteTxnMarkers = 1006,
 }
 
 impl Default for InternalSpuApi {
@@ -30,6 +38,10 @@ impl Default for InternalSpuApi {
 pub enum InternalSpuRequest {
     UpdateSpuRequest(RequestMessage<UpdateSpuRequest>),
     UpdateReplicaRequest(RequestMessage<UpdateReplicaRequest>),
+    UpdateAuthPolicyRequest(RequestMessage<UpdateAuthPolicyRequest>),
+    UpdateQuotaRequest(RequestMessage<UpdateQuotaRequest>),
+    UpdateSchemaRequest(RequestMessage<UpdateSchemaRequest>),
+    WriteTxnMarkersRequest(RequestMessage<WriteTxnMarkersRequest>),
 }
 
 // Added to satisfy Encoder/Decoder traits
@@ -57,6 +69,14 @@ impl ApiMessage for InternalSpuRequest {
         match header.api_key().try_into()? {
             InternalSpuApi::UpdateSpu => api_decode!(Self, UpdateSpuRequest, src, header),
             InternalSpuApi::UpdateReplica => api_decode!(Self, UpdateReplicaRequest, src, header),
+            InternalSpuApi::UpdateAuthPolicy => {
+                api_decode!(Self, UpdateAuthPolicyRequest, src, header)
+            }
+            InternalSpuApi::UpdateQuota => api_decode!(Self, UpdateQuotaRequest, src, header),
+            InternalSpuApi::UpdateSchema => api_decode!(Self, UpdateSchemaRequest, src, header),
+            InternalSpuApi::WriteTxnMarkers => {
+                api_decode!(Self, WriteTxnMarkersRequest, src, header)
+            }
         }
     }
 }
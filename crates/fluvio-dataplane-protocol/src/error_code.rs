@@ -36,9 +36,15 @@ pub enum ErrorCode {
     #[fluvio(tag = 13)]
     #[error("permission denied")]
     PermissionDenied,
+    #[fluvio(tag = 19)]
+    #[error("not enough in sync replicas to accept records")]
+    NotEnoughReplicas,
     #[fluvio(tag = 56)]
     #[error("a storage error occurred")]
     StorageError,
+    #[fluvio(tag = 58)]
+    #[error("authentication failed")]
+    SaslAuthenticationFailed,
 
     // Spu errors
     #[fluvio(tag = 1000)]
@@ -93,6 +99,14 @@ pub enum ErrorCode {
     #[error("the fetch session was not found")]
     FetchSessionNotFoud,
 
+    // Partition election errors
+    #[fluvio(tag = 3003)]
+    #[error("the partition was not found")]
+    PartitionNotFound,
+    #[fluvio(tag = 3004)]
+    #[error("the partition leader election failed")]
+    PartitionElectionFailed,
+
     // SmartStream errors
     #[fluvio(tag = 4000)]
     #[error("a SmartStream error occurred")]
@@ -105,6 +119,42 @@ pub enum ErrorCode {
     #[fluvio(tag = 5001)]
     #[error("the managed connector was not found")]
     ManagedConnectorNotFound,
+
+    // Quota errors
+    #[fluvio(tag = 6000)]
+    #[error("an error occurred while managing a quota")]
+    QuotaError,
+    #[fluvio(tag = 6001)]
+    #[error("the quota was not found")]
+    QuotaNotFound,
+    #[fluvio(tag = 6002)]
+    #[error("the quota already exists")]
+    QuotaAlreadyExists,
+
+    // Schema errors
+    #[fluvio(tag = 7000)]
+    #[error("an error occurred while managing a schema")]
+    SchemaError,
+    #[fluvio(tag = 7001)]
+    #[error("the schema subject was not found")]
+    SchemaNotFound,
+    #[fluvio(tag = 7002)]
+    #[error("the schema is not compatible with the latest version of subject")]
+    SchemaIncompatible,
+    #[fluvio(tag = 7003)]
+    #[error("the record value does not match the topic schema")]
+    SchemaValidationFailed,
+
+    // Transaction errors
+    #[fluvio(tag = 8000)]
+    #[error("an error occurred while managing a transaction")]
+    TransactionError,
+    #[fluvio(tag = 8001)]
+    #[error("the producer has been fenced by a newer instance with the same transactional id")]
+    ProducerFenced,
+    #[fluvio(tag = 8002)]
+    #[error("the request is not valid in the current state of the transaction")]
+    InvalidTransactionState,
 }
 
 impl Default for ErrorCode {
@@ -191,7 +241,9 @@ mod test {
         assert_tag!(ErrorCode::NotLeaderForPartition, 6, 0);
         assert_tag!(ErrorCode::MessageTooLarge, 10, 0);
         assert_tag!(ErrorCode::PermissionDenied, 13, 0);
+        assert_tag!(ErrorCode::NotEnoughReplicas, 19, 0);
         assert_tag!(ErrorCode::StorageError, 56, 0);
+        assert_tag!(ErrorCode::SaslAuthenticationFailed, 58, 0);
 
         // Spu errors
         assert_tag!(ErrorCode::SpuError, 1000, 0);
@@ -214,6 +266,26 @@ mod test {
 
         // Stream Fetch error
         assert_tag!(ErrorCode::FetchSessionNotFoud, 3002, 0);
+
+        // Partition election errors
+        assert_tag!(ErrorCode::PartitionNotFound, 3003, 0);
+        assert_tag!(ErrorCode::PartitionElectionFailed, 3004, 0);
+
+        // Quota errors
+        assert_tag!(ErrorCode::QuotaError, 6000, 0);
+        assert_tag!(ErrorCode::QuotaNotFound, 6001, 0);
+        assert_tag!(ErrorCode::QuotaAlreadyExists, 6002, 0);
+
+        // Schema errors
+        assert_tag!(ErrorCode::SchemaError, 7000, 0);
+        assert_tag!(ErrorCode::SchemaNotFound, 7001, 0);
+        assert_tag!(ErrorCode::SchemaIncompatible, 7002, 0);
+        assert_tag!(ErrorCode::SchemaValidationFailed, 7003, 0);
+
+        // Transaction errors
+        assert_tag!(ErrorCode::TransactionError, 8000, 0);
+        assert_tag!(ErrorCode::ProducerFenced, 8001, 0);
+        assert_tag!(ErrorCode::InvalidTransactionState, 8002, 0);
     }
 
     #[test]
@@ -50,7 +50,7 @@ where
 
 impl<R> Request for FetchRequest<R>
 where
-    R: Debug + Decoder + Encoder,
+    R: Debug + Decoder + Default + Encoder,
 {
     const API_KEY: u16 = 1;
 
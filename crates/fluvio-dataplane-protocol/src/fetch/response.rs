@@ -116,7 +116,7 @@ pub struct AbortedTransaction {
 
 impl<R> FetchResponse<R>
 where
-    R: Encoder + Decoder + Debug,
+    R: Encoder + Decoder + Default + Debug,
 {
     pub fn find_topic(&self, topic: &str) -> Option<&FetchableTopicResponse<R>>
     where
@@ -9,7 +9,9 @@ pub mod record;
 pub mod fetch;
 pub mod produce;
 pub mod versions;
+pub mod sasl;
 pub mod smartstream;
+pub mod transaction;
 
 #[cfg(feature = "fixture")]
 pub mod fixture;
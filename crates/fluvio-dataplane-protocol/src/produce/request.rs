@@ -38,7 +38,7 @@ where
 
 impl<R> Request for ProduceRequest<R>
 where
-    R: Debug + Decoder + Encoder,
+    R: Debug + Decoder + Default + Encoder,
 {
     const API_KEY: u16 = 0;
 
@@ -178,6 +178,17 @@ impl Decoder for RecordData {
 
         let mut len: i64 = 0;
         len.decode_varint(src)?;
+        // length is not trusted, buffer is allocated only if it can be filled
+        if len < 0 || len as usize > src.remaining() {
+            return Err(Error::new(
+                ErrorKind::UnexpectedEof,
+                format!(
+                    "record data length: {} exceeds remaining bytes: {}",
+                    len,
+                    src.remaining()
+                ),
+            ));
+        }
         let len = len as usize;
 
         // Take `len` bytes from `src` and put them into a new BytesMut buffer
@@ -450,7 +461,7 @@ where
 
 impl<B> Decoder for Record<B>
 where
-    B: Decoder,
+    B: Decoder + Default,
 {
     fn decode<T>(&mut self, src: &mut T, version: Version) -> Result<(), Error>
     where
@@ -624,6 +635,7 @@ mod file {
 
     use tracing::trace;
     use bytes::BufMut;
+    use bytes::Bytes;
     use bytes::BytesMut;
 
     use fluvio_future::file_slice::AsyncFileSlice;
@@ -635,7 +647,12 @@ mod file {
     use crate::store::StoreValue;
 
     #[derive(Default, Debug)]
-    pub struct FileRecordSet(AsyncFileSlice);
+    pub struct FileRecordSet {
+        slice: AsyncFileSlice,
+        /// records read from slice into memory, sent instead of slice.
+        /// used when records on disk can't be sent as is, for example when they are encrypted
+        memory: Option<Bytes>,
+    }
 
     impl fmt::Display for FileRecordSet {
         fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
@@ -644,22 +661,41 @@ mod file {
     }
 
     impl FileRecordSet {
+        /// records read from slice
+        pub fn from_memory(slice: AsyncFileSlice, records: Bytes) -> Self {
+            Self {
+                slice,
+                memory: Some(records),
+            }
+        }
+
         pub fn position(&self) -> u64 {
-            self.0.position()
+            self.slice.position()
         }
 
         pub fn len(&self) -> usize {
-            self.0.len() as usize
+            match &self.memory {
+                Some(records) => records.len(),
+                None => self.slice.len() as usize,
+            }
         }
 
         pub fn raw_slice(&self) -> AsyncFileSlice {
-            self.0.clone()
+            self.slice.clone()
+        }
+
+        /// records in memory, if set slice should not be read
+        pub fn memory(&self) -> Option<&Bytes> {
+            self.memory.as_ref()
         }
     }
 
     impl From<AsyncFileSlice> for FileRecordSet {
         fn from(slice: AsyncFileSlice) -> Self {
-            Self(slice)
+            Self {
+                slice,
+                memory: None,
+            }
         }
     }
 
@@ -672,6 +708,13 @@ mod file {
         where
             T: BufMut,
         {
+            if let Some(records) = &self.memory {
+                let len: i32 = records.len() as i32;
+                len.encode(src, version)?;
+                src.put_slice(records);
+                return Ok(());
+            }
+
             // can only encode zero length
             if self.len() == 0 {
                 let len: u32 = 0;
@@ -705,9 +748,14 @@ mod file {
             let len: i32 = self.len() as i32;
             trace!("KfFileRecordSet encoding file slice len: {}", len);
             len.encode(dest, version)?;
+            if let Some(records) = &self.memory {
+                dest.put_slice(records);
+            }
             let bytes = dest.split_to(dest.len()).freeze();
             data.push(StoreValue::Bytes(bytes));
-            data.push(StoreValue::FileSlice(self.raw_slice()));
+            if self.memory.is_none() {
+                data.push(StoreValue::FileSlice(self.raw_slice()));
+            }
             Ok(())
         }
     }
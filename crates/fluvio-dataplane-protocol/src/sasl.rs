@@ -0,0 +1,156 @@
+//!
+//! # SASL Authentication
+//!
+//! Handshake sent by client as first messages of connection when server requires
+//! SASL authentication. Each request carries mechanism specific bytes, server replies until
+//! exchange is complete or has failed.
+//!
+
+use fluvio_protocol::{Encoder, Decoder};
+
+use crate::ErrorCode;
+use crate::api::Request;
+
+pub const SASL_AUTHENTICATE_API_KEY: u16 = 36;
+
+/// username and password, RFC 4616
+pub const PLAIN_MECHANISM: &str = "PLAIN";
+/// salted challenge response, RFC 5802 and RFC 7677
+pub const SCRAM_SHA_256_MECHANISM: &str = "SCRAM-SHA-256";
+/// signed bearer token, RFC 7628
+pub const OAUTHBEARER_MECHANISM: &str = "OAUTHBEARER";
+
+#[derive(Decoder, Encoder, Default, Debug)]
+pub struct SaslAuthenticateRequest {
+    pub mechanism: String,
+    pub auth_bytes: Vec<u8>,
+}
+
+impl SaslAuthenticateRequest {
+    pub fn new<S: Into<String>>(mechanism: S, auth_bytes: Vec<u8>) -> Self {
+        Self {
+            mechanism: mechanism.into(),
+            auth_bytes,
+        }
+    }
+}
+
+impl Request for SaslAuthenticateRequest {
+    const API_KEY: u16 = SASL_AUTHENTICATE_API_KEY;
+    type Response = SaslAuthenticateResponse;
+}
+
+#[derive(Decoder, Encoder, Default, Debug)]
+pub struct SaslAuthenticateResponse {
+    pub error_code: ErrorCode,
+    pub error_message: Option<String>,
+    /// challenge or final message of server
+    pub auth_bytes: Vec<u8>,
+    /// true if authentication is done, otherwise client must continue exchange
+    pub complete: bool,
+}
+
+impl SaslAuthenticateResponse {
+    pub fn challenge(auth_bytes: Vec<u8>) -> Self {
+        Self {
+            auth_bytes,
+            ..Default::default()
+        }
+    }
+
+    pub fn success(auth_bytes: Vec<u8>) -> Self {
+        Self {
+            auth_bytes,
+            complete: true,
+            ..Default::default()
+        }
+    }
+
+    pub fn failure<S: Into<String>>(message: S) -> Self {
+        Self {
+            error_code: ErrorCode::SaslAuthenticationFailed,
+            error_message: Some(message.into()),
+            complete: true,
+            ..Default::default()
+        }
+    }
+}
+
+/// Primitives of SCRAM-SHA-256 shared by client and server, RFC 5802
+pub mod scram {
+
+    use sha2::{Sha256, Digest};
+    use hmac::{Hmac, Mac, NewMac};
+
+    pub const CLIENT_KEY: &[u8] = b"Client Key";
+    pub const SERVER_KEY: &[u8] = b"Server Key";
+
+    /// lowest iteration count accepted, recommended by RFC 7677
+    pub const MIN_ITERATIONS: u32 = 4096;
+    /// highest iteration count accepted, bounds cost of deriving salted password
+    pub const MAX_ITERATIONS: u32 = 100_000;
+
+    /// check that iteration count is within accepted range
+    pub fn validate_iterations(iterations: u32) -> Result<u32, String> {
+        if (MIN_ITERATIONS..=MAX_ITERATIONS).contains(&iterations) {
+            Ok(iterations)
+        } else {
+            Err(format!(
+                "scram iterations must be between {} and {}, got {}",
+                MIN_ITERATIONS, MAX_ITERATIONS, iterations
+            ))
+        }
+    }
+
+    /// `H` of RFC 5802
+    pub fn h(data: &[u8]) -> Vec<u8> {
+        Sha256::digest(data).to_vec()
+    }
+
+    /// `HMAC` of RFC 5802
+    pub fn hmac(key: &[u8], data: &[u8]) -> Vec<u8> {
+        let mut mac = Hmac::<Sha256>::new_varkey(key).expect("hmac accepts key of any size");
+        mac.update(data);
+        mac.finalize().into_bytes().to_vec()
+    }
+
+    /// PBKDF2 with HMAC-SHA-256 and single block output, `Hi` of RFC 5802
+    pub fn hi(password: &[u8], salt: &[u8], iterations: u32) -> Vec<u8> {
+        let mut block = salt.to_vec();
+        block.extend_from_slice(&1u32.to_be_bytes());
+
+        let mut u = hmac(password, &block);
+        let mut result = u.clone();
+        for _ in 1..iterations {
+            u = hmac(password, &u);
+            for (r, b) in result.iter_mut().zip(u.iter()) {
+                *r ^= b;
+            }
+        }
+        result
+    }
+
+    /// XOR of two keys of same length
+    pub fn xor(a: &[u8], b: &[u8]) -> Vec<u8> {
+        a.iter().zip(b.iter()).map(|(x, y)| x ^ y).collect()
+    }
+
+    /// compare without short circuit
+    pub fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+        a.len() == b.len() && a.iter().zip(b.iter()).fold(0, |acc, (x, y)| acc | (x ^ y)) == 0
+    }
+
+    #[cfg(test)]
+    mod test {
+
+        use super::*;
+
+        #[test]
+        fn test_validate_iterations() {
+            assert!(validate_iterations(MIN_ITERATIONS).is_ok());
+            assert!(validate_iterations(MAX_ITERATIONS).is_ok());
+            assert!(validate_iterations(1).is_err());
+            assert!(validate_iterations(MAX_ITERATIONS + 1).is_err());
+        }
+    }
+}
@@ -0,0 +1,151 @@
+//!
+//! # Transactional batches
+//!
+//! Batches produced within transaction are flagged in attributes and carry
+//! producer id and epoch assigned by transaction coordinator.
+//! Transaction is ended by control batch (marker) written to every partition of
+//! transaction, with single record whose key encodes marker type.
+//! Sequence of transaction is kept in first sequence of marker header, so markers
+//! can be told apart from batch headers only.
+//!
+use std::convert::TryInto;
+
+use crate::batch::{Batch, BatchHeader};
+use crate::record::Record;
+
+/// set in batch attributes when batch is part of transaction
+pub const TRANSACTIONAL_ATTRIBUTE: i16 = 0x10;
+
+/// set in batch attributes when batch is transaction marker
+pub const CONTROL_ATTRIBUTE: i16 = 0x20;
+
+/// version of marker key
+pub const CONTROL_RECORD_VERSION: i16 = 0;
+
+/// length of marker key: version and type
+const CONTROL_KEY_LEN: usize = 4;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[repr(i16)]
+pub enum ControlType {
+    Abort = 0,
+    Commit = 1,
+}
+
+impl ControlType {
+    fn from_i16(value: i16) -> Option<Self> {
+        match value {
+            0 => Some(Self::Abort),
+            1 => Some(Self::Commit),
+            _ => None,
+        }
+    }
+
+    /// key of marker record
+    pub fn encode_key(&self) -> Vec<u8> {
+        let mut key = Vec::with_capacity(CONTROL_KEY_LEN);
+        key.extend_from_slice(&CONTROL_RECORD_VERSION.to_be_bytes());
+        key.extend_from_slice(&(*self as i16).to_be_bytes());
+        key
+    }
+
+    /// marker type from key of marker record, none if key is not valid marker
+    pub fn decode_key(key: &[u8]) -> Option<Self> {
+        if key.len() < CONTROL_KEY_LEN {
+            return None;
+        }
+        let version = i16::from_be_bytes(key[0..2].try_into().ok()?);
+        if version != CONTROL_RECORD_VERSION {
+            return None;
+        }
+        Self::from_i16(i16::from_be_bytes(key[2..CONTROL_KEY_LEN].try_into().ok()?))
+    }
+}
+
+impl BatchHeader {
+    pub fn is_transactional(&self) -> bool {
+        self.attributes & TRANSACTIONAL_ATTRIBUTE != 0
+    }
+
+    pub fn is_control(&self) -> bool {
+        self.attributes & CONTROL_ATTRIBUTE != 0
+    }
+
+    /// flag batch as part of transaction of producer
+    pub fn set_transactional(&mut self, producer_id: i64, producer_epoch: i16) {
+        self.attributes |= TRANSACTIONAL_ATTRIBUTE;
+        self.producer_id = producer_id;
+        self.producer_epoch = producer_epoch;
+    }
+}
+
+impl Batch {
+    /// marker which ends transaction of producer in partition
+    pub fn control_marker(
+        producer_id: i64,
+        producer_epoch: i16,
+        txn_sequence: i32,
+        control: ControlType,
+    ) -> Self {
+        let mut batch = Self::from(vec![Record::new_key_value(
+            control.encode_key(),
+            Vec::<u8>::new(),
+        )]);
+        let header = batch.get_mut_header();
+        header.set_transactional(producer_id, producer_epoch);
+        header.first_sequence = txn_sequence;
+        header.attributes |= CONTROL_ATTRIBUTE;
+        batch
+    }
+
+    /// type of marker if batch is transaction marker
+    pub fn control_type(&self) -> Option<ControlType> {
+        if !self.header.is_control() {
+            return None;
+        }
+        self.records()
+            .first()
+            .and_then(|record| record.key())
+            .and_then(|key| ControlType::decode_key(key.as_ref()))
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    use std::io::Cursor;
+
+    use crate::batch::Batch;
+    use crate::core::{Decoder, Encoder};
+    use crate::record::Record;
+
+    use super::ControlType;
+
+    #[test]
+    fn test_control_marker() {
+        let marker = Batch::control_marker(5000, 2, 7, ControlType::Commit);
+        let bytes = marker.as_bytes(0).expect("encode");
+        let decoded = Batch::decode_from(&mut Cursor::new(bytes), 0).expect("decode");
+
+        assert!(decoded.header.is_transactional());
+        assert!(decoded.header.is_control());
+        assert_eq!(decoded.header.producer_id, 5000);
+        assert_eq!(decoded.header.producer_epoch, 2);
+        assert_eq!(decoded.header.first_sequence, 7);
+        assert_eq!(decoded.control_type(), Some(ControlType::Commit));
+
+        let abort = Batch::control_marker(5000, 2, 7, ControlType::Abort);
+        assert_eq!(abort.control_type(), Some(ControlType::Abort));
+
+        // same key in regular batch is not marker
+        let data = Batch::from(vec![Record::new_key_value(
+            ControlType::Commit.encode_key(),
+            Vec::<u8>::new(),
+        )]);
+        assert_eq!(data.control_type(), None);
+
+        assert_eq!(ControlType::decode_key(&[0, 0, 0, 7]), None);
+        assert_eq!(ControlType::decode_key(&[0, 1, 0, 1]), None);
+        assert_eq!(ControlType::decode_key(&[0, 0]), None);
+    }
+}
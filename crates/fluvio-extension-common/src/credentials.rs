@@ -0,0 +1,74 @@
+use structopt::StructOpt;
+
+use fluvio::config::Credentials;
+use crate::target::TargetError;
+
+/// Optional SASL credentials of Client, alternative to client cert
+#[derive(Debug, StructOpt, Default, Clone)]
+pub struct CredentialsOpt {
+    /// Username to authenticate with
+    #[structopt(long, env = "FLUVIO_USERNAME", conflicts_with = "token")]
+    pub username: Option<String>,
+
+    /// Password of username
+    #[structopt(long, env = "FLUVIO_PASSWORD", hide_env_values = true)]
+    pub password: Option<String>,
+
+    /// Send password as is instead of SCRAM-SHA-256 challenge response
+    #[structopt(long)]
+    pub sasl_plain: bool,
+
+    /// Bearer token to authenticate with
+    #[structopt(long, env = "FLUVIO_TOKEN", hide_env_values = true)]
+    pub token: Option<String>,
+}
+
+impl CredentialsOpt {
+    pub fn is_set(&self) -> bool {
+        self.username.is_some() || self.token.is_some()
+    }
+
+    pub fn credentials(self) -> Result<Option<Credentials>, TargetError> {
+        match (self.username, self.password, self.token) {
+            (Some(username), Some(password), None) => {
+                if self.sasl_plain {
+                    Ok(Some(Credentials::plain(username, password)))
+                } else {
+                    Ok(Some(Credentials::scram_sha_256(username, password)))
+                }
+            }
+            (Some(_), None, _) => Err(TargetError::invalid_arg(
+                "password is required when username is used",
+            )),
+            (None, _, Some(token)) => Ok(Some(Credentials::token(token))),
+            (Some(_), _, Some(_)) => Err(TargetError::invalid_arg(
+                "username and token can't be used together",
+            )),
+            (None, _, None) => Ok(None),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_credentials_opt() {
+        let opt = CredentialsOpt::from_iter_safe(vec![
+            "test",
+            "--username",
+            "ci",
+            "--password",
+            "secret",
+        ])
+        .unwrap();
+        assert_eq!(
+            opt.credentials().unwrap(),
+            Some(Credentials::scram_sha_256("ci", "secret"))
+        );
+
+        let opt = CredentialsOpt::from_iter_safe(vec!["test", "--token", "abc"]).unwrap();
+        assert_eq!(opt.credentials().unwrap(), Some(Credentials::token("abc")));
+    }
+}
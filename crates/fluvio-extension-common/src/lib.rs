@@ -4,6 +4,8 @@ mod common;
 
 #[cfg(feature = "target")]
 pub mod tls;
+#[cfg(feature = "target")]
+pub mod credentials;
 
 pub use common::*;
 pub use crate::output::Terminal;
@@ -88,6 +90,7 @@ pub mod target {
     use fluvio::Fluvio;
     use fluvio::config::ConfigFile;
     use crate::tls::TlsClientOpt;
+    use crate::credentials::CredentialsOpt;
 
     #[derive(thiserror::Error, Debug)]
     pub enum TargetError {
@@ -117,6 +120,9 @@ pub mod target {
         #[structopt(flatten)]
         pub tls: TlsClientOpt,
 
+        #[structopt(flatten)]
+        pub credentials: CredentialsOpt,
+
         #[structopt(short = "P", long, value_name = "profile")]
         pub profile: Option<String>,
     }
@@ -147,6 +153,11 @@ pub mod target {
                             "tls is not valid when profile is is used",
                         ));
                     }
+                    if self.credentials.is_set() {
+                        return Err(TargetError::invalid_arg(
+                            "credentials are not valid when profile is used",
+                        ));
+                    }
 
                     let config_file = ConfigFile::load(None)?;
                     let cluster = config_file
@@ -160,7 +171,10 @@ pub mod target {
                     Ok(cluster.clone())
                 }
                 (None, Some(cluster)) => {
-                    let cluster = FluvioConfig::new(cluster).with_tls(tls);
+                    let mut cluster = FluvioConfig::new(cluster).with_tls(tls);
+                    if let Some(credentials) = self.credentials.credentials()? {
+                        cluster = cluster.with_credentials(credentials);
+                    }
                     Ok(cluster)
                 }
                 (None, None) => {
@@ -170,6 +184,11 @@ pub mod target {
                             "tls is only valid if cluster addr is used",
                         ));
                     }
+                    if self.credentials.is_set() {
+                        return Err(TargetError::invalid_arg(
+                            "credentials are only valid if cluster addr is used",
+                        ));
+                    }
 
                     // Try to use the default cluster from saved config
                     let config_file = ConfigFile::load(None)?;
@@ -45,30 +45,6 @@ impl NamedProp {
             Ok(prop)
         }
     }
-
-    pub fn version_check_token_stream(&self, field_stream: TokenStream) -> TokenStream {
-        let min = self.attrs.min_version;
-        let field_name = &self.field_name;
-
-        if let Some(max) = self.attrs.max_version {
-            quote! {
-                #[allow(clippy::double_comparisons)]
-                if version >= #min && version <= #max {
-                    #field_stream
-                } else {
-                    tracing::trace!("Field: <{}> is skipped because version: {} is outside min: {}, max: {}",stringify!(#field_name),version,#min,#max);
-                }
-            }
-        } else {
-            quote! {
-                if version >= #min {
-                    #field_stream
-                } else {
-                    tracing::trace!("Field: <{}> is skipped because version: {} is less than min: {}",stringify!(#field_name),version,#min);
-                }
-            }
-        }
-    }
 }
 
 impl UnnamedProp {
@@ -123,6 +99,33 @@ pub(crate) struct PropAttrs {
 }
 
 impl PropAttrs {
+    pub fn version_check_token_stream(
+        &self,
+        field_name: &str,
+        field_stream: TokenStream,
+    ) -> TokenStream {
+        let min = self.min_version;
+
+        if let Some(max) = self.max_version {
+            quote! {
+                #[allow(clippy::double_comparisons)]
+                if version >= #min && version <= #max {
+                    #field_stream
+                } else {
+                    tracing::trace!("Field: <{}> is skipped because version: {} is outside min: {}, max: {}",stringify!(#field_name),version,#min,#max);
+                }
+            }
+        } else {
+            quote! {
+                if version >= #min {
+                    #field_stream
+                } else {
+                    tracing::trace!("Field: <{}> is skipped because version: {} is less than min: {}",stringify!(#field_name),version,#min);
+                }
+            }
+        }
+    }
+
     pub fn from_ast(attrs: &[Attribute]) -> syn::Result<Self> {
         let mut prop_attrs = Self::default();
 
@@ -1,23 +1,46 @@
-use crate::ast::prop::NamedProp;
-use syn::{Fields, Generics, Ident, ItemStruct};
+use crate::ast::prop::{NamedProp, PropAttrs, UnnamedProp};
+use proc_macro2::TokenStream;
+use quote::{format_ident, ToTokens};
+use syn::{Fields, Generics, Ident, Index, ItemStruct};
+
+pub(crate) enum FluvioStructProps {
+    Named(Vec<NamedProp>),
+    Unnamed(Vec<UnnamedProp>),
+}
 
 pub(crate) struct FluvioStruct {
     pub struct_ident: Ident,
-    pub props: Vec<NamedProp>,
+    pub props: FluvioStructProps,
     pub generics: Generics,
 }
 
+/// field of named or tuple struct
+pub(crate) struct StructField<'a> {
+    /// `name` or index of field, as in `self.name` or `self.0`
+    pub access: TokenStream,
+    pub name: String,
+    pub attrs: &'a PropAttrs,
+}
+
 impl FluvioStruct {
     pub fn from_ast(item: &ItemStruct) -> syn::Result<Self> {
         let struct_ident = item.ident.clone();
-        let props: Vec<NamedProp> = if let Fields::Named(fields) = &item.fields {
-            let mut prp = vec![];
-            for field in fields.named.iter() {
-                prp.push(NamedProp::from_ast(field)?);
+        let props = match &item.fields {
+            Fields::Named(fields) => {
+                let mut prp = vec![];
+                for field in fields.named.iter() {
+                    prp.push(NamedProp::from_ast(field)?);
+                }
+                FluvioStructProps::Named(prp)
             }
-            prp
-        } else {
-            vec![]
+            Fields::Unnamed(fields) => {
+                let mut prp = vec![];
+                for field in fields.unnamed.iter() {
+                    prp.push(UnnamedProp::from_ast(field)?);
+                }
+                FluvioStructProps::Unnamed(prp)
+            }
+            Fields::Unit => FluvioStructProps::Named(vec![]),
         };
         let generics = item.generics.clone();
 
@@ -27,4 +50,26 @@ impl FluvioStruct {
             generics,
         })
     }
+
+    pub fn fields(&self) -> Vec<StructField<'_>> {
+        match &self.props {
+            FluvioStructProps::Named(props) => props
+                .iter()
+                .map(|prop| StructField {
+                    access: format_ident!("{}", prop.field_name).to_token_stream(),
+                    name: prop.field_name.clone(),
+                    attrs: &prop.attrs,
+                })
+                .collect(),
+            FluvioStructProps::Unnamed(props) => props
+                .iter()
+                .enumerate()
+                .map(|(idx, prop)| StructField {
+                    access: Index::from(idx).to_token_stream(),
+                    name: idx.to_string(),
+                    attrs: &prop.attrs,
+                })
+                .collect(),
+        }
+    }
 }
@@ -1,21 +1,23 @@
 use crate::ast::{
-    container::ContainerAttributes, prop::NamedProp, r#enum::EnumProp, r#enum::FieldKind,
-    DeriveItem,
+    container::ContainerAttributes, r#enum::EnumProp, r#enum::FieldKind,
+    r#struct::FluvioStructProps, r#struct::StructField, DeriveItem,
 };
 use proc_macro2::Span;
 use proc_macro2::TokenStream;
 use quote::{format_ident, quote, ToTokens};
 use std::str::FromStr;
 use syn::punctuated::Punctuated;
-use syn::Ident;
 use syn::LitInt;
 use syn::Token;
+use syn::{Generics, Ident};
+
+use crate::util::add_type_param_bounds;
 
 pub(crate) fn generate_decode_trait_impls(input: &DeriveItem) -> TokenStream {
     match &input {
         DeriveItem::Struct(kf_struct, _attrs) => {
             // TODO: struct level attrs is not used.
-            let field_tokens = generate_struct_fields(&kf_struct.props, &kf_struct.struct_ident);
+            let field_tokens = generate_struct_fields(&kf_struct.fields(), &kf_struct.struct_ident);
             let ident = &kf_struct.struct_ident;
             let (impl_generics, ty_generics, where_clause) = kf_struct.generics.split_for_impl();
             quote! {
@@ -30,14 +32,25 @@ pub(crate) fn generate_decode_trait_impls(input: &DeriveItem) -> TokenStream {
         }
         DeriveItem::Enum(kf_enum, attrs) => {
             let ident = &kf_enum.enum_ident;
-            let (impl_generics, ty_generics, where_clause) = kf_enum.generics.split_for_impl();
+            // data of variants of generic enum is decoded with type parameters
+            let generics = add_type_param_bounds(
+                &kf_enum.generics,
+                quote! { fluvio_protocol::Decoder + std::default::Default },
+            );
+            let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
             let int_type = if let Some(int_type_name) = &attrs.repr_type_name {
                 format_ident!("{}", int_type_name)
             } else {
                 Ident::new("u8", Span::call_site())
             };
             let enum_tokens = generate_decode_enum_impl(&kf_enum.props, &int_type, ident, attrs);
-            let try_enum = generate_try_enum_from_kf_enum(&kf_enum.props, &int_type, ident, attrs);
+            let try_enum = generate_try_enum_from_kf_enum(
+                &kf_enum.props,
+                &int_type,
+                ident,
+                &kf_enum.generics,
+                attrs,
+            );
             let res = quote! {
                 impl #impl_generics fluvio_protocol::Decoder for #ident #ty_generics #where_clause {
                     fn decode<T>(&mut self, src: &mut T,version: fluvio_protocol::Version) -> Result<(),std::io::Error> where T: fluvio_protocol::bytes::Buf {
@@ -53,10 +66,10 @@ pub(crate) fn generate_decode_trait_impls(input: &DeriveItem) -> TokenStream {
     }
 }
 
-pub(crate) fn generate_struct_fields(props: &[NamedProp], struct_ident: &Ident) -> TokenStream {
-    let recurse = props.iter().map(|prop| {
-        let fname = format_ident!("{}", prop.field_name);
-        if prop.attrs.varint {
+pub(crate) fn generate_struct_fields(fields: &[StructField], struct_ident: &Ident) -> TokenStream {
+    let recurse = fields.iter().map(|field| {
+        let fname = &field.access;
+        if field.attrs.varint {
             quote! {
                 tracing::trace!("start decoding varint field <{}>", stringify!(#fname));
                 let result = self.#fname.decode_varint(src);
@@ -79,7 +92,7 @@ pub(crate) fn generate_struct_fields(props: &[NamedProp], struct_ident: &Ident)
                 }
             };
 
-            prop.version_check_token_stream(base)
+            field.attrs.version_check_token_stream(&field.name, base)
         }
     });
     quote! {
@@ -196,6 +209,7 @@ fn generate_try_enum_from_kf_enum(
     props: &[EnumProp],
     int_type: &Ident,
     enum_ident: &Ident,
+    generics: &Generics,
     attrs: &ContainerAttributes,
 ) -> TokenStream {
     // If #[fluvio(encode_discriminant)] is used, this is an int-enum
@@ -278,8 +292,9 @@ fn generate_try_enum_from_kf_enum(
             ))
     });
 
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
     quote! {
-        impl std::convert::TryFrom<#int_type> for #enum_ident {
+        impl #impl_generics std::convert::TryFrom<#int_type> for #enum_ident #ty_generics #where_clause {
             type Error = std::io::Error;
 
             fn try_from(value: #int_type) -> Result<Self, Self::Error> {
@@ -296,14 +311,21 @@ pub(crate) fn generate_default_trait_impls(input: &DeriveItem) -> TokenStream {
     match &input {
         DeriveItem::Struct(kf_struct, _attrs) => {
             let ident = &kf_struct.struct_ident;
-            let field_tokens = generate_default_impls(&kf_struct.props);
+            let construct = match &kf_struct.props {
+                FluvioStructProps::Named(_) => {
+                    let field_tokens = generate_default_impls(&kf_struct.fields());
+                    quote! { Self { #field_tokens } }
+                }
+                FluvioStructProps::Unnamed(_) => {
+                    let field_tokens = generate_default_values(&kf_struct.fields());
+                    quote! { Self ( #field_tokens ) }
+                }
+            };
             let (impl_generics, ty_generics, where_clause) = kf_struct.generics.split_for_impl();
             quote! {
                 impl #impl_generics Default for #ident #ty_generics #where_clause {
                     fn default() -> Self {
-                        Self {
-                            #field_tokens
-                        }
+                        #construct
                     }
                 }
             }
@@ -312,26 +334,40 @@ pub(crate) fn generate_default_trait_impls(input: &DeriveItem) -> TokenStream {
     }
 }
 
-pub(crate) fn generate_default_impls(props: &[NamedProp]) -> TokenStream {
-    let recurse = props.iter().map(|prop| {
-        let fname = format_ident!("{}", prop.field_name);
-        if let Some(def) = &prop.attrs.default_value {
-            if let Ok(liter) = TokenStream::from_str(def) {
-                quote! {
-                    #fname: #liter,
-                }
-            } else {
-                quote! {
-                    #fname: std::default::Default::default(),
-                }
-            }
-        } else {
-            quote! {
-                #fname: std::default::Default::default(),
-            }
+pub(crate) fn generate_default_impls(fields: &[StructField]) -> TokenStream {
+    let recurse = fields.iter().map(|field| {
+        let fname = &field.access;
+        let value = default_value(field);
+        quote! {
+            #fname: #value,
         }
     });
     quote! {
         #(#recurse)*
     }
 }
+
+/// default values of tuple struct fields, in order
+fn generate_default_values(fields: &[StructField]) -> TokenStream {
+    let recurse = fields.iter().map(|field| {
+        let value = default_value(field);
+        quote! {
+            #value,
+        }
+    });
+    quote! {
+        #(#recurse)*
+    }
+}
+
+fn default_value(field: &StructField) -> TokenStream {
+    match field
+        .attrs
+        .default_value
+        .as_ref()
+        .and_then(|def| TokenStream::from_str(def).ok())
+    {
+        Some(liter) => liter,
+        None => quote! { std::default::Default::default() },
+    }
+}
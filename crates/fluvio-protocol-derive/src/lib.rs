@@ -39,6 +39,8 @@ use syn::parse_macro_input;
 ///
 ///
 /// Decoder applies to either Struct of Enum.  For enum, it implements `TryFrom` trait.  
+/// Structs can have named fields or be tuple structs.  Type parameters of enum must implement
+/// `Decoder`, so generic enums can carry data.
 /// Currently it only supports integer variants.  
 ///
 /// So this works
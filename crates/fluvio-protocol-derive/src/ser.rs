@@ -1,5 +1,5 @@
 use crate::ast::{
-    container::ContainerAttributes, prop::NamedProp, r#enum::EnumProp, r#enum::FieldKind,
+    container::ContainerAttributes, r#enum::EnumProp, r#enum::FieldKind, r#struct::StructField,
     DeriveItem,
 };
 use proc_macro2::Span;
@@ -9,13 +9,16 @@ use std::str::FromStr;
 use syn::punctuated::Punctuated;
 use syn::{Ident, LitInt, Token};
 
+use crate::util::add_type_param_bounds;
+
 pub(crate) fn generate_encode_trait_impls(input: &DeriveItem) -> TokenStream {
     match &input {
         DeriveItem::Struct(kf_struct, _attrs) => {
             let ident = &kf_struct.struct_ident;
             let (impl_generics, ty_generics, where_clause) = kf_struct.generics.split_for_impl();
-            let encoded_field_tokens = parse_struct_props_encoding(&kf_struct.props, ident);
-            let size_field_tokens = parse_struct_props_size(&kf_struct.props, ident);
+            let fields = kf_struct.fields();
+            let encoded_field_tokens = parse_struct_props_encoding(&fields, ident);
+            let size_field_tokens = parse_struct_props_size(&fields, ident);
             quote! {
                 impl #impl_generics fluvio_protocol::Encoder for #ident #ty_generics #where_clause {
                     fn encode<T>(&self, dest: &mut T, version: fluvio_protocol::Version) -> Result<(),std::io::Error> where T: fluvio_protocol::bytes::BufMut {
@@ -35,7 +38,10 @@ pub(crate) fn generate_encode_trait_impls(input: &DeriveItem) -> TokenStream {
         }
         DeriveItem::Enum(kf_enum, attrs) => {
             let ident = &kf_enum.enum_ident;
-            let (impl_generics, ty_generics, where_clause) = kf_enum.generics.split_for_impl();
+            // data of variants of generic enum is encoded with type parameters
+            let generics =
+                add_type_param_bounds(&kf_enum.generics, quote! { fluvio_protocol::Encoder });
+            let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
             let encoded_variant_tokens = parse_enum_variants_encoding(&kf_enum.props, ident, attrs);
             let size_variant_tokens = parse_enum_variants_size(&kf_enum.props, ident, attrs);
             quote! {
@@ -56,11 +62,11 @@ pub(crate) fn generate_encode_trait_impls(input: &DeriveItem) -> TokenStream {
     }
 }
 
-fn parse_struct_props_encoding(props: &[NamedProp], struct_ident: &Ident) -> TokenStream {
-    let recurse = props.iter().map(|prop| {
-        let fname = format_ident!("{}", prop.field_name);
+fn parse_struct_props_encoding(fields: &[StructField], struct_ident: &Ident) -> TokenStream {
+    let recurse = fields.iter().map(|field| {
+        let fname = &field.access;
 
-        if prop.attrs.varint {
+        if field.attrs.varint {
             quote! {
                 tracing::trace!("encoding varint struct: <{}> field <{}> => {:?}",stringify!(#struct_ident),stringify!(#fname),&self.#fname);
                 let result = self.#fname.encode_varint(dest);
@@ -79,7 +85,7 @@ fn parse_struct_props_encoding(props: &[NamedProp], struct_ident: &Ident) -> Tok
                 }
             };
 
-            prop.version_check_token_stream(base)
+            field.attrs.version_check_token_stream(&field.name, base)
         }
     });
 
@@ -88,10 +94,10 @@ fn parse_struct_props_encoding(props: &[NamedProp], struct_ident: &Ident) -> Tok
     }
 }
 
-fn parse_struct_props_size(props: &[NamedProp], struct_ident: &Ident) -> TokenStream {
-    let recurse = props.iter().map(|prop| {
-        let fname = format_ident!("{}", prop.field_name);
-        if prop.attrs.varint {
+fn parse_struct_props_size(fields: &[StructField], struct_ident: &Ident) -> TokenStream {
+    let recurse = fields.iter().map(|field| {
+        let fname = &field.access;
+        if field.attrs.varint {
             quote! {
                 let write_size = self.#fname.var_write_size();
                 tracing::trace!("varint write size: <{}>, field: <{}> is: {}",stringify!(#struct_ident),stringify!(#fname),write_size);
@@ -103,7 +109,7 @@ fn parse_struct_props_size(props: &[NamedProp], struct_ident: &Ident) -> TokenSt
                 tracing::trace!("write size: <{}> field: <{}> => {}",stringify!(#struct_ident),stringify!(#fname),write_size);
                 len += write_size;
             };
-            prop.version_check_token_stream(base)
+            field.attrs.version_check_token_stream(&field.name, base)
         }
     });
     quote! {
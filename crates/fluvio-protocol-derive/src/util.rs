@@ -1,4 +1,5 @@
-use syn::{Attribute, Lit, LitStr, Meta, MetaNameValue, NestedMeta};
+use proc_macro2::TokenStream;
+use syn::{parse_quote, Attribute, Generics, Lit, LitStr, Meta, MetaNameValue, NestedMeta};
 
 pub(crate) fn find_attr(attrs: &[Attribute], name: &str) -> Option<Meta> {
     attrs.iter().find_map(|a| {
@@ -73,3 +74,22 @@ pub(crate) fn find_string_name_value(version_meta: &Meta, attr_name: &str) -> Op
         None
     }
 }
+
+/// add bound to all type parameters, e.g. `T: fluvio_protocol::Encoder`
+pub(crate) fn add_type_param_bounds(generics: &Generics, bound: TokenStream) -> Generics {
+    let mut generics = generics.clone();
+    let params: Vec<_> = generics
+        .type_params()
+        .map(|param| param.ident.clone())
+        .collect();
+    if params.is_empty() {
+        return generics;
+    }
+    let where_clause = generics.make_where_clause();
+    for param in params {
+        where_clause
+            .predicates
+            .push(parse_quote! { #param: #bound });
+    }
+    generics
+}
@@ -26,18 +26,18 @@ macro_rules! api_decode {
     }};
 }
 
-pub trait Request: Encoder + Decoder + Debug {
+pub trait Request: Encoder + Decoder + Default + Debug {
     const API_KEY: u16;
 
     const DEFAULT_API_VERSION: i16 = 0;
     const MIN_API_VERSION: i16 = 0;
     const MAX_API_VERSION: i16 = -1;
 
-    type Response: Encoder + Decoder + Debug;
+    type Response: Encoder + Decoder + Default + Debug;
 }
 
 pub trait ApiMessage: Sized + Default {
-    type ApiKey: Decoder + Debug;
+    type ApiKey: Decoder + Default + Debug;
 
     fn decode_with_header<T>(src: &mut T, header: RequestHeader) -> Result<Self, IoError>
     where
@@ -78,7 +78,7 @@ pub trait ApiMessage: Sized + Default {
     }
 }
 
-pub trait ApiKey: Sized + Encoder + Decoder + TryFrom<u16> {}
+pub trait ApiKey: Sized + Encoder + Decoder + Default + TryFrom<u16> {}
 
 #[derive(Debug, Encoder, Decoder, Default)]
 pub struct RequestHeader {
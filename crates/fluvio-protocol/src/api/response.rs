@@ -34,7 +34,7 @@ impl<P> ResponseMessage<P> {
 
 impl<P> ResponseMessage<P>
 where
-    P: Decoder,
+    P: Decoder + Default,
 {
     pub fn decode_from<T>(src: &mut T, version: Version) -> Result<Self, IoError>
     where
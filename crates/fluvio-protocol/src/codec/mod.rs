@@ -1,26 +1,52 @@
 use std::io::Cursor;
 use std::io::Error as IoError;
+use std::io::ErrorKind;
 
-use tracing::trace;
+use tracing::{trace, warn};
 use tokio_util::codec::Decoder;
 use tokio_util::codec::Encoder;
 use bytes::{Bytes, BytesMut, BufMut};
 
 use crate::{Encoder as FluvioEncoder, Decoder as FluvioDecoder, Version};
 
+/// default limit of message size, excluding 4 bytes of size
+pub const DEFAULT_MAX_FRAME_SIZE: usize = 100 * 1024 * 1024;
+
 /// Implement Fluvio Encoding
 /// First 4 bytes are size of the message.  Then total buffer = 4 + message content
 ///
-#[derive(Debug, Default)]
-pub struct FluvioCodec {}
+/// Messages with negative size or size over max frame size are rejected
+/// with `InvalidData` error before they are buffered.
+#[derive(Debug)]
+pub struct FluvioCodec {
+    max_frame_size: usize,
+}
 
 /// Type used as input by the [`FluvioCodec`] encoder implementation.
 /// Contains the data of the message and the [`crate::core:Version`].
 pub type FluvioCodecData<T> = (T, Version);
 
+impl Default for FluvioCodec {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl FluvioCodec {
     pub fn new() -> Self {
-        Self {}
+        Self::with_max_frame_size(DEFAULT_MAX_FRAME_SIZE)
+    }
+
+    pub fn with_max_frame_size(max_frame_size: usize) -> Self {
+        Self { max_frame_size }
+    }
+
+    pub fn max_frame_size(&self) -> usize {
+        self.max_frame_size
+    }
+
+    pub fn set_max_frame_size(&mut self, max_frame_size: usize) {
+        self.max_frame_size = max_frame_size;
     }
 }
 
@@ -42,13 +68,35 @@ impl Decoder for FluvioCodec {
                 len,
                 packet_len
             );
-            if (packet_len + 4) as usize <= bytes.len() {
+            if packet_len < 0 {
+                warn!(packet_len, "Decoder: negative message size");
+                return Err(IoError::new(
+                    ErrorKind::InvalidData,
+                    format!("invalid message size: {}", packet_len),
+                ));
+            }
+            let packet_len = packet_len as usize;
+            if packet_len > self.max_frame_size {
+                warn!(
+                    packet_len,
+                    max_frame_size = self.max_frame_size,
+                    "Decoder: message size exceeds limit"
+                );
+                return Err(IoError::new(
+                    ErrorKind::InvalidData,
+                    format!(
+                        "message size: {} exceeds max frame size: {}",
+                        packet_len, self.max_frame_size
+                    ),
+                ));
+            }
+            if packet_len + 4 <= bytes.len() {
                 trace!(
                     "Decoder: all packets are in buffer len: {}, excess {}",
                     packet_len + 4,
-                    bytes.len() - (packet_len + 4) as usize
+                    bytes.len() - (packet_len + 4)
                 );
-                let mut buf = bytes.split_to((packet_len + 4) as usize);
+                let mut buf = bytes.split_to(packet_len + 4);
                 let message = buf.split_off(4); // truncate length
                 Ok(Some(message))
             } else {
@@ -173,7 +221,7 @@ mod test {
             debug!("server: got connection from client");
             let tcp_stream = stream.expect("stream");
 
-            let framed = Framed::new(tcp_stream.compat(), FluvioCodec {});
+            let framed = Framed::new(tcp_stream.compat(), FluvioCodec::new());
             let (mut sink, _) = framed.split();
 
             // send 2 times in order
@@ -195,7 +243,7 @@ mod test {
         debug!("client: trying to connect");
         let tcp_stream = TcpStream::connect(&addr).await.expect("connect");
         debug!("client: got connection. waiting");
-        let framed = Framed::new(tcp_stream.compat(), FluvioCodec {});
+        let framed = Framed::new(tcp_stream.compat(), FluvioCodec::new());
         let (_, mut stream) = framed.split::<(T, _)>();
         for _ in 0..2u16 {
             if let Some(value) = stream.next().await {
@@ -271,4 +319,39 @@ mod test {
 
         let _rt = join(client_ft, server_ft).await;
     }
+
+    #[test]
+    fn test_decode_max_frame_size() {
+        use bytes::{BufMut, BytesMut};
+        use tokio_util::codec::Decoder as _;
+
+        let mut codec = FluvioCodec::with_max_frame_size(4);
+
+        // message within limit is decoded when it's complete
+        let mut bytes = BytesMut::new();
+        bytes.put_i32(4);
+        bytes.put_slice(&[1, 2]);
+        assert!(codec.decode(&mut bytes).expect("partial").is_none());
+        bytes.put_slice(&[3, 4]);
+        let message = codec.decode(&mut bytes).expect("decode").expect("message");
+        assert_eq!(message.as_ref(), &[1, 2, 3, 4]);
+        assert!(bytes.is_empty());
+
+        // oversized message is rejected before it's buffered
+        let mut bytes = BytesMut::new();
+        bytes.put_i32(5);
+        let err = codec.decode(&mut bytes).expect_err("too large");
+        assert_eq!(err.kind(), std::io::ErrorKind::InvalidData);
+
+        let mut bytes = BytesMut::new();
+        bytes.put_i32(-1);
+        let err = codec.decode(&mut bytes).expect_err("negative");
+        assert_eq!(err.kind(), std::io::ErrorKind::InvalidData);
+
+        codec.set_max_frame_size(8);
+        let mut bytes = BytesMut::new();
+        bytes.put_i32(5);
+        bytes.put_slice(&[0; 5]);
+        assert!(codec.decode(&mut bytes).expect("decode").is_some());
+    }
 }
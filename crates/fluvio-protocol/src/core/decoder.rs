@@ -1,19 +1,23 @@
 use std::cmp::Ord;
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, HashMap};
+use std::hash::{BuildHasher, Hash};
 use std::io::Error;
 use std::io::ErrorKind;
 use std::io::Read;
 use std::marker::PhantomData;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
 
 use bytes::Buf;
 use bytes::BufMut;
+use bytes::Bytes;
 use tracing::trace;
 
 use super::varint::varint_decode;
 use crate::Version;
 
-// trait for encoding and decoding using Kafka Protocol
-pub trait Decoder: Sized + Default {
+// trait for encoding and decoding using Kafka Protocol,
+// types without `Default` such as `SystemTime` can be decoded in place only
+pub trait Decoder: Sized {
     /// decode Kafka compliant protocol values from buf
     fn decode_from<T>(src: &mut T, version: Version) -> Result<Self, Error>
     where
@@ -60,11 +64,34 @@ where
     }
 }
 
+/// length of bytes read from source can't be trusted.
+/// Reject length which can't be satisfied by remaining bytes before anything is allocated
+fn check_remaining<T>(len: usize, src: &T, name: &str) -> Result<(), Error>
+where
+    T: Buf,
+{
+    if len > src.remaining() {
+        return Err(Error::new(
+            ErrorKind::UnexpectedEof,
+            format!(
+                "{} length: {} exceeds remaining bytes: {}",
+                name,
+                len,
+                src.remaining()
+            ),
+        ));
+    }
+    Ok(())
+}
+
 fn decode_vec<T, M>(len: i32, item: &mut Vec<M>, src: &mut T, version: Version) -> Result<(), Error>
 where
     T: Buf,
     M: Default + Decoder,
 {
+    // length can't be trusted and elements may be encoded with zero bytes,
+    // so pre-allocation is capped by remaining bytes, short input fails in decoding of element
+    item.reserve((len as usize).min(src.remaining()));
     for _ in 0..len {
         let mut value = <M>::default();
         value.decode(src, version)?;
@@ -109,8 +136,8 @@ where
 
 impl<K, V> Decoder for BTreeMap<K, V>
 where
-    K: Decoder + Ord,
-    V: Decoder,
+    K: Decoder + Default + Ord,
+    V: Decoder + Default,
 {
     fn decode<T>(&mut self, src: &mut T, version: Version) -> Result<(), Error>
     where
@@ -133,6 +160,71 @@ where
     }
 }
 
+impl<K, V, S> Decoder for HashMap<K, V, S>
+where
+    K: Decoder + Default + Eq + Hash,
+    V: Decoder + Default,
+    S: BuildHasher + Default,
+{
+    fn decode<T>(&mut self, src: &mut T, version: Version) -> Result<(), Error>
+    where
+        T: Buf,
+    {
+        let mut len: u16 = 0;
+        len.decode(src, version)?;
+
+        let mut map: HashMap<K, V, S> =
+            HashMap::with_capacity_and_hasher((len as usize).min(src.remaining()), S::default());
+        for _i in 0..len {
+            let mut key = K::default();
+            key.decode(src, version)?;
+            let mut value = V::default();
+            value.decode(src, version)?;
+            map.insert(key, value);
+        }
+
+        *self = map;
+        Ok(())
+    }
+}
+
+impl<M, const N: usize> Decoder for [M; N]
+where
+    M: Decoder,
+    [M; N]: Default,
+{
+    fn decode<T>(&mut self, src: &mut T, version: Version) -> Result<(), Error>
+    where
+        T: Buf,
+    {
+        for value in self.iter_mut() {
+            value.decode(src, version)?;
+        }
+        Ok(())
+    }
+}
+
+macro_rules! impl_decoder_for_tuple {
+    ($($name:ident $idx:tt),+) => {
+        impl<$($name: Decoder),+> Decoder for ($($name,)+) {
+            fn decode<T>(&mut self, src: &mut T, version: Version) -> Result<(), Error>
+            where
+                T: Buf,
+            {
+                $(self.$idx.decode(src, version)?;)+
+                Ok(())
+            }
+        }
+    };
+}
+
+impl_decoder_for_tuple!(A 0);
+impl_decoder_for_tuple!(A 0, B 1);
+impl_decoder_for_tuple!(A 0, B 1, C 2);
+impl_decoder_for_tuple!(A 0, B 1, C 2, D 3);
+impl_decoder_for_tuple!(A 0, B 1, C 2, D 3, E 4);
+impl_decoder_for_tuple!(A 0, B 1, C 2, D 3, E 4, F 5);
+
 impl Decoder for bool {
     fn decode<T>(&mut self, src: &mut T, _version: Version) -> Result<(), Error>
     where
@@ -265,6 +357,47 @@ impl Decoder for i64 {
     }
 }
 
+impl Decoder for u64 {
+    fn decode<T>(&mut self, src: &mut T, _version: Version) -> Result<(), Error>
+    where
+        T: Buf,
+    {
+        if src.remaining() < 8 {
+            return Err(Error::new(ErrorKind::UnexpectedEof, "can't read u64"));
+        }
+        let value = src.get_u64();
+        trace!("u64: {:#x} => {}", &value, &value);
+        *self = value;
+        Ok(())
+    }
+}
+
+impl Decoder for f32 {
+    fn decode<T>(&mut self, src: &mut T, _version: Version) -> Result<(), Error>
+    where
+        T: Buf,
+    {
+        if src.remaining() < 4 {
+            return Err(Error::new(ErrorKind::UnexpectedEof, "can't read f32"));
+        }
+        *self = src.get_f32();
+        Ok(())
+    }
+}
+
+impl Decoder for f64 {
+    fn decode<T>(&mut self, src: &mut T, _version: Version) -> Result<(), Error>
+    where
+        T: Buf,
+    {
+        if src.remaining() < 8 {
+            return Err(Error::new(ErrorKind::UnexpectedEof, "can't read f64"));
+        }
+        *self = src.get_f64();
+        Ok(())
+    }
+}
+
 impl DecoderVarInt for i64 {
     fn decode_varint<T>(&mut self, src: &mut T) -> Result<(), Error>
     where
@@ -280,6 +413,7 @@ fn decode_string<T>(len: i16, src: &mut T) -> Result<String, Error>
 where
     T: Buf,
 {
+    check_remaining(len as usize, src, "String")?;
     let mut value = String::default();
     let read_size = src.take(len as usize).reader().read_to_string(&mut value)?;
 
@@ -311,6 +445,80 @@ impl Decoder for String {
     }
 }
 
+impl Decoder for Bytes {
+    fn decode<T>(&mut self, src: &mut T, version: Version) -> Result<(), Error>
+    where
+        T: Buf,
+    {
+        let mut len: i32 = 0;
+        len.decode(src, version)?;
+
+        if len < 1 {
+            *self = Bytes::new();
+            return Ok(());
+        }
+
+        check_remaining(len as usize, src, "Bytes")?;
+        *self = src.copy_to_bytes(len as usize);
+        Ok(())
+    }
+}
+
+/// seconds as u64 followed by nanoseconds as u32
+impl Decoder for Duration {
+    fn decode<T>(&mut self, src: &mut T, version: Version) -> Result<(), Error>
+    where
+        T: Buf,
+    {
+        let mut secs: u64 = 0;
+        secs.decode(src, version)?;
+        let mut nanos: u32 = 0;
+        nanos.decode(src, version)?;
+        if nanos >= 1_000_000_000 {
+            return Err(Error::new(
+                ErrorKind::InvalidData,
+                format!("invalid nanoseconds of duration: {}", nanos),
+            ));
+        }
+        *self = Duration::new(secs, nanos);
+        Ok(())
+    }
+}
+
+/// `Duration` since unix epoch
+impl Decoder for SystemTime {
+    fn decode<T>(&mut self, src: &mut T, version: Version) -> Result<(), Error>
+    where
+        T: Buf,
+    {
+        let mut since_epoch = Duration::default();
+        since_epoch.decode(src, version)?;
+        *self = UNIX_EPOCH.checked_add(since_epoch).ok_or_else(|| {
+            Error::new(
+                ErrorKind::InvalidData,
+                format!("time since unix epoch out of range: {:?}", since_epoch),
+            )
+        })?;
+        Ok(())
+    }
+}
+
+#[cfg(feature = "uuid")]
+impl Decoder for uuid::Uuid {
+    fn decode<T>(&mut self, src: &mut T, _version: Version) -> Result<(), Error>
+    where
+        T: Buf,
+    {
+        if src.remaining() < 16 {
+            return Err(Error::new(ErrorKind::UnexpectedEof, "can't read uuid"));
+        }
+        let mut bytes = [0; 16];
+        src.copy_to_slice(&mut bytes);
+        *self = uuid::Uuid::from_bytes(bytes);
+        Ok(())
+    }
+}
+
 impl DecoderVarInt for Vec<u8> {
     fn decode_varint<T>(&mut self, src: &mut T) -> Result<(), Error>
     where
@@ -323,6 +531,7 @@ impl DecoderVarInt for Vec<u8> {
             return Ok(());
         }
 
+        check_remaining(len as usize, src, "varint: Vec<u8>")?;
         let mut buf = src.take(len as usize);
         self.put(&mut buf);
         if self.len() != len as usize {
@@ -354,6 +563,7 @@ where
         return Ok(());
     }
 
+    check_remaining(len as usize, src, "Option<Vec<u8>>")?;
     let mut buf = src.take(len as usize);
     let mut value: Vec<u8> = Vec::new();
     value.put(&mut buf);
@@ -394,6 +604,7 @@ mod test {
     use bytes::Buf;
     use std::io::Cursor;
     use std::io::Error;
+    use std::marker::PhantomData;
 
     #[test]
     fn test_decode_i18_not_enough() {
@@ -638,6 +849,16 @@ mod test {
         assert_eq!(first_str, "test");
     }
 
+    #[test]
+    fn test_decode_zero_sized_vectors() {
+        // elements without encoded bytes, length is larger than remaining bytes
+        let data = [0x00, 0x00, 0x00, 0x03];
+        let mut values: Vec<PhantomData<i32>> = Vec::new();
+        let result = values.decode(&mut Cursor::new(&data), 0);
+        assert!(result.is_ok());
+        assert_eq!(values.len(), 3);
+    }
+
     #[test]
     fn test_decode_varint_trait() {
         let data = [0x7e];
@@ -1,9 +1,10 @@
 // decode values
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, HashMap};
 use std::io::Error;
 use std::io::ErrorKind;
 use std::io::Write;
 use std::marker::PhantomData;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
 
 use bytes::BufMut;
 use bytes::Bytes;
@@ -135,8 +136,7 @@ where
     where
         T: BufMut,
     {
-        let len = self.len() as u16;
-        len.encode(dest, version)?;
+        encode_map_len(self.len(), dest, version)?;
 
         for (key, value) in self.iter() {
             key.encode(dest, version)?;
@@ -147,6 +147,98 @@ where
     }
 }
 
+impl<K, V, S> Encoder for HashMap<K, V, S>
+where
+    K: Encoder,
+    V: Encoder,
+{
+    fn write_size(&self, version: Version) -> usize {
+        let mut len: usize = (0_u16).write_size(version);
+
+        for (key, value) in self.iter() {
+            len += key.write_size(version);
+            len += value.write_size(version);
+        }
+
+        len
+    }
+
+    /// same format as `BTreeMap`, entries are encoded in iteration order
+    fn encode<T>(&self, dest: &mut T, version: Version) -> Result<(), Error>
+    where
+        T: BufMut,
+    {
+        encode_map_len(self.len(), dest, version)?;
+
+        for (key, value) in self.iter() {
+            key.encode(dest, version)?;
+            value.encode(dest, version)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// maps are encoded with u16 length, larger maps can't be encoded
+fn encode_map_len<T>(len: usize, dest: &mut T, version: Version) -> Result<(), Error>
+where
+    T: BufMut,
+{
+    if len > u16::MAX as usize {
+        return Err(Error::new(
+            ErrorKind::InvalidInput,
+            format!("map length: {} exceeds max: {}", len, u16::MAX),
+        ));
+    }
+    (len as u16).encode(dest, version)
+}
+
+/// fixed size array is encoded without length
+impl<M, const N: usize> Encoder for [M; N]
+where
+    M: Encoder,
+{
+    fn write_size(&self, version: Version) -> usize {
+        self.iter().map(|val| val.write_size(version)).sum()
+    }
+
+    fn encode<T>(&self, dest: &mut T, version: Version) -> Result<(), Error>
+    where
+        T: BufMut,
+    {
+        for value in self.iter() {
+            value.encode(dest, version)?;
+        }
+        Ok(())
+    }
+}
+
+/// tuple is encoded as its elements in order
+macro_rules! impl_encoder_for_tuple {
+    ($($name:ident $idx:tt),+) => {
+        impl<$($name: Encoder),+> Encoder for ($($name,)+) {
+            fn write_size(&self, version: Version) -> usize {
+                0 $(+ self.$idx.write_size(version))+
+            }
+
+            fn encode<T>(&self, dest: &mut T, version: Version) -> Result<(), Error>
+            where
+                T: BufMut,
+            {
+                $(self.$idx.encode(dest, version)?;)+
+                Ok(())
+            }
+        }
+    };
+}
+
+impl_encoder_for_tuple!(A 0);
+impl_encoder_for_tuple!(A 0, B 1);
+impl_encoder_for_tuple!(A 0, B 1, C 2);
+impl_encoder_for_tuple!(A 0, B 1, C 2, D 3);
+impl_encoder_for_tuple!(A 0, B 1, C 2, D 3, E 4);
+impl_encoder_for_tuple!(A 0, B 1, C 2, D 3, E 4, F 5);
+
 impl Encoder for bool {
     fn write_size(&self, _version: Version) -> usize {
         1
@@ -314,6 +406,66 @@ impl Encoder for i64 {
     }
 }
 
+impl Encoder for u64 {
+    fn write_size(&self, _version: Version) -> usize {
+        8
+    }
+
+    fn encode<T>(&self, dest: &mut T, _version: Version) -> Result<(), Error>
+    where
+        T: BufMut,
+    {
+        if dest.remaining_mut() < 8 {
+            return Err(Error::new(
+                ErrorKind::UnexpectedEof,
+                "not enough capacity for u64",
+            ));
+        }
+        dest.put_u64(*self);
+        Ok(())
+    }
+}
+
+impl Encoder for f32 {
+    fn write_size(&self, _version: Version) -> usize {
+        4
+    }
+
+    fn encode<T>(&self, dest: &mut T, _version: Version) -> Result<(), Error>
+    where
+        T: BufMut,
+    {
+        if dest.remaining_mut() < 4 {
+            return Err(Error::new(
+                ErrorKind::UnexpectedEof,
+                "not enough capacity for f32",
+            ));
+        }
+        dest.put_f32(*self);
+        Ok(())
+    }
+}
+
+impl Encoder for f64 {
+    fn write_size(&self, _version: Version) -> usize {
+        8
+    }
+
+    fn encode<T>(&self, dest: &mut T, _version: Version) -> Result<(), Error>
+    where
+        T: BufMut,
+    {
+        if dest.remaining_mut() < 8 {
+            return Err(Error::new(
+                ErrorKind::UnexpectedEof,
+                "not enough capacity for f64",
+            ));
+        }
+        dest.put_f64(*self);
+        Ok(())
+    }
+}
+
 impl EncoderVarInt for i64 {
     fn var_write_size(&self) -> usize {
         variant_size(*self)
@@ -364,6 +516,84 @@ impl Encoder for String {
     }
 }
 
+/// same format as `Vec<u8>`
+impl Encoder for Bytes {
+    fn write_size(&self, _version: Version) -> usize {
+        4 + self.len()
+    }
+
+    fn encode<T>(&self, dest: &mut T, _version: Version) -> Result<(), Error>
+    where
+        T: BufMut,
+    {
+        if dest.remaining_mut() < 4 + self.len() {
+            return Err(Error::new(
+                ErrorKind::UnexpectedEof,
+                "not enough capacity for bytes",
+            ));
+        }
+        dest.put_u32(self.len() as u32);
+        dest.put_slice(self);
+        Ok(())
+    }
+}
+
+/// seconds as u64 followed by nanoseconds as u32
+impl Encoder for Duration {
+    fn write_size(&self, version: Version) -> usize {
+        self.as_secs().write_size(version) + self.subsec_nanos().write_size(version)
+    }
+
+    fn encode<T>(&self, dest: &mut T, version: Version) -> Result<(), Error>
+    where
+        T: BufMut,
+    {
+        self.as_secs().encode(dest, version)?;
+        self.subsec_nanos().encode(dest, version)
+    }
+}
+
+/// `Duration` since unix epoch, time before epoch can't be encoded
+impl Encoder for SystemTime {
+    fn write_size(&self, version: Version) -> usize {
+        Duration::default().write_size(version)
+    }
+
+    fn encode<T>(&self, dest: &mut T, version: Version) -> Result<(), Error>
+    where
+        T: BufMut,
+    {
+        let since_epoch = self.duration_since(UNIX_EPOCH).map_err(|_| {
+            Error::new(
+                ErrorKind::InvalidData,
+                "can't encode time before unix epoch",
+            )
+        })?;
+        since_epoch.encode(dest, version)
+    }
+}
+
+#[cfg(feature = "uuid")]
+impl Encoder for uuid::Uuid {
+    fn write_size(&self, _version: Version) -> usize {
+        16
+    }
+
+    fn encode<T>(&self, dest: &mut T, _version: Version) -> Result<(), Error>
+    where
+        T: BufMut,
+    {
+        if dest.remaining_mut() < 16 {
+            return Err(Error::new(
+                ErrorKind::UnexpectedEof,
+                "not enough capacity for uuid",
+            ));
+        }
+        dest.put_slice(self.as_bytes());
+        Ok(())
+    }
+}
+
 impl<M> Encoder for &M
 where
     M: Encoder,
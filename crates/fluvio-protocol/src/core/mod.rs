@@ -1,5 +1,6 @@
 mod decoder;
 mod encoder;
+mod timestamp;
 mod varint;
 mod zerocopy;
 
@@ -7,6 +8,7 @@ pub use self::decoder::Decoder;
 pub use self::decoder::DecoderVarInt;
 pub use self::encoder::Encoder;
 pub use self::encoder::EncoderVarInt;
+pub use self::timestamp::Timestamp;
 pub type Version = i16;
 
 #[cfg(test)]
@@ -0,0 +1,61 @@
+use std::io::Error;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use bytes::Buf;
+use bytes::BufMut;
+
+use crate::Version;
+
+use super::Decoder;
+use super::Encoder;
+
+/// `SystemTime` with default of unix epoch, so it can be used in types deriving `Default`.
+/// Encoded same as `SystemTime`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Timestamp(pub SystemTime);
+
+impl Timestamp {
+    pub fn now() -> Self {
+        Self(SystemTime::now())
+    }
+}
+
+impl Default for Timestamp {
+    fn default() -> Self {
+        Self(UNIX_EPOCH)
+    }
+}
+
+impl From<SystemTime> for Timestamp {
+    fn from(time: SystemTime) -> Self {
+        Self(time)
+    }
+}
+
+impl From<Timestamp> for SystemTime {
+    fn from(timestamp: Timestamp) -> Self {
+        timestamp.0
+    }
+}
+
+impl Encoder for Timestamp {
+    fn write_size(&self, version: Version) -> usize {
+        self.0.write_size(version)
+    }
+
+    fn encode<T>(&self, dest: &mut T, version: Version) -> Result<(), Error>
+    where
+        T: BufMut,
+    {
+        self.0.encode(dest, version)
+    }
+}
+
+impl Decoder for Timestamp {
+    fn decode<T>(&mut self, src: &mut T, version: Version) -> Result<(), Error>
+    where
+        T: Buf,
+    {
+        self.0.decode(src, version)
+    }
+}
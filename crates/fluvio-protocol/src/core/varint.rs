@@ -25,6 +25,13 @@ where
             ));
         }
 
+        if shift > 63 {
+            return Err(Error::new(
+                ErrorKind::InvalidData,
+                "varint decoding exceeds 64 bits",
+            ));
+        }
+
         let b = buf.get_u8();
         trace!("var byte: {:#X}", b);
 
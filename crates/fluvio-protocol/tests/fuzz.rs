@@ -0,0 +1,158 @@
+use std::collections::BTreeMap;
+use std::fmt::Debug;
+use std::io::Cursor;
+
+use fluvio_protocol::{Decoder, DecoderVarInt, Encoder};
+
+const ITERATIONS: usize = 2000;
+
+/// xorshift generator, so failures can be reproduced from seed
+struct Rng(u64);
+
+impl Rng {
+    fn next_u64(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+
+    fn bytes(&mut self, max_len: usize) -> Vec<u8> {
+        let len = self.next_u64() as usize % (max_len + 1);
+        (0..len).map(|_| self.next_u64() as u8).collect()
+    }
+}
+
+#[derive(Encoder, Decoder, Default, Debug, PartialEq, Clone)]
+pub struct FuzzRecord {
+    name: String,
+    values: Vec<i64>,
+    #[varint]
+    len: i64,
+    tags: Vec<String>,
+    parent: Option<String>,
+    properties: BTreeMap<String, Vec<u8>>,
+}
+
+fn sample() -> FuzzRecord {
+    let mut properties = BTreeMap::new();
+    properties.insert("key".to_owned(), vec![1, 2, 3]);
+    FuzzRecord {
+        name: "fuzz".to_owned(),
+        values: vec![-1, 0, i64::MAX],
+        len: 300,
+        tags: vec!["a".to_owned(), "".to_owned()],
+        parent: Some("root".to_owned()),
+        properties,
+    }
+}
+
+/// random input must be decoded or rejected without panic
+fn fuzz_random<D: Decoder + Default + Debug>(seed: u64) {
+    let mut rng = Rng(seed);
+    for _ in 0..ITERATIONS {
+        let data = rng.bytes(64);
+        let _ = D::decode_from(&mut Cursor::new(&data), 0);
+    }
+}
+
+/// every truncation of valid encoding must be rejected
+fn fuzz_truncated<D>(value: D)
+where
+    D: Encoder + Decoder + Default + Debug + PartialEq,
+{
+    let data = value.as_bytes(0).expect("encode");
+    for len in 0..data.len() {
+        let result = D::decode_from(&mut Cursor::new(&data[..len]), 0);
+        assert!(
+            result.is_err(),
+            "truncated at {} decoded: {:?}",
+            len,
+            result
+        );
+    }
+    let decoded = D::decode_from(&mut Cursor::new(&data), 0).expect("decode");
+    assert_eq!(decoded, value);
+}
+
+#[test]
+fn test_fuzz_primitives() {
+    fuzz_random::<bool>(1);
+    fuzz_random::<i8>(2);
+    fuzz_random::<u16>(3);
+    fuzz_random::<i32>(4);
+    fuzz_random::<u32>(5);
+    fuzz_random::<i64>(6);
+    fuzz_random::<String>(7);
+    fuzz_random::<Option<String>>(8);
+}
+
+#[test]
+fn test_fuzz_collections() {
+    fuzz_random::<Vec<u8>>(11);
+    fuzz_random::<Vec<String>>(12);
+    fuzz_random::<Vec<Vec<i32>>>(13);
+    fuzz_random::<BTreeMap<String, String>>(14);
+    fuzz_random::<FuzzRecord>(15);
+    fuzz_random::<Vec<FuzzRecord>>(16);
+}
+
+#[test]
+fn test_fuzz_varint() {
+    let mut rng = Rng(21);
+    for _ in 0..ITERATIONS {
+        let data = rng.bytes(32);
+        let mut value: Vec<u8> = Vec::new();
+        let _ = value.decode_varint(&mut Cursor::new(&data));
+        let mut value: Option<Vec<u8>> = None;
+        let _ = value.decode_varint(&mut Cursor::new(&data));
+        let mut value: i64 = 0;
+        let _ = value.decode_varint(&mut Cursor::new(&data));
+    }
+}
+
+#[test]
+fn test_fuzz_truncated() {
+    fuzz_truncated(String::from("truncated"));
+    fuzz_truncated(vec![1_i32, 2, 3]);
+    fuzz_truncated(vec![String::from("a"), String::from("b")]);
+    fuzz_truncated(sample());
+}
+
+#[test]
+fn test_fuzz_mutated() {
+    let data = sample().as_bytes(0).expect("encode");
+    let mut rng = Rng(31);
+    for _ in 0..ITERATIONS {
+        let mut mutated = data.clone();
+        for _ in 0..=(rng.next_u64() % 4) {
+            let index = rng.next_u64() as usize % mutated.len();
+            mutated[index] = rng.next_u64() as u8;
+        }
+        let _ = FuzzRecord::decode_from(&mut Cursor::new(&mutated), 0);
+    }
+}
+
+#[test]
+fn test_decode_oversized_length() {
+    // lengths larger than input fail without allocating elements for length
+    let data = [0x7f, 0xff, 0xff, 0xff];
+    assert!(Vec::<FuzzRecord>::decode_from(&mut Cursor::new(&data), 0).is_err());
+    assert!(Vec::<bool>::decode_from(&mut Cursor::new(&data), 0).is_err());
+
+    let data = [0x7f, 0xff, b'a'];
+    assert!(String::decode_from(&mut Cursor::new(&data), 0).is_err());
+
+    let data = [0xff, 0xff];
+    assert!(BTreeMap::<String, String>::decode_from(&mut Cursor::new(&data), 0).is_err());
+
+    // varint length of 1_000_000
+    let data = [0x80, 0x89, 0x7a, 0x01];
+    let mut value: Vec<u8> = Vec::new();
+    assert!(value.decode_varint(&mut Cursor::new(&data)).is_err());
+
+    // varint longer than 64 bits
+    let data = [0xff; 11];
+    let mut value: i64 = 0;
+    assert!(value.decode_varint(&mut Cursor::new(&data)).is_err());
+}
@@ -6,7 +6,7 @@ use fluvio_protocol::{Decoder, Encoder};
 #[derive(Encoder, Decoder, Default, Debug)]
 pub struct GenericRecord<R>
 where
-    R: Encoder + Decoder + Debug,
+    R: Encoder + Decoder + Default + Debug,
 {
     len: i64,
     value: R,
@@ -31,3 +31,37 @@ fn test_generic() {
     assert_eq!(decoded_record.len, 20);
     assert_eq!(decoded_record.value, 25);
 }
+
+#[derive(Encoder, Decoder, Debug, PartialEq)]
+pub enum GenericEnum<K, V> {
+    Empty,
+    Entry(K, V),
+    Named { key: K, values: Vec<V> },
+}
+
+impl<K, V> Default for GenericEnum<K, V> {
+    fn default() -> Self {
+        Self::Empty
+    }
+}
+
+#[test]
+fn test_generic_enum() {
+    let entry: GenericEnum<String, i32> = GenericEnum::Entry("a".to_owned(), 5);
+    let mut src = vec![];
+    entry.encode(&mut src, 0).expect("encode");
+    assert_eq!(src, vec![0x01, 0x00, 0x01, 0x61, 0x00, 0x00, 0x00, 0x05]);
+    assert_eq!(entry.write_size(0), src.len());
+    let decoded =
+        GenericEnum::<String, i32>::decode_from(&mut Cursor::new(&src), 0).expect("decode");
+    assert_eq!(decoded, entry);
+
+    let named: GenericEnum<u8, bool> = GenericEnum::Named {
+        key: 7,
+        values: vec![true, false],
+    };
+    let mut src = vec![];
+    named.encode(&mut src, 0).expect("encode");
+    let decoded = GenericEnum::<u8, bool>::decode_from(&mut Cursor::new(&src), 0).expect("decode");
+    assert_eq!(decoded, named);
+}
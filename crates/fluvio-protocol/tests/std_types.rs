@@ -0,0 +1,188 @@
+use std::collections::HashMap;
+use std::fmt::Debug;
+use std::io::Cursor;
+use std::io::Error;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use fluvio_protocol::bytes::Bytes;
+use fluvio_protocol::core::Timestamp;
+use fluvio_protocol::{Decoder, Encoder};
+
+fn round_trip<T>(value: &T) -> Result<T, Error>
+where
+    T: Encoder + Decoder + Default,
+{
+    let mut dest = vec![];
+    value.encode(&mut dest, 0)?;
+    assert_eq!(dest.len(), value.write_size(0));
+    let mut src = Cursor::new(&dest);
+    let decoded = T::decode_from(&mut src, 0)?;
+    assert_eq!(src.position() as usize, dest.len());
+    Ok(decoded)
+}
+
+fn assert_round_trip<T>(value: T)
+where
+    T: Encoder + Decoder + Default + PartialEq + Debug,
+{
+    assert_eq!(round_trip(&value).expect("round trip"), value);
+}
+
+#[derive(Encoder, Decoder, Default, Debug, PartialEq)]
+pub struct Measurement {
+    id: u64,
+    ratio: f32,
+    value: f64,
+    labels: HashMap<String, String>,
+    range: (i32, i64),
+    checksum: [u8; 4],
+    payload: Bytes,
+    elapsed: Duration,
+    created: Timestamp,
+}
+
+#[test]
+fn test_numbers() {
+    assert_round_trip(u64::MAX);
+    assert_round_trip(-1.5_f32);
+    assert_round_trip(f64::MIN_POSITIVE);
+
+    let mut dest = vec![];
+    1_u64.encode(&mut dest, 0).expect("encode");
+    assert_eq!(dest, vec![0, 0, 0, 0, 0, 0, 0, 1]);
+
+    let nan = round_trip(&f64::NAN).expect("round trip");
+    assert!(nan.is_nan());
+}
+
+#[test]
+fn test_hashmap() {
+    let mut map = HashMap::new();
+    map.insert(1_i32, vec![String::from("a")]);
+    map.insert(2, vec![]);
+    assert_round_trip(map);
+    assert_round_trip(HashMap::<String, i64>::new());
+
+    // length is encoded as u16
+    let large: HashMap<u32, bool> = (0..=u16::MAX as u32).map(|key| (key, true)).collect();
+    assert!(large.encode(&mut Vec::<u8>::new(), 0).is_err());
+}
+
+#[test]
+fn test_tuple_and_array() {
+    assert_round_trip((1_u8,));
+    assert_round_trip((1_u8, String::from("b"), -3_i64));
+    assert_round_trip((1_u8, 2_u16, 3_u32, 4_u64, 5_i8, String::from("f")));
+    assert_round_trip([1_i32, 2, 3]);
+    assert_round_trip([(1_u8, true), (2, false)]);
+
+    // array is encoded without length
+    let mut dest = vec![];
+    [7_u8; 3].encode(&mut dest, 0).expect("encode");
+    assert_eq!(dest, vec![7, 7, 7]);
+    assert!(<[u8; 3]>::decode_from(&mut Cursor::new(&[7_u8, 7]), 0).is_err());
+}
+
+#[test]
+fn test_bytes() {
+    let bytes = Bytes::from_static(b"payload");
+    let mut dest = vec![];
+    bytes.encode(&mut dest, 0).expect("encode");
+
+    // same format as Vec<u8>
+    let mut vec_dest = vec![];
+    b"payload"
+        .to_vec()
+        .encode(&mut vec_dest, 0)
+        .expect("encode");
+    assert_eq!(dest, vec_dest);
+
+    assert_round_trip(bytes);
+    assert_round_trip(Bytes::new());
+}
+
+#[test]
+fn test_time() {
+    assert_round_trip(Duration::new(5, 999_999_999));
+    assert_round_trip(Duration::default());
+
+    // invalid nanoseconds
+    let data = [0, 0, 0, 0, 0, 0, 0, 1, 0x3b, 0x9a, 0xca, 0x00];
+    assert!(Duration::decode_from(&mut Cursor::new(&data), 0).is_err());
+
+    // time is encoded as duration since unix epoch
+    let now = SystemTime::now();
+    let mut dest = vec![];
+    now.encode(&mut dest, 0).expect("encode");
+    assert_eq!(dest.len(), now.write_size(0));
+    let since_epoch = Duration::decode_from(&mut Cursor::new(&dest), 0).expect("decode");
+    assert_eq!(UNIX_EPOCH + since_epoch, now);
+    let mut decoded = UNIX_EPOCH;
+    decoded.decode(&mut Cursor::new(&dest), 0).expect("decode");
+    assert_eq!(decoded, now);
+
+    let before_epoch = UNIX_EPOCH - Duration::from_secs(1);
+    assert!(before_epoch.encode(&mut Vec::<u8>::new(), 0).is_err());
+
+    assert_round_trip(Timestamp::from(now));
+    assert_round_trip(Timestamp::default());
+    let mut timestamp_dest = vec![];
+    Timestamp(now)
+        .encode(&mut timestamp_dest, 0)
+        .expect("encode");
+    assert_eq!(timestamp_dest, dest);
+}
+
+/// struct with `SystemTime` field, which has no default
+#[derive(Encoder, Decoder, Debug, PartialEq)]
+pub struct Event {
+    id: u32,
+    created: SystemTime,
+}
+
+impl Default for Event {
+    fn default() -> Self {
+        Self {
+            id: 0,
+            created: UNIX_EPOCH,
+        }
+    }
+}
+
+#[test]
+fn test_struct_with_system_time() {
+    assert_round_trip(Event {
+        id: 7,
+        created: SystemTime::now(),
+    });
+}
+
+#[cfg(feature = "uuid")]
+#[test]
+fn test_uuid() {
+    let id = uuid::Uuid::from_bytes([
+        0xa1, 0xa2, 0xa3, 0xa4, 0xb1, 0xb2, 0xc1, 0xc2, 0xd1, 0xd2, 0xd3, 0xd4, 0xd5, 0xd6, 0xd7,
+        0xd8,
+    ]);
+    let mut dest = vec![];
+    id.encode(&mut dest, 0).expect("encode");
+    assert_eq!(dest.as_slice(), id.as_bytes());
+    assert_round_trip(id);
+}
+
+#[test]
+fn test_struct_with_std_types() {
+    let mut labels = HashMap::new();
+    labels.insert("host".to_owned(), "spu-0".to_owned());
+    assert_round_trip(Measurement {
+        id: 42,
+        ratio: 0.5,
+        value: 1e10,
+        labels,
+        range: (-1, i64::MAX),
+        checksum: [0xde, 0xad, 0xbe, 0xef],
+        payload: Bytes::from_static(&[1, 2, 3]),
+        elapsed: Duration::from_millis(1500),
+        created: Timestamp::now(),
+    });
+}
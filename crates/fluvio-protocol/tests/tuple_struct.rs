@@ -0,0 +1,46 @@
+use std::io::Cursor;
+
+use fluvio_protocol::{Decoder, DecoderVarInt, Encoder, EncoderVarInt, FluvioDefault};
+
+#[derive(Encoder, Decoder, Default, Debug, PartialEq)]
+pub struct Offset(i64);
+
+#[derive(Encoder, Decoder, FluvioDefault, Debug, PartialEq)]
+pub struct VersionedPair(
+    #[fluvio(default = "-1")] i32,
+    #[varint] i64,
+    #[fluvio(min_version = 1)] String,
+);
+
+#[test]
+fn test_tuple_struct() {
+    let offset = Offset(20);
+    let mut src = vec![];
+    offset.encode(&mut src, 0).expect("encode");
+    assert_eq!(src, vec![0, 0, 0, 0, 0, 0, 0, 20]);
+    assert_eq!(offset.write_size(0), 8);
+
+    let decoded = Offset::decode_from(&mut Cursor::new(&src), 0).expect("decode");
+    assert_eq!(decoded, offset);
+}
+
+#[test]
+fn test_tuple_struct_attributes() {
+    let default = VersionedPair::default();
+    assert_eq!(default, VersionedPair(-1, 0, String::new()));
+
+    let pair = VersionedPair(5, 63, "v1".to_owned());
+
+    // string is skipped in version 0
+    let mut src = vec![];
+    pair.encode(&mut src, 0).expect("encode");
+    assert_eq!(src, vec![0, 0, 0, 5, 0x7e]);
+    let decoded = VersionedPair::decode_from(&mut Cursor::new(&src), 0).expect("decode");
+    assert_eq!(decoded, VersionedPair(5, 63, String::new()));
+
+    let mut src = vec![];
+    pair.encode(&mut src, 1).expect("encode");
+    assert_eq!(src.len(), pair.write_size(1));
+    let decoded = VersionedPair::decode_from(&mut Cursor::new(&src), 1).expect("decode");
+    assert_eq!(decoded, pair);
+}
@@ -0,0 +1,74 @@
+#![allow(clippy::assign_op_pattern)]
+
+//!
+//! # Describe ACL
+//!
+//! Request to list and test permissions of principal against authorization policy of SC.
+//!
+
+use dataplane::core::{Encoder, Decoder};
+use dataplane::api::Request;
+
+use crate::Status;
+use crate::AdminPublicApiKey;
+use crate::AdminRequest;
+
+/// Describe permissions of principal with scopes.
+/// If check is specified, SC also evaluates it
+#[derive(Encoder, Decoder, Default, Debug)]
+pub struct DescribeAclRequest {
+    pub principal: String,
+    /// roles of principal
+    pub scopes: Vec<String>,
+    pub check: Option<AclCheck>,
+}
+
+impl DescribeAclRequest {
+    pub fn new<S: Into<String>>(
+        principal: S,
+        scopes: Vec<String>,
+        check: Option<AclCheck>,
+    ) -> Self {
+        Self {
+            principal: principal.into(),
+            scopes,
+            check,
+        }
+    }
+}
+
+impl Request for DescribeAclRequest {
+    const API_KEY: u16 = AdminPublicApiKey::DescribeAcl as u16;
+    const DEFAULT_API_VERSION: i16 = 0;
+    type Response = DescribeAclResponse;
+}
+
+impl AdminRequest for DescribeAclRequest {}
+
+/// Action on object to be tested.
+/// If resource is not specified, action is tested on object type
+#[derive(Encoder, Decoder, Default, Debug, Clone, PartialEq)]
+pub struct AclCheck {
+    pub object: String,
+    pub action: String,
+    pub resource: Option<String>,
+}
+
+#[derive(Encoder, Decoder, Default, Debug)]
+pub struct DescribeAclResponse {
+    pub status: Status,
+    /// policy rules which apply to principal
+    pub rules: Vec<AclRule>,
+    /// result of check, if it was requested
+    pub allowed: Option<bool>,
+}
+
+/// Permission granted by policy role
+#[derive(Encoder, Decoder, Default, Debug, Clone, PartialEq)]
+pub struct AclRule {
+    pub role: String,
+    pub object: String,
+    pub action: String,
+    /// resource pattern, none if rule applies to all instances
+    pub resource: Option<String>,
+}
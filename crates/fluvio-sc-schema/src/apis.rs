@@ -22,6 +22,13 @@ pub enum AdminPublicApiKey {
     Delete = 1002,
     List = 1003,
     Watch = 1004,
+    ElectLeader = 1005,
+    DrainSpu = 1006,
+    DescribeAcl = 1007,
+    RegisterSchema = 1008,
+    InitProducerId = 1009,
+    AddPartitionsToTxn = 1010,
+    EndTxn = 1011,
 }
 
 impl Default for AdminPublicApiKey {
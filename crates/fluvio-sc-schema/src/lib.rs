@@ -2,7 +2,11 @@ pub mod topic;
 pub mod spu;
 pub mod spg;
 pub mod connector;
+pub mod quota;
+pub mod schema;
 pub mod partition;
+pub mod acl;
+pub mod transaction;
 pub mod versions;
 pub mod objects;
 mod apis;
@@ -20,7 +20,7 @@ pub struct CreateRequest {
 
 impl Request for CreateRequest {
     const API_KEY: u16 = AdminPublicApiKey::Create as u16;
-    const DEFAULT_API_VERSION: i16 = 1;
+    const DEFAULT_API_VERSION: i16 = 5;
     type Response = Status;
 }
 
@@ -40,12 +40,14 @@ mod create {
     use fluvio_controlplane_metadata::spu::CustomSpuSpec;
     use fluvio_controlplane_metadata::spg::SpuGroupSpec;
     use fluvio_controlplane_metadata::connector::ManagedConnectorSpec;
+    use fluvio_controlplane_metadata::quota::QuotaSpec;
     use super::*;
 
     const TOPIC: u8 = 0;
     const CUSTOM_SPU: u8 = 1;
     const SPG: u8 = 2;
     const MANAGED_CONNECTOR: u8 = 3;
+    const QUOTA: u8 = 4;
 
     #[derive(Debug)]
     /// enum of spec that can be created
@@ -54,6 +56,7 @@ mod create {
         CustomSpu(CustomSpuSpec),
         SpuGroup(SpuGroupSpec),
         ManagedConnector(ManagedConnectorSpec),
+        Quota(QuotaSpec),
     }
 
     impl Default for AllCreatableSpec {
@@ -72,6 +75,7 @@ mod create {
                     Self::CustomSpu(s) => s.write_size(version),
                     Self::SpuGroup(s) => s.write_size(version),
                     Self::ManagedConnector(s) => s.write_size(version),
+                    Self::Quota(s) => s.write_size(version),
                 }
         }
 
@@ -104,6 +108,12 @@ mod create {
                     typ.encode(dest, version)?;
                     s.encode(dest, version)?;
                 }
+
+                Self::Quota(s) => {
+                    let typ: u8 = QUOTA;
+                    typ.encode(dest, version)?;
+                    s.encode(dest, version)?;
+                }
             }
 
             Ok(())
@@ -146,6 +156,12 @@ mod create {
                     *self = Self::ManagedConnector(response);
                     Ok(())
                 }
+                QUOTA => {
+                    let mut response = QuotaSpec::default();
+                    response.decode(src, version)?;
+                    *self = Self::Quota(response);
+                    Ok(())
+                }
 
                 // Unexpected type
                 _ => Err(Error::new(
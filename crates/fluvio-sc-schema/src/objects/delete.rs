@@ -16,6 +16,7 @@ use fluvio_controlplane_metadata::spu::CustomSpuSpec;
 use fluvio_controlplane_metadata::spu::CustomSpuKey;
 use fluvio_controlplane_metadata::spg::SpuGroupSpec;
 use fluvio_controlplane_metadata::connector::ManagedConnectorSpec;
+use fluvio_controlplane_metadata::quota::QuotaSpec;
 use fluvio_controlplane_metadata::core::Spec;
 use fluvio_controlplane_metadata::core::Removable;
 
@@ -38,6 +39,7 @@ pub enum DeleteRequest {
     CustomSpu(CustomSpuKey),
     SpuGroup(String),
     ManagedConnector(String),
+    Quota(String),
 }
 
 impl Default for DeleteRequest {
@@ -54,6 +56,7 @@ impl DeleteRequest {
             Self::CustomSpu(_) => CustomSpuSpec::LABEL,
             Self::SpuGroup(_) => SpuGroupSpec::LABEL,
             Self::ManagedConnector(_) => ManagedConnectorSpec::LABEL,
+            Self::Quota(_) => QuotaSpec::LABEL,
         }
     }
 }
@@ -76,6 +79,7 @@ impl Encoder for DeleteRequest {
                 Self::CustomSpu(s) => s.write_size(version),
                 Self::SpuGroup(s) => s.write_size(version),
                 Self::ManagedConnector(s) => s.write_size(version),
+                Self::Quota(s) => s.write_size(version),
             }
     }
 
@@ -91,6 +95,7 @@ impl Encoder for DeleteRequest {
             Self::CustomSpu(s) => s.encode(dest, version)?,
             Self::SpuGroup(s) => s.encode(dest, version)?,
             Self::ManagedConnector(s) => s.encode(dest, version)?,
+            Self::Quota(s) => s.encode(dest, version)?,
         }
 
         Ok(())
@@ -133,6 +138,12 @@ impl Decoder for DeleteRequest {
                 *self = Self::ManagedConnector(response);
                 Ok(())
             }
+            QuotaSpec::LABEL => {
+                let mut response = String::default();
+                response.decode(src, version)?;
+                *self = Self::Quota(response);
+                Ok(())
+            }
 
             // Unexpected type
             _ => Err(Error::new(
@@ -17,6 +17,8 @@ use fluvio_controlplane_metadata::spg::SpuGroupSpec;
 use fluvio_controlplane_metadata::store::*;
 use fluvio_controlplane_metadata::partition::PartitionSpec;
 use fluvio_controlplane_metadata::connector::ManagedConnectorSpec;
+use fluvio_controlplane_metadata::quota::QuotaSpec;
+use fluvio_controlplane_metadata::schema::SchemaSpec;
 use crate::AdminPublicApiKey;
 use crate::AdminRequest;
 
@@ -45,6 +47,8 @@ pub enum ListRequest {
     CustomSpu(Vec<NameFilter>),
     Partition(Vec<NameFilter>),
     ManagedConnector(Vec<NameFilter>),
+    Quota(Vec<NameFilter>),
+    Schema(Vec<NameFilter>),
 }
 
 impl Default for ListRequest {
@@ -55,7 +59,7 @@ impl Default for ListRequest {
 
 impl Request for ListRequest {
     const API_KEY: u16 = AdminPublicApiKey::List as u16;
-    const DEFAULT_API_VERSION: i16 = 1;
+    const DEFAULT_API_VERSION: i16 = 5;
     type Response = ListResponse;
 }
 
@@ -69,6 +73,8 @@ pub enum ListResponse {
     SpuGroup(Vec<Metadata<SpuGroupSpec>>),
     Partition(Vec<Metadata<PartitionSpec>>),
     ManagedConnector(Vec<Metadata<ManagedConnectorSpec>>),
+    Quota(Vec<Metadata<QuotaSpec>>),
+    Schema(Vec<Metadata<SchemaSpec>>),
 }
 
 impl Default for ListResponse {
@@ -85,8 +91,8 @@ impl Default for ListResponse {
 )]
 pub struct Metadata<S>
 where
-    S: Spec + Debug + Encoder + Decoder,
-    S::Status: Debug + Encoder + Decoder,
+    S: Spec + Debug + Encoder + Decoder + Default,
+    S::Status: Debug + Encoder + Decoder + Default,
 {
     pub name: String,
     pub spec: S,
@@ -95,9 +101,9 @@ where
 
 impl<S, C> From<MetadataStoreObject<S, C>> for Metadata<S>
 where
-    S: Spec + Encoder + Decoder,
+    S: Spec + Encoder + Decoder + Default,
     S::IndexKey: ToString,
-    S::Status: Encoder + Decoder,
+    S::Status: Encoder + Decoder + Default,
     C: MetadataItem,
 {
     fn from(meta: MetadataStoreObject<S, C>) -> Self {
@@ -111,8 +117,8 @@ where
 
 impl<S, C> TryFrom<Metadata<S>> for MetadataStoreObject<S, C>
 where
-    S: Spec + Encoder + Decoder,
-    S::Status: Encoder + Decoder,
+    S: Spec + Encoder + Decoder + Default,
+    S::Status: Encoder + Decoder + Default,
     C: MetadataItem,
     <S as Spec>::IndexKey: TryFrom<String>,
     <<S as Spec>::IndexKey as TryFrom<String>>::Error: Display,
@@ -159,6 +165,8 @@ mod encoding {
                 Self::CustomSpu(_) => CustomSpuSpec::LABEL,
                 Self::Partition(_) => PartitionSpec::LABEL,
                 Self::ManagedConnector(_) => ManagedConnectorSpec::LABEL,
+                Self::Quota(_) => QuotaSpec::LABEL,
+                Self::Schema(_) => SchemaSpec::LABEL,
             }
         }
     }
@@ -175,6 +183,8 @@ mod encoding {
                     Self::Spu(s) => s.write_size(version),
                     Self::Partition(s) => s.write_size(version),
                     Self::ManagedConnector(s) => s.write_size(version),
+                    Self::Quota(s) => s.write_size(version),
+                    Self::Schema(s) => s.write_size(version),
                 }
         }
 
@@ -192,6 +202,8 @@ mod encoding {
                 Self::Spu(s) => s.encode(dest, version)?,
                 Self::Partition(s) => s.encode(dest, version)?,
                 Self::ManagedConnector(s) => s.encode(dest, version)?,
+                Self::Quota(s) => s.encode(dest, version)?,
+                Self::Schema(s) => s.encode(dest, version)?,
             }
 
             Ok(())
@@ -248,6 +260,18 @@ mod encoding {
                     *self = Self::ManagedConnector(response);
                     Ok(())
                 }
+                QuotaSpec::LABEL => {
+                    let mut response: Vec<NameFilter> = vec![];
+                    response.decode(src, version)?;
+                    *self = Self::Quota(response);
+                    Ok(())
+                }
+                SchemaSpec::LABEL => {
+                    let mut response: Vec<NameFilter> = vec![];
+                    response.decode(src, version)?;
+                    *self = Self::Schema(response);
+                    Ok(())
+                }
 
                 // Unexpected type
                 _ => Err(Error::new(
@@ -268,6 +292,8 @@ mod encoding {
                 Self::CustomSpu(_) => CustomSpuSpec::LABEL,
                 Self::Partition(_) => PartitionSpec::LABEL,
                 Self::ManagedConnector(_) => ManagedConnectorSpec::LABEL,
+                Self::Quota(_) => QuotaSpec::LABEL,
+                Self::Schema(_) => SchemaSpec::LABEL,
             }
         }
     }
@@ -284,6 +310,8 @@ mod encoding {
                     Self::Spu(s) => s.write_size(version),
                     Self::Partition(s) => s.write_size(version),
                     Self::ManagedConnector(s) => s.write_size(version),
+                    Self::Quota(s) => s.write_size(version),
+                    Self::Schema(s) => s.write_size(version),
                 }
         }
 
@@ -301,6 +329,8 @@ mod encoding {
                 Self::Spu(s) => s.encode(dest, version)?,
                 Self::Partition(s) => s.encode(dest, version)?,
                 Self::ManagedConnector(s) => s.encode(dest, version)?,
+                Self::Quota(s) => s.encode(dest, version)?,
+                Self::Schema(s) => s.encode(dest, version)?,
             }
 
             Ok(())
@@ -357,6 +387,18 @@ mod encoding {
                     *self = Self::ManagedConnector(response);
                     Ok(())
                 }
+                QuotaSpec::LABEL => {
+                    let mut response: Vec<Metadata<QuotaSpec>> = vec![];
+                    response.decode(src, version)?;
+                    *self = Self::Quota(response);
+                    Ok(())
+                }
+                SchemaSpec::LABEL => {
+                    let mut response: Vec<Metadata<SchemaSpec>> = vec![];
+                    response.decode(src, version)?;
+                    *self = Self::Schema(response);
+                    Ok(())
+                }
 
                 // Unexpected type
                 _ => Err(Error::new(
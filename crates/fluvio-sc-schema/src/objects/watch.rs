@@ -45,7 +45,7 @@ impl Default for WatchRequest {
 
 impl Request for WatchRequest {
     const API_KEY: u16 = AdminPublicApiKey::Watch as u16;
-    const DEFAULT_API_VERSION: i16 = 1;
+    const DEFAULT_API_VERSION: i16 = 5;
     type Response = WatchResponse;
 }
 
@@ -70,8 +70,8 @@ impl Default for WatchResponse {
 #[derive(Encoder, Decoder, Default, Clone, Debug)]
 pub struct MetadataUpdate<S>
 where
-    S: Spec + Debug + Encoder + Decoder,
-    S::Status: Debug + Encoder + Decoder,
+    S: Spec + Debug + Encoder + Decoder + Default,
+    S::Status: Debug + Encoder + Decoder + Default,
 {
     pub epoch: Epoch,
     pub changes: Vec<Message<Metadata<S>>>,
@@ -80,8 +80,8 @@ where
 
 impl<S> MetadataUpdate<S>
 where
-    S: Spec + Debug + Encoder + Decoder,
-    S::Status: Debug + Encoder + Decoder,
+    S: Spec + Debug + Encoder + Decoder + Default,
+    S::Status: Debug + Encoder + Decoder + Default,
 {
     pub fn with_changes(epoch: i64, changes: Vec<Message<Metadata<S>>>) -> Self {
         Self {
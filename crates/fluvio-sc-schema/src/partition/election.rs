@@ -0,0 +1,42 @@
+#![allow(clippy::assign_op_pattern)]
+
+//!
+//! # Elect Leader
+//!
+//! Request to move leadership of partition to another replica.
+//!
+
+use dataplane::core::{Encoder, Decoder};
+use dataplane::api::Request;
+use fluvio_types::SpuId;
+
+use crate::Status;
+use crate::AdminPublicApiKey;
+use crate::AdminRequest;
+
+/// Elect new leader for partition.
+/// If leader is not specified, preferred replica (first in replica map) is elected
+#[derive(Encoder, Decoder, Default, Debug)]
+pub struct ElectLeaderRequest {
+    pub topic: String,
+    pub partition: i32,
+    pub leader: Option<SpuId>,
+}
+
+impl ElectLeaderRequest {
+    pub fn new<S: Into<String>>(topic: S, partition: i32, leader: Option<SpuId>) -> Self {
+        Self {
+            topic: topic.into(),
+            partition,
+            leader,
+        }
+    }
+}
+
+impl Request for ElectLeaderRequest {
+    const API_KEY: u16 = AdminPublicApiKey::ElectLeader as u16;
+    const DEFAULT_API_VERSION: i16 = 0;
+    type Response = Status;
+}
+
+impl AdminRequest for ElectLeaderRequest {}
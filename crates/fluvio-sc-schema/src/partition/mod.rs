@@ -1,4 +1,7 @@
 pub use fluvio_controlplane_metadata::partition::*;
+pub use election::*;
+
+mod election;
 
 mod convert {
 
@@ -0,0 +1,45 @@
+pub use fluvio_controlplane_metadata::quota::*;
+
+mod convert {
+
+    use std::io::Error;
+    use std::io::ErrorKind;
+    use std::convert::TryInto;
+
+    use crate::objects::*;
+    use super::*;
+
+    impl From<QuotaSpec> for AllCreatableSpec {
+        fn from(spec: QuotaSpec) -> Self {
+            Self::Quota(spec)
+        }
+    }
+
+    impl DeleteSpec for QuotaSpec {
+        fn into_request<K>(key: K) -> DeleteRequest
+        where
+            K: Into<Self::DeleteKey>,
+        {
+            DeleteRequest::Quota(key.into())
+        }
+    }
+
+    impl ListSpec for QuotaSpec {
+        type Filter = NameFilter;
+
+        fn into_list_request(filters: Vec<Self::Filter>) -> ListRequest {
+            ListRequest::Quota(filters)
+        }
+    }
+
+    impl TryInto<Vec<Metadata<QuotaSpec>>> for ListResponse {
+        type Error = Error;
+
+        fn try_into(self) -> Result<Vec<Metadata<QuotaSpec>>, Self::Error> {
+            match self {
+                ListResponse::Quota(s) => Ok(s),
+                _ => Err(Error::new(ErrorKind::Other, "not quota")),
+            }
+        }
+    }
+}
@@ -19,6 +19,11 @@ use dataplane::core::Encoder;
 use dataplane::versions::ApiVersionsRequest;
 
 use super::objects::*;
+use super::partition::ElectLeaderRequest;
+use super::spu::DrainSpuRequest;
+use super::acl::DescribeAclRequest;
+use super::schema::RegisterSchemaRequest;
+use super::transaction::{AddPartitionsToTxnRequest, EndTxnRequest, InitProducerIdRequest};
 use super::AdminPublicApiKey;
 
 #[derive(Debug, Encoder)]
@@ -29,6 +34,13 @@ pub enum AdminPublicRequest {
     DeleteRequest(RequestMessage<DeleteRequest>),
     ListRequest(RequestMessage<ListRequest>),
     WatchRequest(RequestMessage<WatchRequest>),
+    ElectLeaderRequest(RequestMessage<ElectLeaderRequest>),
+    DrainSpuRequest(RequestMessage<DrainSpuRequest>),
+    DescribeAclRequest(RequestMessage<DescribeAclRequest>),
+    RegisterSchemaRequest(RequestMessage<RegisterSchemaRequest>),
+    InitProducerIdRequest(RequestMessage<InitProducerIdRequest>),
+    AddPartitionsToTxnRequest(RequestMessage<AddPartitionsToTxnRequest>),
+    EndTxnRequest(RequestMessage<EndTxnRequest>),
 }
 
 impl Default for AdminPublicRequest {
@@ -59,6 +71,23 @@ impl ApiMessage for AdminPublicRequest {
             AdminPublicApiKey::Delete => api_decode!(Self, DeleteRequest, src, header),
             AdminPublicApiKey::List => api_decode!(Self, ListRequest, src, header),
             AdminPublicApiKey::Watch => api_decode!(Self, WatchRequest, src, header),
+            AdminPublicApiKey::ElectLeader => {
+                api_decode!(Self, ElectLeaderRequest, src, header)
+            }
+            AdminPublicApiKey::DrainSpu => api_decode!(Self, DrainSpuRequest, src, header),
+            AdminPublicApiKey::DescribeAcl => {
+                api_decode!(Self, DescribeAclRequest, src, header)
+            }
+            AdminPublicApiKey::RegisterSchema => {
+                api_decode!(Self, RegisterSchemaRequest, src, header)
+            }
+            AdminPublicApiKey::InitProducerId => {
+                api_decode!(Self, InitProducerIdRequest, src, header)
+            }
+            AdminPublicApiKey::AddPartitionsToTxn => {
+                api_decode!(Self, AddPartitionsToTxnRequest, src, header)
+            }
+            AdminPublicApiKey::EndTxn => api_decode!(Self, EndTxnRequest, src, header),
         }
     }
 }
@@ -0,0 +1,90 @@
+#![allow(clippy::assign_op_pattern)]
+
+//!
+//! # Register Schema
+//!
+//! Request to register new version of schema under subject.
+//!
+
+use dataplane::core::{Encoder, Decoder};
+use dataplane::api::Request;
+
+use crate::Status;
+use crate::AdminPublicApiKey;
+use crate::AdminRequest;
+
+pub use fluvio_controlplane_metadata::schema::*;
+
+/// Register schema definition as new version of subject.
+/// Subject is created if it doesn't exist.
+/// If definition is already registered, existing version is returned
+#[derive(Encoder, Decoder, Default, Debug)]
+pub struct RegisterSchemaRequest {
+    pub subject: String,
+    pub schema_type: SchemaType,
+    pub definition: String,
+    /// change compatibility mode of subject
+    pub compatibility: Option<SchemaCompatibility>,
+    /// change validation of produced values
+    pub validate: Option<bool>,
+}
+
+impl RegisterSchemaRequest {
+    pub fn new<S: Into<String>, D: Into<String>>(
+        subject: S,
+        schema_type: SchemaType,
+        definition: D,
+    ) -> Self {
+        Self {
+            subject: subject.into(),
+            schema_type,
+            definition: definition.into(),
+            ..Default::default()
+        }
+    }
+}
+
+impl Request for RegisterSchemaRequest {
+    const API_KEY: u16 = AdminPublicApiKey::RegisterSchema as u16;
+    const DEFAULT_API_VERSION: i16 = 0;
+    type Response = RegisterSchemaResponse;
+}
+
+impl AdminRequest for RegisterSchemaRequest {}
+
+#[derive(Encoder, Decoder, Default, Debug)]
+pub struct RegisterSchemaResponse {
+    pub status: Status,
+    /// schema id to be embedded in values
+    pub id: u32,
+    pub version: u32,
+}
+
+mod convert {
+
+    use std::io::Error;
+    use std::io::ErrorKind;
+    use std::convert::TryInto;
+
+    use crate::objects::*;
+    use super::*;
+
+    impl ListSpec for SchemaSpec {
+        type Filter = NameFilter;
+
+        fn into_list_request(filters: Vec<Self::Filter>) -> ListRequest {
+            ListRequest::Schema(filters)
+        }
+    }
+
+    impl TryInto<Vec<Metadata<SchemaSpec>>> for ListResponse {
+        type Error = Error;
+
+        fn try_into(self) -> Result<Vec<Metadata<SchemaSpec>>, Self::Error> {
+            match self {
+                ListResponse::Schema(s) => Ok(s),
+                _ => Err(Error::new(ErrorKind::Other, "not schema")),
+            }
+        }
+    }
+}
@@ -0,0 +1,68 @@
+#![allow(clippy::assign_op_pattern)]
+
+//!
+//! # Drain Spu
+//!
+//! Request to move all replicas out of SPU so it can be removed.
+//!
+
+use dataplane::core::{Encoder, Decoder};
+use dataplane::api::Request;
+use fluvio_types::SpuId;
+
+use crate::Status;
+use crate::AdminPublicApiKey;
+use crate::AdminRequest;
+
+/// Drain SPU.
+/// SPU is marked as draining and its replicas are moved to other SPUs.
+/// In dry run, only planned moves are returned
+#[derive(Encoder, Decoder, Default, Debug)]
+pub struct DrainSpuRequest {
+    pub spu: SpuId,
+    pub dry_run: bool,
+    /// stop draining SPU, replicas already moved are not moved back
+    pub undrain: bool,
+}
+
+impl DrainSpuRequest {
+    pub fn new(spu: SpuId, dry_run: bool) -> Self {
+        Self {
+            spu,
+            dry_run,
+            undrain: false,
+        }
+    }
+
+    pub fn undrain(spu: SpuId) -> Self {
+        Self {
+            spu,
+            dry_run: false,
+            undrain: true,
+        }
+    }
+}
+
+impl Request for DrainSpuRequest {
+    const API_KEY: u16 = AdminPublicApiKey::DrainSpu as u16;
+    const DEFAULT_API_VERSION: i16 = 0;
+    type Response = DrainSpuResponse;
+}
+
+impl AdminRequest for DrainSpuRequest {}
+
+#[derive(Encoder, Decoder, Default, Debug)]
+pub struct DrainSpuResponse {
+    pub status: Status,
+    pub moves: Vec<ReplicaMove>,
+}
+
+/// Planned move of replica from drained SPU to another SPU
+#[derive(Encoder, Decoder, Default, Debug, Clone, PartialEq)]
+pub struct ReplicaMove {
+    pub partition: String,
+    pub from: SpuId,
+    pub to: SpuId,
+    /// leader after replica is moved
+    pub leader: SpuId,
+}
@@ -1,4 +1,7 @@
 pub use fluvio_controlplane_metadata::spu::*;
+pub use drain::*;
+
+mod drain;
 
 mod convert {
 
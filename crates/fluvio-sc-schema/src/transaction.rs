@@ -0,0 +1,120 @@
+#![allow(clippy::assign_op_pattern)]
+
+//!
+//! # Transactions
+//!
+//! Requests to transaction coordinator of SC.
+//!
+//! Producer registers transactional id to get producer id and epoch,
+//! adds partitions to transaction before producing to them, then ends transaction
+//! in two phases: decision is prepared, then producer waits until markers are written
+//! to partitions by SPUs and transaction is complete.
+//!
+
+use dataplane::core::{Encoder, Decoder};
+use dataplane::api::Request;
+use dataplane::ReplicaKey;
+
+use crate::Status;
+use crate::AdminPublicApiKey;
+use crate::AdminRequest;
+
+/// Register transactional producer.
+/// Epoch of producer id is bumped, so previous instance with same transactional id is fenced
+#[derive(Encoder, Decoder, Default, Debug)]
+pub struct InitProducerIdRequest {
+    pub transactional_id: String,
+}
+
+impl InitProducerIdRequest {
+    pub fn new<S: Into<String>>(transactional_id: S) -> Self {
+        Self {
+            transactional_id: transactional_id.into(),
+        }
+    }
+}
+
+impl Request for InitProducerIdRequest {
+    const API_KEY: u16 = AdminPublicApiKey::InitProducerId as u16;
+    const DEFAULT_API_VERSION: i16 = 0;
+    type Response = InitProducerIdResponse;
+}
+
+impl AdminRequest for InitProducerIdRequest {}
+
+#[derive(Encoder, Decoder, Default, Debug)]
+pub struct InitProducerIdResponse {
+    pub status: Status,
+    pub producer_id: i64,
+    pub producer_epoch: i16,
+    /// transaction of previous instance which must be complete before new transaction
+    pub pending: Option<PendingTransaction>,
+}
+
+/// Transaction of previous instance whose markers are not written yet
+#[derive(Encoder, Decoder, Default, Debug, Clone, PartialEq)]
+pub struct PendingTransaction {
+    pub commit: bool,
+    pub partitions: Vec<ReplicaKey>,
+}
+
+/// Add partitions to ongoing transaction, transaction is started by first partition
+#[derive(Encoder, Decoder, Default, Debug)]
+pub struct AddPartitionsToTxnRequest {
+    pub transactional_id: String,
+    pub producer_id: i64,
+    pub producer_epoch: i16,
+    pub partitions: Vec<ReplicaKey>,
+}
+
+impl Request for AddPartitionsToTxnRequest {
+    const API_KEY: u16 = AdminPublicApiKey::AddPartitionsToTxn as u16;
+    const DEFAULT_API_VERSION: i16 = 0;
+    type Response = AddPartitionsToTxnResponse;
+}
+
+impl AdminRequest for AddPartitionsToTxnRequest {}
+
+#[derive(Encoder, Decoder, Default, Debug)]
+pub struct AddPartitionsToTxnResponse {
+    pub status: Status,
+}
+
+#[derive(Encoder, Decoder, Debug, Clone, Copy, PartialEq)]
+pub enum EndTxnPhase {
+    /// record decision, partitions of transaction are returned
+    Prepare,
+    /// check if markers are written to all partitions
+    Complete,
+}
+
+impl Default for EndTxnPhase {
+    fn default() -> Self {
+        Self::Prepare
+    }
+}
+
+/// Commit or abort transaction
+#[derive(Encoder, Decoder, Default, Debug)]
+pub struct EndTxnRequest {
+    pub transactional_id: String,
+    pub producer_id: i64,
+    pub producer_epoch: i16,
+    pub commit: bool,
+    pub phase: EndTxnPhase,
+}
+
+impl Request for EndTxnRequest {
+    const API_KEY: u16 = AdminPublicApiKey::EndTxn as u16;
+    const DEFAULT_API_VERSION: i16 = 0;
+    type Response = EndTxnResponse;
+}
+
+impl AdminRequest for EndTxnRequest {}
+
+#[derive(Encoder, Decoder, Default, Debug)]
+pub struct EndTxnResponse {
+    pub status: Status,
+    /// partitions where markers are not written yet
+    pub partitions: Vec<ReplicaKey>,
+}
@@ -0,0 +1,114 @@
+use std::fs::{self, File, OpenOptions};
+use std::io::{Error as IoError, Write};
+use std::path::{Path, PathBuf};
+
+use tracing::{debug, info};
+
+/// JSON lines file which is rotated when it reaches max bytes.
+/// Rotated files are suffixed with index, oldest has highest index.
+#[derive(Debug)]
+pub struct FileSink {
+    path: PathBuf,
+    max_bytes: u64,
+    max_files: u32,
+    file: Option<File>,
+    size: u64,
+}
+
+impl FileSink {
+    pub fn new(path: PathBuf, max_bytes: u64, max_files: u32) -> Self {
+        Self {
+            path,
+            max_bytes,
+            max_files,
+            file: None,
+            size: 0,
+        }
+    }
+
+    /// append event as single line
+    pub fn write(&mut self, line: &[u8]) -> Result<(), IoError> {
+        if self.file.is_some() && self.size + line.len() as u64 + 1 > self.max_bytes {
+            self.rotate()?;
+        }
+
+        // file is reopened after failed write
+        let mut file = match self.file.take() {
+            Some(file) => file,
+            None => self.open()?,
+        };
+
+        file.write_all(line)?;
+        file.write_all(b"\n")?;
+        file.flush()?;
+        self.size += line.len() as u64 + 1;
+        self.file = Some(file);
+        Ok(())
+    }
+
+    fn open(&mut self) -> Result<File, IoError> {
+        if let Some(parent) = self.path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        let file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)?;
+        self.size = file.metadata()?.len();
+        debug!(path = ?self.path, size = self.size, "opened audit file");
+        Ok(file)
+    }
+
+    fn rotate(&mut self) -> Result<(), IoError> {
+        self.file = None;
+
+        if self.max_files == 0 {
+            fs::remove_file(&self.path)?;
+        } else {
+            for index in (1..self.max_files).rev() {
+                let from = rotated_path(&self.path, index);
+                if from.exists() {
+                    fs::rename(&from, rotated_path(&self.path, index + 1))?;
+                }
+            }
+            fs::rename(&self.path, rotated_path(&self.path, 1))?;
+        }
+
+        info!(path = ?self.path, "rotated audit file");
+        Ok(())
+    }
+}
+
+fn rotated_path(path: &Path, index: u32) -> PathBuf {
+    let mut name = path.as_os_str().to_owned();
+    name.push(format!(".{}", index));
+    PathBuf::from(name)
+}
+
+#[cfg(test)]
+mod test {
+
+    use super::*;
+
+    #[test]
+    fn test_file_rotation() {
+        let dir = std::env::temp_dir().join(format!("fluvio-audit-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        let path = dir.join("audit.log");
+
+        // each line is 10 bytes with new line, 2 lines fit in a file
+        let mut sink = FileSink::new(path.clone(), 25, 2);
+        for index in 0..7 {
+            sink.write(format!("event-{:03}", index).as_bytes())
+                .expect("write");
+        }
+
+        let read = |path: PathBuf| fs::read_to_string(path).expect("read");
+        assert_eq!(read(path.clone()), "event-006\n");
+        assert_eq!(read(rotated_path(&path, 1)), "event-004\nevent-005\n");
+        assert_eq!(read(rotated_path(&path, 2)), "event-002\nevent-003\n");
+        assert!(!rotated_path(&path, 3).exists());
+
+        fs::remove_dir_all(&dir).expect("cleanup");
+    }
+}
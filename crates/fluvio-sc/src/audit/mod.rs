@@ -0,0 +1,283 @@
+//!
+//! # Audit Log
+//!
+//! Structured record of authorization decisions and admin requests.
+//! Events are queued and written by background task to file or topic sink.
+//!
+mod file;
+mod topic;
+
+use std::path::PathBuf;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use tracing::{debug, error, warn};
+use serde::Serialize;
+use async_channel::{bounded, Sender, Receiver, TrySendError};
+
+use fluvio::config::Credentials;
+use fluvio_future::task::spawn;
+use fluvio_controlplane_metadata::extended::ObjectType;
+
+pub use file::FileSink;
+pub use topic::TopicSink;
+
+/// principal recorded when client is not authenticated
+pub const ANONYMOUS_PRINCIPAL: &str = "anonymous";
+
+/// default size of audit file before it is rotated
+pub const DEFAULT_AUDIT_FILE_MAX_BYTES: u64 = 100 * 1024 * 1024;
+/// default number of rotated audit files to keep
+pub const DEFAULT_AUDIT_FILE_MAX_FILES: u32 = 5;
+
+/// events waiting to be written, events are dropped if sink can't keep up
+const AUDIT_QUEUE_SIZE: usize = 1000;
+
+/// where audit events are written
+#[derive(Debug, Clone, PartialEq)]
+pub enum AuditConfig {
+    /// JSON lines file, rotated when max bytes is reached
+    File {
+        path: PathBuf,
+        max_bytes: u64,
+        max_files: u32,
+    },
+    /// Fluvio topic, produced through public endpoint of this SC.
+    /// Credentials are required if clients are authenticated with SASL
+    Topic {
+        topic: String,
+        endpoint: String,
+        credentials: Option<Credentials>,
+    },
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum AuditEventKind {
+    /// authorization decision
+    Authorization,
+    /// outcome of admin request
+    Request,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct AuditEvent {
+    /// milliseconds since unix epoch
+    pub timestamp: u64,
+    pub kind: AuditEventKind,
+    pub principal: String,
+    pub action: String,
+    pub object_type: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    pub allowed: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+impl AuditEvent {
+    pub fn new(
+        kind: AuditEventKind,
+        principal: Option<&str>,
+        action: impl ToString,
+        ty: &ObjectType,
+        name: Option<&str>,
+        allowed: bool,
+    ) -> Self {
+        let timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|duration| duration.as_millis() as u64)
+            .unwrap_or_default();
+
+        Self {
+            timestamp,
+            kind,
+            principal: principal.unwrap_or(ANONYMOUS_PRINCIPAL).to_owned(),
+            action: action.to_string(),
+            object_type: format!("{:?}", ty),
+            name: name.map(|name| name.to_owned()),
+            allowed,
+            error: None,
+        }
+    }
+
+    pub fn with_error(mut self, error: Option<String>) -> Self {
+        self.error = error;
+        self
+    }
+}
+
+/// Handle to record audit events, does nothing if audit is not configured
+#[derive(Debug, Clone, Default)]
+pub struct AuditLog {
+    sender: Option<Sender<AuditEvent>>,
+    dropped: Arc<AtomicU64>,
+}
+
+impl AuditLog {
+    /// start background writer for sink
+    pub fn start(config: &AuditConfig) -> Self {
+        let (sender, receiver) = bounded(AUDIT_QUEUE_SIZE);
+        match config {
+            AuditConfig::File {
+                path,
+                max_bytes,
+                max_files,
+            } => {
+                let sink = FileSink::new(path.clone(), *max_bytes, *max_files);
+                spawn(write_loop(receiver, AuditSink::File(sink)));
+            }
+            AuditConfig::Topic {
+                topic,
+                endpoint,
+                credentials,
+            } => {
+                let sink = TopicSink::new(topic.clone(), endpoint.clone(), credentials.clone());
+                spawn(write_loop(receiver, AuditSink::Topic(sink)));
+            }
+        }
+
+        Self::with_sender(sender)
+    }
+
+    fn with_sender(sender: Sender<AuditEvent>) -> Self {
+        Self {
+            sender: Some(sender),
+            dropped: Arc::new(AtomicU64::new(0)),
+        }
+    }
+
+    pub fn is_enabled(&self) -> bool {
+        self.sender.is_some()
+    }
+
+    /// number of events dropped because they could not be queued
+    pub fn dropped(&self) -> u64 {
+        self.dropped.load(Ordering::Relaxed)
+    }
+
+    /// queue event, never blocks request processing.
+    /// Events which can't be queued are counted, count is logged on first drop and then
+    /// each time it doubles so slow sink doesn't flood the log
+    pub fn record(&self, event: AuditEvent) {
+        if let Some(sender) = &self.sender {
+            match sender.try_send(event) {
+                Ok(_) => {}
+                Err(TrySendError::Full(event)) => {
+                    let dropped = self.dropped.fetch_add(1, Ordering::Relaxed) + 1;
+                    if dropped.is_power_of_two() {
+                        warn!(dropped, ?event, "audit queue is full, dropping events");
+                    }
+                }
+                Err(TrySendError::Closed(event)) => {
+                    let dropped = self.dropped.fetch_add(1, Ordering::Relaxed) + 1;
+                    if dropped.is_power_of_two() {
+                        error!(dropped, ?event, "audit writer is stopped, dropping events");
+                    }
+                }
+            }
+        }
+    }
+}
+
+enum AuditSink {
+    File(FileSink),
+    Topic(TopicSink),
+}
+
+async fn write_loop(receiver: Receiver<AuditEvent>, mut sink: AuditSink) {
+    debug!("starting audit writer");
+    while let Ok(event) = receiver.recv().await {
+        let line = match serde_json::to_vec(&event) {
+            Ok(line) => line,
+            Err(err) => {
+                error!(%err, "unable to serialize audit event");
+                continue;
+            }
+        };
+
+        let result = match &mut sink {
+            AuditSink::File(sink) => sink.write(&line),
+            AuditSink::Topic(sink) => sink.write(line).await,
+        };
+        if let Err(err) = result {
+            error!(%err, "unable to write audit event");
+        }
+    }
+    debug!("audit writer terminated");
+}
+
+#[cfg(test)]
+mod test {
+
+    use super::*;
+
+    #[test]
+    fn test_event_json() {
+        let mut event = AuditEvent::new(
+            AuditEventKind::Request,
+            Some("alice"),
+            "Delete",
+            &ObjectType::Topic,
+            Some("orders"),
+            false,
+        )
+        .with_error(Some("permission denied".to_owned()));
+        event.timestamp = 1;
+
+        assert_eq!(
+            serde_json::to_string(&event).expect("json"),
+            r#"{"timestamp":1,"kind":"request","principal":"alice","action":"Delete","object_type":"Topic","name":"orders","allowed":false,"error":"permission denied"}"#
+        );
+
+        let event = AuditEvent::new(
+            AuditEventKind::Authorization,
+            None,
+            "Create",
+            &ObjectType::Topic,
+            None,
+            true,
+        );
+        let json = serde_json::to_string(&event).expect("json");
+        assert!(json.contains(r#""principal":"anonymous""#));
+        assert!(!json.contains("name"));
+    }
+
+    fn event(name: &str) -> AuditEvent {
+        AuditEvent::new(
+            AuditEventKind::Request,
+            Some("alice"),
+            "Create",
+            &ObjectType::Topic,
+            Some(name),
+            true,
+        )
+    }
+
+    #[test]
+    fn test_record_counts_dropped_events() {
+        let (sender, receiver) = bounded(2);
+        let audit = AuditLog::with_sender(sender);
+
+        for index in 0..5 {
+            audit.record(event(&format!("topic-{}", index)));
+        }
+        assert_eq!(audit.dropped(), 3);
+        assert_eq!(receiver.len(), 2);
+        assert_eq!(
+            receiver.try_recv().expect("event").name.as_deref(),
+            Some("topic-0")
+        );
+
+        // events are dropped once writer is gone
+        drop(receiver);
+        audit.record(event("topic-5"));
+        assert_eq!(audit.dropped(), 4);
+
+        // disabled log records nothing
+        let disabled = AuditLog::default();
+        disabled.record(event("topic-6"));
+        assert_eq!(disabled.dropped(), 0);
+    }
+}
@@ -0,0 +1,49 @@
+use tracing::{debug, info};
+
+use fluvio::{Fluvio, FluvioConfig, FluvioError, RecordKey, TopicProducer};
+use fluvio::config::Credentials;
+
+/// Produce events to topic, connection is established on first event
+/// and re-established after failure.
+/// Producer authenticates with credentials if public service requires SASL.
+pub struct TopicSink {
+    topic: String,
+    endpoint: String,
+    credentials: Option<Credentials>,
+    producer: Option<TopicProducer>,
+}
+
+impl TopicSink {
+    pub fn new(topic: String, endpoint: String, credentials: Option<Credentials>) -> Self {
+        Self {
+            topic,
+            endpoint,
+            credentials,
+            producer: None,
+        }
+    }
+
+    pub async fn write(&mut self, line: Vec<u8>) -> Result<(), FluvioError> {
+        // producer is dropped on failure, so next event reconnects
+        let producer = match self.producer.take() {
+            Some(producer) => producer,
+            None => self.connect().await?,
+        };
+
+        producer.send(RecordKey::NULL, line).await?;
+        self.producer = Some(producer);
+        Ok(())
+    }
+
+    async fn connect(&self) -> Result<TopicProducer, FluvioError> {
+        debug!(endpoint = %self.endpoint, topic = %self.topic, "connecting audit producer");
+        let mut config = FluvioConfig::new(&self.endpoint);
+        if let Some(credentials) = &self.credentials {
+            config = config.with_credentials(credentials.clone());
+        }
+        let fluvio = Fluvio::connect_with_config(&config).await?;
+        let producer = fluvio.topic_producer(&self.topic).await?;
+        info!(topic = %self.topic, "writing audit events to topic");
+        Ok(producer)
+    }
+}
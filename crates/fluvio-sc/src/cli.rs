@@ -12,19 +12,23 @@ use std::io::Error as IoError;
 use std::io::ErrorKind;
 use std::path::PathBuf;
 use std::convert::TryFrom;
+use std::time::Duration;
 
 use tracing::info;
 use tracing::debug;
 use structopt::StructOpt;
 
+use fluvio::config::Credentials;
 use fluvio_types::print_cli_err;
 use k8_client::K8Config;
+use fluvio_auth::x509::validate_non_tls_endpoint;
 use fluvio_future::openssl::TlsAcceptor;
 use fluvio_future::openssl::SslVerifyMode;
 
 use crate::services::auth::basic::BasicRbacPolicy;
 use crate::error::ScError;
 use crate::config::ScConfig;
+use crate::audit::{AuditConfig, DEFAULT_AUDIT_FILE_MAX_BYTES, DEFAULT_AUDIT_FILE_MAX_FILES};
 
 type Config = (ScConfig, Option<BasicRbacPolicy>);
 
@@ -65,9 +69,79 @@ pub struct ScOpt {
     )]
     auth_policy: Option<PathBuf>,
 
+    /// SASL credentials file, enables PLAIN and SCRAM-SHA-256 authentication
+    #[structopt(long, value_name = "sasl credentials path", env)]
+    sasl_credentials: Option<PathBuf>,
+
+    /// public key (PEM) to verify bearer tokens, enables OAUTHBEARER authentication
+    #[structopt(long, value_name = "token public key path", env)]
+    token_public_key: Option<PathBuf>,
+
+    /// algorithm of bearer tokens
+    #[structopt(long, value_name = "algorithm", default_value = "RS256")]
+    token_algorithm: String,
+
+    /// audience which must be in `aud` claim of bearer tokens
+    #[structopt(long, value_name = "audience", env)]
+    token_audience: Option<String>,
+
+    /// issuer which must be `iss` claim of bearer tokens
+    #[structopt(long, value_name = "issuer", env)]
+    token_issuer: Option<String>,
+
     /// only allow white list of controllers
     #[structopt(long)]
     white_list: Vec<String>,
+
+    /// periodically move partition leaders back to preferred replicas
+    #[structopt(long)]
+    auto_leader_rebalance: bool,
+
+    /// interval in seconds between preferred leader rebalance
+    #[structopt(long, value_name = "seconds")]
+    leader_rebalance_interval: Option<u64>,
+
+    /// seconds after which open transaction is aborted
+    #[structopt(long, value_name = "seconds")]
+    transaction_timeout: Option<u64>,
+
+    /// file where state of transactions is stored, so it survives restart
+    #[structopt(long, value_name = "path")]
+    transaction_state_file: Option<PathBuf>,
+
+    /// max size of client request in bytes, connection is closed on larger request
+    #[structopt(
+        long,
+        value_name = "integer",
+        env = "FLV_MAX_FRAME_SIZE",
+        default_value = "104857600"
+    )]
+    max_frame_size: usize,
+
+    /// write audit events as JSON lines to file
+    #[structopt(long, value_name = "path", conflicts_with = "audit-topic")]
+    audit_file: Option<PathBuf>,
+
+    /// size in bytes of audit file before it is rotated
+    #[structopt(long, value_name = "bytes")]
+    audit_file_max_bytes: Option<u64>,
+
+    /// number of rotated audit files to keep
+    #[structopt(long, value_name = "count")]
+    audit_file_max_files: Option<u32>,
+
+    /// write audit events to topic, not supported with client certificate authentication
+    #[structopt(long, value_name = "topic")]
+    audit_topic: Option<String>,
+
+    /// SCRAM-SHA-256 username of audit topic producer, required with SASL authentication.
+    /// Policy must allow its scopes to write to audit topic
+    #[structopt(long, value_name = "username", env = "FLUVIO_AUDIT_USERNAME")]
+    audit_topic_username: Option<String>,
+
+    /// password of audit topic producer
+    #[structopt(long, env = "FLUVIO_AUDIT_PASSWORD", hide_env_values = true)]
+    audit_topic_password: Option<String>,
 }
 
 impl ScOpt {
@@ -114,13 +188,29 @@ impl ScOpt {
 
         config.namespace = self.namespace.unwrap();
         config.x509_auth_scopes = self.x509_auth_scopes;
+        config.sasl_credentials = self.sasl_credentials;
+        config.token_public_key = self.token_public_key;
+        config.token_algorithm = self.token_algorithm;
+        config.token_audience = self.token_audience;
+        config.token_issuer = self.token_issuer;
+        let sasl_enabled = config.sasl_authenticator()?.is_some();
         config.white_list = self.white_list.into_iter().collect();
+        config.auto_leader_rebalance = self.auto_leader_rebalance;
+        if let Some(interval) = self.leader_rebalance_interval {
+            config.leader_rebalance_interval = Duration::from_secs(interval);
+        }
+        if let Some(timeout) = self.transaction_timeout {
+            config.transaction_timeout = Duration::from_secs(timeout);
+        }
+        config.transaction_state_file = self.transaction_state_file;
+        config.max_frame_size = self.max_frame_size;
 
         // Set Configuration Authorzation Policy
         let policy = match self.auth_policy {
             // Lookup a policy from a path
             Some(p) => Some(BasicRbacPolicy::try_from(p)?),
-            // Use root-only default policy if no policy path is found;
+            // Use root-only default policy if clients are authenticated with sasl
+            None if sasl_enabled => Some(BasicRbacPolicy::default()),
             None => None,
         };
 
@@ -128,7 +218,7 @@ impl ScOpt {
 
         // if tls is on, we need to assign public service(internal) to another port
         // because public is used by proxy which forward traffic to internal public port
-        if tls.tls {
+        let tls_option = if tls.tls {
             let proxy_addr = config.public_endpoint.clone();
             debug!("using tls proxy addr: {}", proxy_addr);
             config.public_endpoint = tls.bind_non_tls_public.clone().ok_or_else(|| {
@@ -138,10 +228,82 @@ impl ScOpt {
                 )
             })?;
 
-            Ok(((config, policy), Some((proxy_addr, tls))))
+            if tls.enable_private_tls {
+                if !tls.enable_client_cert {
+                    return Err(IoError::new(
+                        ErrorKind::InvalidInput,
+                        "private tls requires client cert",
+                    ));
+                }
+                let private_proxy_addr = config.private_endpoint.clone();
+                debug!("using private tls proxy addr: {}", private_proxy_addr);
+                config.private_endpoint = tls.bind_non_tls_private.clone().ok_or_else(|| {
+                    IoError::new(
+                        ErrorKind::NotFound,
+                        "non tls addr for private must be specified",
+                    )
+                })?;
+                // identity of SPU is forwarded by proxy, it must not be reachable directly
+                validate_non_tls_endpoint(&config.private_endpoint)?;
+                config.private_tls_endpoint = Some(private_proxy_addr);
+            }
+
+            Some((proxy_addr, tls))
+        } else if tls.enable_private_tls {
+            return Err(IoError::new(
+                ErrorKind::InvalidInput,
+                "private tls requires tls",
+            ));
         } else {
-            Ok(((config, policy), None))
-        }
+            None
+        };
+
+        config.audit = if let Some(path) = self.audit_file {
+            Some(AuditConfig::File {
+                path,
+                max_bytes: self
+                    .audit_file_max_bytes
+                    .unwrap_or(DEFAULT_AUDIT_FILE_MAX_BYTES),
+                max_files: self
+                    .audit_file_max_files
+                    .unwrap_or(DEFAULT_AUDIT_FILE_MAX_FILES),
+            })
+        } else if let Some(topic) = self.audit_topic {
+            // non tls public service has no client certificate to identify SC with
+            if config.x509_auth_scopes.is_some() {
+                return Err(IoError::new(
+                    ErrorKind::InvalidInput,
+                    "audit topic can't be used with client certificates, use audit file",
+                ));
+            }
+            let credentials =
+                match (self.audit_topic_username, self.audit_topic_password) {
+                    (Some(username), Some(password)) => {
+                        Some(Credentials::scram_sha_256(username, password))
+                    }
+                    (Some(_), None) => {
+                        return Err(IoError::new(
+                            ErrorKind::InvalidInput,
+                            "audit topic password is required when username is used",
+                        ))
+                    }
+                    (None, _) if sasl_enabled => return Err(IoError::new(
+                        ErrorKind::InvalidInput,
+                        "audit topic username and password are required with sasl authentication",
+                    )),
+                    (None, _) => None,
+                };
+            // SC produces through its own non tls public service
+            Some(AuditConfig::Topic {
+                topic,
+                endpoint: config.public_endpoint.replace("0.0.0.0", "127.0.0.1"),
+                credentials,
+            })
+        } else {
+            None
+        };
+
+        Ok(((config, policy), tls_option))
     }
 
     pub fn parse_cli_or_exit(self) -> (Config, K8Config, Option<(String, TlsConfig)>) {
@@ -180,6 +342,15 @@ pub struct TlsConfig {
     #[structopt(long)]
     /// TLS: address of non tls public service, required
     bind_non_tls_public: Option<String>,
+
+    /// TLS: require SPUs to connect to private service with client cert,
+    /// common name of cert must be `<spu id>` or `spu-<spu id>`
+    #[structopt(long)]
+    pub enable_private_tls: bool,
+
+    /// TLS: loopback address of non tls private service, required if private tls is enabled
+    #[structopt(long)]
+    bind_non_tls_private: Option<String>,
 }
 
 impl TlsConfig {
@@ -216,3 +387,55 @@ impl TlsConfig {
         Ok(builder.build())
     }
 }
+
+#[cfg(test)]
+mod test {
+
+    use super::*;
+
+    fn audit_config(args: &[&str]) -> Result<Option<AuditConfig>, IoError> {
+        let opt = ScOpt::from_iter_safe(
+            [
+                "sc-server",
+                "--namespace",
+                "default",
+                "--audit-topic",
+                "audit",
+            ]
+            .iter()
+            .chain(args.iter()),
+        )
+        .expect("parse");
+        let ((config, _), _) = opt.as_sc_config()?;
+        Ok(config.audit)
+    }
+
+    #[test]
+    fn test_audit_topic_credentials() {
+        assert_eq!(
+            audit_config(&[]).expect("config"),
+            Some(AuditConfig::Topic {
+                topic: "audit".to_owned(),
+                endpoint: ScConfig::default()
+                    .public_endpoint
+                    .replace("0.0.0.0", "127.0.0.1"),
+                credentials: None,
+            })
+        );
+
+        let config = audit_config(&[
+            "--audit-topic-username",
+            "auditor",
+            "--audit-topic-password",
+            "secret",
+        ])
+        .expect("config");
+        assert!(matches!(
+            config,
+            Some(AuditConfig::Topic { credentials: Some(credentials), .. })
+                if credentials == Credentials::scram_sha_256("auditor", "secret")
+        ));
+
+        assert!(audit_config(&["--audit-topic-username", "auditor"]).is_err());
+    }
+}
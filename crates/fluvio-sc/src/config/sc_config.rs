@@ -4,10 +4,20 @@
 //! Stores configuration parameter used by Streaming Controller module.
 //!
 use std::collections::HashSet;
-use std::{io::Error as IoError, path::PathBuf};
+use std::time::Duration;
+use std::{io::Error as IoError, io::ErrorKind, path::PathBuf};
 
 use fluvio_types::defaults::SC_PUBLIC_PORT;
 use fluvio_types::defaults::SC_PRIVATE_PORT;
+use fluvio_auth::sasl::{SaslAuthenticator, DEFAULT_TOKEN_ALGORITHM};
+
+use crate::audit::AuditConfig;
+
+/// default interval between preferred leader rebalance
+pub const DEFAULT_LEADER_REBALANCE_INTERVAL_SECS: u64 = 300;
+
+/// default time after which open transaction is aborted
+pub const DEFAULT_TRANSACTION_TIMEOUT_SECS: u64 = 60;
 
 // -----------------------------------
 // Traits
@@ -23,10 +33,29 @@ pub trait ScConfigBuilder {
 pub struct ScConfig {
     pub public_endpoint: String,
     pub private_endpoint: String,
+    /// if set, SPUs connect to mutual TLS proxy at this address which forwards to private endpoint,
+    /// common name of SPU certificate must match SPU id or name
+    pub private_tls_endpoint: Option<String>,
     pub run_k8_dispatchers: bool,
     pub namespace: String,
     pub x509_auth_scopes: Option<PathBuf>,
+    pub sasl_credentials: Option<PathBuf>,
+    pub token_public_key: Option<PathBuf>,
+    pub token_algorithm: String,
+    /// if set, bearer token `aud` claim must contain it
+    pub token_audience: Option<String>,
+    /// if set, bearer token `iss` claim must match it
+    pub token_issuer: Option<String>,
     pub white_list: HashSet<String>,
+    pub auto_leader_rebalance: bool,
+    pub leader_rebalance_interval: Duration,
+    pub audit: Option<AuditConfig>,
+    /// transaction which is open longer is aborted and its producer is fenced
+    pub transaction_timeout: Duration,
+    /// if set, transaction coordinator state is stored in this file and loaded on start
+    pub transaction_state_file: Option<PathBuf>,
+    /// max size of client request, connection is closed on larger request
+    pub max_frame_size: usize,
 }
 
 impl ::std::default::Default for ScConfig {
@@ -34,10 +63,22 @@ impl ::std::default::Default for ScConfig {
         Self {
             public_endpoint: format!("0.0.0.0:{}", SC_PUBLIC_PORT),
             private_endpoint: format!("0.0.0.0:{}", SC_PRIVATE_PORT),
+            private_tls_endpoint: None,
             run_k8_dispatchers: true,
             namespace: "default".to_owned(),
             x509_auth_scopes: None,
+            sasl_credentials: None,
+            token_public_key: None,
+            token_algorithm: DEFAULT_TOKEN_ALGORITHM.to_owned(),
+            token_audience: None,
+            token_issuer: None,
             white_list: HashSet::new(),
+            auto_leader_rebalance: false,
+            leader_rebalance_interval: Duration::from_secs(DEFAULT_LEADER_REBALANCE_INTERVAL_SECS),
+            audit: None,
+            transaction_timeout: Duration::from_secs(DEFAULT_TRANSACTION_TIMEOUT_SECS),
+            transaction_state_file: None,
+            max_frame_size: fluvio_service::DEFAULT_MAX_FRAME_SIZE,
         }
     }
 }
@@ -53,4 +94,32 @@ impl ScConfig {
             self.white_list.contains(name)
         }
     }
+
+    /// SASL authenticator, None if neither credentials nor token public key is configured.
+    /// SASL can't be used with authorization scopes
+    pub fn sasl_authenticator(&self) -> Result<Option<SaslAuthenticator>, IoError> {
+        let authenticator = SaslAuthenticator::load(
+            self.sasl_credentials.as_deref(),
+            self.token_public_key.as_deref(),
+            &self.token_algorithm,
+            self.token_audience.as_deref(),
+            self.token_issuer.as_deref(),
+        )?;
+        if authenticator.is_some() && self.x509_auth_scopes.is_some() {
+            return Err(IoError::new(
+                ErrorKind::InvalidInput,
+                "sasl authentication can't be used with authorization scopes",
+            ));
+        }
+        Ok(authenticator)
+    }
+
+    /// interval for preferred leader rebalance, None if auto rebalance is disabled
+    pub fn leader_rebalance(&self) -> Option<Duration> {
+        if self.auto_leader_rebalance {
+            Some(self.leader_rebalance_interval)
+        } else {
+            None
+        }
+    }
 }
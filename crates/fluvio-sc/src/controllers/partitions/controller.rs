@@ -2,7 +2,7 @@
 //! # Auth Controller
 //!
 
-use std::time::Duration;
+use std::time::{Duration, Instant};
 
 use fluvio_controlplane_metadata::store::ChangeListener;
 use fluvio_future::timer::sleep;
@@ -27,17 +27,25 @@ where
     partitions: StoreContext<PartitionSpec, C>,
     spus: StoreContext<SpuSpec, C>,
     reducer: PartitionReducer<C>,
+    leader_rebalance_interval: Option<Duration>,
 }
 
 impl<C> PartitionController<C>
 where
     C: MetadataItem + Send + Sync + 'static,
 {
-    pub fn start(partitions: StoreContext<PartitionSpec, C>, spus: StoreContext<SpuSpec, C>) {
+    /// start controller.
+    /// if leader rebalance interval is set, leadership is periodically moved back to preferred replica
+    pub fn start(
+        partitions: StoreContext<PartitionSpec, C>,
+        spus: StoreContext<SpuSpec, C>,
+        leader_rebalance_interval: Option<Duration>,
+    ) {
         let controller = Self {
             reducer: PartitionReducer::new(partitions.store().clone(), spus.store().clone()),
             partitions,
             spus,
+            leader_rebalance_interval,
         };
 
         spawn(controller.dispatch_loop());
@@ -72,9 +80,22 @@ where
 
         debug!("finish initializing listeners");
 
+        let mut last_rebalance = Instant::now();
+
         loop {
             self.sync_spu_changes(&mut spu_status_listener).await;
             self.sync_partition_changes(&mut partition_listener).await;
+            self.complete_spu_drain().await;
+
+            let rebalance_wait = if let Some(interval) = self.leader_rebalance_interval {
+                if last_rebalance.elapsed() >= interval {
+                    self.rebalance_preferred_leaders().await;
+                    last_rebalance = Instant::now();
+                }
+                interval.saturating_sub(last_rebalance.elapsed())
+            } else {
+                Duration::from_secs(0)
+            };
 
             trace!("waiting for events");
 
@@ -85,6 +106,9 @@ where
                 },
                 _ = partition_listener.listen() => {
                     debug!("detected partition changes");
+                },
+                _ = sleep(rebalance_wait), if self.leader_rebalance_interval.is_some() => {
+                    debug!("leader rebalance timer expired");
                 }
 
             }
@@ -121,6 +145,26 @@ where
         }
     }
 
+    /// move leaders back to preferred replicas
+    async fn rebalance_preferred_leaders(&mut self) {
+        let actions = self.reducer.preferred_leader_rebalance().await;
+
+        debug!("there were leader rebalance actions: {}", actions.len());
+        for action in actions.into_iter() {
+            self.partitions.send_action(action).await;
+        }
+    }
+
+    /// remove replicas of draining spus once they have been replaced
+    async fn complete_spu_drain(&mut self) {
+        let actions = self.reducer.complete_spu_drain().await;
+
+        debug!("there were spu drain actions: {}", actions.len());
+        for action in actions.into_iter() {
+            self.partitions.send_action(action).await;
+        }
+    }
+
     /// sync spu states to partition
     /// check to make sure
     async fn sync_spu_changes(&mut self, listener: &mut ChangeListener<SpuSpec, C>) {
@@ -216,7 +260,7 @@ mod test {
         let partitions: StoreContext<PartitionSpec, MemoryMeta> = StoreContext::new();
         let spus: StoreContext<SpuSpec, MemoryMeta> = StoreContext::new();
 
-        let controller = PartitionController::start(partitions.clone(), spus.clone());
+        let controller = PartitionController::start(partitions.clone(), spus.clone(), None);
 
         // add partitions
         spus.store().sync_all(vec![]).await;
@@ -3,6 +3,7 @@
 //!
 //! Partition metadata information on cached in the local Controller.
 //!
+use std::collections::HashSet;
 use std::sync::Arc;
 
 use fluvio_controlplane_metadata::partition::store::{PartitionLocalStore, PartitionMetadata};
@@ -11,6 +12,7 @@ use fluvio_controlplane_metadata::store::k8::K8MetaItem;
 use tracing::{debug, info, instrument};
 
 use fluvio_controlplane_metadata::core::MetadataItem;
+use fluvio_types::SpuId;
 
 use crate::stores::partition::{
     PartitionSpec, ReplicaStatus, PartitionResolution, ElectionPolicy, ElectionScoring,
@@ -160,13 +162,12 @@ where
 
         let spu_status = self.spu_store.online_status().await;
 
-        let policy = SimplePolicy::new();
-
         // go thru each partitions whose leader matches offline spu.
         for partition_kv_epoch in self.partition_store.read().await.values() {
             let partition_kv = partition_kv_epoch.inner();
             // find partition who's leader is same as offline spu
             if partition_kv.spec.leader == offline_leader_spu_id {
+                let policy = SimplePolicy::new(&partition_kv.spec);
                 // find suitable leader
                 if let Some(candidate_leader) =
                     partition_kv.status.candidate_leader(&spu_status, &policy)
@@ -223,13 +224,13 @@ where
         debug!(spu = %online_spu.key(),"performing election check spu online");
         let online_leader_spu_id = online_spu.spec.id;
 
-        let policy = SimplePolicy::new();
         // go thru each partitions which are not online and try to promote given online spu
 
         for partition_kv_epoch in self.partition_store.read().await.values() {
             let partition_kv = partition_kv_epoch.inner();
             if partition_kv.status.is_offline() {
                 if partition_kv.spec.leader != online_leader_spu_id {
+                    let policy = SimplePolicy::new(&partition_kv.spec);
                     // switch leader if online leader is different
                     for replica_status in partition_kv.status.replica_iter() {
                         if replica_status.spu == online_leader_spu_id
@@ -269,13 +270,166 @@ where
             }
         }
     }
+
+    /// move leadership back to preferred replica (first replica in the replica map)
+    /// only if preferred replica is online and has caught up with current leader
+    #[instrument(skip(self))]
+    pub async fn preferred_leader_rebalance(&self) -> Vec<PartitionWSAction<C>> {
+        let mut actions = vec![];
+
+        let spu_status = self.spu_store.online_status().await;
+
+        for partition_kv_epoch in self.partition_store.read().await.values() {
+            let partition_kv = partition_kv_epoch.inner();
+            if partition_kv.spec.is_preferred_leader()
+                || partition_kv.status.is_offline()
+                || partition_kv.status.is_being_deleted
+            {
+                continue;
+            }
+
+            if let Some(preferred_leader) = partition_kv.spec.preferred_leader() {
+                if !spu_status.contains(&preferred_leader) {
+                    debug!(
+                        partition = %partition_kv.key(),
+                        preferred_leader,
+                        "preferred leader is not online",
+                    );
+                    continue;
+                }
+
+                if !partition_kv.status.is_in_sync(preferred_leader) {
+                    debug!(
+                        partition = %partition_kv.key(),
+                        preferred_leader,
+                        "preferred leader is not in sync",
+                    );
+                    continue;
+                }
+
+                let mut part_kv_change = partition_kv.clone();
+                part_kv_change.spec.leader = preferred_leader;
+                actions.push(PartitionWSAction::UpdateSpec((
+                    part_kv_change.key_owned(),
+                    part_kv_change.spec,
+                )));
+                info!(
+                    partition = %partition_kv.key(),
+                    old_leader = partition_kv.spec.leader,
+                    preferred_leader,
+                    "moving leader back to preferred replica",
+                );
+            }
+        }
+
+        actions
+    }
+
+    /// remove draining spus from partition replicas.
+    /// replica is only removed when it is no longer leader and all remaining replicas are in sync
+    #[instrument(skip(self))]
+    pub async fn complete_spu_drain(&self) -> Vec<PartitionWSAction<C>> {
+        let draining_spus: HashSet<SpuId> = self
+            .spu_store
+            .read()
+            .await
+            .values()
+            .filter(|spu| spu.status.is_draining())
+            .map(|spu| spu.spec.id)
+            .collect();
+
+        if draining_spus.is_empty() {
+            return vec![];
+        }
+
+        let online_spus = self.spu_store.online_status().await;
+        let mut actions = vec![];
+
+        for partition_kv_epoch in self.partition_store.read().await.values() {
+            let partition_kv = partition_kv_epoch.inner();
+            if partition_kv.status.is_being_deleted {
+                continue;
+            }
+
+            // move leadership away first, replica is removed once it is no longer leader
+            if draining_spus.contains(&partition_kv.spec.leader) {
+                if let Some(new_leader) =
+                    partition_kv.spec.replicas.iter().copied().find(|replica| {
+                        !draining_spus.contains(replica)
+                            && online_spus.contains(replica)
+                            && partition_kv.status.is_in_sync(*replica)
+                    })
+                {
+                    let mut part_kv_change = partition_kv.clone();
+                    part_kv_change.spec.leader = new_leader;
+                    actions.push(PartitionWSAction::UpdateSpec((
+                        part_kv_change.key_owned(),
+                        part_kv_change.spec,
+                    )));
+                    info!(
+                        partition = %partition_kv.key(),
+                        old_leader = partition_kv.spec.leader,
+                        new_leader,
+                        "moving leader away from draining spu",
+                    );
+                }
+                continue;
+            }
+
+            let (drained, remaining): (Vec<SpuId>, Vec<SpuId>) = partition_kv
+                .spec
+                .replicas
+                .iter()
+                .partition(|replica| draining_spus.contains(replica));
+
+            if drained.is_empty() || remaining.is_empty() {
+                continue;
+            }
+
+            if let Some(lagging) = remaining
+                .iter()
+                .find(|replica| !partition_kv.status.is_in_sync(**replica))
+            {
+                debug!(
+                    partition = %partition_kv.key(),
+                    lagging,
+                    "waiting for replica to be in sync before removing draining replicas",
+                );
+                continue;
+            }
+
+            let mut part_kv_change = partition_kv.clone();
+            part_kv_change.spec.replicas = remaining;
+            actions.push(PartitionWSAction::UpdateSpec((
+                part_kv_change.key_owned(),
+                part_kv_change.spec,
+            )));
+            info!(
+                partition = %partition_kv.key(),
+                ?drained,
+                "removing draining replicas",
+            );
+        }
+
+        actions
+    }
 }
 
-struct SimplePolicy {}
+/// follower lagging less than this can become leader, unless election is strict
+const MAX_LEADER_LAG: i64 = 4;
+
+/// only in sync replica can become leader, unless partition allows unclean leader election
+struct SimplePolicy {
+    unclean_leader_election: bool,
+    strict_leader_election: bool,
+}
 
 impl SimplePolicy {
-    fn new() -> Self {
-        SimplePolicy {}
+    fn new(spec: &PartitionSpec) -> Self {
+        SimplePolicy {
+            unclean_leader_election: spec.replication_policy.unclean_leader_election,
+            strict_leader_election: spec.replication_policy.strict_leader_election,
+        }
     }
 }
 
@@ -286,7 +440,11 @@ impl ElectionPolicy for SimplePolicy {
         leader: &ReplicaStatus,
     ) -> ElectionScoring {
         let lag = leader.leo - replica_status.leo;
-        if lag < 4 {
+        if lag <= 0 {
+            ElectionScoring::Score(0)
+        } else if self.unclean_leader_election {
+            ElectionScoring::Score(lag.min(i64::from(u16::MAX)) as u16)
+        } else if !self.strict_leader_election && lag < MAX_LEADER_LAG {
             ElectionScoring::Score(lag as u16)
         } else {
             ElectionScoring::NotSuitable
@@ -303,6 +461,242 @@ impl ElectionPolicy for SimplePolicy {
 #[cfg(test)]
 pub mod test {
 
+    use fluvio_controlplane_metadata::partition::store::{PartitionMetadata, PartitionLocalStore};
+    use fluvio_controlplane_metadata::spu::store::{SpuMd, SpuMetadata, SpuLocalStore};
+
+    use crate::stores::actions::WSAction;
+    use crate::stores::partition::{
+        PartitionSpec, PartitionStatus, PartitionResolution, ReplicaStatus, ElectionPolicy,
+        ElectionScoring,
+    };
+
+    use super::{PartitionReducer, SimplePolicy};
+
+    fn partition(
+        topic: &str,
+        leader: i32,
+        replicas: Vec<i32>,
+        status: PartitionStatus,
+    ) -> PartitionMetadata<u32> {
+        PartitionMetadata::new(
+            (topic, 0).into(),
+            PartitionSpec::new(leader, replicas),
+            status,
+        )
+    }
+
+    fn online_status(leader: (i32, i64, i64), replicas: Vec<(i32, i64, i64)>) -> PartitionStatus {
+        PartitionStatus::new2(
+            leader,
+            replicas.into_iter().map(|r| r.into()).collect(),
+            PartitionResolution::Online,
+        )
+    }
+
+    #[test]
+    fn test_leader_score() {
+        let leader = ReplicaStatus::new(0, 10, 10);
+        let clean = SimplePolicy {
+            unclean_leader_election: false,
+            strict_leader_election: true,
+        };
+        let unclean = SimplePolicy {
+            unclean_leader_election: true,
+            strict_leader_election: false,
+        };
+
+        // lag 0, replica has caught up with leader
+        let caught_up = ReplicaStatus::new(1, 10, 10);
+        assert!(matches!(
+            clean.potential_leader_score(&caught_up, &leader),
+            ElectionScoring::Score(0)
+        ));
+        assert!(matches!(
+            unclean.potential_leader_score(&caught_up, &leader),
+            ElectionScoring::Score(0)
+        ));
+
+        // negative lag, replica is ahead of last known leader status
+        let ahead = ReplicaStatus::new(1, 10, 12);
+        assert!(matches!(
+            clean.potential_leader_score(&ahead, &leader),
+            ElectionScoring::Score(0)
+        ));
+        assert!(matches!(
+            unclean.potential_leader_score(&ahead, &leader),
+            ElectionScoring::Score(0)
+        ));
+
+        // positive lag, only suitable for unclean election
+        let behind = ReplicaStatus::new(1, 5, 7);
+        assert!(matches!(
+            clean.potential_leader_score(&behind, &leader),
+            ElectionScoring::NotSuitable
+        ));
+        assert!(matches!(
+            unclean.potential_leader_score(&behind, &leader),
+            ElectionScoring::Score(3)
+        ));
+    }
+
+    #[test]
+    fn test_default_leader_score() {
+        let leader = ReplicaStatus::new(0, 10, 10);
+        let policy = SimplePolicy::new(&PartitionSpec::new(0, vec![0, 1]));
+
+        // follower lagging by less than 4 records is still eligible by default
+        let behind = ReplicaStatus::new(1, 7, 7);
+        assert!(matches!(
+            policy.potential_leader_score(&behind, &leader),
+            ElectionScoring::Score(3)
+        ));
+
+        let far_behind = ReplicaStatus::new(1, 6, 6);
+        assert!(matches!(
+            policy.potential_leader_score(&far_behind, &leader),
+            ElectionScoring::NotSuitable
+        ));
+    }
+
+    #[fluvio_future::test]
+    async fn test_complete_spu_drain() {
+        let mut draining = SpuMetadata::<u32>::quick(("spu-0", 0, true, None));
+        draining.status.set_draining(true);
+        let spus = SpuLocalStore::bulk_new(vec![
+            draining,
+            SpuMetadata::<u32>::quick(("spu-1", 1, true, None)),
+            SpuMetadata::<u32>::quick(("spu-2", 2, true, None)),
+        ]);
+
+        let partitions = PartitionLocalStore::bulk_new(vec![
+            // new replica is in sync, drained replica is removed
+            partition(
+                "synced",
+                1,
+                vec![1, 2, 0],
+                online_status((1, 10, 10), vec![(2, 10, 10), (0, 10, 10)]),
+            ),
+            // new replica is catching up
+            partition(
+                "catching-up",
+                1,
+                vec![1, 2, 0],
+                online_status((1, 10, 10), vec![(2, 5, 5), (0, 10, 10)]),
+            ),
+            // draining spu is leader, leadership is moved first
+            partition(
+                "leader",
+                0,
+                vec![2, 1, 0],
+                online_status((0, 10, 10), vec![(2, 5, 5), (1, 10, 10)]),
+            ),
+            // not affected by drain
+            partition(
+                "other",
+                1,
+                vec![1, 2],
+                online_status((1, 10, 10), vec![(2, 10, 10)]),
+            ),
+        ]);
+
+        let reducer = PartitionReducer::new(partitions, spus);
+        let mut updates: Vec<(String, PartitionSpec)> = reducer
+            .complete_spu_drain()
+            .await
+            .into_iter()
+            .map(|action| match action {
+                WSAction::UpdateSpec((key, spec)) => (key.topic, spec),
+                _ => panic!("expected spec update"),
+            })
+            .collect();
+        updates.sort_by(|a, b| a.0.cmp(&b.0));
+
+        assert_eq!(updates.len(), 2);
+        assert_eq!(updates[0].0, "leader");
+        assert_eq!(updates[0].1.leader, 1);
+        assert_eq!(updates[0].1.replicas, vec![2, 1, 0]);
+        assert_eq!(updates[1].0, "synced");
+        assert_eq!(updates[1].1.leader, 1);
+        assert_eq!(updates[1].1.replicas, vec![1, 2]);
+    }
+
+    #[fluvio_future::test]
+    async fn test_complete_spu_drain_without_draining_spu() {
+        let spus = SpuLocalStore::bulk_new(vec![
+            SpuMetadata::<u32>::quick(("spu-0", 0, true, None)),
+            SpuMetadata::<u32>::quick(("spu-1", 1, true, None)),
+        ]);
+        let partitions = PartitionLocalStore::bulk_new(vec![partition(
+            "topic",
+            1,
+            vec![1, 0],
+            online_status((1, 10, 10), vec![(0, 10, 10)]),
+        )]);
+
+        let reducer = PartitionReducer::new(partitions, spus);
+        assert!(reducer.complete_spu_drain().await.is_empty());
+    }
+
+    #[fluvio_future::test]
+    async fn test_preferred_leader_rebalance() {
+        let spus = SpuLocalStore::bulk_new(vec![
+            SpuMetadata::<u32>::quick(("spu-0", 0, true, None)),
+            SpuMetadata::<u32>::quick(("spu-1", 1, true, None)),
+            SpuMetadata::<u32>::quick(("spu-2", 2, false, None)),
+        ]);
+
+        let partitions = PartitionLocalStore::bulk_new(vec![
+            // already on preferred leader
+            partition(
+                "preferred",
+                0,
+                vec![0, 1],
+                online_status((0, 10, 10), vec![(1, 10, 10)]),
+            ),
+            // preferred leader is in sync, must be moved back
+            partition(
+                "moved",
+                1,
+                vec![0, 1],
+                online_status((1, 10, 10), vec![(0, 10, 10)]),
+            ),
+            // preferred leader is behind
+            partition(
+                "behind",
+                1,
+                vec![0, 1],
+                online_status((1, 10, 10), vec![(0, 5, 5)]),
+            ),
+            // preferred leader is offline
+            partition(
+                "offline-spu",
+                1,
+                vec![2, 1],
+                online_status((1, 10, 10), vec![(2, 10, 10)]),
+            ),
+            // partition is offline
+            partition(
+                "offline",
+                1,
+                vec![0, 1],
+                PartitionStatus::new((1, 10, 10), vec![(0, 10, 10).into()]),
+            ),
+        ]);
+
+        let reducer = PartitionReducer::new(partitions, spus);
+        let actions = reducer.preferred_leader_rebalance().await;
+
+        assert_eq!(actions.len(), 1);
+        match &actions[0] {
+            WSAction::UpdateSpec((key, spec)) => {
+                assert_eq!(key.topic, "moved");
+                assert_eq!(spec.leader, 0);
+                assert_eq!(spec.replicas, vec![0, 1]);
+            }
+            _ => panic!("expected spec update"),
+        }
+    }
+
     /*
     #[fluvio_future::test]
     async fn test_process_partition_actions_without_partitions()  {
@@ -16,9 +16,21 @@ use crate::stores::spu::*;
 /// Validate assigned topic spec parameters and update topic status
 ///  * error is passed to the topic reason.
 ///
-pub fn validate_assigned_topic_parameters(partition_map: &PartitionMaps) -> TopicNextState {
+pub async fn validate_assigned_topic_parameters(
+    partition_map: &PartitionMaps,
+    spu_store: &SpuAdminStore,
+) -> TopicNextState {
     if let Err(err) = partition_map.valid_partition_map() {
         TopicStatus::next_resolution_invalid_config(&err.to_string()).into()
+    } else if let Err(err) = partition_map.valid_rack_assignment(&spu_store.spu_rack_map().await) {
+        TopicStatus::next_resolution_invalid_config(&err.to_string()).into()
+    } else if let Err(err) = TopicSpec::valid_min_in_sync_replicas(
+        partition_map.replication_policy(),
+        &partition_map.replication_factor().unwrap_or_default(),
+    ) {
+        TopicStatus::next_resolution_invalid_config(&err.to_string()).into()
+    } else if let Err(err) = partition_map.config().validate() {
+        TopicStatus::next_resolution_invalid_config(&err.to_string()).into()
     } else {
         TopicStatus::next_resolution_pending().into()
     }
@@ -33,6 +45,12 @@ pub fn validate_computed_topic_parameters(param: &TopicReplicaParam) -> TopicNex
         TopicStatus::next_resolution_invalid_config(&err.to_string()).into()
     } else if let Err(err) = TopicSpec::valid_replication_factor(&param.replication_factor) {
         TopicStatus::next_resolution_invalid_config(&err.to_string()).into()
+    } else if let Err(err) =
+        TopicSpec::valid_min_in_sync_replicas(&param.replication_policy, &param.replication_factor)
+    {
+        TopicStatus::next_resolution_invalid_config(&err.to_string()).into()
+    } else if let Err(err) = param.config.validate() {
+        TopicStatus::next_resolution_invalid_config(&err.to_string()).into()
     } else {
         TopicStatus::next_resolution_pending().into()
     }
@@ -48,7 +66,7 @@ pub async fn generate_replica_map(
     spus: &SpuAdminStore,
     param: &TopicReplicaParam,
 ) -> TopicNextState {
-    let spu_count = spus.count().await;
+    let spu_count = spus.spu_used_for_replica().await;
     if spu_count < param.replication_factor {
         trace!(
             "R-MAP needs {:?} online spus, found {:?}",
@@ -205,7 +223,7 @@ impl TopicNextState {
             // Assign Topic
             TopicSpec::Assigned(ref partition_map) => match topic.status.resolution {
                 TopicResolution::Init | TopicResolution::InvalidConfig => {
-                    validate_assigned_topic_parameters(partition_map)
+                    validate_assigned_topic_parameters(partition_map, spu_store).await
                 }
                 TopicResolution::Pending | TopicResolution::InsufficientResources => {
                     let mut next_state =
@@ -263,7 +281,7 @@ async fn generate_partitions_with_rack_assignment(
     let mut partition_map = BTreeMap::new();
     let rack_map = SpuAdminStore::live_spu_rack_map_sorted(spus).await;
     let spu_list = SpuAdminStore::online_spus_in_rack(&rack_map);
-    let spu_cnt = spus.online_spu_count().await;
+    let spu_cnt = spu_list.len() as i32;
 
     let s_idx = if start_index >= 0 {
         start_index
@@ -293,7 +311,7 @@ async fn generate_partitions_without_rack(
 ) -> ReplicaMap {
     let mut partition_map = BTreeMap::new();
     let spu_cnt = spus.spu_used_for_replica().await;
-    let spu_ids = spus.spu_ids().await;
+    let spu_ids = spus.spu_ids_for_replica().await;
 
     let s_idx = if start_index >= 0 {
         start_index
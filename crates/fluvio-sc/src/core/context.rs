@@ -5,12 +5,19 @@
 //!
 use std::sync::Arc;
 
+use async_lock::Mutex;
+use fluvio_auth::basic::BasicRbacPolicy;
+
 use crate::config::ScConfig;
+use crate::audit::AuditLog;
+use crate::transaction::TransactionCoordinator;
 use crate::stores::spu::*;
 use crate::stores::partition::*;
 use crate::stores::topic::*;
 use crate::stores::spg::*;
 use crate::stores::connector::*;
+use crate::stores::quota::*;
+use crate::stores::schema::*;
 use crate::stores::*;
 
 pub type SharedContext = Arc<Context>;
@@ -24,8 +31,14 @@ pub struct Context {
     topics: StoreContext<TopicSpec>,
     spgs: StoreContext<SpuGroupSpec>,
     managed_connectors: StoreContext<ManagedConnectorSpec>,
+    quotas: StoreContext<QuotaSpec>,
+    schemas: StoreContext<SchemaSpec>,
+    schema_ids: Mutex<u32>,
     health: SharedHealthCheck,
     config: ScConfig,
+    auth_policy: Option<BasicRbacPolicy>,
+    audit: AuditLog,
+    transactions: TransactionCoordinator,
 }
 
 // -----------------------------------
@@ -34,19 +47,38 @@ pub struct Context {
 
 impl Context {
     pub fn shared_metadata(config: ScConfig) -> Arc<Self> {
-        Arc::new(Self::new(config))
+        Arc::new(Self::new(config, None))
+    }
+
+    /// shared metadata with authorization policy which is distributed to SPUs
+    pub fn shared_metadata_with_auth_policy(
+        config: ScConfig,
+        auth_policy: Option<BasicRbacPolicy>,
+    ) -> Arc<Self> {
+        Arc::new(Self::new(config, auth_policy))
     }
 
     /// private function to provision metadata
-    fn new(config: ScConfig) -> Self {
+    fn new(config: ScConfig, auth_policy: Option<BasicRbacPolicy>) -> Self {
+        let audit = config
+            .audit
+            .as_ref()
+            .map(AuditLog::start)
+            .unwrap_or_default();
         Self {
             spus: StoreContext::new(),
             partitions: StoreContext::new(),
             topics: StoreContext::new(),
             spgs: StoreContext::new(),
             managed_connectors: StoreContext::new(),
+            quotas: StoreContext::new(),
+            schemas: StoreContext::new(),
+            schema_ids: Mutex::new(0),
             health: HealthCheck::shared(),
             config,
+            auth_policy,
+            audit,
+            transactions: TransactionCoordinator::default(),
         }
     }
 
@@ -73,6 +105,22 @@ impl Context {
         &self.managed_connectors
     }
 
+    /// reference to quotas, which are distributed to SPUs
+    pub fn quotas(&self) -> &StoreContext<QuotaSpec> {
+        &self.quotas
+    }
+
+    /// reference to schema subjects, which are distributed to SPUs
+    pub fn schemas(&self) -> &StoreContext<SchemaSpec> {
+        &self.schemas
+    }
+
+    /// highest schema id assigned by this SC, so ids of deleted subjects are not reused.
+    /// Ids are assigned from all subjects, so registrations hold lock while assigning id
+    pub fn schema_ids(&self) -> &Mutex<u32> {
+        &self.schema_ids
+    }
+
     /// spu health channel
     pub fn health(&self) -> &SharedHealthCheck {
         &self.health
@@ -86,4 +134,19 @@ impl Context {
     pub fn namespace(&self) -> &str {
         &self.config.namespace
     }
+
+    /// authorization policy, if none then everything is allowed
+    pub fn auth_policy(&self) -> Option<&BasicRbacPolicy> {
+        self.auth_policy.as_ref()
+    }
+
+    /// audit log, records nothing if audit is not configured
+    pub fn audit(&self) -> &AuditLog {
+        &self.audit
+    }
+
+    /// coordinator of transactional producers
+    pub fn transactions(&self) -> &TransactionCoordinator {
+        &self.transactions
+    }
 }
@@ -18,6 +18,7 @@ use crate::controllers::topics::TopicController;
 use crate::controllers::partitions::PartitionController;
 use crate::config::{ScConfig};
 use crate::services::start_internal_server;
+use crate::transaction::start_transaction_timeout;
 use crate::dispatcher::dispatcher::K8ClusterStateDispatcher;
 use crate::services::auth::basic::BasicRbacPolicy;
 
@@ -34,6 +35,8 @@ where
     use crate::stores::partition::PartitionSpec;
     use crate::stores::spg::SpuGroupSpec;
     use crate::stores::connector::ManagedConnectorSpec;
+    use crate::stores::quota::QuotaSpec;
+    use crate::stores::schema::SchemaSpec;
     info!("SC Platform Version: {}", &*crate::VERSION);
 
     let mut sys = System::new_all();
@@ -51,9 +54,15 @@ where
     let (sc_config, auth_policy) = sc_config_policy;
 
     let namespace = sc_config.namespace.clone();
-    let ctx = Context::shared_metadata(sc_config);
+    let ctx = Context::shared_metadata_with_auth_policy(sc_config, auth_policy.clone());
     let config = ctx.config();
 
+    if let Some(state_file) = &config.transaction_state_file {
+        ctx.transactions()
+            .open(state_file)
+            .expect("can't load transaction state");
+    }
+
     K8ClusterStateDispatcher::<SpuSpec, C>::start(
         namespace.clone(),
         metadata_client.clone(),
@@ -79,9 +88,21 @@ where
     );
 
     K8ClusterStateDispatcher::<ManagedConnectorSpec, C>::start(
+        namespace.clone(),
+        metadata_client.clone(),
+        ctx.managed_connectors().clone(),
+    );
+
+    K8ClusterStateDispatcher::<QuotaSpec, C>::start(
+        namespace.clone(),
+        metadata_client.clone(),
+        ctx.quotas().clone(),
+    );
+
+    K8ClusterStateDispatcher::<SchemaSpec, C>::start(
         namespace,
         metadata_client,
-        ctx.managed_connectors().clone(),
+        ctx.schemas().clone(),
     );
 
     whitelist!(config, "spu", SpuController::start(ctx.clone()));
@@ -89,9 +110,18 @@ where
     whitelist!(
         config,
         "partition",
-        PartitionController::start(ctx.partitions().clone(), ctx.spus().clone())
+        PartitionController::start(
+            ctx.partitions().clone(),
+            ctx.spus().clone(),
+            config.leader_rebalance()
+        )
     );
 
+    whitelist!(
+        config,
+        "transaction",
+        start_transaction_timeout(ctx.clone())
+    );
     whitelist!(config, "internal", start_internal_server(ctx.clone()));
     whitelist!(
         config,
@@ -111,7 +141,20 @@ where
         use crate::services::auth::basic::{BasicAuthorization, BasicRbacPolicy};
 
         pub fn start(ctx: SharedContext, auth_policy_option: Option<BasicRbacPolicy>) {
-            if let Some(policy) = auth_policy_option {
+            let sasl = ctx
+                .config()
+                .sasl_authenticator()
+                .expect("can't load sasl configuration");
+
+            if let Some(sasl) = sasl {
+                info!("using basic authorization with sasl authentication");
+                // cli always sets policy when sasl is enabled
+                let policy = auth_policy_option.unwrap_or_default();
+                start_public_server(AuthGlobalContext::new(
+                    ctx,
+                    Arc::new(BasicAuthorization::new(policy).with_sasl(Arc::new(sasl))),
+                ));
+            } else if let Some(policy) = auth_policy_option {
                 info!("using basic authorization");
                 start_public_server(AuthGlobalContext::new(
                     ctx,
@@ -1,4 +1,7 @@
-use std::{collections::HashMap, time::Duration};
+use std::{
+    collections::{BTreeSet, HashMap},
+    time::Duration,
+};
 
 use tracing::{debug, error, trace, instrument};
 use k8_client::ClientError;
@@ -6,6 +9,7 @@ use k8_types::{
     LabelSelector, TemplateSpec, TemplateMeta,
     core::pod::{
         PodSpec, ContainerSpec, VolumeMount, ConfigMapVolumeSource, KeyToPath, VolumeSpec,
+        SecretVolumeSpec,
     },
     LabelProvider,
 };
@@ -174,6 +178,7 @@ impl ManagedConnectorDeploymentController {
     }
 
     const DEFAULT_CONNECTOR_NAME: &'static str = "fluvio-connector";
+    const SECRETS_MOUNT_PATH: &'static str = "/var/secrets";
     pub fn generate_k8_deployment_spec(
         mc_spec: &ManagedConnectorSpec,
         _namespace: &str,
@@ -206,6 +211,46 @@ impl ManagedConnectorDeploymentController {
         let mut args = vec!["--".to_string()];
         args.extend(parameters);
 
+        let mut volume_mounts = vec![VolumeMount {
+            name: "fluvio-config-volume".to_string(),
+            mount_path: "/home/fluvio/.fluvio".to_string(),
+            ..Default::default()
+        }];
+        let mut volumes = vec![config_map_volume_spec];
+
+        // secret objects are mounted as files, connector is given path to value instead of value
+        let secret_objects: BTreeSet<&str> = mc_spec
+            .secrets
+            .values()
+            .map(|secret| secret.name.as_str())
+            .collect();
+        for (index, secret_name) in secret_objects.into_iter().enumerate() {
+            let volume_name = format!("secret-{}", index);
+            volume_mounts.push(VolumeMount {
+                name: volume_name.clone(),
+                mount_path: format!("{}/{}", Self::SECRETS_MOUNT_PATH, secret_name),
+                read_only: Some(true),
+                ..Default::default()
+            });
+            volumes.push(VolumeSpec {
+                name: volume_name,
+                secret: Some(SecretVolumeSpec {
+                    secret_name: secret_name.to_owned(),
+                    ..Default::default()
+                }),
+                ..Default::default()
+            });
+        }
+        args.extend(mc_spec.secrets.iter().map(|(parameter, secret)| {
+            format!(
+                "--{}-file={}/{}/{}",
+                parameter,
+                Self::SECRETS_MOUNT_PATH,
+                secret.name,
+                secret.key
+            )
+        }));
+
         debug!(
             "Starting connector for image: {:?} with arguments {:?}",
             image, args
@@ -223,15 +268,11 @@ impl ManagedConnectorDeploymentController {
                     /*
                     env, // TODO
                     */
-                    volume_mounts: vec![VolumeMount {
-                        name: "fluvio-config-volume".to_string(),
-                        mount_path: "/home/fluvio/.fluvio".to_string(),
-                        ..Default::default()
-                    }],
+                    volume_mounts,
                     args,
                     ..Default::default()
                 }],
-                volumes: vec![config_map_volume_spec],
+                volumes,
                 //security_context: spu_k8_config.pod_security_context.clone(),
                 //node_selector: Some(spu_pod_config.node_selector.clone()),
                 ..Default::default()
@@ -248,3 +289,46 @@ impl ManagedConnectorDeploymentController {
         }
     }
 }
+
+#[cfg(test)]
+mod test {
+
+    use fluvio_controlplane_metadata::connector::ManagedConnectorSecret;
+
+    use super::*;
+
+    #[test]
+    fn test_deployment_secrets() {
+        let mut spec = ManagedConnectorSpec {
+            name: "github".to_owned(),
+            type_: "github-stars".to_owned(),
+            topic: "stars".to_owned(),
+            ..Default::default()
+        };
+        spec.secrets.insert(
+            "token".to_owned(),
+            ManagedConnectorSecret::new("fluvio-connector-github", "token"),
+        );
+
+        let deployment = ManagedConnectorDeploymentController::generate_k8_deployment_spec(
+            &spec, "default", "github",
+        );
+        let pod = &deployment.template.spec;
+        let container = &pod.containers[0];
+        assert!(container
+            .args
+            .contains(&"--token-file=/var/secrets/fluvio-connector-github/token".to_owned()));
+        assert_eq!(pod.volumes.len(), 2);
+        assert_eq!(
+            pod.volumes[1]
+                .secret
+                .as_ref()
+                .map(|secret| secret.secret_name.as_str()),
+            Some("fluvio-connector-github")
+        );
+        assert_eq!(
+            container.volume_mounts[1].mount_path,
+            "/var/secrets/fluvio-connector-github"
+        );
+    }
+}
@@ -52,6 +52,16 @@ pub fn main_k8_loop(opt: ScOpt) {
             let tls_acceptor = tls_config
                 .try_build_tls_acceptor()
                 .expect("can't build tls acceptor");
+            if let Some(private_proxy_addr) = sc_config.private_tls_endpoint.clone() {
+                let private_acceptor = tls_config
+                    .try_build_tls_acceptor()
+                    .expect("can't build private tls acceptor");
+                proxy::start_private_proxy(
+                    sc_config.private_endpoint.clone(),
+                    (private_acceptor, private_proxy_addr),
+                )
+                .await;
+            }
             proxy::start_proxy(sc_config, (tls_acceptor, proxy_port)).await;
         }
 
@@ -95,4 +105,18 @@ mod proxy {
             process::exit(-1);
         }
     }
+
+    /// proxy for SPUs, forwards identity of client cert to private service
+    pub async fn start_private_proxy(target: String, acceptor: (TlsAcceptor, String)) {
+        let (tls_acceptor, proxy_addr) = acceptor;
+        info!("starting private TLS proxy: {}", proxy_addr);
+
+        let authenticator = Box::new(X509Authenticator::identity_only());
+        if let Err(err) =
+            proxy_start_with_authenticator(&proxy_addr, tls_acceptor, target, authenticator).await
+        {
+            print_cli_err!(err);
+            process::exit(-1);
+        }
+    }
 }
@@ -130,7 +130,7 @@ impl SpuGroupObj {
                 port: spu_private_ep.port,
                 encryption: spu_private_ep.encryption,
             },
-            rack: None,
+            rack: spec.spu_config.rack_for_replica(spu),
             public_endpoint_local: Some(Endpoint {
                 host: format!("{}.{}", full_spu_name, full_group_name),
                 port: spu_public_ep.port,
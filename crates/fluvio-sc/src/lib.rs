@@ -10,6 +10,8 @@ mod init;
 mod error;
 mod services;
 mod controllers;
+mod audit;
+mod transaction;
 
 pub use init::start_main_loop;
 
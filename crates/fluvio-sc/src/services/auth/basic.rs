@@ -2,23 +2,32 @@ use std::sync::Arc;
 
 use tracing::instrument;
 use async_trait::async_trait;
-pub use policy::BasicRbacPolicy;
+pub use fluvio_auth::basic::BasicRbacPolicy;
 
-use fluvio_auth::{AuthContext, Authorization, TypeAction, InstanceAction, AuthError};
+use fluvio_auth::{AuthContext, Authorization, TypeAction, InstanceAction, AuthError, Identity};
 use fluvio_controlplane_metadata::extended::ObjectType;
 use fluvio_auth::x509::X509Identity;
+use fluvio_auth::sasl::SaslAuthenticator;
 
 #[derive(Debug, Clone)]
 pub struct BasicAuthorization {
     policy: Arc<BasicRbacPolicy>,
+    sasl: Option<Arc<SaslAuthenticator>>,
 }
 
 impl BasicAuthorization {
     pub fn new(policy: BasicRbacPolicy) -> Self {
         Self {
             policy: Arc::new(policy),
+            sasl: None,
         }
     }
+
+    /// authenticate clients with SASL handshake instead of X509 identity from TLS proxy
+    pub fn with_sasl(mut self, sasl: Arc<SaslAuthenticator>) -> Self {
+        self.sasl = Some(sasl);
+        self
+    }
 }
 
 #[async_trait]
@@ -30,7 +39,10 @@ impl Authorization for BasicAuthorization {
         &self,
         socket: &mut fluvio_socket::FluvioSocket,
     ) -> Result<Self::Context, AuthError> {
-        let identity = X509Identity::create_from_connection(socket).await?;
+        let identity: Box<dyn Identity> = match &self.sasl {
+            Some(sasl) => Box::new(sasl.authenticate(socket).await?),
+            None => Box::new(X509Identity::create_from_connection(socket).await?),
+        };
         Ok(BasicAuthContext {
             identity,
             policy: self.policy.clone(),
@@ -40,7 +52,7 @@ impl Authorization for BasicAuthorization {
 
 #[derive(Debug)]
 pub struct BasicAuthContext {
-    identity: X509Identity,
+    identity: Box<dyn Identity>,
     policy: Arc<BasicRbacPolicy>,
 }
 
@@ -52,133 +64,23 @@ impl AuthContext for BasicAuthContext {
         action: TypeAction,
     ) -> Result<bool, AuthError> {
         self.policy
-            .evaluate(action.into(), ty, None, &self.identity)
+            .evaluate(action.into(), ty, None, self.identity.as_ref())
             .await
     }
 
-    /// check if specific instance of spec can be deleted
     async fn allow_instance_action(
         &self,
-        _ty: ObjectType,
-        _action: InstanceAction,
-        _key: &str,
+        ty: ObjectType,
+        action: InstanceAction,
+        key: &str,
     ) -> Result<bool, AuthError> {
-        Ok(true)
-    }
-}
-
-/// basic policy module
-/// does imple subtitution
-mod policy {
-
-    use std::fs::read;
-    use std::collections::HashMap;
-    use std::path::PathBuf;
-    use std::convert::TryFrom;
-
-    use tracing::debug;
-    use serde::{Serialize, Deserialize};
-
-    use fluvio_auth::{AuthError, TypeAction, InstanceAction};
-    use fluvio_auth::x509::X509Identity;
-
-    use super::ObjectType;
-
-    type Role = String;
-
-    #[derive(Debug, Clone, PartialEq, Hash, Eq, Deserialize, Serialize)]
-    pub enum Action {
-        Create,
-        Read,
-        Update,
-        Delete,
-        All,
-    }
-
-    impl From<TypeAction> for Action {
-        fn from(action: TypeAction) -> Self {
-            match action {
-                TypeAction::Create => Action::Create,
-                TypeAction::Read => Action::Read,
-            }
-        }
-    }
-
-    impl From<InstanceAction> for Action {
-        fn from(action: InstanceAction) -> Self {
-            match action {
-                InstanceAction::Delete => Action::Delete,
-            }
-        }
-    }
-
-    #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
-    pub struct BasicRbacPolicy(pub HashMap<Role, HashMap<ObjectType, Vec<Action>>>);
-
-    impl From<HashMap<Role, HashMap<ObjectType, Vec<Action>>>> for BasicRbacPolicy {
-        fn from(map: HashMap<Role, HashMap<ObjectType, Vec<Action>>>) -> Self {
-            Self(map)
-        }
-    }
-
-    impl TryFrom<PathBuf> for BasicRbacPolicy {
-        type Error = std::io::Error;
-        fn try_from(path: PathBuf) -> Result<Self, Self::Error> {
-            debug!("reading basic policy: {:#?}", path);
-            let file = read(path)?;
-            let policy: BasicRbacPolicy = serde_json::from_slice(&file)?;
-            Ok(policy)
-        }
-    }
-
-    impl BasicRbacPolicy {
-        pub async fn evaluate(
-            &self,
-            action: Action,
-            object_type: ObjectType,
-            _instance: Option<&str>,
-            identity: &X509Identity,
-        ) -> Result<bool, AuthError> {
-            //   let (action,object,_instance) = request;
-            // For each scope provided in the identity,
-            // check if there is a match;
-            let is_allowed = identity.scopes().iter().any(|scope| {
-                self.0
-                    .get(scope)
-                    .map(|objects| {
-                        objects
-                            .get(&object_type)
-                            .map(|actions| {
-                                actions.iter().any(|permission| {
-                                    permission == &action || permission == &Action::All
-                                })
-                            })
-                            .unwrap_or(false)
-                    })
-                    .unwrap_or(false)
-            });
-
-            Ok(is_allowed)
-        }
+        self.policy
+            .evaluate(action.into(), ty, Some(key), self.identity.as_ref())
+            .await
     }
 
-    impl Default for BasicRbacPolicy {
-        // default only allows the `Root` role to have full permissions;
-        fn default() -> Self {
-            let mut root_policy = HashMap::new();
-
-            root_policy.insert(ObjectType::Spu, vec![Action::All]);
-            root_policy.insert(ObjectType::CustomSpu, vec![Action::All]);
-            root_policy.insert(ObjectType::SpuGroup, vec![Action::All]);
-            root_policy.insert(ObjectType::Topic, vec![Action::All]);
-            root_policy.insert(ObjectType::Partition, vec![Action::All]);
-
-            let mut policy = HashMap::new();
-
-            policy.insert(String::from("Root"), root_policy);
-
-            Self(policy)
-        }
+    fn principal(&self) -> Option<&str> {
+        Some(self.identity.principal())
     }
 }
 
@@ -192,7 +94,7 @@ mod test {
 
     use fluvio_auth::x509::X509Identity;
 
-    use super::policy::*;
+    use fluvio_auth::basic::*;
     use super::ObjectType;
 
     #[test]
@@ -201,11 +103,20 @@ mod test {
 
         let mut default_role = HashMap::new();
 
-        default_role.insert(ObjectType::Topic, vec![Action::All]);
-        default_role.insert(ObjectType::Partition, vec![Action::All]);
-        default_role.insert(ObjectType::SpuGroup, vec![Action::Read]);
-        default_role.insert(ObjectType::CustomSpu, vec![Action::Read]);
-        default_role.insert(ObjectType::Spu, vec![Action::Read]);
+        default_role.insert(ObjectType::Topic, vec![Action::All.into()]);
+        default_role.insert(ObjectType::Partition, vec![Action::All.into()]);
+        default_role.insert(ObjectType::SpuGroup, vec![Action::Read.into()]);
+        default_role.insert(ObjectType::CustomSpu, vec![Action::Read.into()]);
+        default_role.insert(
+            ObjectType::Spu,
+            vec![
+                Action::Read.into(),
+                Permission::Resource {
+                    action: Action::Update,
+                    resource: ResourcePattern::Glob("spu-?".to_owned()),
+                },
+            ],
+        );
 
         policy.0.insert(String::from("Default"), default_role);
 
@@ -228,7 +139,10 @@ mod test {
         let identity = X509Identity::new("User".to_owned(), vec!["Default".to_owned()]);
 
         let mut role1 = HashMap::new();
-        role1.insert(ObjectType::Topic, vec![Action::Delete, Action::Read]);
+        role1.insert(
+            ObjectType::Topic,
+            vec![Action::Delete.into(), Action::Read.into()],
+        );
 
         policy.0.insert(String::from("Default"), role1);
 
@@ -249,4 +163,81 @@ mod test {
             .await
             .expect("eval"));
     }
+
+    #[fluvio_future::test]
+    async fn test_policy_enforcement_resource() {
+        let identity = X509Identity::new("User".to_owned(), vec!["TeamA".to_owned()]);
+
+        let mut team_a = HashMap::new();
+        team_a.insert(
+            ObjectType::Topic,
+            vec![
+                Action::Read.into(),
+                Permission::Resource {
+                    action: Action::Delete,
+                    resource: ResourcePattern::Prefix("team-a-".to_owned()),
+                },
+                Permission::Resource {
+                    action: Action::All,
+                    resource: ResourcePattern::Literal("shared".to_owned()),
+                },
+            ],
+        );
+        let mut policy = BasicRbacPolicy::default();
+        policy.0.insert(String::from("TeamA"), team_a);
+
+        assert!(policy
+            .evaluate(
+                Action::Delete,
+                ObjectType::Topic,
+                Some("team-a-orders"),
+                &identity
+            )
+            .await
+            .expect("eval"));
+        assert!(!policy
+            .evaluate(
+                Action::Delete,
+                ObjectType::Topic,
+                Some("team-b-orders"),
+                &identity
+            )
+            .await
+            .expect("eval"));
+        assert!(policy
+            .evaluate(
+                Action::Read,
+                ObjectType::Topic,
+                Some("team-b-orders"),
+                &identity
+            )
+            .await
+            .expect("eval"));
+        assert!(policy
+            .evaluate(Action::Create, ObjectType::Topic, Some("shared"), &identity)
+            .await
+            .expect("eval"));
+        assert!(!policy
+            .evaluate(
+                Action::Create,
+                ObjectType::Topic,
+                Some("shared-2"),
+                &identity
+            )
+            .await
+            .expect("eval"));
+        // type level action is allowed when some instance may be allowed
+        assert!(policy
+            .evaluate(Action::Delete, ObjectType::Topic, None, &identity)
+            .await
+            .expect("eval"));
+        assert!(!policy
+            .evaluate(Action::Delete, ObjectType::Partition, None, &identity)
+            .await
+            .expect("eval"));
+
+        let rules = policy.rules(&["TeamA".to_owned(), "Unknown".to_owned()]);
+        assert_eq!(rules.len(), 3);
+        assert!(rules.iter().all(|rule| rule.role == "TeamA"));
+    }
 }
@@ -10,10 +10,17 @@ mod common {
     use async_trait::async_trait;
 
     use fluvio_auth::{AuthContext, Authorization, TypeAction, InstanceAction, AuthError};
+    use fluvio_auth::basic::Action;
     use fluvio_socket::FluvioSocket;
     use fluvio_controlplane_metadata::extended::ObjectType;
+    use fluvio_controlplane_metadata::core::Spec;
+    use fluvio_sc_schema::objects::Metadata;
+    use fluvio_sc_schema::Status;
+    use dataplane::ErrorCode;
+    use dataplane::core::{Encoder, Decoder};
 
     use crate::core::SharedContext;
+    use crate::audit::{AuditLog, AuditEvent, AuditEventKind};
 
     /// SC global context with authorization
     /// auth is trait object which contains global auth auth policy
@@ -76,6 +83,70 @@ mod common {
         }
     }
 
+    /// Auth context which records authorization decisions to audit log.
+    /// Allowed reads are not recorded, they are issued for every object listed or watched.
+    #[derive(Debug)]
+    pub struct AuditAuthContext<AC> {
+        inner: AC,
+        audit: AuditLog,
+    }
+
+    impl<AC> AuditAuthContext<AC> {
+        pub fn new(inner: AC, audit: AuditLog) -> Self {
+            Self { inner, audit }
+        }
+    }
+
+    impl<AC: AuthContext> AuditAuthContext<AC> {
+        fn record(&self, action: Action, ty: &ObjectType, name: Option<&str>, allowed: bool) {
+            if allowed && action == Action::Read {
+                return;
+            }
+            self.audit.record(AuditEvent::new(
+                AuditEventKind::Authorization,
+                self.inner.principal(),
+                action,
+                ty,
+                name,
+                allowed,
+            ));
+        }
+    }
+
+    #[async_trait]
+    impl<AC: AuthContext + Send + Sync> AuthContext for AuditAuthContext<AC> {
+        async fn allow_type_action(
+            &self,
+            ty: ObjectType,
+            action: TypeAction,
+        ) -> Result<bool, AuthError> {
+            let allowed = self
+                .inner
+                .allow_type_action(ty.clone(), action.clone())
+                .await?;
+            self.record(action.into(), &ty, None, allowed);
+            Ok(allowed)
+        }
+
+        async fn allow_instance_action(
+            &self,
+            ty: ObjectType,
+            action: InstanceAction,
+            key: &str,
+        ) -> Result<bool, AuthError> {
+            let allowed = self
+                .inner
+                .allow_instance_action(ty.clone(), action.clone(), key)
+                .await?;
+            self.record(action.into(), &ty, Some(key), allowed);
+            Ok(allowed)
+        }
+
+        fn principal(&self) -> Option<&str> {
+            self.inner.principal()
+        }
+    }
+
     /// Auth Service Context, this hold individual context that is enough enforce auth
     /// for this service context
     #[derive(Debug, Clone)]
@@ -89,4 +160,52 @@ mod common {
             Self { global_ctx, auth }
         }
     }
+
+    impl<AC: AuthContext> AuthServiceContext<AC> {
+        /// record outcome of admin request
+        pub fn audit_request(&self, action: Action, ty: ObjectType, status: &Status) {
+            let event = AuditEvent::new(
+                AuditEventKind::Request,
+                self.auth.principal(),
+                action,
+                &ty,
+                Some(&status.name),
+                status.error_code != ErrorCode::PermissionDenied,
+            );
+            let error = if status.is_error() {
+                Some(
+                    status
+                        .error_message
+                        .clone()
+                        .unwrap_or_else(|| status.error_code.to_sentence()),
+                )
+            } else {
+                None
+            };
+            self.global_ctx.audit().record(event.with_error(error));
+        }
+
+        /// keep only objects which can be read
+        pub async fn readable<S>(
+            &self,
+            ty: ObjectType,
+            objects: Vec<Metadata<S>>,
+        ) -> Result<Vec<Metadata<S>>, AuthError>
+        where
+            S: Spec + Debug + Encoder + Decoder + Default,
+            S::Status: Debug + Encoder + Decoder + Default,
+        {
+            let mut readable = Vec::with_capacity(objects.len());
+            for object in objects {
+                if self
+                    .auth
+                    .allow_instance_action(ty.clone(), InstanceAction::Read, &object.name)
+                    .await?
+                {
+                    readable.push(object);
+                }
+            }
+            Ok(readable)
+        }
+    }
 }
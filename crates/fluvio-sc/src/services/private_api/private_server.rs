@@ -6,19 +6,21 @@ use std::io::Error as IoError;
 use std::io::ErrorKind;
 use std::time::Duration;
 
-use tracing::error;
+use tracing::{error, warn};
 use tracing::{debug, info, trace, instrument};
 use async_trait::async_trait;
 use futures_util::stream::Stream;
 
 use fluvio_types::SpuId;
+use fluvio_auth::x509::X509Identity;
 use dataplane::api::RequestMessage;
 use fluvio_controlplane_metadata::spu::store::SpuLocalStorePolicy;
 use fluvio_service::{FluvioService, wait_for_request};
 use fluvio_socket::{FluvioSocket, SocketError, FluvioSink};
 use fluvio_controlplane::{
     InternalScRequest, InternalScKey, RegisterSpuResponse, UpdateLrsRequest, UpdateReplicaRequest,
-    UpdateSpuRequest, ReplicaRemovedRequest,
+    UpdateSpuRequest, ReplicaRemovedRequest, UpdateAuthPolicyRequest, UpdateQuotaRequest, Quota,
+    UpdateSchemaRequest, SchemaSubject, WriteTxnMarkersRequest,
 };
 use fluvio_controlplane_metadata::message::{ReplicaMsg, Message, SpuMsg};
 
@@ -26,6 +28,8 @@ use crate::core::SharedContext;
 use crate::stores::{K8ChangeListener};
 use crate::stores::partition::{PartitionSpec, PartitionStatus, PartitionResolution};
 use crate::stores::spu::SpuSpec;
+use crate::stores::quota::QuotaSpec;
+use crate::stores::schema::SchemaSpec;
 use crate::stores::actions::WSAction;
 
 const HEALTH_DURATION: u64 = 90;
@@ -48,9 +52,16 @@ impl FluvioService for ScInternalService {
     async fn respond(
         self: Arc<Self>,
         context: SharedContext,
-        socket: FluvioSocket,
+        mut socket: FluvioSocket,
         _connection: ConnectInfo,
     ) -> Result<(), SocketError> {
+        // with private tls, identity of SPU certificate is forwarded by proxy
+        let identity = if context.config().private_tls_endpoint.is_some() {
+            Some(X509Identity::create_from_connection(&mut socket).await?)
+        } else {
+            None
+        };
+
         let (mut sink, mut stream) = socket.split();
         let mut api_stream = stream.api_stream::<InternalScRequest, InternalScKey>();
 
@@ -61,12 +72,20 @@ impl FluvioService for ScInternalService {
                 let mut status = true;
                 debug!(spu_id,"registration req");
 
-                let register_res = if context.spus().store().validate_spu_for_registered(spu_id).await {
-                    RegisterSpuResponse::ok()
-                } else {
-                    status = false;
-                    debug!(spu_id,"spu validation failed");
-                    RegisterSpuResponse::failed_registeration()
+                let register_res = match &identity {
+                    Some(identity) if !identity.is_spu(spu_id) => {
+                        status = false;
+                        warn!(spu_id, principal = %identity.principal, "spu certificate doesn't match spu id");
+                        RegisterSpuResponse::failed_registeration()
+                    }
+                    _ if context.spus().store().validate_spu_for_registered(spu_id).await => {
+                        RegisterSpuResponse::ok()
+                    }
+                    _ => {
+                        status = false;
+                        debug!(spu_id,"spu validation failed");
+                        RegisterSpuResponse::failed_registeration()
+                    }
                 };
 
                 let response = req_msg.new_response(register_res);
@@ -108,8 +127,12 @@ async fn dispatch_loop(
 ) -> Result<(), SocketError> {
     let mut spu_spec_listener = context.spus().change_listener();
     let mut partition_spec_listener = context.partitions().change_listener();
+    let mut quota_listener = context.quotas().change_listener();
+    let mut schema_listener = context.schemas().change_listener();
+    let mut txn_listener = context.transactions().change_listener();
 
     // send initial changes
+    send_auth_policy(&context, &mut sink, spu_id).await?;
 
     let mut health_check_timer = sleep(Duration::from_secs(HEALTH_DURATION));
 
@@ -119,6 +142,8 @@ async fn dispatch_loop(
 
         send_spu_spec_changes(&mut spu_spec_listener, &mut sink, spu_id).await?;
         send_replica_spec_changes(&mut partition_spec_listener, &mut sink, spu_id).await?;
+        send_quota_changes(&context, &mut quota_listener, &mut sink, spu_id).await?;
+        send_schema_changes(&context, &mut schema_listener, &mut sink, spu_id).await?;
 
         trace!(spu_id, "waiting for SPU channel");
 
@@ -145,6 +170,10 @@ async fn dispatch_loop(
                             },
                             InternalScRequest::ReplicaRemovedRequest(msg) => {
                                 receive_replica_remove(&context,msg.request).await;
+                            },
+                            InternalScRequest::TxnMarkersWrittenRequest(msg) => {
+                                debug!(spu_id, "transaction markers written");
+                                context.transactions().markers_written(&msg.request.markers);
                             }
                         }
                         // reset timer
@@ -170,6 +199,19 @@ async fn dispatch_loop(
             _ = partition_spec_listener.listen() => {
                 debug!("partition lister changed");
 
+            },
+
+            _ = quota_listener.listen() => {
+                debug!("quota lister changed");
+            },
+
+            _ = schema_listener.listen() => {
+                debug!("schema lister changed");
+            },
+
+            _ = txn_listener.listen() => {
+                debug!("transaction markers changed");
+                send_txn_markers(&context, &mut sink, spu_id).await?;
             }
 
         }
@@ -250,6 +292,127 @@ async fn receive_replica_remove(ctx: &SharedContext, request: ReplicaRemovedRequ
     }
 }
 
+/// send authorization policy, SPU uses it to authorize produce and consume
+#[instrument(skip(context, sink))]
+async fn send_auth_policy(
+    context: &SharedContext,
+    sink: &mut FluvioSink,
+    spu_id: SpuId,
+) -> Result<(), SocketError> {
+    let policy = match context.auth_policy() {
+        Some(policy) => Some(
+            serde_json::to_string(policy)
+                .map_err(|err| IoError::new(ErrorKind::InvalidData, err))?,
+        ),
+        None => None,
+    };
+
+    let mut message = RequestMessage::new_request(UpdateAuthPolicyRequest::new(policy));
+    message.get_mut_header().set_client_id("sc");
+
+    debug!(
+        spu_id,
+        policy = message.request.policy.is_some(),
+        "sending auth policy to spu",
+    );
+    sink.send_request(&message).await?;
+    Ok(())
+}
+
+/// send all quotas if any quota has changed, SPU replaces its quotas
+#[instrument(skip(context, listener, sink))]
+async fn send_quota_changes(
+    context: &SharedContext,
+    listener: &mut K8ChangeListener<QuotaSpec>,
+    sink: &mut FluvioSink,
+    spu_id: SpuId,
+) -> Result<(), SocketError> {
+    if !listener.has_change() {
+        return Ok(());
+    }
+
+    // changes are only used to advance listener
+    let _ = listener.sync_spec_changes().await;
+
+    let quotas: Vec<Quota> = context
+        .quotas()
+        .store()
+        .read()
+        .await
+        .values()
+        .map(|quota| Quota::new(quota.key().clone(), quota.spec.clone()))
+        .collect();
+
+    let mut message = RequestMessage::new_request(UpdateQuotaRequest::new(quotas));
+    message.get_mut_header().set_client_id("sc");
+
+    debug!(
+        spu_id,
+        quotas = message.request.quotas.len(),
+        "sending quotas to spu"
+    );
+    sink.send_request(&message).await?;
+    Ok(())
+}
+
+/// send subjects with validation if any subject has changed, SPU replaces its subjects
+#[instrument(skip(context, listener, sink))]
+async fn send_schema_changes(
+    context: &SharedContext,
+    listener: &mut K8ChangeListener<SchemaSpec>,
+    sink: &mut FluvioSink,
+    spu_id: SpuId,
+) -> Result<(), SocketError> {
+    if !listener.has_change() {
+        return Ok(());
+    }
+
+    // changes are only used to advance listener
+    let _ = listener.sync_spec_changes().await;
+
+    let subjects: Vec<SchemaSubject> = context
+        .schemas()
+        .store()
+        .read()
+        .await
+        .values()
+        .filter(|subject| subject.spec.validate)
+        .map(|subject| SchemaSubject::new(subject.key().clone(), subject.spec.clone()))
+        .collect();
+
+    let mut message = RequestMessage::new_request(UpdateSchemaRequest::new(subjects));
+    message.get_mut_header().set_client_id("sc");
+
+    debug!(
+        spu_id,
+        subjects = message.request.subjects.len(),
+        "sending schema subjects to spu"
+    );
+    sink.send_request(&message).await?;
+    Ok(())
+}
+
+/// send markers of prepared transactions, SPU writes markers to replicas it leads
+#[instrument(skip(context, sink))]
+async fn send_txn_markers(
+    context: &SharedContext,
+    sink: &mut FluvioSink,
+    spu_id: SpuId,
+) -> Result<(), SocketError> {
+    let markers = context.transactions().markers();
+
+    let mut message = RequestMessage::new_request(WriteTxnMarkersRequest::new(markers));
+    message.get_mut_header().set_client_id("sc");
+
+    debug!(
+        spu_id,
+        markers = message.request.markers.len(),
+        "sending transaction markers to spu"
+    );
+    sink.send_request(&message).await?;
+    Ok(())
+}
+
 /// send spu spec changes only
 #[instrument(skip(sink))]
 async fn send_spu_spec_changes(
@@ -0,0 +1,151 @@
+//!
+//! # Describe ACL Request
+//!
+//! List policy rules of principal and test action against policy.
+//!
+use std::io::{Error, ErrorKind};
+use std::str::FromStr;
+
+use tracing::{debug, trace, instrument};
+
+use dataplane::ErrorCode;
+use dataplane::api::{RequestMessage, ResponseMessage};
+use fluvio_sc_schema::Status;
+use fluvio_sc_schema::acl::{AclCheck, AclRule, DescribeAclRequest, DescribeAclResponse};
+use fluvio_sc_schema::spu::SpuSpec;
+use fluvio_controlplane_metadata::extended::{ObjectType, SpecExt};
+use fluvio_auth::{AuthContext, TypeAction};
+use fluvio_auth::basic::{Action, BasicRbacPolicy};
+use fluvio_auth::x509::X509Identity;
+
+use crate::services::auth::AuthServiceContext;
+
+/// Handler for describe acl request.
+/// Principal must be able to read SPUs to describe its own rules,
+/// describing other principals requires admin (create SPU) permission
+#[instrument(skip(request, auth_ctx))]
+pub async fn handle_describe_acl_request<AC: AuthContext>(
+    request: RequestMessage<DescribeAclRequest>,
+    auth_ctx: &AuthServiceContext<AC>,
+) -> Result<ResponseMessage<DescribeAclResponse>, Error> {
+    let (header, req) = request.get_header_request();
+
+    debug!(principal = %req.principal, "api request: describe acl");
+
+    if let Ok(authorized) = auth_ctx
+        .auth
+        .allow_type_action(
+            SpuSpec::OBJECT_TYPE,
+            required_action(auth_ctx.auth.principal(), &req.principal),
+        )
+        .await
+    {
+        if !authorized {
+            trace!("authorization failed");
+            return Ok(ResponseMessage::from_header(
+                &header,
+                DescribeAclResponse {
+                    status: Status::new(
+                        req.principal,
+                        ErrorCode::PermissionDenied,
+                        Some(String::from("permission denied")),
+                    ),
+                    ..Default::default()
+                },
+            ));
+        }
+    } else {
+        return Err(Error::new(ErrorKind::Interrupted, "authorization io error"));
+    }
+
+    let response = match auth_ctx.global_ctx.auth_policy() {
+        Some(policy) => describe_acl(policy, req).await,
+        None => DescribeAclResponse {
+            status: Status::new(
+                req.principal,
+                ErrorCode::UnknownServerError,
+                Some("authorization policy is not enabled".to_owned()),
+            ),
+            ..Default::default()
+        },
+    };
+
+    trace!("describe acl resp {:#?}", response);
+
+    Ok(ResponseMessage::from_header(&header, response))
+}
+
+/// action needed on SPUs to describe rules of `principal`
+fn required_action(caller: Option<&str>, principal: &str) -> TypeAction {
+    match caller {
+        Some(caller) if caller != principal => TypeAction::Create,
+        _ => TypeAction::Read,
+    }
+}
+
+async fn describe_acl(policy: &BasicRbacPolicy, req: DescribeAclRequest) -> DescribeAclResponse {
+    let rules = policy
+        .rules(&req.scopes)
+        .into_iter()
+        .map(|rule| AclRule {
+            role: rule.role,
+            object: format!("{:?}", rule.object_type),
+            action: rule.permission.action().to_string(),
+            resource: rule
+                .permission
+                .resource()
+                .map(|resource| resource.to_string()),
+        })
+        .collect();
+
+    let identity = X509Identity::new(req.principal.clone(), req.scopes);
+
+    let allowed = match req.check {
+        Some(check) => match evaluate_check(policy, &identity, check).await {
+            Ok(allowed) => Some(allowed),
+            Err(err) => {
+                return DescribeAclResponse {
+                    status: Status::new(req.principal, ErrorCode::UnknownServerError, Some(err)),
+                    rules,
+                    allowed: None,
+                }
+            }
+        },
+        None => None,
+    };
+
+    DescribeAclResponse {
+        status: Status::new_ok(req.principal),
+        rules,
+        allowed,
+    }
+}
+
+async fn evaluate_check(
+    policy: &BasicRbacPolicy,
+    identity: &X509Identity,
+    check: AclCheck,
+) -> Result<bool, String> {
+    let object_type = ObjectType::from_str(&check.object)?;
+    let action = Action::from_str(&check.action)?;
+
+    policy
+        .evaluate(action, object_type, check.resource.as_deref(), identity)
+        .await
+        .map_err(|err| err.to_string())
+}
+
+#[cfg(test)]
+mod test {
+
+    use fluvio_auth::TypeAction;
+
+    use super::required_action;
+
+    #[test]
+    fn test_describe_other_principal_requires_admin() {
+        assert_eq!(required_action(Some("alice"), "alice"), TypeAction::Read);
+        assert_eq!(required_action(None, "alice"), TypeAction::Read);
+        assert_eq!(required_action(Some("bob"), "alice"), TypeAction::Create);
+    }
+}
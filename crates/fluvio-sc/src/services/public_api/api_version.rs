@@ -5,6 +5,11 @@ use dataplane::api::{RequestMessage, ResponseMessage, Request};
 use dataplane::versions::{ApiVersionKey, ApiVersionsRequest, ApiVersionsResponse, PlatformVersion};
 use fluvio_sc_schema::objects::*;
 use fluvio_sc_schema::AdminPublicApiKey;
+use fluvio_sc_schema::partition::ElectLeaderRequest;
+use fluvio_sc_schema::spu::DrainSpuRequest;
+use fluvio_sc_schema::acl::DescribeAclRequest;
+use fluvio_sc_schema::schema::RegisterSchemaRequest;
+use fluvio_sc_schema::transaction::{AddPartitionsToTxnRequest, EndTxnRequest, InitProducerIdRequest};
 
 #[instrument(skip(request))]
 pub async fn handle_api_versions_request(
@@ -37,6 +42,41 @@ pub async fn handle_api_versions_request(
         WatchRequest::DEFAULT_API_VERSION,
         WatchRequest::DEFAULT_API_VERSION,
     ));
+    response.api_keys.push(make_version_key(
+        AdminPublicApiKey::ElectLeader,
+        ElectLeaderRequest::DEFAULT_API_VERSION,
+        ElectLeaderRequest::DEFAULT_API_VERSION,
+    ));
+    response.api_keys.push(make_version_key(
+        AdminPublicApiKey::DrainSpu,
+        DrainSpuRequest::DEFAULT_API_VERSION,
+        DrainSpuRequest::DEFAULT_API_VERSION,
+    ));
+    response.api_keys.push(make_version_key(
+        AdminPublicApiKey::DescribeAcl,
+        DescribeAclRequest::DEFAULT_API_VERSION,
+        DescribeAclRequest::DEFAULT_API_VERSION,
+    ));
+    response.api_keys.push(make_version_key(
+        AdminPublicApiKey::RegisterSchema,
+        RegisterSchemaRequest::DEFAULT_API_VERSION,
+        RegisterSchemaRequest::DEFAULT_API_VERSION,
+    ));
+    response.api_keys.push(make_version_key(
+        AdminPublicApiKey::InitProducerId,
+        InitProducerIdRequest::DEFAULT_API_VERSION,
+        InitProducerIdRequest::DEFAULT_API_VERSION,
+    ));
+    response.api_keys.push(make_version_key(
+        AdminPublicApiKey::AddPartitionsToTxn,
+        AddPartitionsToTxnRequest::DEFAULT_API_VERSION,
+        AddPartitionsToTxnRequest::DEFAULT_API_VERSION,
+    ));
+    response.api_keys.push(make_version_key(
+        AdminPublicApiKey::EndTxn,
+        EndTxnRequest::DEFAULT_API_VERSION,
+        EndTxnRequest::DEFAULT_API_VERSION,
+    ));
 
     trace!("flv api versions response: {:#?}", response);
 
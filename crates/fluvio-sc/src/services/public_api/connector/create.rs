@@ -12,7 +12,7 @@ use dataplane::ErrorCode;
 use fluvio_sc_schema::Status;
 use fluvio_controlplane_metadata::connector::ManagedConnectorSpec;
 use fluvio_controlplane_metadata::extended::SpecExt;
-use fluvio_auth::{AuthContext, TypeAction};
+use fluvio_auth::{AuthContext, InstanceAction};
 
 use crate::core::Context;
 use crate::services::auth::AuthServiceContext;
@@ -29,7 +29,11 @@ pub async fn handle_create_managed_connector_request<AC: AuthContext>(
 
     if let Ok(authorized) = auth_ctx
         .auth
-        .allow_type_action(ManagedConnectorSpec::OBJECT_TYPE, TypeAction::Create)
+        .allow_instance_action(
+            ManagedConnectorSpec::OBJECT_TYPE,
+            InstanceAction::Create,
+            &name,
+        )
         .await
     {
         if !authorized {
@@ -47,6 +47,11 @@ pub async fn handle_fetch_request<AC: AuthContext>(
         })
         .collect();
 
+    let connectors = auth_ctx
+        .readable(ManagedConnectorSpec::OBJECT_TYPE, connectors)
+        .await
+        .map_err(|_| Error::new(ErrorKind::Interrupted, "authorization io error"))?;
+
     debug!("flv fetch connectors resp: {} items", connectors.len());
     trace!("flv fetch connectors resp {:#?}", connectors);
 
@@ -6,6 +6,8 @@ use dataplane::api::{RequestMessage, ResponseMessage};
 use fluvio_sc_schema::Status;
 use fluvio_sc_schema::objects::{CreateRequest, AllCreatableSpec};
 use fluvio_auth::AuthContext;
+use fluvio_auth::basic::Action;
+use fluvio_controlplane_metadata::extended::ObjectType;
 
 use crate::services::auth::AuthServiceContext;
 
@@ -21,32 +23,44 @@ pub async fn handle_create_request<AC: AuthContext>(
     let name = req.name;
     tracing::debug!("Handling create request for {:#?}", req.spec);
 
-    let status = match req.spec {
-        AllCreatableSpec::Topic(topic) => {
-            super::topic::handle_create_topics_request(name, dry_run, topic, auth_context).await?
-        }
-        AllCreatableSpec::SpuGroup(group) => {
-            super::spg::handle_create_spu_group_request(name, group, dry_run, auth_context).await?
-        }
-        AllCreatableSpec::CustomSpu(custom) => {
+    let (ty, status) = match req.spec {
+        AllCreatableSpec::Topic(topic) => (
+            ObjectType::Topic,
+            super::topic::handle_create_topics_request(name, dry_run, topic, auth_context).await?,
+        ),
+        AllCreatableSpec::SpuGroup(group) => (
+            ObjectType::SpuGroup,
+            super::spg::handle_create_spu_group_request(name, group, dry_run, auth_context).await?,
+        ),
+        AllCreatableSpec::CustomSpu(custom) => (
+            ObjectType::CustomSpu,
             super::spu::RegisterCustomSpu::handle_register_custom_spu_request(
                 name,
                 custom,
                 dry_run,
                 auth_context,
             )
-            .await
-        }
-        AllCreatableSpec::ManagedConnector(spec) => {
+            .await,
+        ),
+        AllCreatableSpec::ManagedConnector(spec) => (
+            ObjectType::ManagedConnector,
             super::connector::handle_create_managed_connector_request(
                 name,
                 spec,
                 dry_run,
                 auth_context,
             )
-            .await?
-        }
+            .await?,
+        ),
+        AllCreatableSpec::Quota(spec) => (
+            ObjectType::Quota,
+            super::quota::handle_create_quota_request(name, spec, dry_run, auth_context).await?,
+        ),
     };
 
+    if !dry_run {
+        auth_context.audit_request(Action::Create, ty, &status);
+    }
+
     Ok(ResponseMessage::from_header(&header, status))
 }
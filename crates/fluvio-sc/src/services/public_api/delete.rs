@@ -11,6 +11,8 @@ use dataplane::api::{RequestMessage, ResponseMessage};
 use fluvio_sc_schema::Status;
 use fluvio_sc_schema::objects::{DeleteRequest};
 use fluvio_auth::{AuthContext};
+use fluvio_auth::basic::Action;
+use fluvio_controlplane_metadata::extended::ObjectType;
 
 use crate::services::auth::AuthServiceContext;
 
@@ -22,19 +24,31 @@ pub async fn handle_delete_request<AC: AuthContext>(
 ) -> Result<ResponseMessage<Status>, Error> {
     let (header, req) = request.get_header_request();
 
-    let status = match req {
-        DeleteRequest::Topic(name) => super::topic::handle_delete_topic(name, auth_ctx).await?,
-        DeleteRequest::CustomSpu(key) => {
-            super::spu::handle_un_register_custom_spu_request(key, auth_ctx).await?
-        }
-        DeleteRequest::SpuGroup(name) => {
-            super::spg::handle_delete_spu_group(name, auth_ctx).await?
-        }
-        DeleteRequest::ManagedConnector(name) => {
-            super::connector::handle_delete_managed_connector(name, auth_ctx).await?
-        }
+    let (ty, status) = match req {
+        DeleteRequest::Topic(name) => (
+            ObjectType::Topic,
+            super::topic::handle_delete_topic(name, auth_ctx).await?,
+        ),
+        DeleteRequest::CustomSpu(key) => (
+            ObjectType::CustomSpu,
+            super::spu::handle_un_register_custom_spu_request(key, auth_ctx).await?,
+        ),
+        DeleteRequest::SpuGroup(name) => (
+            ObjectType::SpuGroup,
+            super::spg::handle_delete_spu_group(name, auth_ctx).await?,
+        ),
+        DeleteRequest::ManagedConnector(name) => (
+            ObjectType::ManagedConnector,
+            super::connector::handle_delete_managed_connector(name, auth_ctx).await?,
+        ),
+        DeleteRequest::Quota(name) => (
+            ObjectType::Quota,
+            super::quota::handle_delete_quota(name, auth_ctx).await?,
+        ),
     };
 
+    auth_ctx.audit_request(Action::Delete, ty, &status);
+
     trace!("flv delete topics resp {:#?}", status);
 
     Ok(ResponseMessage::from_header(&header, status))
@@ -1,9 +1,13 @@
 mod public_server;
 mod spg;
 mod connector;
+mod quota;
+mod schema;
 mod spu;
 mod topic;
 mod partition;
+mod acl;
+mod transaction;
 mod api_version;
 mod create;
 mod delete;
@@ -32,8 +36,10 @@ mod server {
         <A as Authorization>::Context: Send + Sync,
     {
         let addr = ctx.global_ctx.config().public_endpoint.clone();
+        let max_frame_size = ctx.global_ctx.config().max_frame_size;
         debug!("starting public api service");
-        let server = FluvioApiServer::new(addr, ctx, PublicService::new());
+        let server = FluvioApiServer::new(addr, ctx, PublicService::new())
+            .with_max_frame_size(max_frame_size);
         server.run();
     }
 }
@@ -0,0 +1,244 @@
+//!
+//! # Elect Leader Request
+//!
+//! Move leadership of partition to the requested replica or to the preferred replica.
+//! Only online replicas which have caught up with the current leader can be elected.
+//!
+use std::collections::HashSet;
+use std::io::{Error, ErrorKind};
+
+use tracing::{debug, trace, info, instrument};
+
+use dataplane::ErrorCode;
+use dataplane::ReplicaKey;
+use dataplane::api::{RequestMessage, ResponseMessage};
+use fluvio_sc_schema::Status;
+use fluvio_sc_schema::partition::{ElectLeaderRequest, PartitionSpec, PartitionStatus};
+use fluvio_controlplane_metadata::extended::SpecExt;
+use fluvio_controlplane_metadata::spu::store::SpuLocalStorePolicy;
+use fluvio_auth::{AuthContext, InstanceAction};
+use fluvio_types::SpuId;
+
+use crate::services::auth::AuthServiceContext;
+
+/// Handler for elect leader request
+#[instrument(skip(request, auth_ctx))]
+pub async fn handle_elect_leader_request<AC: AuthContext>(
+    request: RequestMessage<ElectLeaderRequest>,
+    auth_ctx: &AuthServiceContext<AC>,
+) -> Result<ResponseMessage<Status>, Error> {
+    let (header, req) = request.get_header_request();
+
+    let replica_key = ReplicaKey::new(req.topic, req.partition);
+    let name = replica_key.to_string();
+    debug!("api request: elect leader for partition '{}'", name);
+
+    if let Ok(authorized) = auth_ctx
+        .auth
+        .allow_instance_action(PartitionSpec::OBJECT_TYPE, InstanceAction::Update, &name)
+        .await
+    {
+        if !authorized {
+            trace!("authorization failed");
+            return Ok(ResponseMessage::from_header(
+                &header,
+                Status::new(
+                    name,
+                    ErrorCode::PermissionDenied,
+                    Some(String::from("permission denied")),
+                ),
+            ));
+        }
+    } else {
+        return Err(Error::new(ErrorKind::Interrupted, "authorization io error"));
+    }
+
+    let status = elect_leader(replica_key, req.leader, auth_ctx).await;
+
+    trace!("elect leader resp {:#?}", status);
+
+    Ok(ResponseMessage::from_header(&header, status))
+}
+
+async fn elect_leader<AC: AuthContext>(
+    replica_key: ReplicaKey,
+    leader: Option<i32>,
+    auth_ctx: &AuthServiceContext<AC>,
+) -> Status {
+    let name = replica_key.to_string();
+
+    let partition = match auth_ctx
+        .global_ctx
+        .partitions()
+        .store()
+        .value(&replica_key)
+        .await
+    {
+        Some(partition) => partition.inner_owned(),
+        None => {
+            return Status::new(
+                name.clone(),
+                ErrorCode::PartitionNotFound,
+                Some(format!("partition '{}' not found", name)),
+            )
+        }
+    };
+
+    let online_spus = auth_ctx.global_ctx.spus().store().online_status().await;
+    let candidate = match select_candidate(
+        &name,
+        &partition.spec,
+        &partition.status,
+        leader,
+        &online_spus,
+    ) {
+        Ok(Some(candidate)) => candidate,
+        Ok(None) => {
+            debug!("candidate is already leader");
+            return Status::new_ok(name);
+        }
+        Err(status) => return status,
+    };
+
+    let mut spec = partition.spec.clone();
+    spec.leader = candidate;
+
+    info!(
+        partition = %name,
+        old_leader = partition.spec.leader,
+        new_leader = candidate,
+        "electing new leader",
+    );
+
+    if let Err(err) = auth_ctx
+        .global_ctx
+        .partitions()
+        .create_spec(replica_key, spec)
+        .await
+    {
+        Status::new(
+            name,
+            ErrorCode::PartitionElectionFailed,
+            Some(format!("error: {}", err)),
+        )
+    } else {
+        Status::new_ok(name)
+    }
+}
+
+/// validate election and find new leader.
+/// return None if candidate is already leader
+fn select_candidate(
+    name: &str,
+    spec: &PartitionSpec,
+    status: &PartitionStatus,
+    leader: Option<SpuId>,
+    online_spus: &HashSet<SpuId>,
+) -> Result<Option<SpuId>, Status> {
+    let candidate = match leader.or_else(|| spec.preferred_leader()) {
+        Some(candidate) => candidate,
+        None => {
+            return Err(Status::new(
+                name.to_owned(),
+                ErrorCode::PartitionElectionFailed,
+                Some("partition has no replicas".to_owned()),
+            ))
+        }
+    };
+
+    if candidate == spec.leader {
+        return Ok(None);
+    }
+
+    if !spec.has_spu(&candidate) {
+        return Err(Status::new(
+            name.to_owned(),
+            ErrorCode::PartitionElectionFailed,
+            Some(format!("spu {} is not a replica of partition", candidate)),
+        ));
+    }
+
+    if !online_spus.contains(&candidate) {
+        return Err(Status::new(
+            name.to_owned(),
+            ErrorCode::SpuOffline,
+            Some(format!("spu {} is not online", candidate)),
+        ));
+    }
+
+    if !status.is_in_sync(candidate) {
+        return Err(Status::new(
+            name.to_owned(),
+            ErrorCode::PartitionElectionFailed,
+            Some(format!("spu {} is not in sync with leader", candidate)),
+        ));
+    }
+
+    Ok(Some(candidate))
+}
+
+#[cfg(test)]
+mod test {
+
+    use std::collections::HashSet;
+
+    use dataplane::ErrorCode;
+    use fluvio_sc_schema::partition::{PartitionSpec, PartitionStatus};
+
+    use super::select_candidate;
+
+    fn partition() -> (PartitionSpec, PartitionStatus) {
+        let spec = PartitionSpec::new(5001, vec![5000, 5001, 5002]);
+        let status = PartitionStatus::new(
+            (5001, 100, 110),
+            vec![(5000, 100, 110).into(), (5002, 100, 105).into()],
+        );
+        (spec, status)
+    }
+
+    fn online(spus: &[i32]) -> HashSet<i32> {
+        spus.iter().copied().collect()
+    }
+
+    #[test]
+    fn test_elect_preferred_leader() {
+        let (spec, status) = partition();
+        let candidate =
+            select_candidate("t-0", &spec, &status, None, &online(&[5000, 5001])).expect("elected");
+        assert_eq!(candidate, Some(5000));
+    }
+
+    #[test]
+    fn test_elect_current_leader() {
+        let (spec, status) = partition();
+        let candidate =
+            select_candidate("t-0", &spec, &status, Some(5001), &online(&[5001])).expect("elected");
+        assert_eq!(candidate, None);
+    }
+
+    #[test]
+    fn test_elect_rejected() {
+        let (spec, status) = partition();
+
+        // not a replica
+        let err = select_candidate("t-0", &spec, &status, Some(5003), &online(&[5003]))
+            .expect_err("not replica");
+        assert!(matches!(err.error_code, ErrorCode::PartitionElectionFailed));
+
+        // offline
+        let err = select_candidate("t-0", &spec, &status, Some(5000), &online(&[5001]))
+            .expect_err("offline");
+        assert!(matches!(err.error_code, ErrorCode::SpuOffline));
+
+        // behind leader
+        let err = select_candidate("t-0", &spec, &status, Some(5002), &online(&[5001, 5002]))
+            .expect_err("not in sync");
+        assert!(matches!(err.error_code, ErrorCode::PartitionElectionFailed));
+
+        // no replicas
+        let spec = PartitionSpec::new(5001, vec![]);
+        let err =
+            select_candidate("t-0", &spec, &status, None, &online(&[5001])).expect_err("empty");
+        assert!(matches!(err.error_code, ErrorCode::PartitionElectionFailed));
+    }
+}
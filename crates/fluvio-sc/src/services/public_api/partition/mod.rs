@@ -1,3 +1,7 @@
+mod elect;
+
+pub use elect::*;
+
 use std::io::{Error, ErrorKind};
 
 use tracing::{trace, debug, instrument};
@@ -39,6 +43,11 @@ pub async fn handle_fetch_request<AC: AuthContext>(
         .map(|value| value.inner().clone().into())
         .collect();
 
+    let partitions = auth_ctx
+        .readable(PartitionSpec::OBJECT_TYPE, partitions)
+        .await
+        .map_err(|_| Error::new(ErrorKind::Interrupted, "authorization io error"))?;
+
     debug!("flv fetch partitions resp: {} items", partitions.len());
     trace!("flv fetch partitions resp {:#?}", partitions);
 
@@ -26,7 +26,7 @@ use fluvio_service::FluvioService;
 use fluvio_sc_schema::AdminPublicApiKey;
 use fluvio_sc_schema::AdminPublicRequest;
 
-use crate::services::auth::{AuthGlobalContext, AuthServiceContext};
+use crate::services::auth::{AuthGlobalContext, AuthServiceContext, AuditAuthContext};
 
 #[derive(Debug)]
 pub struct PublicService<A> {
@@ -43,7 +43,7 @@ impl<A> PublicService<A> {
 impl<A> FluvioService for PublicService<A>
 where
     A: Authorization + Sync + Send,
-    <A as Authorization>::Context: Send + Sync,
+    <A as Authorization>::Context: Send + Sync + 'static,
 {
     type Context = AuthGlobalContext<A>;
     type Request = AdminPublicRequest;
@@ -65,7 +65,7 @@ where
             })?;
         let service_context = Arc::new(AuthServiceContext::new(
             ctx.global_ctx.clone(),
-            auth_context,
+            AuditAuthContext::new(auth_context, ctx.global_ctx.audit().clone()),
         ));
 
         let (sink, mut stream) = socket.split();
@@ -104,11 +104,53 @@ where
                 shared_sink,
                 "list handler"
             ),
+            AdminPublicRequest::ElectLeaderRequest(request) => call_service!(
+                request,
+                super::partition::handle_elect_leader_request(request, &service_context),
+                shared_sink,
+                "elect leader handler"
+            ),
+            AdminPublicRequest::DrainSpuRequest(request) => call_service!(
+                request,
+                super::spu::handle_drain_spu_request(request, &service_context),
+                shared_sink,
+                "drain spu handler"
+            ),
+            AdminPublicRequest::DescribeAclRequest(request) => call_service!(
+                request,
+                super::acl::handle_describe_acl_request(request, &service_context),
+                shared_sink,
+                "describe acl handler"
+            ),
+            AdminPublicRequest::RegisterSchemaRequest(request) => call_service!(
+                request,
+                super::schema::handle_register_schema_request(request, &service_context),
+                shared_sink,
+                "register schema handler"
+            ),
+            AdminPublicRequest::InitProducerIdRequest(request) => call_service!(
+                request,
+                super::transaction::handle_init_producer_id_request(request, &service_context),
+                shared_sink,
+                "init producer id handler"
+            ),
+            AdminPublicRequest::AddPartitionsToTxnRequest(request) => call_service!(
+                request,
+                super::transaction::handle_add_partitions_request(request, &service_context),
+                shared_sink,
+                "add partitions to transaction handler"
+            ),
+            AdminPublicRequest::EndTxnRequest(request) => call_service!(
+                request,
+                super::transaction::handle_end_txn_request(request, &service_context),
+                shared_sink,
+                "end transaction handler"
+            ),
             AdminPublicRequest::WatchRequest(request) =>
 
                 super::watch::handle_watch_request(
                     request,
-                    &service_context,
+                    service_context.clone(),
                     shared_sink.clone(),
                     end_event.clone(),
                 )
@@ -0,0 +1,117 @@
+//!
+//! # Create Quota Request
+//!
+//! Validates quota and sends it to KV store, SPUs are updated by private api.
+//!
+
+use std::io::{Error, ErrorKind};
+
+use tracing::{debug, trace, instrument};
+
+use dataplane::ErrorCode;
+use fluvio_sc_schema::Status;
+use fluvio_controlplane_metadata::quota::QuotaSpec;
+use fluvio_controlplane_metadata::extended::SpecExt;
+use fluvio_auth::{AuthContext, InstanceAction};
+
+use crate::core::Context;
+use crate::services::auth::AuthServiceContext;
+
+/// Handler for create quota request
+#[instrument(skip(name, spec, dry_run, auth_ctx))]
+pub async fn handle_create_quota_request<AC: AuthContext>(
+    name: String,
+    spec: QuotaSpec,
+    dry_run: bool,
+    auth_ctx: &AuthServiceContext<AC>,
+) -> Result<Status, Error> {
+    debug!("creating quota: {}", name);
+
+    if let Ok(authorized) = auth_ctx
+        .auth
+        .allow_instance_action(QuotaSpec::OBJECT_TYPE, InstanceAction::Create, &name)
+        .await
+    {
+        if !authorized {
+            trace!("authorization failed");
+            return Ok(Status::new(
+                name.clone(),
+                ErrorCode::PermissionDenied,
+                Some(String::from("permission denied")),
+            ));
+        }
+    } else {
+        return Err(Error::new(ErrorKind::Interrupted, "authorization io error"));
+    }
+
+    if let Some(status) = validate_quota(&auth_ctx.global_ctx, &name, &spec).await {
+        return Ok(status);
+    }
+
+    if dry_run {
+        return Ok(Status::new_ok(name));
+    }
+
+    let status = if let Err(err) = auth_ctx
+        .global_ctx
+        .quotas()
+        .create_spec(name.clone(), spec)
+        .await
+    {
+        Status::new(name, ErrorCode::QuotaError, Some(err.to_string()))
+    } else {
+        Status::new_ok(name)
+    };
+    trace!("create quota response {:#?}", status);
+
+    Ok(status)
+}
+
+/// quota must set at least one limit and can't have same principal and client id as other quota
+async fn validate_quota(ctx: &Context, name: &str, spec: &QuotaSpec) -> Option<Status> {
+    let invalid = |error: &str| {
+        Some(Status::new(
+            name.to_owned(),
+            ErrorCode::QuotaError,
+            Some(error.to_owned()),
+        ))
+    };
+
+    if spec.produce_byte_rate.is_none()
+        && spec.fetch_byte_rate.is_none()
+        && spec.request_rate.is_none()
+    {
+        return invalid("quota must set at least one rate");
+    }
+
+    if spec.produce_byte_rate == Some(0)
+        || spec.fetch_byte_rate == Some(0)
+        || spec.request_rate == Some(0)
+    {
+        return invalid("quota rate must be greater than zero");
+    }
+
+    let quotas = ctx.quotas().store().read().await;
+    if quotas.contains_key(name) {
+        return Some(Status::new(
+            name.to_owned(),
+            ErrorCode::QuotaAlreadyExists,
+            Some(format!("quota '{}' already exists", name)),
+        ));
+    }
+
+    if let Some(existing) = quotas.values().find(|quota| {
+        quota.spec.principal == spec.principal && quota.spec.client_id == spec.client_id
+    }) {
+        return Some(Status::new(
+            name.to_owned(),
+            ErrorCode::QuotaAlreadyExists,
+            Some(format!(
+                "quota '{}' has same principal and client id",
+                existing.key()
+            )),
+        ));
+    }
+
+    None
+}
@@ -0,0 +1,58 @@
+use std::io::{Error, ErrorKind};
+
+use tracing::{debug, trace, instrument};
+
+use dataplane::ErrorCode;
+use fluvio_sc_schema::Status;
+use fluvio_auth::{AuthContext, InstanceAction};
+use fluvio_controlplane_metadata::quota::QuotaSpec;
+use fluvio_controlplane_metadata::extended::SpecExt;
+
+use crate::services::auth::AuthServiceContext;
+
+/// Handler for delete quota request
+#[instrument(skip(name, auth_ctx))]
+pub async fn handle_delete_quota<AC: AuthContext>(
+    name: String,
+    auth_ctx: &AuthServiceContext<AC>,
+) -> Result<Status, Error> {
+    debug!("delete quota: {}", name);
+
+    if let Ok(authorized) = auth_ctx
+        .auth
+        .allow_instance_action(QuotaSpec::OBJECT_TYPE, InstanceAction::Delete, &name)
+        .await
+    {
+        if !authorized {
+            trace!("authorization failed");
+            return Ok(Status::new(
+                name.clone(),
+                ErrorCode::PermissionDenied,
+                Some(String::from("permission denied")),
+            ));
+        }
+    } else {
+        return Err(Error::new(ErrorKind::Interrupted, "authorization io error"));
+    }
+
+    let status = if auth_ctx
+        .global_ctx
+        .quotas()
+        .store()
+        .value(&name)
+        .await
+        .is_some()
+    {
+        if let Err(err) = auth_ctx.global_ctx.quotas().delete(name.clone()).await {
+            Status::new(name.clone(), ErrorCode::QuotaError, Some(err.to_string()))
+        } else {
+            Status::new_ok(name)
+        }
+    } else {
+        Status::new(name, ErrorCode::QuotaNotFound, Some("not found".to_owned()))
+    };
+
+    trace!("flv delete quota resp {:#?}", status);
+
+    Ok(status)
+}
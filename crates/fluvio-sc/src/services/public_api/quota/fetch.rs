@@ -0,0 +1,59 @@
+use std::io::{Error, ErrorKind};
+
+use tracing::{debug, trace, instrument};
+
+use fluvio_sc_schema::objects::{ListResponse, NameFilter, Metadata};
+use fluvio_sc_schema::quota::QuotaSpec;
+use fluvio_auth::{AuthContext, TypeAction};
+use fluvio_controlplane_metadata::store::KeyFilter;
+use fluvio_controlplane_metadata::extended::SpecExt;
+
+use crate::services::auth::AuthServiceContext;
+
+#[instrument(skip(filters, auth_ctx))]
+pub async fn handle_fetch_request<AC: AuthContext>(
+    filters: Vec<NameFilter>,
+    auth_ctx: &AuthServiceContext<AC>,
+) -> Result<ListResponse, Error> {
+    trace!("fetching quotas");
+
+    if let Ok(authorized) = auth_ctx
+        .auth
+        .allow_type_action(QuotaSpec::OBJECT_TYPE, TypeAction::Read)
+        .await
+    {
+        if !authorized {
+            debug!("fetch quota authorization failed");
+            // If permission denied, return empty list;
+            return Ok(ListResponse::Quota(vec![]));
+        }
+    } else {
+        return Err(Error::new(ErrorKind::Interrupted, "authorization io error"));
+    }
+
+    let quotas: Vec<Metadata<QuotaSpec>> = auth_ctx
+        .global_ctx
+        .quotas()
+        .store()
+        .read()
+        .await
+        .values()
+        .filter_map(|value| {
+            if filters.filter(value.key()) {
+                Some(value.inner().clone().into())
+            } else {
+                None
+            }
+        })
+        .collect();
+
+    let quotas = auth_ctx
+        .readable(QuotaSpec::OBJECT_TYPE, quotas)
+        .await
+        .map_err(|_| Error::new(ErrorKind::Interrupted, "authorization io error"))?;
+
+    debug!("flv fetch quotas resp: {} items", quotas.len());
+    trace!("flv fetch quotas resp {:#?}", quotas);
+
+    Ok(ListResponse::Quota(quotas))
+}
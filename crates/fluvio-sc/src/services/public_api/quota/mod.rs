@@ -0,0 +1,7 @@
+mod create;
+mod delete;
+mod fetch;
+
+pub use create::*;
+pub use fetch::*;
+pub use delete::*;
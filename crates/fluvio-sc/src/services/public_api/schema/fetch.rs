@@ -0,0 +1,59 @@
+use std::io::{Error, ErrorKind};
+
+use tracing::{debug, trace, instrument};
+
+use fluvio_sc_schema::objects::{ListResponse, NameFilter, Metadata};
+use fluvio_sc_schema::schema::SchemaSpec;
+use fluvio_auth::{AuthContext, TypeAction};
+use fluvio_controlplane_metadata::store::KeyFilter;
+use fluvio_controlplane_metadata::extended::SpecExt;
+
+use crate::services::auth::AuthServiceContext;
+
+#[instrument(skip(filters, auth_ctx))]
+pub async fn handle_fetch_request<AC: AuthContext>(
+    filters: Vec<NameFilter>,
+    auth_ctx: &AuthServiceContext<AC>,
+) -> Result<ListResponse, Error> {
+    trace!("fetching schemas");
+
+    if let Ok(authorized) = auth_ctx
+        .auth
+        .allow_type_action(SchemaSpec::OBJECT_TYPE, TypeAction::Read)
+        .await
+    {
+        if !authorized {
+            debug!("fetch schema authorization failed");
+            // If permission denied, return empty list;
+            return Ok(ListResponse::Schema(vec![]));
+        }
+    } else {
+        return Err(Error::new(ErrorKind::Interrupted, "authorization io error"));
+    }
+
+    let schemas: Vec<Metadata<SchemaSpec>> = auth_ctx
+        .global_ctx
+        .schemas()
+        .store()
+        .read()
+        .await
+        .values()
+        .filter_map(|value| {
+            if filters.filter(value.key()) {
+                Some(value.inner().clone().into())
+            } else {
+                None
+            }
+        })
+        .collect();
+
+    let schemas = auth_ctx
+        .readable(SchemaSpec::OBJECT_TYPE, schemas)
+        .await
+        .map_err(|_| Error::new(ErrorKind::Interrupted, "authorization io error"))?;
+
+    debug!("flv fetch schemas resp: {} items", schemas.len());
+    trace!("flv fetch schemas resp {:#?}", schemas);
+
+    Ok(ListResponse::Schema(schemas))
+}
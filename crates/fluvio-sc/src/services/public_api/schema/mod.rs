@@ -0,0 +1,5 @@
+mod fetch;
+mod register;
+
+pub use fetch::*;
+pub use register::*;
@@ -0,0 +1,153 @@
+//!
+//! # Register Schema Request
+//!
+//! Checks schema against latest version of subject and stores it as new version.
+//! Subjects with validation enabled are sent to SPUs by private api.
+//!
+
+use std::io::{Error, ErrorKind};
+
+use tracing::{debug, trace, instrument};
+
+use dataplane::ErrorCode;
+use dataplane::api::{RequestMessage, ResponseMessage};
+use fluvio_sc_schema::Status;
+use fluvio_sc_schema::schema::{
+    RegisterSchemaRequest, RegisterSchemaResponse, SchemaSpec, SchemaVersion,
+};
+use fluvio_sc_schema::schema::{check_compatibility, validate_definition};
+use fluvio_sc_schema::topic::validate::valid_topic_name;
+use fluvio_controlplane_metadata::extended::SpecExt;
+use fluvio_auth::{AuthContext, InstanceAction};
+
+use crate::core::Context;
+use crate::services::auth::AuthServiceContext;
+
+/// Handler for register schema request
+#[instrument(skip(request, auth_ctx))]
+pub async fn handle_register_schema_request<AC: AuthContext>(
+    request: RequestMessage<RegisterSchemaRequest>,
+    auth_ctx: &AuthServiceContext<AC>,
+) -> Result<ResponseMessage<RegisterSchemaResponse>, Error> {
+    let (header, req) = request.get_header_request();
+
+    debug!(subject = %req.subject, "api request: register schema");
+
+    let action = if auth_ctx
+        .global_ctx
+        .schemas()
+        .store()
+        .contains_key(&req.subject)
+        .await
+    {
+        InstanceAction::Update
+    } else {
+        InstanceAction::Create
+    };
+
+    if let Ok(authorized) = auth_ctx
+        .auth
+        .allow_instance_action(SchemaSpec::OBJECT_TYPE, action, &req.subject)
+        .await
+    {
+        if !authorized {
+            trace!("authorization failed");
+            return Ok(ResponseMessage::from_header(
+                &header,
+                RegisterSchemaResponse {
+                    status: Status::new(
+                        req.subject,
+                        ErrorCode::PermissionDenied,
+                        Some(String::from("permission denied")),
+                    ),
+                    ..Default::default()
+                },
+            ));
+        }
+    } else {
+        return Err(Error::new(ErrorKind::Interrupted, "authorization io error"));
+    }
+
+    let response = register_schema(&auth_ctx.global_ctx, req).await;
+    trace!("register schema response {:#?}", response);
+
+    Ok(ResponseMessage::from_header(&header, response))
+}
+
+async fn register_schema(ctx: &Context, req: RegisterSchemaRequest) -> RegisterSchemaResponse {
+    let failed = |subject: String, code: ErrorCode, error: String| RegisterSchemaResponse {
+        status: Status::new(subject, code, Some(error)),
+        ..Default::default()
+    };
+
+    // subject applies to topic with same name
+    if req.subject.is_empty() || !valid_topic_name(&req.subject) {
+        return failed(
+            req.subject,
+            ErrorCode::SchemaError,
+            "subject must be valid topic name".to_owned(),
+        );
+    }
+
+    if let Err(err) = validate_definition(req.schema_type, &req.definition) {
+        return failed(req.subject, ErrorCode::SchemaError, err);
+    }
+
+    let mut last_id = ctx.schema_ids().lock().await;
+
+    let (mut spec, next_id) = {
+        let schemas = ctx.schemas().store().read().await;
+        let next_id = schemas
+            .values()
+            .flat_map(|subject| subject.spec.versions.iter().map(|schema| schema.id))
+            .chain(std::iter::once(*last_id))
+            .max()
+            .unwrap_or(0)
+            + 1;
+        let spec = schemas
+            .get(&req.subject)
+            .map(|subject| subject.spec.clone())
+            .unwrap_or_default();
+        (spec, next_id)
+    };
+
+    let original = spec.clone();
+    if let Some(compatibility) = req.compatibility {
+        spec.compatibility = compatibility;
+    }
+    if let Some(validate) = req.validate {
+        spec.validate = validate;
+    }
+
+    let (id, version) = match spec.find_definition(req.schema_type, &req.definition) {
+        Some(existing) => (existing.id, existing.version),
+        None => {
+            if let Err(err) =
+                check_compatibility(&spec, spec.compatibility, req.schema_type, &req.definition)
+            {
+                return failed(req.subject, ErrorCode::SchemaIncompatible, err);
+            }
+            let version = spec.latest().map(|latest| latest.version).unwrap_or(0) + 1;
+            spec.versions.push(SchemaVersion {
+                version,
+                id: next_id,
+                schema_type: req.schema_type,
+                definition: req.definition,
+            });
+            *last_id = next_id;
+            (next_id, version)
+        }
+    };
+
+    if spec != original {
+        if let Err(err) = ctx.schemas().create_spec(req.subject.clone(), spec).await {
+            return failed(req.subject, ErrorCode::SchemaError, err.to_string());
+        }
+    }
+
+    RegisterSchemaResponse {
+        status: Status::new_ok(req.subject),
+        id,
+        version,
+    }
+}
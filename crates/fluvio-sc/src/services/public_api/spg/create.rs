@@ -14,7 +14,7 @@ use dataplane::ErrorCode;
 use fluvio_sc_schema::Status;
 use fluvio_controlplane_metadata::spg::SpuGroupSpec;
 use fluvio_controlplane_metadata::extended::SpecExt;
-use fluvio_auth::{AuthContext, TypeAction};
+use fluvio_auth::{AuthContext, InstanceAction};
 
 use crate::core::Context;
 use crate::services::auth::AuthServiceContext;
@@ -31,7 +31,7 @@ pub async fn handle_create_spu_group_request<AC: AuthContext>(
 
     if let Ok(authorized) = auth_ctx
         .auth
-        .allow_type_action(SpuGroupSpec::OBJECT_TYPE, TypeAction::Create)
+        .allow_instance_action(SpuGroupSpec::OBJECT_TYPE, InstanceAction::Create, &name)
         .await
     {
         if !authorized {
@@ -47,6 +47,11 @@ pub async fn handle_fetch_spu_groups_request<AC: AuthContext>(
         })
         .collect();
 
+    let spgs = auth_ctx
+        .readable(SpuGroupSpec::OBJECT_TYPE, spgs)
+        .await
+        .map_err(|_| Error::new(ErrorKind::Interrupted, "authorization io error"))?;
+
     debug!("flv fetch spgs resp: {} items", spgs.len());
     trace!("flv fetch spgs resp {:#?}", spgs);
 
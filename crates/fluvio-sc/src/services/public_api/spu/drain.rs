@@ -0,0 +1,455 @@
+//!
+//! # Drain Spu Request
+//!
+//! Mark SPU as draining and move its replicas and leaderships to other SPUs.
+//! Replicas on drained SPU are removed by partition controller once the new replicas are in sync.
+//! Drain can be repeated, partitions whose replica has already been moved are not planned again.
+//!
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::io::{Error, ErrorKind};
+
+use tracing::{debug, trace, info, instrument};
+
+use dataplane::ErrorCode;
+use dataplane::api::{RequestMessage, ResponseMessage};
+use fluvio_types::{SpuId, ReplicationFactor};
+use fluvio_sc_schema::Status;
+use fluvio_sc_schema::spu::{DrainSpuRequest, DrainSpuResponse, ReplicaMove, SpuSpec};
+use fluvio_controlplane_metadata::extended::SpecExt;
+use fluvio_controlplane_metadata::core::MetadataItem;
+use fluvio_controlplane_metadata::partition::store::PartitionMetadata;
+use fluvio_controlplane_metadata::spu::store::SpuMetadata;
+use fluvio_auth::{AuthContext, InstanceAction};
+
+use crate::stores::partition::{PartitionSpec, ReplicaKey};
+use crate::services::auth::AuthServiceContext;
+
+/// Handler for drain spu request
+#[instrument(skip(request, auth_ctx))]
+pub async fn handle_drain_spu_request<AC: AuthContext>(
+    request: RequestMessage<DrainSpuRequest>,
+    auth_ctx: &AuthServiceContext<AC>,
+) -> Result<ResponseMessage<DrainSpuResponse>, Error> {
+    let (header, req) = request.get_header_request();
+
+    debug!(
+        "api request: drain spu '{}', undrain: {}",
+        req.spu, req.undrain
+    );
+
+    let spu = match auth_ctx.global_ctx.spus().store().get_by_id(req.spu).await {
+        Some(spu) => spu,
+        None => {
+            let name = format!("spu-{}", req.spu);
+            return Ok(ResponseMessage::from_header(
+                &header,
+                DrainSpuResponse {
+                    status: Status::new(name, ErrorCode::SpuNotFound, Some("not found".to_owned())),
+                    moves: vec![],
+                },
+            ));
+        }
+    };
+
+    let spu_name = spu.key_owned();
+
+    if let Ok(authorized) = auth_ctx
+        .auth
+        .allow_instance_action(SpuSpec::OBJECT_TYPE, InstanceAction::Update, &spu_name)
+        .await
+    {
+        if !authorized {
+            trace!("authorization failed");
+            return Ok(ResponseMessage::from_header(
+                &header,
+                DrainSpuResponse {
+                    status: Status::new(
+                        spu_name,
+                        ErrorCode::PermissionDenied,
+                        Some(String::from("permission denied")),
+                    ),
+                    moves: vec![],
+                },
+            ));
+        }
+    } else {
+        return Err(Error::new(ErrorKind::Interrupted, "authorization io error"));
+    }
+
+    let response = if req.undrain {
+        undrain_spu(spu_name, req.dry_run, auth_ctx).await
+    } else {
+        drain_spu(spu_name, req.spu, req.dry_run, auth_ctx).await
+    };
+
+    trace!("drain spu resp {:#?}", response);
+
+    Ok(ResponseMessage::from_header(&header, response))
+}
+
+async fn drain_spu<AC: AuthContext>(
+    spu_name: String,
+    spu_id: SpuId,
+    dry_run: bool,
+    auth_ctx: &AuthServiceContext<AC>,
+) -> DrainSpuResponse {
+    let spus: Vec<_> = auth_ctx
+        .global_ctx
+        .spus()
+        .store()
+        .read()
+        .await
+        .values()
+        .map(|value| value.inner().clone())
+        .collect();
+
+    let partitions: Vec<_> = auth_ctx
+        .global_ctx
+        .partitions()
+        .store()
+        .read()
+        .await
+        .values()
+        .map(|value| value.inner().clone())
+        .collect();
+
+    let replication_factors: HashMap<String, ReplicationFactor> = auth_ctx
+        .global_ctx
+        .topics()
+        .store()
+        .read()
+        .await
+        .values()
+        .filter_map(|topic| {
+            topic
+                .spec
+                .replication_factor()
+                .map(|factor| (topic.key_owned(), factor))
+        })
+        .collect();
+
+    let plan = match plan_moves(spu_id, &spus, partitions, &replication_factors) {
+        Ok(plan) => plan,
+        Err(err) => {
+            return DrainSpuResponse {
+                status: Status::new(spu_name, ErrorCode::SpuError, Some(err)),
+                moves: vec![],
+            }
+        }
+    };
+
+    let moves: Vec<ReplicaMove> = plan
+        .iter()
+        .map(|(_, _, replica_move)| replica_move.clone())
+        .collect();
+
+    if dry_run {
+        return DrainSpuResponse {
+            status: Status::new_ok(spu_name),
+            moves,
+        };
+    }
+
+    // mark spu as draining first so no new replicas are assigned to it
+    if let Some(spu) = auth_ctx.global_ctx.spus().store().value(&spu_name).await {
+        let mut status = spu.inner_owned().status;
+        if !status.is_draining() {
+            status.set_draining(true);
+            if let Err(err) = auth_ctx
+                .global_ctx
+                .spus()
+                .update_status(spu_name.clone(), status)
+                .await
+            {
+                return DrainSpuResponse {
+                    status: Status::new(
+                        spu_name,
+                        ErrorCode::SpuError,
+                        Some(format!("error marking spu as draining: {}", err)),
+                    ),
+                    moves,
+                };
+            }
+        }
+    }
+
+    for (replica_key, spec, replica_move) in plan.into_iter() {
+        info!(
+            partition = %replica_key,
+            from = replica_move.from,
+            to = replica_move.to,
+            leader = spec.leader,
+            "moving replica from draining spu",
+        );
+
+        if let Err(err) = auth_ctx
+            .global_ctx
+            .partitions()
+            .create_spec(replica_key.clone(), spec)
+            .await
+        {
+            return DrainSpuResponse {
+                status: Status::new(
+                    spu_name,
+                    ErrorCode::SpuError,
+                    Some(format!("error moving partition {}: {}", replica_key, err)),
+                ),
+                moves,
+            };
+        }
+    }
+
+    DrainSpuResponse {
+        status: Status::new_ok(spu_name),
+        moves,
+    }
+}
+
+/// stop draining spu so it can receive replicas again.
+/// replicas which have already been moved are not moved back
+async fn undrain_spu<AC: AuthContext>(
+    spu_name: String,
+    dry_run: bool,
+    auth_ctx: &AuthServiceContext<AC>,
+) -> DrainSpuResponse {
+    if let Some(spu) = auth_ctx.global_ctx.spus().store().value(&spu_name).await {
+        let mut status = spu.inner_owned().status;
+        if status.is_draining() && !dry_run {
+            info!(spu = %spu_name, "stop draining spu");
+            status.set_draining(false);
+            if let Err(err) = auth_ctx
+                .global_ctx
+                .spus()
+                .update_status(spu_name.clone(), status)
+                .await
+            {
+                return DrainSpuResponse {
+                    status: Status::new(
+                        spu_name,
+                        ErrorCode::SpuError,
+                        Some(format!("error clearing spu draining: {}", err)),
+                    ),
+                    moves: vec![],
+                };
+            }
+        }
+    }
+
+    DrainSpuResponse {
+        status: Status::new_ok(spu_name),
+        moves: vec![],
+    }
+}
+
+/// check if replica of drained spu has already been moved.
+/// moved replica is kept at the end of replicas in addition to the replication factor
+fn is_moved(
+    spu_id: SpuId,
+    spec: &PartitionSpec,
+    replication_factor: Option<ReplicationFactor>,
+) -> bool {
+    match replication_factor {
+        Some(factor) => {
+            spec.replicas.last() == Some(&spu_id) && spec.replicas.len() > factor as usize
+        }
+        None => false,
+    }
+}
+
+/// compute new replica assignment for every partition which has replica on drained spu.
+/// target spu is online, not draining, not already a replica and has least number of replicas
+fn plan_moves<C: MetadataItem>(
+    spu_id: SpuId,
+    spus: &[SpuMetadata<C>],
+    partitions: Vec<PartitionMetadata<C>>,
+    replication_factors: &HashMap<String, ReplicationFactor>,
+) -> Result<Vec<(ReplicaKey, PartitionSpec, ReplicaMove)>, String> {
+    let online_spus: HashSet<SpuId> = spus
+        .iter()
+        .filter(|spu| spu.status.is_online())
+        .map(|spu| spu.spec.id)
+        .collect();
+
+    // number of replicas for each spu which can receive replicas
+    let mut spu_load: BTreeMap<SpuId, usize> = spus
+        .iter()
+        .filter(|spu| spu.spec.id != spu_id && spu.status.is_online() && !spu.status.is_draining())
+        .map(|spu| (spu.spec.id, 0))
+        .collect();
+
+    for partition in &partitions {
+        for replica in &partition.spec.replicas {
+            if let Some(load) = spu_load.get_mut(replica) {
+                *load += 1;
+            }
+        }
+    }
+
+    let mut plan = vec![];
+
+    let mut drained_partitions: Vec<_> = partitions
+        .into_iter()
+        .filter(|partition| {
+            partition.spec.has_spu(&spu_id)
+                && !is_moved(
+                    spu_id,
+                    &partition.spec,
+                    replication_factors.get(&partition.key().topic).copied(),
+                )
+        })
+        .collect();
+    drained_partitions.sort_by(|a, b| a.key().to_string().cmp(&b.key().to_string()));
+
+    for partition in drained_partitions {
+        let replica_key = partition.key_owned();
+
+        let assigned: HashSet<SpuId> = partition.spec.replicas.iter().copied().collect();
+        let target = spu_load
+            .iter()
+            .filter(|(spu, _)| !assigned.contains(spu))
+            .min_by_key(|(_, load)| **load)
+            .map(|(spu, _)| *spu)
+            .ok_or_else(|| {
+                format!(
+                    "no spu available to move replica of partition {}",
+                    replica_key
+                )
+            })?;
+
+        if let Some(load) = spu_load.get_mut(&target) {
+            *load += 1;
+        }
+
+        // target takes position of drained replica, drained replica is kept at the end until it is removed
+        let mut spec = partition.spec.clone();
+        spec.replicas = partition
+            .spec
+            .replicas
+            .iter()
+            .map(|replica| if *replica == spu_id { target } else { *replica })
+            .collect();
+        spec.replicas.push(spu_id);
+
+        // leadership is moved to in sync replica, otherwise to target once it catches up
+        let leader = if partition.spec.leader != spu_id {
+            partition.spec.leader
+        } else if let Some(in_sync) = spec.replicas.iter().copied().find(|replica| {
+            *replica != spu_id
+                && *replica != target
+                && online_spus.contains(replica)
+                && partition.status.is_in_sync(*replica)
+        }) {
+            spec.leader = in_sync;
+            in_sync
+        } else {
+            target
+        };
+
+        let replica_move = ReplicaMove {
+            partition: replica_key.to_string(),
+            from: spu_id,
+            to: target,
+            leader,
+        };
+
+        plan.push((replica_key, spec, replica_move));
+    }
+
+    Ok(plan)
+}
+
+#[cfg(test)]
+mod test {
+
+    use std::collections::HashMap;
+
+    use fluvio_controlplane_metadata::partition::store::PartitionMetadata;
+    use fluvio_controlplane_metadata::partition::{PartitionSpec, PartitionStatus};
+    use fluvio_controlplane_metadata::spu::store::{SpuMd, SpuMetadata};
+
+    use super::plan_moves;
+
+    fn spus() -> Vec<SpuMetadata<u32>> {
+        let mut draining = SpuMetadata::quick(("spu-3", 3, true, None));
+        draining.status.set_draining(true);
+        vec![
+            SpuMetadata::quick(("spu-0", 0, true, None)),
+            SpuMetadata::quick(("spu-1", 1, true, None)),
+            SpuMetadata::quick(("spu-2", 2, true, None)),
+            draining,
+            SpuMetadata::quick(("spu-4", 4, false, None)),
+        ]
+    }
+
+    fn partition(topic: &str, leader: i32, replicas: Vec<i32>) -> PartitionMetadata<u32> {
+        let status = PartitionStatus::new(
+            (leader, 10, 10),
+            replicas
+                .iter()
+                .filter(|replica| **replica != leader)
+                .map(|replica| (*replica, 10, 10).into())
+                .collect(),
+        );
+        PartitionMetadata::new(
+            (topic, 0).into(),
+            PartitionSpec::new(leader, replicas),
+            status,
+        )
+    }
+
+    fn replication(factors: &[(&str, i32)]) -> HashMap<String, i32> {
+        factors
+            .iter()
+            .map(|(topic, factor)| (topic.to_string(), *factor))
+            .collect()
+    }
+
+    #[test]
+    fn test_plan_moves() {
+        let partitions = vec![
+            partition("follower", 1, vec![1, 0]),
+            partition("leader", 0, vec![0, 1]),
+            partition("other", 1, vec![1, 2]),
+        ];
+        let factors = replication(&[("follower", 2), ("leader", 2), ("other", 2)]);
+
+        let plan = plan_moves(0, &spus(), partitions, &factors).expect("plan");
+        assert_eq!(plan.len(), 2);
+
+        // spu 2 has least replicas, draining and offline spus are not used
+        let (key, spec, replica_move) = &plan[0];
+        assert_eq!(key.topic, "follower");
+        assert_eq!(spec.replicas, vec![1, 2, 0]);
+        assert_eq!(spec.leader, 1);
+        assert_eq!(replica_move.to, 2);
+
+        // leadership moves to in sync replica
+        let (key, spec, replica_move) = &plan[1];
+        assert_eq!(key.topic, "leader");
+        assert_eq!(spec.replicas, vec![2, 1, 0]);
+        assert_eq!(spec.leader, 1);
+        assert_eq!(replica_move.leader, 1);
+    }
+
+    #[test]
+    fn test_plan_moves_is_not_repeated() {
+        // replica of spu 0 has already been moved to spu 2
+        let partitions = vec![
+            partition("moved", 1, vec![1, 2, 0]),
+            partition("pending", 1, vec![1, 0]),
+        ];
+        let factors = replication(&[("moved", 2), ("pending", 2)]);
+
+        let plan = plan_moves(0, &spus(), partitions, &factors).expect("plan");
+        assert_eq!(plan.len(), 1);
+        assert_eq!(plan[0].0.topic, "pending");
+    }
+
+    #[test]
+    fn test_plan_moves_no_target() {
+        let partitions = vec![partition("full", 0, vec![0, 1, 2])];
+        let factors = replication(&[("full", 3)]);
+
+        assert!(plan_moves(0, &spus(), partitions, &factors).is_err());
+    }
+}
@@ -53,6 +53,11 @@ pub async fn handle_fetch_custom_spu_request<AC: AuthContext>(
         })
         .collect();
 
+    let custom_spus = auth_ctx
+        .readable(CustomSpuSpec::OBJECT_TYPE, custom_spus)
+        .await
+        .map_err(|_| Error::new(ErrorKind::Interrupted, "authorization io error"))?;
+
     debug!("flv fetch custom resp: {} items", custom_spus.len());
     trace!("flv fetch custom spus resp {:#?}", custom_spus);
 
@@ -94,6 +99,11 @@ pub async fn handle_fetch_spus_request<AC: AuthContext>(
         })
         .collect();
 
+    let spus = auth_ctx
+        .readable(SpuSpec::OBJECT_TYPE, spus)
+        .await
+        .map_err(|_| Error::new(ErrorKind::Interrupted, "authorization io error"))?;
+
     debug!("fetched {} spu items", spus.len());
     trace!("fetch spus items detail: {:#?}", spus);
 
@@ -1,7 +1,9 @@
 mod fetch;
+mod drain;
 mod register_custom_spus_req;
 mod unregister_custom_spus_req;
 
 pub use fetch::*;
+pub use drain::*;
 pub use register_custom_spus_req::*;
 pub use unregister_custom_spus_req::*;
@@ -11,7 +11,7 @@ use fluvio_controlplane_metadata::spu::store::SpuLocalStorePolicy;
 use fluvio_sc_schema::Status;
 use fluvio_sc_schema::spu::SpuSpec;
 use fluvio_controlplane_metadata::spu::CustomSpuSpec;
-use fluvio_auth::{AuthContext, TypeAction};
+use fluvio_auth::{AuthContext, InstanceAction};
 use fluvio_controlplane_metadata::extended::SpecExt;
 
 use crate::core::{SharedContext};
@@ -35,7 +35,7 @@ impl RegisterCustomSpu {
         debug!("api request: create custom-spu '{}({})'", name, spec.id);
         if let Ok(authorized) = auth_ctx
             .auth
-            .allow_type_action(CustomSpuSpec::OBJECT_TYPE, TypeAction::Read)
+            .allow_instance_action(CustomSpuSpec::OBJECT_TYPE, InstanceAction::Create, &name)
             .await
         {
             if !authorized {
@@ -102,6 +102,27 @@ async fn un_register_custom_spu<AC: AuthContext>(
         );
     }
 
+    // spu must not hold any replicas, those are moved by draining spu
+    let spu_id = spu.spec.id;
+    if let Some(partition) = auth_ctx
+        .global_ctx
+        .partitions()
+        .store()
+        .read()
+        .await
+        .values()
+        .find(|partition| partition.inner().spec.has_spu(&spu_id))
+    {
+        return Status::new(
+            spu_name,
+            ErrorCode::SpuError,
+            Some(format!(
+                "spu still has replica of partition {}, drain spu before deleting it",
+                partition.inner().key()
+            )),
+        );
+    }
+
     // delete custom spec and return result
     if let Err(err) = auth_ctx.global_ctx.spus().delete(spu_name.clone()).await {
         Status::new(
@@ -45,6 +45,11 @@ pub async fn handle_fetch_topics_request<AC: AuthContext>(
         })
         .collect();
 
+    let topics = auth_ctx
+        .readable(TopicSpec::OBJECT_TYPE, topics)
+        .await
+        .map_err(|_| Error::new(ErrorKind::Interrupted, "authorization io error"))?;
+
     debug!("flv fetch topics resp: {} items", topics.len());
     trace!("flv fetch topics resp {:#?}", topics);
 
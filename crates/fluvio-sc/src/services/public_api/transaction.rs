@@ -0,0 +1,207 @@
+//!
+//! # Transaction Requests
+//!
+//! Register transactional producers, add partitions to transactions and end them.
+//! Producer must be able to write transactional id, otherwise it could fence
+//! other producers or end their transactions. Partitions are authorized when
+//! they are added, since markers are written to them.
+//!
+use std::collections::BTreeSet;
+use std::io::{Error, ErrorKind};
+
+use tracing::{debug, trace, instrument};
+
+use dataplane::ErrorCode;
+use dataplane::api::{RequestMessage, ResponseMessage};
+use fluvio_sc_schema::Status;
+use fluvio_sc_schema::topic::TopicSpec;
+use fluvio_sc_schema::transaction::{
+    AddPartitionsToTxnRequest, AddPartitionsToTxnResponse, EndTxnRequest, EndTxnResponse,
+    InitProducerIdRequest, InitProducerIdResponse,
+};
+use fluvio_controlplane_metadata::extended::{ObjectType, SpecExt};
+use fluvio_auth::{AuthContext, InstanceAction};
+
+use crate::services::auth::AuthServiceContext;
+
+/// Handler for init producer id request
+#[instrument(skip(request, auth_ctx))]
+pub async fn handle_init_producer_id_request<AC: AuthContext>(
+    request: RequestMessage<InitProducerIdRequest>,
+    auth_ctx: &AuthServiceContext<AC>,
+) -> Result<ResponseMessage<InitProducerIdResponse>, Error> {
+    let (header, req) = request.get_header_request();
+
+    debug!(transactional_id = %req.transactional_id, "api request: init producer id");
+
+    if let Some(status) = authorize_transactional_id(auth_ctx, &req.transactional_id).await? {
+        return Ok(ResponseMessage::from_header(
+            &header,
+            InitProducerIdResponse {
+                status,
+                ..Default::default()
+            },
+        ));
+    }
+
+    let response = match auth_ctx
+        .global_ctx
+        .transactions()
+        .init_producer_id(&req.transactional_id)
+    {
+        Ok((producer_id, producer_epoch, pending)) => InitProducerIdResponse {
+            status: Status::new_ok(req.transactional_id),
+            producer_id,
+            producer_epoch,
+            pending,
+        },
+        Err((code, err)) => InitProducerIdResponse {
+            status: Status::new(req.transactional_id, code, Some(err)),
+            ..Default::default()
+        },
+    };
+    trace!("init producer id response {:#?}", response);
+
+    Ok(ResponseMessage::from_header(&header, response))
+}
+
+/// Handler for add partitions to transaction request.
+/// Producer must be able to write to topics of partitions
+#[instrument(skip(request, auth_ctx))]
+pub async fn handle_add_partitions_request<AC: AuthContext>(
+    request: RequestMessage<AddPartitionsToTxnRequest>,
+    auth_ctx: &AuthServiceContext<AC>,
+) -> Result<ResponseMessage<AddPartitionsToTxnResponse>, Error> {
+    let (header, req) = request.get_header_request();
+
+    debug!(
+        transactional_id = %req.transactional_id,
+        partitions = req.partitions.len(),
+        "api request: add partitions to transaction"
+    );
+
+    if let Some(status) = authorize_transactional_id(auth_ctx, &req.transactional_id).await? {
+        return Ok(ResponseMessage::from_header(
+            &header,
+            AddPartitionsToTxnResponse { status },
+        ));
+    }
+
+    let topics: BTreeSet<&str> = req
+        .partitions
+        .iter()
+        .map(|partition| partition.topic.as_str())
+        .collect();
+    for topic in topics {
+        if let Ok(authorized) = auth_ctx
+            .auth
+            .allow_instance_action(TopicSpec::OBJECT_TYPE, InstanceAction::Write, topic)
+            .await
+        {
+            if !authorized {
+                trace!(topic, "authorization failed");
+                return Ok(ResponseMessage::from_header(
+                    &header,
+                    AddPartitionsToTxnResponse {
+                        status: Status::new(
+                            req.transactional_id,
+                            ErrorCode::PermissionDenied,
+                            Some(format!("permission denied on topic {}", topic)),
+                        ),
+                    },
+                ));
+            }
+        } else {
+            return Err(Error::new(ErrorKind::Interrupted, "authorization io error"));
+        }
+    }
+
+    let status = match auth_ctx.global_ctx.transactions().add_partitions(
+        &req.transactional_id,
+        req.producer_id,
+        req.producer_epoch,
+        req.partitions,
+    ) {
+        Ok(()) => Status::new_ok(req.transactional_id),
+        Err((code, err)) => Status::new(req.transactional_id, code, Some(err)),
+    };
+    let response = AddPartitionsToTxnResponse { status };
+    trace!("add partitions response {:#?}", response);
+
+    Ok(ResponseMessage::from_header(&header, response))
+}
+
+/// Handler for end transaction request
+#[instrument(skip(request, auth_ctx))]
+pub async fn handle_end_txn_request<AC: AuthContext>(
+    request: RequestMessage<EndTxnRequest>,
+    auth_ctx: &AuthServiceContext<AC>,
+) -> Result<ResponseMessage<EndTxnResponse>, Error> {
+    let (header, req) = request.get_header_request();
+
+    debug!(
+        transactional_id = %req.transactional_id,
+        commit = req.commit,
+        phase = ?req.phase,
+        "api request: end transaction"
+    );
+
+    if let Some(status) = authorize_transactional_id(auth_ctx, &req.transactional_id).await? {
+        return Ok(ResponseMessage::from_header(
+            &header,
+            EndTxnResponse {
+                status,
+                ..Default::default()
+            },
+        ));
+    }
+
+    let response = match auth_ctx.global_ctx.transactions().end_transaction(
+        &req.transactional_id,
+        req.producer_id,
+        req.producer_epoch,
+        req.commit,
+        req.phase,
+    ) {
+        Ok(partitions) => EndTxnResponse {
+            status: Status::new_ok(req.transactional_id),
+            partitions,
+        },
+        Err((code, err)) => EndTxnResponse {
+            status: Status::new(req.transactional_id, code, Some(err)),
+            ..Default::default()
+        },
+    };
+    trace!("end transaction response {:#?}", response);
+
+    Ok(ResponseMessage::from_header(&header, response))
+}
+
+/// status of denied request if producer can't write transactional id
+async fn authorize_transactional_id<AC: AuthContext>(
+    auth_ctx: &AuthServiceContext<AC>,
+    transactional_id: &str,
+) -> Result<Option<Status>, Error> {
+    let authorized = auth_ctx
+        .auth
+        .allow_instance_action(
+            ObjectType::Transaction,
+            InstanceAction::Write,
+            transactional_id,
+        )
+        .await
+        .map_err(|_| Error::new(ErrorKind::Interrupted, "authorization io error"))?;
+    if authorized {
+        Ok(None)
+    } else {
+        trace!(transactional_id, "authorization failed");
+        Ok(Some(Status::new(
+            transactional_id.to_owned(),
+            ErrorCode::PermissionDenied,
+            Some(format!(
+                "permission denied on transactional id {}",
+                transactional_id
+            )),
+        )))
+    }
+}
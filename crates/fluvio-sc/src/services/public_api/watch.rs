@@ -10,6 +10,9 @@ use dataplane::api::{RequestMessage, RequestHeader, ResponseMessage};
 use fluvio_sc_schema::objects::{WatchRequest, WatchResponse, Metadata, MetadataUpdate};
 
 use fluvio_controlplane_metadata::core::Spec;
+use fluvio_controlplane_metadata::extended::SpecExt;
+use fluvio_controlplane_metadata::message::Message;
+use fluvio_auth::{AuthContext, TypeAction, InstanceAction, AuthError};
 use fluvio_controlplane_metadata::partition::PartitionSpec;
 use fluvio_controlplane_metadata::spu::SpuSpec;
 use fluvio_controlplane_metadata::topic::TopicSpec;
@@ -19,14 +22,17 @@ use crate::services::auth::AuthServiceContext;
 use crate::stores::{StoreContext, K8ChangeListener};
 use fluvio_controlplane_metadata::spg::SpuGroupSpec;
 
-/// handle watch request by spawning watch controller for each store
+/// handle watch request by spawning watch controller for each store.
+/// only objects which can be read by client are sent
 #[instrument(skip(request, auth_ctx, sink, end_event))]
 pub fn handle_watch_request<AC>(
     request: RequestMessage<WatchRequest>,
-    auth_ctx: &AuthServiceContext<AC>,
+    auth_ctx: Arc<AuthServiceContext<AC>>,
     sink: ExclusiveFlvSink,
     end_event: Arc<StickyEvent>,
-) {
+) where
+    AC: AuthContext + Send + Sync + 'static,
+{
     debug!("handling watch request");
     let (header, req) = request.get_header_request();
 
@@ -35,58 +41,66 @@ pub fn handle_watch_request<AC>(
             sink,
             end_event,
             auth_ctx.global_ctx.topics().clone(),
+            auth_ctx.clone(),
             header,
         ),
         WatchRequest::Spu(_) => WatchController::<SpuSpec>::update(
             sink,
             end_event,
             auth_ctx.global_ctx.spus().clone(),
+            auth_ctx.clone(),
             header,
         ),
         WatchRequest::SpuGroup(_) => WatchController::<SpuGroupSpec>::update(
             sink,
             end_event,
             auth_ctx.global_ctx.spgs().clone(),
+            auth_ctx.clone(),
             header,
         ),
         WatchRequest::Partition(_) => WatchController::<PartitionSpec>::update(
             sink,
             end_event,
             auth_ctx.global_ctx.partitions().clone(),
+            auth_ctx.clone(),
             header,
         ),
         WatchRequest::ManagedConnector(_) => WatchController::<ManagedConnectorSpec>::update(
             sink,
             end_event,
             auth_ctx.global_ctx.managed_connectors().clone(),
+            auth_ctx.clone(),
             header,
         ),
     }
 }
 
-struct WatchController<S>
+struct WatchController<S, AC>
 where
     S: Spec,
 {
     response_sink: ExclusiveFlvSink,
     store: StoreContext<S>,
+    auth_ctx: Arc<AuthServiceContext<AC>>,
     header: RequestHeader,
     end_event: Arc<StickyEvent>,
 }
 
-impl<S> WatchController<S>
+impl<S, AC> WatchController<S, AC>
 where
-    S: Spec + Debug + 'static + Send + Sync + Encoder + Decoder,
+    S: SpecExt + Debug + 'static + Send + Sync + Encoder + Decoder + Default,
     S::IndexKey: ToString,
-    <S as Spec>::Status: Sync + Send + Encoder + Decoder,
+    <S as Spec>::Status: Sync + Send + Encoder + Decoder + Default,
     <S as Spec>::IndexKey: Sync + Send,
     MetadataUpdate<S>: Into<WatchResponse>,
+    AC: AuthContext + Send + Sync + 'static,
 {
     /// start watch controller
     fn update(
         response_sink: ExclusiveFlvSink,
         end_event: Arc<StickyEvent>,
         store: StoreContext<S>,
+        auth_ctx: Arc<AuthServiceContext<AC>>,
         header: RequestHeader,
     ) {
         use fluvio_future::task::spawn;
@@ -94,6 +108,7 @@ where
         let controller = Self {
             response_sink,
             store,
+            auth_ctx,
             header,
             end_event,
         };
@@ -112,6 +127,25 @@ where
     async fn dispatch_loop(mut self) {
         use tokio::select;
 
+        match self
+            .auth_ctx
+            .auth
+            .allow_type_action(S::OBJECT_TYPE, TypeAction::Read)
+            .await
+        {
+            Ok(true) => {}
+            Ok(false) => {
+                debug!("authorization failed, sending empty update");
+                self.send_update(MetadataUpdate::with_all(0, vec![])).await;
+                return;
+            }
+            Err(err) => {
+                error!(%err, "authorization error, terminating watch");
+                self.end_event.notify();
+                return;
+            }
+        }
+
         let mut change_listener = self.store.change_listener();
 
         loop {
@@ -141,8 +175,6 @@ where
     /// if can't send, then signal end and return false
     #[instrument(skip(self, listener))]
     async fn sync_and_send_changes(&mut self, listener: &mut K8ChangeListener<S>) -> bool {
-        use fluvio_controlplane_metadata::message::*;
-
         if !listener.has_change() {
             debug!("no changes, skipping");
         }
@@ -158,7 +190,14 @@ where
 
         let updates = if changes.is_sync_all() {
             let (updates, _) = changes.parts();
-            MetadataUpdate::with_all(epoch, updates.into_iter().map(|u| u.into()).collect())
+            let all = updates.into_iter().map(|u| u.into()).collect();
+            match self.auth_ctx.readable(S::OBJECT_TYPE, all).await {
+                Ok(all) => MetadataUpdate::with_all(epoch, all),
+                Err(err) => {
+                    error!(%err, "authorization error");
+                    return false;
+                }
+            }
         } else {
             let (updates, deletes) = changes.parts();
             let mut changes: Vec<Message<Metadata<S>>> = updates
@@ -170,9 +209,39 @@ where
                 .map(|d| Message::delete(d.into()))
                 .collect();
             changes.append(&mut deletes);
-            MetadataUpdate::with_changes(epoch, changes)
+            match self.readable_changes(changes).await {
+                Ok(changes) => MetadataUpdate::with_changes(epoch, changes),
+                Err(err) => {
+                    error!(%err, "authorization error");
+                    return false;
+                }
+            }
         };
 
+        self.send_update(updates).await
+    }
+
+    /// keep only changes of objects which can be read
+    async fn readable_changes(
+        &self,
+        changes: Vec<Message<Metadata<S>>>,
+    ) -> Result<Vec<Message<Metadata<S>>>, AuthError> {
+        let mut readable = Vec::with_capacity(changes.len());
+        for change in changes {
+            if self
+                .auth_ctx
+                .auth
+                .allow_instance_action(S::OBJECT_TYPE, InstanceAction::Read, &change.content.name)
+                .await?
+            {
+                readable.push(change);
+            }
+        }
+        Ok(readable)
+    }
+
+    /// send update to client, return false if it can't be sent
+    async fn send_update(&mut self, updates: MetadataUpdate<S>) -> bool {
         let resp_msg: ResponseMessage<WatchResponse> =
             ResponseMessage::from_header(&self.header, updates.into());
 
@@ -3,6 +3,8 @@ pub mod topic;
 pub mod partition;
 pub mod spg;
 pub mod connector;
+pub mod quota;
+pub mod schema;
 
 pub use crate::dispatcher::store::*;
 
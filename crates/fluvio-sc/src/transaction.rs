@@ -0,0 +1,931 @@
+//!
+//! # Transaction Coordinator
+//!
+//! Tracks producer id, epoch and partitions of transactions by transactional id.
+//! Decision of transaction is prepared here, then markers are sent to SPUs which
+//! write them to partitions they lead and confirm written markers. Transaction is
+//! complete once markers are written to all of its partitions.
+//!
+//! Epoch is bumped when producer is initialized again or its transaction is open
+//! longer than transaction timeout. Ongoing transaction is then aborted with marker
+//! of new epoch, so SPU fences previous instance of producer.
+//!
+//! Every transaction of transactional id gets next sequence, which is sent with
+//! markers, so markers of transactions with same epoch are told apart.
+//!
+//! State is written to state file (`--transaction-state-file`) on every change and
+//! loaded when SC starts. Without state file, transactions which are open when SC
+//! restarts are not tracked anymore.
+//!
+use std::collections::{BTreeSet, HashMap};
+use std::fs;
+use std::io::Error as IoError;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+use serde::{Deserialize, Serialize};
+use tracing::{debug, error, info};
+
+use dataplane::{ErrorCode, ReplicaKey};
+use fluvio_controlplane::TxnMarker;
+use fluvio_future::task::spawn;
+use fluvio_future::timer::sleep;
+use fluvio_sc_schema::transaction::{EndTxnPhase, PendingTransaction};
+use fluvio_types::event::offsets::{OffsetChangeListener, OffsetPublisher};
+
+use crate::core::SharedContext;
+
+pub type TxnResult<T> = Result<T, (ErrorCode, String)>;
+
+/// interval between checks of expired transactions
+const TIMEOUT_CHECK_INTERVAL: Duration = Duration::from_secs(5);
+
+#[derive(Debug, Clone, PartialEq)]
+enum TransactionState {
+    Empty,
+    Ongoing {
+        txn_sequence: i32,
+        partitions: BTreeSet<ReplicaKey>,
+        started: Instant,
+    },
+    Prepared {
+        commit: bool,
+        /// producer id and epoch of markers
+        producer_id: i64,
+        producer_epoch: i16,
+        txn_sequence: i32,
+        /// partitions where markers are not written yet
+        partitions: BTreeSet<ReplicaKey>,
+    },
+}
+
+#[derive(Debug)]
+struct TransactionMetadata {
+    producer_id: i64,
+    producer_epoch: i16,
+    /// sequence of next transaction
+    next_txn_sequence: i32,
+    state: TransactionState,
+}
+
+impl TransactionMetadata {
+    fn check_producer(&self, producer_id: i64, producer_epoch: i16) -> TxnResult<()> {
+        if self.producer_id != producer_id || self.producer_epoch != producer_epoch {
+            return Err((
+                ErrorCode::ProducerFenced,
+                format!(
+                    "producer {} epoch {} is fenced by epoch {}",
+                    producer_id, producer_epoch, self.producer_epoch
+                ),
+            ));
+        }
+        Ok(())
+    }
+
+    /// bump epoch, new producer id is allocated when epoch is exhausted.
+    /// Returns producer id and epoch which fence previous instance
+    fn bump_epoch(&mut self, next_producer_id: &mut i64) -> (i64, i16) {
+        let fenced_id = self.producer_id;
+        if self.producer_epoch == i16::MAX {
+            self.producer_id = *next_producer_id;
+            self.producer_epoch = 0;
+            *next_producer_id += 1;
+            (fenced_id, i16::MAX)
+        } else {
+            self.producer_epoch += 1;
+            (fenced_id, self.producer_epoch)
+        }
+    }
+
+    /// abort ongoing transaction with markers of producer id and epoch
+    fn abort_ongoing(&mut self, producer_id: i64, producer_epoch: i16) -> bool {
+        if let TransactionState::Ongoing {
+            txn_sequence,
+            partitions,
+            ..
+        } = &mut self.state
+        {
+            let txn_sequence = *txn_sequence;
+            let partitions = std::mem::take(partitions);
+            self.state = TransactionState::prepared(
+                false,
+                producer_id,
+                producer_epoch,
+                txn_sequence,
+                partitions,
+            );
+            true
+        } else {
+            false
+        }
+    }
+}
+
+impl TransactionState {
+    fn prepared(
+        commit: bool,
+        producer_id: i64,
+        producer_epoch: i16,
+        txn_sequence: i32,
+        partitions: BTreeSet<ReplicaKey>,
+    ) -> Self {
+        if partitions.is_empty() {
+            Self::Empty
+        } else {
+            Self::Prepared {
+                commit,
+                producer_id,
+                producer_epoch,
+                txn_sequence,
+                partitions,
+            }
+        }
+    }
+}
+
+#[derive(Debug)]
+struct CoordinatorState {
+    next_producer_id: i64,
+    transactions: HashMap<String, TransactionMetadata>,
+    /// if set, state is written to this file on every change
+    state_file: Option<PathBuf>,
+}
+
+impl CoordinatorState {
+    /// write state to state file, file is replaced so partially written state is never read
+    fn store(&self) -> Result<(), IoError> {
+        let path = match &self.state_file {
+            Some(path) => path,
+            None => return Ok(()),
+        };
+        let stored = StoredCoordinator {
+            next_producer_id: self.next_producer_id,
+            transactions: self
+                .transactions
+                .iter()
+                .map(|(transactional_id, txn)| StoredTransaction::new(transactional_id, txn))
+                .collect(),
+        };
+        let tmp_path = path.with_extension("tmp");
+        fs::write(&tmp_path, serde_json::to_vec(&stored)?)?;
+        fs::rename(&tmp_path, path)
+    }
+
+    /// store state, request fails if state can't be stored
+    fn try_store(&self) -> TxnResult<()> {
+        self.store().map_err(|err| {
+            error!("error storing transaction state: {}", err);
+            (
+                ErrorCode::TransactionError,
+                format!("transaction state can't be stored: {}", err),
+            )
+        })
+    }
+
+    /// store state of background change, which is stored again on next change
+    fn store_or_log(&self) {
+        if let Err(err) = self.store() {
+            error!("error storing transaction state: {}", err);
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct TransactionCoordinator {
+    inner: Mutex<CoordinatorState>,
+    /// bumped when markers must be sent to SPUs
+    markers_changed: Arc<OffsetPublisher>,
+}
+
+impl Default for TransactionCoordinator {
+    fn default() -> Self {
+        // ids are not persisted, start from clock so ids are not reused after restart
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|time| time.as_millis() as i64)
+            .unwrap_or(0);
+        Self::new(now)
+    }
+}
+
+impl TransactionCoordinator {
+    fn new(first_producer_id: i64) -> Self {
+        Self {
+            inner: Mutex::new(CoordinatorState {
+                next_producer_id: first_producer_id,
+                transactions: HashMap::new(),
+                state_file: None,
+            }),
+            markers_changed: OffsetPublisher::shared(0),
+        }
+    }
+
+    /// load state from state file if it exists, state is written to it from now on.
+    /// Open transactions are timed out from now
+    pub fn open(&self, state_file: &Path) -> Result<(), IoError> {
+        let mut inner = self.inner.lock().unwrap();
+        if state_file.exists() {
+            let stored: StoredCoordinator = serde_json::from_slice(&fs::read(state_file)?)?;
+            inner.next_producer_id = stored.next_producer_id;
+            inner.transactions = stored
+                .transactions
+                .into_iter()
+                .map(StoredTransaction::into_metadata)
+                .collect();
+            info!(
+                count = inner.transactions.len(),
+                "loaded transactional producers"
+            );
+        }
+        inner.state_file = Some(state_file.to_owned());
+        inner.store()?;
+        drop(inner);
+        // markers of prepared transactions are sent again
+        self.resend_markers();
+        Ok(())
+    }
+
+    /// listener which is notified when markers must be sent to SPUs
+    pub fn change_listener(&self) -> OffsetChangeListener {
+        self.markers_changed.change_listner()
+    }
+
+    /// register producer, bump epoch of existing producer id.
+    /// Ongoing transaction of previous instance is aborted
+    pub fn init_producer_id(
+        &self,
+        transactional_id: &str,
+    ) -> TxnResult<(i64, i16, Option<PendingTransaction>)> {
+        if transactional_id.is_empty() {
+            return Err((
+                ErrorCode::TransactionError,
+                "transactional id can't be empty".to_owned(),
+            ));
+        }
+
+        let mut inner = self.inner.lock().unwrap();
+        let CoordinatorState {
+            next_producer_id,
+            transactions,
+            ..
+        } = &mut *inner;
+
+        let txn = transactions
+            .entry(transactional_id.to_owned())
+            .or_insert_with(|| {
+                let producer_id = *next_producer_id;
+                *next_producer_id += 1;
+                TransactionMetadata {
+                    producer_id,
+                    producer_epoch: -1,
+                    next_txn_sequence: 0,
+                    state: TransactionState::Empty,
+                }
+            });
+
+        let (fenced_id, fenced_epoch) = txn.bump_epoch(next_producer_id);
+        if txn.abort_ongoing(fenced_id, fenced_epoch) {
+            debug!(transactional_id, "aborting transaction of fenced producer");
+            self.markers_changed.update_increment();
+        }
+
+        let pending = match &txn.state {
+            TransactionState::Prepared {
+                commit, partitions, ..
+            } => Some(PendingTransaction {
+                commit: *commit,
+                partitions: partitions.iter().cloned().collect(),
+            }),
+            _ => None,
+        };
+
+        debug!(
+            transactional_id,
+            producer_id = txn.producer_id,
+            producer_epoch = txn.producer_epoch,
+            pending = pending.is_some(),
+            "initialized transactional producer"
+        );
+        let initialized = (txn.producer_id, txn.producer_epoch, pending);
+        inner.try_store()?;
+        Ok(initialized)
+    }
+
+    /// add partitions to transaction, transaction is started if there is none
+    pub fn add_partitions(
+        &self,
+        transactional_id: &str,
+        producer_id: i64,
+        producer_epoch: i16,
+        partitions: Vec<ReplicaKey>,
+    ) -> TxnResult<()> {
+        let mut inner = self.inner.lock().unwrap();
+        let txn = Self::lookup(&mut inner, transactional_id)?;
+        txn.check_producer(producer_id, producer_epoch)?;
+
+        match &mut txn.state {
+            TransactionState::Empty => {
+                txn.state = TransactionState::Ongoing {
+                    txn_sequence: txn.next_txn_sequence,
+                    partitions: partitions.into_iter().collect(),
+                    started: Instant::now(),
+                };
+                txn.next_txn_sequence += 1;
+            }
+            TransactionState::Ongoing {
+                partitions: existing,
+                ..
+            } => existing.extend(partitions),
+            TransactionState::Prepared { .. } => {
+                return Err((
+                    ErrorCode::InvalidTransactionState,
+                    "transaction is being completed".to_owned(),
+                ))
+            }
+        }
+        inner.try_store()
+    }
+
+    /// prepare decision of transaction or check if it's complete.
+    /// Partitions where markers are not written yet are returned,
+    /// transaction is complete when there are none
+    pub fn end_transaction(
+        &self,
+        transactional_id: &str,
+        producer_id: i64,
+        producer_epoch: i16,
+        commit: bool,
+        phase: EndTxnPhase,
+    ) -> TxnResult<Vec<ReplicaKey>> {
+        let mut inner = self.inner.lock().unwrap();
+        let txn = Self::lookup(&mut inner, transactional_id)?;
+        txn.check_producer(producer_id, producer_epoch)?;
+
+        let invalid = |message: &str| Err((ErrorCode::InvalidTransactionState, message.to_owned()));
+
+        match (phase, &mut txn.state) {
+            (_, TransactionState::Empty) => Ok(vec![]),
+            (
+                EndTxnPhase::Prepare,
+                TransactionState::Ongoing {
+                    txn_sequence,
+                    partitions,
+                    ..
+                },
+            ) => {
+                let txn_sequence = *txn_sequence;
+                let partitions = std::mem::take(partitions);
+                let remaining: Vec<ReplicaKey> = partitions.iter().cloned().collect();
+                txn.state = TransactionState::prepared(
+                    commit,
+                    producer_id,
+                    producer_epoch,
+                    txn_sequence,
+                    partitions,
+                );
+                debug!(
+                    transactional_id,
+                    commit, txn_sequence, "prepared transaction"
+                );
+                inner.try_store()?;
+                self.markers_changed.update_increment();
+                Ok(remaining)
+            }
+            (EndTxnPhase::Complete, TransactionState::Ongoing { .. }) => {
+                invalid("transaction is not prepared")
+            }
+            (
+                _,
+                TransactionState::Prepared {
+                    commit: prepared, ..
+                },
+            ) if *prepared != commit => invalid("transaction is prepared with other decision"),
+            (_, TransactionState::Prepared { partitions, .. }) => {
+                Ok(partitions.iter().cloned().collect())
+            }
+        }
+    }
+
+    /// markers of all prepared transactions
+    pub fn markers(&self) -> Vec<TxnMarker> {
+        let inner = self.inner.lock().unwrap();
+        inner
+            .transactions
+            .values()
+            .flat_map(|txn| match &txn.state {
+                TransactionState::Prepared {
+                    commit,
+                    producer_id,
+                    producer_epoch,
+                    txn_sequence,
+                    partitions,
+                } => partitions
+                    .iter()
+                    .map(|replica| {
+                        TxnMarker::new(
+                            replica.clone(),
+                            *producer_id,
+                            *producer_epoch,
+                            *txn_sequence,
+                            *commit,
+                        )
+                    })
+                    .collect(),
+                _ => vec![],
+            })
+            .collect()
+    }
+
+    /// markers written by SPU, transaction is complete when all of its markers are written
+    pub fn markers_written(&self, markers: &[TxnMarker]) {
+        let mut inner = self.inner.lock().unwrap();
+        let mut changed = false;
+        for (transactional_id, txn) in inner.transactions.iter_mut() {
+            if let TransactionState::Prepared {
+                commit,
+                producer_id,
+                producer_epoch,
+                txn_sequence,
+                partitions,
+            } = &mut txn.state
+            {
+                for marker in markers.iter().filter(|marker| {
+                    marker.producer_id == *producer_id
+                        && marker.producer_epoch == *producer_epoch
+                        && marker.txn_sequence == *txn_sequence
+                        && marker.commit == *commit
+                }) {
+                    changed |= partitions.remove(&marker.replica);
+                }
+                if partitions.is_empty() {
+                    debug!(%transactional_id, commit = *commit, "completed transaction");
+                    txn.state = TransactionState::Empty;
+                }
+            }
+        }
+        if changed {
+            inner.store_or_log();
+        }
+    }
+
+    /// abort transactions which are open longer than timeout, producer is fenced
+    pub fn abort_expired(&self, timeout: Duration) {
+        let mut inner = self.inner.lock().unwrap();
+        let CoordinatorState {
+            next_producer_id,
+            transactions,
+            ..
+        } = &mut *inner;
+
+        let mut changed = false;
+        for (transactional_id, txn) in transactions.iter_mut() {
+            let expired = match &txn.state {
+                TransactionState::Ongoing { started, .. } => started.elapsed() >= timeout,
+                _ => false,
+            };
+            if expired {
+                let (fenced_id, fenced_epoch) = txn.bump_epoch(next_producer_id);
+                txn.abort_ongoing(fenced_id, fenced_epoch);
+                info!(%transactional_id, "aborting expired transaction");
+                changed = true;
+            }
+        }
+        if changed {
+            inner.store_or_log();
+            self.markers_changed.update_increment();
+        }
+    }
+
+    /// send markers again if any transaction is prepared,
+    /// replica may have no leader when markers are sent
+    pub fn resend_markers(&self) {
+        let inner = self.inner.lock().unwrap();
+        if inner
+            .transactions
+            .values()
+            .any(|txn| matches!(txn.state, TransactionState::Prepared { .. }))
+        {
+            self.markers_changed.update_increment();
+        }
+    }
+
+    fn lookup<'a>(
+        inner: &'a mut CoordinatorState,
+        transactional_id: &str,
+    ) -> TxnResult<&'a mut TransactionMetadata> {
+        inner.transactions.get_mut(transactional_id).ok_or_else(|| {
+            (
+                ErrorCode::TransactionError,
+                format!("transactional id {} is not initialized", transactional_id),
+            )
+        })
+    }
+}
+
+/// transaction state in state file
+#[derive(Debug, Serialize, Deserialize)]
+struct StoredCoordinator {
+    next_producer_id: i64,
+    transactions: Vec<StoredTransaction>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct StoredTransaction {
+    transactional_id: String,
+    producer_id: i64,
+    producer_epoch: i16,
+    next_txn_sequence: i32,
+    state: StoredState,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+enum StoredState {
+    Empty,
+    Ongoing {
+        txn_sequence: i32,
+        partitions: Vec<(String, i32)>,
+    },
+    Prepared {
+        commit: bool,
+        producer_id: i64,
+        producer_epoch: i16,
+        txn_sequence: i32,
+        partitions: Vec<(String, i32)>,
+    },
+}
+
+fn store_partitions(partitions: &BTreeSet<ReplicaKey>) -> Vec<(String, i32)> {
+    partitions
+        .iter()
+        .map(|replica| (replica.topic.clone(), replica.partition))
+        .collect()
+}
+
+fn load_partitions(partitions: Vec<(String, i32)>) -> BTreeSet<ReplicaKey> {
+    partitions
+        .into_iter()
+        .map(|(topic, partition)| ReplicaKey::new(topic, partition))
+        .collect()
+}
+
+impl StoredTransaction {
+    fn new(transactional_id: &str, txn: &TransactionMetadata) -> Self {
+        let state = match &txn.state {
+            TransactionState::Empty => StoredState::Empty,
+            TransactionState::Ongoing {
+                txn_sequence,
+                partitions,
+                ..
+            } => StoredState::Ongoing {
+                txn_sequence: *txn_sequence,
+                partitions: store_partitions(partitions),
+            },
+            TransactionState::Prepared {
+                commit,
+                producer_id,
+                producer_epoch,
+                txn_sequence,
+                partitions,
+            } => StoredState::Prepared {
+                commit: *commit,
+                producer_id: *producer_id,
+                producer_epoch: *producer_epoch,
+                txn_sequence: *txn_sequence,
+                partitions: store_partitions(partitions),
+            },
+        };
+        Self {
+            transactional_id: transactional_id.to_owned(),
+            producer_id: txn.producer_id,
+            producer_epoch: txn.producer_epoch,
+            next_txn_sequence: txn.next_txn_sequence,
+            state,
+        }
+    }
+
+    fn into_metadata(self) -> (String, TransactionMetadata) {
+        let state = match self.state {
+            StoredState::Empty => TransactionState::Empty,
+            StoredState::Ongoing {
+                txn_sequence,
+                partitions,
+            } => TransactionState::Ongoing {
+                txn_sequence,
+                partitions: load_partitions(partitions),
+                started: Instant::now(),
+            },
+            StoredState::Prepared {
+                commit,
+                producer_id,
+                producer_epoch,
+                txn_sequence,
+                partitions,
+            } => TransactionState::Prepared {
+                commit,
+                producer_id,
+                producer_epoch,
+                txn_sequence,
+                partitions: load_partitions(partitions),
+            },
+        };
+        (
+            self.transactional_id,
+            TransactionMetadata {
+                producer_id: self.producer_id,
+                producer_epoch: self.producer_epoch,
+                next_txn_sequence: self.next_txn_sequence,
+                state,
+            },
+        )
+    }
+}
+
+/// periodically abort expired transactions and send markers which are not written yet
+pub fn start_transaction_timeout(ctx: SharedContext) {
+    let timeout = ctx.config().transaction_timeout;
+    spawn(async move {
+        loop {
+            sleep(TIMEOUT_CHECK_INTERVAL).await;
+            ctx.transactions().abort_expired(timeout);
+            ctx.transactions().resend_markers();
+        }
+    });
+}
+
+#[cfg(test)]
+mod test {
+
+    use std::time::Duration;
+
+    use dataplane::{ErrorCode, ReplicaKey};
+    use fluvio_controlplane::TxnMarker;
+    use fluvio_sc_schema::transaction::{EndTxnPhase, PendingTransaction};
+
+    use super::TransactionCoordinator;
+
+    #[test]
+    fn test_commit_transaction() {
+        let coordinator = TransactionCoordinator::new(100);
+        let (id, epoch, pending) = coordinator.init_producer_id("app").expect("init");
+        assert_eq!((id, epoch, pending), (100, 0, None));
+
+        let partitions = vec![ReplicaKey::new("out", 0), ReplicaKey::new("offsets", 0)];
+        coordinator
+            .add_partitions("app", id, epoch, partitions.clone())
+            .expect("add");
+        coordinator
+            .add_partitions("app", id, epoch, vec![ReplicaKey::new("out", 0)])
+            .expect("add");
+        assert!(coordinator.markers().is_empty());
+
+        let mut prepared = coordinator
+            .end_transaction("app", id, epoch, true, EndTxnPhase::Prepare)
+            .expect("prepare");
+        prepared.sort();
+        let mut expected = partitions;
+        expected.sort();
+        assert_eq!(prepared, expected);
+
+        let mut markers = coordinator.markers();
+        markers.sort_by(|a, b| a.replica.cmp(&b.replica));
+        assert_eq!(
+            markers,
+            vec![
+                TxnMarker::new(ReplicaKey::new("offsets", 0), id, epoch, 0, true),
+                TxnMarker::new(ReplicaKey::new("out", 0), id, epoch, 0, true),
+            ]
+        );
+
+        // decision can't be changed, prepare can be retried
+        assert_eq!(
+            coordinator
+                .end_transaction("app", id, epoch, false, EndTxnPhase::Complete)
+                .unwrap_err()
+                .0,
+            ErrorCode::InvalidTransactionState
+        );
+        assert!(coordinator
+            .add_partitions("app", id, epoch, vec![ReplicaKey::new("out", 1)])
+            .is_err());
+        assert_eq!(
+            coordinator
+                .end_transaction("app", id, epoch, true, EndTxnPhase::Prepare)
+                .expect("prepare")
+                .len(),
+            2
+        );
+
+        // transaction is complete when all markers are written
+        coordinator.markers_written(&markers[0..1]);
+        assert_eq!(
+            coordinator
+                .end_transaction("app", id, epoch, true, EndTxnPhase::Complete)
+                .expect("complete"),
+            vec![ReplicaKey::new("out", 0)]
+        );
+        // marker with other epoch doesn't complete transaction
+        coordinator.markers_written(&[TxnMarker::new(
+            ReplicaKey::new("out", 0),
+            id,
+            epoch + 1,
+            0,
+            true,
+        )]);
+        assert_eq!(coordinator.markers().len(), 1);
+        coordinator.markers_written(&markers);
+        assert!(coordinator
+            .end_transaction("app", id, epoch, true, EndTxnPhase::Complete)
+            .expect("complete")
+            .is_empty());
+        assert!(coordinator.markers().is_empty());
+
+        // new producer gets new id
+        let (other, _, _) = coordinator.init_producer_id("other").expect("init");
+        assert_eq!(other, 101);
+
+        // next transaction with same epoch has next sequence
+        coordinator
+            .add_partitions("app", id, epoch, vec![ReplicaKey::new("out", 0)])
+            .expect("add");
+        coordinator
+            .end_transaction("app", id, epoch, true, EndTxnPhase::Prepare)
+            .expect("prepare");
+        assert_eq!(
+            coordinator.markers(),
+            vec![TxnMarker::new(
+                ReplicaKey::new("out", 0),
+                id,
+                epoch,
+                1,
+                true
+            )]
+        );
+        // marker of previous transaction doesn't complete it
+        coordinator.markers_written(&markers);
+        assert_eq!(coordinator.markers().len(), 1);
+    }
+
+    #[test]
+    fn test_fence_producer() {
+        let coordinator = TransactionCoordinator::new(0);
+        let mut listener = coordinator.change_listener();
+        let (id, epoch, _) = coordinator.init_producer_id("app").expect("init");
+        coordinator
+            .add_partitions("app", id, epoch, vec![ReplicaKey::new("out", 0)])
+            .expect("add");
+
+        // ongoing transaction of previous instance is aborted with marker of new epoch
+        let (new_id, new_epoch, pending) = coordinator.init_producer_id("app").expect("init");
+        assert_eq!((new_id, new_epoch), (id, epoch + 1));
+        assert_eq!(
+            pending,
+            Some(PendingTransaction {
+                commit: false,
+                partitions: vec![ReplicaKey::new("out", 0)],
+            })
+        );
+        let markers = coordinator.markers();
+        assert_eq!(
+            markers,
+            vec![TxnMarker::new(
+                ReplicaKey::new("out", 0),
+                new_id,
+                new_epoch,
+                0,
+                false
+            )]
+        );
+        assert_eq!(fluvio_future::task::run_block_on(listener.listen()), 1);
+
+        assert_eq!(
+            coordinator
+                .add_partitions("app", id, epoch, vec![ReplicaKey::new("out", 1)])
+                .unwrap_err()
+                .0,
+            ErrorCode::ProducerFenced
+        );
+        assert_eq!(
+            coordinator
+                .end_transaction("app", id, epoch, true, EndTxnPhase::Prepare)
+                .unwrap_err()
+                .0,
+            ErrorCode::ProducerFenced
+        );
+
+        // new producer waits for abort of pending transaction
+        assert_eq!(
+            coordinator
+                .end_transaction("app", new_id, new_epoch, false, EndTxnPhase::Complete)
+                .expect("complete")
+                .len(),
+            1
+        );
+        coordinator.markers_written(&markers);
+        assert!(coordinator
+            .end_transaction("app", new_id, new_epoch, false, EndTxnPhase::Complete)
+            .expect("complete")
+            .is_empty());
+        let (_, _, pending) = coordinator.init_producer_id("app").expect("init");
+        assert_eq!(pending, None);
+
+        assert!(coordinator.init_producer_id("").is_err());
+        assert!(coordinator.add_partitions("unknown", 0, 0, vec![]).is_err());
+    }
+
+    #[test]
+    fn test_abort_expired_transaction() {
+        let coordinator = TransactionCoordinator::new(0);
+        let (id, epoch, _) = coordinator.init_producer_id("app").expect("init");
+        coordinator
+            .add_partitions("app", id, epoch, vec![ReplicaKey::new("out", 0)])
+            .expect("add");
+
+        coordinator.abort_expired(Duration::from_secs(60));
+        assert!(coordinator.markers().is_empty());
+
+        coordinator.abort_expired(Duration::from_secs(0));
+        let markers = coordinator.markers();
+        assert_eq!(
+            markers,
+            vec![TxnMarker::new(
+                ReplicaKey::new("out", 0),
+                id,
+                epoch + 1,
+                0,
+                false
+            )]
+        );
+
+        // producer of expired transaction is fenced
+        assert_eq!(
+            coordinator
+                .end_transaction("app", id, epoch, true, EndTxnPhase::Prepare)
+                .unwrap_err()
+                .0,
+            ErrorCode::ProducerFenced
+        );
+
+        coordinator.markers_written(&markers);
+        let (new_id, new_epoch, pending) = coordinator.init_producer_id("app").expect("init");
+        assert_eq!((new_id, new_epoch, pending), (id, epoch + 2, None));
+    }
+
+    #[test]
+    fn test_store_state() {
+        use std::env::temp_dir;
+        use std::fs;
+
+        let state_file = temp_dir().join("test_transaction_state.json");
+        let _ = fs::remove_file(&state_file);
+
+        let coordinator = TransactionCoordinator::new(0);
+        coordinator.open(&state_file).expect("open");
+        let (id, epoch, _) = coordinator.init_producer_id("app").expect("init");
+        coordinator
+            .add_partitions("app", id, epoch, vec![ReplicaKey::new("out", 0)])
+            .expect("add");
+        coordinator
+            .end_transaction("app", id, epoch, true, EndTxnPhase::Prepare)
+            .expect("prepare");
+        let (other, other_epoch, _) = coordinator.init_producer_id("other").expect("init");
+        coordinator
+            .add_partitions("other", other, other_epoch, vec![ReplicaKey::new("out", 1)])
+            .expect("add");
+        let markers = coordinator.markers();
+        drop(coordinator);
+
+        // prepared transaction is completed after restart
+        let coordinator = TransactionCoordinator::new(0);
+        coordinator.open(&state_file).expect("load");
+        assert_eq!(coordinator.markers(), markers);
+        assert_eq!(
+            coordinator
+                .end_transaction("app", id, epoch, true, EndTxnPhase::Complete)
+                .expect("complete"),
+            vec![ReplicaKey::new("out", 0)]
+        );
+        coordinator.markers_written(&markers);
+
+        // ongoing transaction of other producer is aborted by new instance
+        let (new_id, new_epoch, pending) = coordinator.init_producer_id("other").expect("init");
+        assert_eq!((new_id, new_epoch), (other, other_epoch + 1));
+        assert!(pending.is_some());
+        let (third, _, _) = coordinator.init_producer_id("third").expect("init");
+        assert_eq!(third, 2);
+        drop(coordinator);
+
+        let coordinator = TransactionCoordinator::new(0);
+        coordinator.open(&state_file).expect("load");
+        assert_eq!(
+            coordinator.markers(),
+            vec![TxnMarker::new(
+                ReplicaKey::new("out", 1),
+                other,
+                other_epoch + 1,
+                0,
+                false
+            )]
+        );
+    }
+}
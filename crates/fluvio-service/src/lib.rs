@@ -5,7 +5,7 @@ mod server;
 pub mod test_request;
 
 pub use self::server::*;
-pub use fluvio_protocol::codec::FluvioCodec;
+pub use fluvio_protocol::codec::{FluvioCodec, DEFAULT_MAX_FRAME_SIZE};
 
 #[macro_export]
 macro_rules! call_service {
@@ -34,14 +34,18 @@ macro_rules! api_loop {
 
             tracing::debug!("waiting for next api request");
             if let Some(msg) = $api_stream.next().await {
-                if let Ok(req_message) = msg {
-                    tracing::trace!("received request: {:#?}",req_message);
-                    match req_message {
-                        $($matcher => $result),*
+                match msg {
+                    Ok(req_message) => {
+                        tracing::trace!("received request: {:#?}",req_message);
+                        match req_message {
+                            $($matcher => $result),*
+                        }
+                    }
+                    Err(err) => {
+                        // includes requests over max frame size
+                        tracing::warn!("invalid request, closing connection: {}", err);
+                        break;
                     }
-                } else {
-                    tracing::debug!("no content, end of connection {:#?}", msg);
-                    break;
                 }
 
             } else {
@@ -58,14 +62,17 @@ macro_rules! api_loop {
 
             tracing::debug!("waiting for next api request: {}",$debug_msg);
             if let Some(msg) = $api_stream.next().await {
-                if let Ok(req_message) = msg {
-                    tracing::trace!("received request: {:#?}",req_message);
-                    match req_message {
-                        $($matcher => $result),*
+                match msg {
+                    Ok(req_message) => {
+                        tracing::trace!("received request: {:#?}",req_message);
+                        match req_message {
+                            $($matcher => $result),*
+                        }
+                    }
+                    Err(err) => {
+                        tracing::warn!("invalid request, closing connection: {}, {}", $debug_msg, err);
+                        break;
                     }
-                } else {
-                    tracing::debug!("no content, end of connection {}", $debug_msg);
-                    break;
                 }
 
             } else {
@@ -93,7 +100,7 @@ macro_rules! wait_for_request {
                     }
                 }
             } else {
-                tracing::trace!("no content, end of connection");
+                tracing::warn!("invalid request, closing connection: {:?}", msg);
                 return Ok(());
             }
         } else {
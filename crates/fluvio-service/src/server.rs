@@ -12,6 +12,7 @@ use tracing::{instrument, debug, error, info};
 use fluvio_future::net::{TcpListener, TcpStream};
 use fluvio_future::task::spawn;
 use fluvio_protocol::api::ApiMessage;
+use fluvio_protocol::codec::DEFAULT_MAX_FRAME_SIZE;
 use fluvio_protocol::Decoder as FluvioDecoder;
 use fluvio_socket::{FluvioSocket, SocketError};
 use fluvio_types::event::StickyEvent;
@@ -50,6 +51,7 @@ pub struct FluvioApiServer<R, A, C, S> {
     context: C,
     service: Arc<S>,
     addr: String,
+    max_frame_size: usize,
 }
 
 impl<R, A, C, S> fmt::Debug for FluvioApiServer<R, A, C, S> {
@@ -69,15 +71,22 @@ where
             service: Arc::new(service),
             context,
             addr,
+            max_frame_size: DEFAULT_MAX_FRAME_SIZE,
         }
     }
+
+    /// limit size of request, connection is closed when client sends larger request
+    pub fn with_max_frame_size(mut self, max_frame_size: usize) -> Self {
+        self.max_frame_size = max_frame_size;
+        self
+    }
 }
 
 impl<R, A, C, S> FluvioApiServer<R, A, C, S>
 where
     R: ApiMessage<ApiKey = A> + Send + Debug + 'static,
     C: Clone + Sync + Send + Debug + 'static,
-    A: Send + FluvioDecoder + Debug + 'static,
+    A: Send + FluvioDecoder + Default + Debug + 'static,
     S: FluvioService<Request = R, Context = C> + Send + Sync + Debug + 'static,
 {
     pub fn run(self) -> Arc<StickyEvent> {
@@ -108,7 +117,13 @@ where
                     let context = self.context.clone();
                     let service = self.service.clone();
                     let host = self.addr.clone();
-                    spawn(Self::handle_request(stream, context, service, host));
+                    spawn(Self::handle_request(
+                        stream,
+                        context,
+                        service,
+                        host,
+                        self.max_frame_size,
+                    ));
                 }
                 Err(e) => {
                     error!("Error from TCP Stream: {:?}", e);
@@ -120,7 +135,13 @@ where
     }
 
     #[instrument(skip(stream, context, service))]
-    async fn handle_request(stream: TcpStream, context: C, service: Arc<S>, host: String) {
+    async fn handle_request(
+        stream: TcpStream,
+        context: C,
+        service: Arc<S>,
+        host: String,
+        max_frame_size: usize,
+    ) {
         let peer_addr = stream
             .peer_addr()
             .map(|addr| addr.to_string())
@@ -129,7 +150,10 @@ where
 
         let socket = {
             let fd = stream.as_raw_fd();
-            FluvioSocket::from_stream(Box::new(stream.clone()), Box::new(stream), fd)
+            let mut socket =
+                FluvioSocket::from_stream(Box::new(stream.clone()), Box::new(stream), fd);
+            socket.get_mut_stream().set_max_frame_size(max_frame_size);
+            socket
         };
 
         let connection_info = ConnectInfo {
@@ -221,4 +245,26 @@ mod test {
         let shutdown = create_server(socket_addr.clone()).run();
         test_client(socket_addr.clone(), shutdown).await;
     }
+
+    #[fluvio_future::test]
+    async fn test_server_max_frame_size() {
+        let socket_addr = "127.0.0.1:30002".to_owned();
+
+        let shutdown = create_server(socket_addr.clone())
+            .with_max_frame_size(64)
+            .run();
+
+        // connection is closed when request is over limit
+        let mut socket = create_client(socket_addr.clone()).await.expect("client");
+        let msg = RequestMessage::new_request(EchoRequest::new("x".repeat(100)));
+        assert!(socket.send(&msg).await.is_err());
+
+        // other connections are not affected
+        let mut socket = create_client(socket_addr).await.expect("client");
+        let msg = RequestMessage::new_request(EchoRequest::new("small".to_owned()));
+        let reply = socket.send(&msg).await.expect("send");
+        assert_eq!(reply.response.msg, "small");
+
+        shutdown.notify();
+    }
 }
@@ -5,8 +5,9 @@ use std::io::Error as IoError;
 use std::io::ErrorKind;
 use std::marker::PhantomData;
 use std::pin::Pin;
-use std::sync::Arc;
+use std::sync::{Arc, Weak};
 use std::sync::atomic::AtomicBool;
+use std::sync::atomic::AtomicU64;
 use std::sync::atomic::Ordering::SeqCst;
 use std::sync::atomic::AtomicI32;
 use std::time::Duration;
@@ -18,6 +19,7 @@ use async_channel::Sender;
 use async_lock::Mutex;
 use bytes::{Bytes};
 use event_listener::Event;
+use once_cell::sync::Lazy;
 use fluvio_future::net::ConnectionFd;
 use futures_util::stream::{Stream, StreamExt};
 use pin_project::{pin_project, pinned_drop};
@@ -49,13 +51,47 @@ enum SharedSender {
 
 type Senders = Arc<Mutex<HashMap<i32, SharedSender>>>;
 
+/// default time to wait for response
+static MAX_WAIT_TIME: Lazy<u64> = Lazy::new(|| {
+    use std::env;
+
+    let var_value = env::var("FLV_SOCKET_WAIT").unwrap_or_default();
+    let wait_time: u64 = var_value.parse().unwrap_or(300); // match TCP socket timeout
+    wait_time
+});
+
+/// set when connection can't be used anymore
+struct Stale {
+    flag: AtomicBool,
+    event: Event,
+}
+
+impl Stale {
+    fn new() -> Self {
+        Self {
+            flag: AtomicBool::new(false),
+            event: Event::new(),
+        }
+    }
+
+    fn set(&self) {
+        self.flag.store(true, SeqCst);
+        self.event.notify(usize::MAX);
+    }
+
+    fn is_set(&self) -> bool {
+        self.flag.load(SeqCst)
+    }
+}
+
 /// Socket that can multiplex connections
 pub struct MultiplexerSocket {
     correlation_id_counter: AtomicI32,
     senders: Senders,
     sink: ExclusiveFlvSink,
-    stale: Arc<AtomicBool>,
+    stale: Arc<Stale>,
     terminate: Arc<Event>,
+    request_timeout_ms: AtomicU64,
 }
 
 impl fmt::Debug for MultiplexerSocket {
@@ -84,7 +120,7 @@ impl MultiplexerSocket {
         debug!(socket = %id, "spawning dispatcher");
 
         let (sink, stream) = socket.split();
-        let stale = Arc::new(AtomicBool::new(false));
+        let stale = Arc::new(Stale::new());
 
         let multiplexer = Self {
             correlation_id_counter: AtomicI32::new(1),
@@ -92,6 +128,7 @@ impl MultiplexerSocket {
             sink: ExclusiveFlvSink::new(sink),
             terminate: Arc::new(Event::new()),
             stale: stale.clone(),
+            request_timeout_ms: AtomicU64::new(*MAX_WAIT_TIME * 1000),
         };
 
         MultiPlexingResponseDispatcher::run(
@@ -106,11 +143,84 @@ impl MultiplexerSocket {
     }
 
     pub fn set_stale(&self) {
-        self.stale.store(true, SeqCst);
+        self.stale.set();
     }
 
     pub fn is_stale(&self) -> bool {
-        self.stale.load(SeqCst)
+        self.stale.is_set()
+    }
+
+    /// wait until socket becomes stale
+    pub async fn wait_stale(&self) {
+        loop {
+            if self.is_stale() {
+                return;
+            }
+            let listener = self.stale.event.listen();
+            if self.is_stale() {
+                return;
+            }
+            listener.await;
+        }
+    }
+
+    /// mark socket as stale and close it, pending requests and streams fail with closed socket error
+    pub fn close_stale(&self) {
+        self.set_stale();
+        self.terminate.notify(usize::MAX);
+    }
+
+    /// time to wait for response of `send_and_receive`
+    pub fn request_timeout(&self) -> Duration {
+        Duration::from_millis(self.request_timeout_ms.load(SeqCst))
+    }
+
+    pub fn set_request_timeout(&self, timeout: Duration) {
+        self.request_timeout_ms
+            .store(timeout.as_millis() as u64, SeqCst);
+    }
+
+    /// periodically send request created by `request` to check connection health.
+    /// if there is no response within `timeout`, socket is closed as stale.
+    /// heartbeat ends when socket is dropped or becomes stale
+    pub fn start_heartbeat<R, F>(
+        self: &Arc<Self>,
+        interval: Duration,
+        timeout: Duration,
+        request: F,
+    ) where
+        R: Request + Send + Sync + 'static,
+        R::Response: Send,
+        F: Fn() -> RequestMessage<R> + Send + 'static,
+    {
+        use fluvio_future::task::spawn;
+
+        let socket: Weak<Self> = Arc::downgrade(self);
+        spawn(async move {
+            loop {
+                sleep(interval).await;
+                let socket = match socket.upgrade() {
+                    Some(socket) => socket,
+                    None => break,
+                };
+                if socket.is_stale() {
+                    break;
+                }
+                trace!(api = R::API_KEY, "sending heartbeat");
+                if let Err(err) = socket
+                    .send_and_receive_with_timeout(request(), timeout)
+                    .await
+                {
+                    error!(
+                        api = R::API_KEY,
+                        "heartbeat failed, closing socket: {}", err
+                    );
+                    socket.close_stale();
+                    break;
+                }
+            }
+            debug!("heartbeat terminated");
+        });
     }
 
     /// get next available correlation to use
@@ -118,24 +228,31 @@ impl MultiplexerSocket {
         self.correlation_id_counter.fetch_add(1, SeqCst)
     }
 
-    /// create socket to perform request and response
-    #[instrument(skip(req_msg))]
+    /// create socket to perform request and response, waiting up to request timeout
     pub async fn send_and_receive<R>(
         &self,
-        mut req_msg: RequestMessage<R>,
+        req_msg: RequestMessage<R>,
     ) -> Result<R::Response, SocketError>
     where
         R: Request,
     {
-        use once_cell::sync::Lazy;
-
-        static MAX_WAIT_TIME: Lazy<u64> = Lazy::new(|| {
-            use std::env;
+        self.send_and_receive_with_timeout(req_msg, self.request_timeout())
+            .await
+    }
 
-            let var_value = env::var("FLV_SOCKET_WAIT").unwrap_or_default();
-            let wait_time: u64 = var_value.parse().unwrap_or(300); // match TCP socket timeout
-            wait_time
-        });
+    /// create socket to perform request and response with deadline for this request
+    #[instrument(skip(req_msg))]
+    pub async fn send_and_receive_with_timeout<R>(
+        &self,
+        mut req_msg: RequestMessage<R>,
+        timeout: Duration,
+    ) -> Result<R::Response, SocketError>
+    where
+        R: Request,
+    {
+        if self.is_stale() {
+            return Err(SocketError::SocketClosed);
+        }
 
         let correlation_id = self.next_correlation_id();
         let bytes_lock: SharedMsg = (Arc::new(Mutex::new(None)), Arc::new(Event::new()));
@@ -157,15 +274,14 @@ impl MultiplexerSocket {
 
         select! {
 
-            _ = sleep(Duration::from_secs(*MAX_WAIT_TIME)) => {
+            _ = sleep(timeout) => {
 
                 trace!("serial socket for: {}  timeout happen, id: {}", R::API_KEY, correlation_id);
                 // clean channel
                 let mut senders = self.senders.lock().await;
                 senders.remove(&correlation_id);
                 drop(senders);
-                self.set_stale();
-
+                // only this request failed, connection health is checked by heartbeat
 
                 Err(IoError::new(
                     ErrorKind::TimedOut,
@@ -333,7 +449,7 @@ struct MultiPlexingResponseDispatcher {
     id: ConnectionFd,
     senders: Senders,
     terminate: Arc<Event>,
-    stale: Arc<AtomicBool>,
+    stale: Arc<Stale>,
 }
 
 impl fmt::Debug for MultiPlexingResponseDispatcher {
@@ -348,7 +464,7 @@ impl MultiPlexingResponseDispatcher {
         stream: FluvioStream,
         senders: Senders,
         terminate: Arc<Event>,
-        stale: Arc<AtomicBool>,
+        stale: Arc<Stale>,
     ) {
         use fluvio_future::task::spawn;
 
@@ -391,25 +507,19 @@ impl MultiPlexingResponseDispatcher {
                         }
                     } else {
                         debug!("inner stream has terminated ");
-                        self.stale.store(true, SeqCst);
-
-                        let guard = self.senders.lock().await;
-                        for sender in guard.values() {
-                            match sender {
-                                SharedSender::Serial(_) => {},
-                                SharedSender::Queue(stream_sender) => {
-                                    let _ = stream_sender.send(None).await;
-                                }
-                            }
-                        }
-
+                        self.stale.set();
+                        self.close_senders().await;
                         break;
                     }
                 },
 
                 _ = self.terminate.listen() => {
-                    // terminate all channels
+                    if self.stale.is_set() {
+                        debug!("stale socket closed");
+                        self.close_senders().await;
+                    }
 
+                    // terminate all channels
                     let guard = self.senders.lock().await;
                     for sender in guard.values() {
                         match sender {
@@ -428,6 +538,21 @@ impl MultiPlexingResponseDispatcher {
         }
     }
 
+    /// wake up waiting requests and streams, they will receive closed connection error
+    async fn close_senders(&self) {
+        let guard = self.senders.lock().await;
+        for sender in guard.values() {
+            match sender {
+                SharedSender::Serial(serial_sender) => {
+                    serial_sender.1.notify(1);
+                }
+                SharedSender::Queue(stream_sender) => {
+                    let _ = stream_sender.send(None).await;
+                }
+            }
+        }
+    }
+
     /// send message to correct receiver
     #[instrument(skip(self, msg),fields( msg = msg.len()))]
     async fn send(&mut self, correlation_id: i32, msg: Bytes) -> Result<(), SocketError> {
@@ -711,6 +836,89 @@ mod tests {
         .await;
     }
 
+    /// server which accepts requests but never responds
+    async fn silent_server(addr: &str) -> FluvioSocket {
+        let listener = TcpListener::bind(addr).await.expect("binding");
+        let mut incoming = listener.incoming();
+        let stream = incoming.next().await.expect("next").expect("stream");
+        stream.into()
+    }
+
+    #[fluvio_future::test]
+    async fn test_request_timeout() {
+        use std::io::ErrorKind;
+
+        use tokio::select;
+
+        use crate::SocketError;
+
+        let addr = "127.0.0.1:6002";
+
+        let (_server, client) = join(silent_server(addr), async {
+            sleep(Duration::from_millis(20)).await;
+            let tcp_stream = TcpStream::connect(&addr).await.expect("connection fail");
+            let socket: FluvioSocket = tcp_stream.into();
+            MultiplexerSocket::shared(socket)
+        })
+        .await;
+
+        let mut status = client
+            .create_stream(RequestMessage::new_request(AsyncStatusRequest::new(1)), 10)
+            .await
+            .expect("stream");
+
+        // request deadline
+        for _ in 0..2 {
+            let request = RequestMessage::new_request(EchoRequest::new("hello".to_owned()));
+            let err = client
+                .send_and_receive_with_timeout(request, Duration::from_millis(100))
+                .await
+                .expect_err("timeout");
+            match err {
+                SocketError::Io(err) => assert_eq!(err.kind(), ErrorKind::TimedOut),
+                _ => panic!("expected timeout"),
+            }
+        }
+
+        // timeout of request doesn't close socket or other streams
+        assert!(!client.is_stale());
+        let next = select! {
+            item = status.next() => Some(item),
+            _ = sleep(Duration::from_millis(100)) => None,
+        };
+        assert!(next.is_none());
+    }
+
+    #[fluvio_future::test]
+    async fn test_heartbeat_closes_stream() {
+        let addr = "127.0.0.1:6003";
+
+        let (_server, client) = join(silent_server(addr), async {
+            sleep(Duration::from_millis(20)).await;
+            let tcp_stream = TcpStream::connect(&addr).await.expect("connection fail");
+            let socket: FluvioSocket = tcp_stream.into();
+            MultiplexerSocket::shared(socket)
+        })
+        .await;
+
+        let mut status = client
+            .create_stream(RequestMessage::new_request(AsyncStatusRequest::new(1)), 10)
+            .await
+            .expect("stream");
+
+        // heartbeat doesn't wait for request timeout
+        client.set_request_timeout(Duration::from_secs(300));
+        client.start_heartbeat(
+            Duration::from_millis(50),
+            Duration::from_millis(100),
+            || RequestMessage::new_request(EchoRequest::new("ping".to_owned())),
+        );
+
+        client.wait_stale().await;
+        // stream is woken up with closed socket error instead of hanging
+        assert!(status.next().await.expect("item").is_err());
+    }
+
     #[cfg(unix)]
     mod tls_test {
         use std::os::unix::io::AsRawFd;
@@ -44,12 +44,17 @@ impl FluvioStream {
         &mut self.inner
     }
 
+    /// messages larger than max frame size are rejected and end the stream
+    pub fn set_max_frame_size(&mut self, max_frame_size: usize) {
+        self.inner.decoder_mut().set_max_frame_size(max_frame_size);
+    }
+
     /// as server, get stream of request coming from client
     pub fn request_stream<R>(
         &mut self,
     ) -> impl Stream<Item = Result<RequestMessage<R>, SocketError>> + '_
     where
-        RequestMessage<R>: FluvioDecoder + Debug,
+        RequestMessage<R>: FluvioDecoder + Default + Debug,
     {
         (&mut self.inner).map(|req_bytes_r| match req_bytes_r {
             Ok(req_bytes) => {
@@ -64,7 +69,7 @@ impl FluvioStream {
     /// as server, get next request from client
     pub async fn next_request_item<R>(&mut self) -> Option<Result<RequestMessage<R>, SocketError>>
     where
-        RequestMessage<R>: FluvioDecoder + Debug,
+        RequestMessage<R>: FluvioDecoder + Default + Debug,
     {
         let mut stream = self.request_stream();
         stream.next().await
@@ -105,7 +110,7 @@ impl FluvioStream {
     pub fn api_stream<R, A>(&mut self) -> impl Stream<Item = Result<R, SocketError>> + '_
     where
         R: ApiMessage<ApiKey = A>,
-        A: FluvioDecoder + Debug,
+        A: FluvioDecoder + Default + Debug,
     {
         (&mut self.inner).map(|req_bytes_r| match req_bytes_r {
             Ok(req_bytes) => {
@@ -120,7 +125,7 @@ impl FluvioStream {
     pub async fn next_api_item<R, A>(&mut self) -> Option<Result<R, SocketError>>
     where
         R: ApiMessage<ApiKey = A>,
-        A: FluvioDecoder + Debug,
+        A: FluvioDecoder + Default + Debug,
     {
         let mut stream = self.api_stream();
         stream.next().await
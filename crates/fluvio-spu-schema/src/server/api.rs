@@ -22,6 +22,7 @@ use super::SpuServerApiKey;
 use super::fetch_offset::FetchOffsetsRequest;
 use super::stream_fetch::FileStreamFetchRequest;
 use super::update_offset::UpdateOffsetsRequest;
+use super::stream_control::StreamControlRequest;
 
 /// Request to Spu Server
 #[derive(Debug, Encoder)]
@@ -35,6 +36,7 @@ pub enum SpuServerRequest {
     FetchOffsetsRequest(RequestMessage<FetchOffsetsRequest>),
     FileStreamFetchRequest(RequestMessage<FileStreamFetchRequest>),
     UpdateOffsetsRequest(RequestMessage<UpdateOffsetsRequest>),
+    StreamControlRequest(RequestMessage<StreamControlRequest>),
 }
 
 impl fmt::Display for SpuServerRequest {
@@ -46,6 +48,7 @@ impl fmt::Display for SpuServerRequest {
             Self::FetchOffsetsRequest(_) => write!(f, "FetchOffsetsRequest"),
             Self::FileStreamFetchRequest(_) => write!(f, "FileStreamFetchRequest"),
             Self::UpdateOffsetsRequest(_) => write!(f, "UpdateOffsetsRequest"),
+            Self::StreamControlRequest(_) => write!(f, "StreamControlRequest"),
         }
     }
 }
@@ -78,6 +81,7 @@ impl ApiMessage for SpuServerRequest {
             SpuServerApiKey::FetchOffsets => api_decode!(Self, FetchOffsetsRequest, src, header),
             SpuServerApiKey::StreamFetch => api_decode!(Self, FileStreamFetchRequest, src, header),
             SpuServerApiKey::UpdateOffsets => api_decode!(Self, UpdateOffsetsRequest, src, header),
+            SpuServerApiKey::StreamControl => api_decode!(Self, StreamControlRequest, src, header),
         }
     }
 }
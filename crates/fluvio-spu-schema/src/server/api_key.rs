@@ -20,6 +20,7 @@ pub enum SpuServerApiKey {
     FetchOffsets = 1002,
     StreamFetch = 1003,
     UpdateOffsets = 1005,
+    StreamControl = 1006,
 }
 
 impl Default for SpuServerApiKey {
@@ -12,6 +12,9 @@ use dataplane::ReplicaKey;
 use crate::errors::ErrorCode;
 use super::SpuServerApiKey;
 
+/// version which returns log end offset and stream sessions
+pub const STREAM_SESSIONS_API: i16 = 1;
+
 // -----------------------------------
 // FlvFetchOffsetsRequest
 // -----------------------------------
@@ -21,11 +24,15 @@ use super::SpuServerApiKey;
 pub struct FetchOffsetsRequest {
     /// Each topic in the request.
     pub topics: Vec<FetchOffsetTopic>,
+
+    /// Return active stream sessions of each partition
+    #[fluvio(min_version = 1)]
+    pub include_sessions: bool,
 }
 
 impl Request for FetchOffsetsRequest {
     const API_KEY: u16 = SpuServerApiKey::FetchOffsets as u16;
-    const DEFAULT_API_VERSION: i16 = 0;
+    const DEFAULT_API_VERSION: i16 = STREAM_SESSIONS_API;
     type Response = FetchOffsetsResponse;
 }
 
@@ -39,6 +46,21 @@ impl FetchOffsetsRequest {
                     partition_index: partition,
                 }],
             }],
+            include_sessions: false,
+        }
+    }
+
+    /// create request for partitions of topic including their stream sessions
+    pub fn with_sessions(topic: String, partitions: Vec<i32>) -> Self {
+        Self {
+            topics: vec![FetchOffsetTopic {
+                name: topic,
+                partitions: partitions
+                    .into_iter()
+                    .map(|partition_index| FetchOffsetPartition { partition_index })
+                    .collect(),
+            }],
+            include_sessions: true,
         }
     }
 }
@@ -106,6 +128,29 @@ pub struct FetchOffsetPartitionResponse {
 
     /// Last readable offset
     pub last_stable_offset: i64,
+
+    /// Log end offset
+    #[fluvio(min_version = 1)]
+    pub leo: i64,
+
+    /// Active stream sessions, only if requested
+    #[fluvio(min_version = 1)]
+    pub sessions: Vec<StreamSessionOffset>,
+}
+
+/// Position of consumer stream session on leader
+#[derive(Encoder, Decoder, Default, Debug, Clone, PartialEq)]
+pub struct StreamSessionOffset {
+    pub session_id: u32,
+
+    /// client id of consumer
+    pub client_id: String,
+
+    /// Offset after last record read by stream
+    pub delivered_offset: i64,
+
+    /// Offset acknowledged by consumer through UpdateOffsets, -1 if none yet
+    pub committed_offset: i64,
 }
 
 impl fmt::Display for FetchOffsetPartitionResponse {
@@ -127,3 +172,75 @@ impl PartitionOffset for FetchOffsetPartitionResponse {
         self.start_offset
     }
 }
+
+#[cfg(test)]
+mod tests {
+
+    use std::io::Cursor;
+
+    use super::*;
+
+    #[test]
+    fn test_request_versions() {
+        let request = FetchOffsetsRequest::with_sessions("test".to_owned(), vec![0, 1]);
+
+        let mut v0 = vec![];
+        request.encode(&mut v0, 0).expect("encode");
+        let mut v1 = vec![];
+        request.encode(&mut v1, 1).expect("encode");
+        // only v1 has include sessions flag
+        assert_eq!(v1.len(), v0.len() + 1);
+
+        let decoded = FetchOffsetsRequest::decode_from(&mut Cursor::new(&v0), 0).expect("decode");
+        assert!(!decoded.include_sessions);
+        assert_eq!(decoded.topics[0].partitions.len(), 2);
+        let decoded = FetchOffsetsRequest::decode_from(&mut Cursor::new(&v1), 1).expect("decode");
+        assert!(decoded.include_sessions);
+    }
+
+    #[test]
+    fn test_response_versions() {
+        let session = StreamSessionOffset {
+            session_id: 3,
+            client_id: "consumer".to_owned(),
+            delivered_offset: 10,
+            committed_offset: -1,
+        };
+        let response = FetchOffsetsResponse {
+            topics: vec![FetchOffsetTopicResponse {
+                name: "test".to_owned(),
+                partitions: vec![FetchOffsetPartitionResponse {
+                    partition_index: 1,
+                    start_offset: 2,
+                    last_stable_offset: 8,
+                    leo: 10,
+                    sessions: vec![session.clone()],
+                    ..Default::default()
+                }],
+            }],
+        };
+
+        let mut v1 = vec![];
+        response.encode(&mut v1, 1).expect("encode");
+        let decoded = FetchOffsetsResponse::decode_from(&mut Cursor::new(&v1), 1)
+            .expect("decode")
+            .find_partition(&ReplicaKey::new("test", 1))
+            .expect("partition");
+        assert_eq!(decoded.last_stable_offset, 8);
+        assert_eq!(decoded.leo, 10);
+        assert_eq!(decoded.sessions, vec![session]);
+
+        // v0 clients don't receive log end offset and sessions
+        let mut v0 = vec![];
+        response.encode(&mut v0, 0).expect("encode");
+        assert!(v0.len() < v1.len());
+        let decoded = FetchOffsetsResponse::decode_from(&mut Cursor::new(&v0), 0)
+            .expect("decode")
+            .find_partition(&ReplicaKey::new("test", 1))
+            .expect("partition");
+        assert_eq!(decoded.start_offset, 2);
+        assert_eq!(decoded.last_stable_offset, 8);
+        assert_eq!(decoded.leo, 0);
+        assert!(decoded.sessions.is_empty());
+    }
+}
@@ -4,6 +4,7 @@ mod api;
 pub mod fetch_offset;
 pub mod stream_fetch;
 pub mod update_offset;
+pub mod stream_control;
 
 pub use self::api_key::*;
 
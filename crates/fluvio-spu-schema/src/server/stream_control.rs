@@ -0,0 +1,56 @@
+//!
+//! # Stream Control
+//!
+//! Control active stream fetch session without creating new one
+//!
+
+use dataplane::api::Request;
+use dataplane::core::{Encoder, Decoder};
+use dataplane::Offset;
+
+use crate::errors::ErrorCode;
+use super::SpuServerApiKey;
+
+/// Command for stream fetch session
+#[derive(Decoder, Encoder, Debug, Clone, PartialEq)]
+pub enum StreamControl {
+    /// Continue stream from absolute offset
+    Seek(Offset),
+    /// Stop sending records until resumed
+    Pause,
+    /// Continue sending records
+    Resume,
+}
+
+impl Default for StreamControl {
+    fn default() -> Self {
+        Self::Resume
+    }
+}
+
+#[derive(Decoder, Encoder, Default, Debug)]
+pub struct StreamControlRequest {
+    pub session_id: u32,
+    pub control: StreamControl,
+}
+
+impl Request for StreamControlRequest {
+    const API_KEY: u16 = SpuServerApiKey::StreamControl as u16;
+    const DEFAULT_API_VERSION: i16 = 0;
+    type Response = StreamControlResponse;
+}
+
+impl StreamControlRequest {
+    pub fn new(session_id: u32, control: StreamControl) -> Self {
+        Self {
+            session_id,
+            control,
+        }
+    }
+}
+
+#[derive(Encoder, Decoder, Default, Debug)]
+pub struct StreamControlResponse {
+    pub session_id: u32,
+    pub error: ErrorCode,
+}
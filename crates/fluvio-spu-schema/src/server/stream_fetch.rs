@@ -36,6 +36,9 @@ pub const AGGREGATOR_API: i16 = 13;
 // version for gzipped WASM payloads
 pub const GZIP_WASM_API: i16 = 14;
 
+// version for stream control, session id is sent back in first response
+pub const STREAM_CONTROL_API: i16 = 15;
+
 /// Fetch records continuously
 /// Output will be send back as stream
 #[derive(Decoder, Encoder, Default, Debug)]
@@ -57,10 +60,10 @@ where
 
 impl<R> Request for StreamFetchRequest<R>
 where
-    R: Debug + Decoder + Encoder,
+    R: Debug + Decoder + Default + Encoder,
 {
     const API_KEY: u16 = SpuServerApiKey::StreamFetch as u16;
-    const DEFAULT_API_VERSION: i16 = GZIP_WASM_API;
+    const DEFAULT_API_VERSION: i16 = STREAM_CONTROL_API;
     type Response = StreamFetchResponse<R>;
 }
 
@@ -15,8 +15,10 @@ use structopt::StructOpt;
 use fluvio_types::print_cli_err;
 use fluvio_types::SpuId;
 use fluvio_future::openssl::TlsAcceptor;
+use fluvio_auth::x509::validate_non_tls_endpoint;
+use fluvio_storage::encryption::SegmentCipher;
 
-use super::SpuConfig;
+use super::{SpuConfig, PrivateTlsConfig};
 
 /// cli options
 #[derive(Debug, Default, StructOpt)]
@@ -50,6 +52,19 @@ pub struct SpuOpt {
     #[structopt(long, value_name = "integer", env = "FLV_LOG_INDEX_MAX_INTERVAL_BYTES")]
     pub index_max_interval_bytes: Option<u32>,
 
+    /// AES-256 key (32 bytes or 64 hex characters), enables encryption of log segments
+    #[structopt(
+        long,
+        value_name = "key path",
+        env = "FLV_LOG_ENCRYPTION_KEY_FILE",
+        conflicts_with = "log_encryption_key_dir"
+    )]
+    pub log_encryption_key_file: Option<std::path::PathBuf>,
+
+    /// directory of `<id>.key` files, key with highest id encrypts new batches
+    #[structopt(long, value_name = "dir", env = "FLV_LOG_ENCRYPTION_KEY_DIR")]
+    pub log_encryption_key_dir: Option<std::path::PathBuf>,
+
     /// max bytes to transfer between leader and follower
     #[structopt(
         long,
@@ -59,8 +74,44 @@ pub struct SpuOpt {
     )]
     pub peer_max_bytes: u32,
 
+    /// max size of client request in bytes, connection is closed on larger request
+    #[structopt(
+        long,
+        value_name = "integer",
+        env = "FLV_MAX_FRAME_SIZE",
+        default_value = "104857600"
+    )]
+    pub max_frame_size: usize,
+
     #[structopt(flatten)]
     tls: TlsConfig,
+
+    #[structopt(
+        long = "authorization-scopes",
+        value_name = "authorization scopes path",
+        env
+    )]
+    x509_auth_scopes: Option<std::path::PathBuf>,
+
+    /// SASL credentials file, enables PLAIN and SCRAM-SHA-256 authentication
+    #[structopt(long, value_name = "sasl credentials path", env)]
+    sasl_credentials: Option<std::path::PathBuf>,
+
+    /// public key (PEM) to verify bearer tokens, enables OAUTHBEARER authentication
+    #[structopt(long, value_name = "token public key path", env)]
+    token_public_key: Option<std::path::PathBuf>,
+
+    /// algorithm of bearer tokens
+    #[structopt(long, value_name = "algorithm", default_value = "RS256")]
+    token_algorithm: String,
+
+    /// audience which must be in `aud` claim of bearer tokens
+    #[structopt(long, value_name = "audience", env)]
+    token_audience: Option<String>,
+
+    /// issuer which must be `iss` claim of bearer tokens
+    #[structopt(long, value_name = "issuer", env)]
+    token_issuer: Option<String>,
 }
 
 impl SpuOpt {
@@ -112,6 +163,14 @@ impl SpuOpt {
             config.log.index_max_interval_bytes = index_max_interval_bytes;
         }
 
+        if let Some(key_file) = self.log_encryption_key_file {
+            info!("encrypting log with key: {}", key_file.display());
+            config.log.encryption = Some(SegmentCipher::from_key_file(key_file)?);
+        } else if let Some(key_dir) = self.log_encryption_key_dir {
+            info!("encrypting log with keys in: {}", key_dir.display());
+            config.log.encryption = Some(SegmentCipher::from_key_dir(key_dir)?);
+        }
+
         if let Some(public_addr) = self.bind_public {
             info!("overriding public addr: {}", public_addr);
             config.public_endpoint = public_addr;
@@ -123,7 +182,7 @@ impl SpuOpt {
             let proxy_addr = config.public_endpoint.clone();
             debug!("using tls proxy addr: {}", proxy_addr);
             tls_port = Some(proxy_addr);
-            config.public_endpoint = self.tls.bind_non_tls_public.ok_or_else(|| {
+            config.public_endpoint = self.tls.bind_non_tls_public.clone().ok_or_else(|| {
                 IoError::new(
                     ErrorKind::NotFound,
                     "non tls addr for public must be specified",
@@ -136,7 +195,35 @@ impl SpuOpt {
             config.private_endpoint = private_addr;
         }
 
+        if self.tls.enable_private_tls {
+            config.private_tls = Some(self.tls.private_tls_config(&config.private_endpoint)?);
+            debug!("using private tls proxy addr: {}", config.private_endpoint);
+            config.private_endpoint = self.tls.bind_non_tls_private.ok_or_else(|| {
+                IoError::new(
+                    ErrorKind::NotFound,
+                    "non tls addr for private must be specified",
+                )
+            })?;
+            // identity of peer is forwarded by proxy, it must not be reachable directly
+            validate_non_tls_endpoint(&config.private_endpoint)?;
+        }
+
         config.peer_max_bytes = self.peer_max_bytes;
+        config.max_frame_size = self.max_frame_size;
+        if self.x509_auth_scopes.is_some() && !self.tls.tls {
+            return Err(IoError::new(
+                ErrorKind::InvalidInput,
+                "authorization scopes require tls",
+            ));
+        }
+        config.x509_auth_scopes = self.x509_auth_scopes;
+        config.sasl_credentials = self.sasl_credentials;
+        config.token_public_key = self.token_public_key;
+        config.token_algorithm = self.token_algorithm;
+        config.token_audience = self.token_audience;
+        config.token_issuer = self.token_issuer;
+        // validate sasl configuration
+        config.sasl_authenticator()?;
 
         Ok((config, tls_port))
     }
@@ -256,4 +343,58 @@ struct TlsConfig {
     #[structopt(long)]
     /// TLS: address of non tls public service, required
     pub bind_non_tls_public: Option<String>,
+
+    /// TLS: connect to SC and peer SPUs with client cert and require it from peers,
+    /// common name of cert must be `<spu id>` or `spu-<spu id>`
+    #[structopt(long)]
+    pub enable_private_tls: bool,
+
+    /// TLS: path to client cert of this SPU, defaults to server cert
+    #[structopt(long)]
+    pub private_cert: Option<String>,
+
+    /// TLS: path to private key of client cert, defaults to server key
+    #[structopt(long)]
+    pub private_key: Option<String>,
+
+    #[structopt(long)]
+    /// TLS: loopback address of non tls private service, required if private tls is enabled
+    pub bind_non_tls_private: Option<String>,
+
+    /// TLS: common name of SC certificate, if not set SC can use any certificate
+    /// signed by CA except one of SPU
+    #[structopt(long)]
+    pub private_sc_name: Option<String>,
+}
+
+impl TlsConfig {
+    fn private_tls_config(&self, proxy_endpoint: &str) -> Result<PrivateTlsConfig, IoError> {
+        if !self.tls || !self.enable_client_cert {
+            return Err(IoError::new(
+                ErrorKind::InvalidInput,
+                "private tls requires tls and client cert",
+            ));
+        }
+
+        let missing = |name: &str| IoError::new(ErrorKind::NotFound, format!("missing {}", name));
+        let cert = self
+            .private_cert
+            .as_ref()
+            .or_else(|| self.server_cert.as_ref())
+            .ok_or_else(|| missing("private cert"))?;
+        let key = self
+            .private_key
+            .as_ref()
+            .or_else(|| self.server_key.as_ref())
+            .ok_or_else(|| missing("private key"))?;
+        let ca_cert = self.ca_cert.as_ref().ok_or_else(|| missing("ca cert"))?;
+
+        Ok(PrivateTlsConfig {
+            proxy_endpoint: proxy_endpoint.to_owned(),
+            cert: cert.into(),
+            key: key.into(),
+            ca_cert: ca_cert.into(),
+            sc_name: self.private_sc_name.clone(),
+        })
+    }
 }
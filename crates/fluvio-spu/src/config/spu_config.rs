@@ -10,6 +10,20 @@
 
 use std::env;
 use std::path::PathBuf;
+use std::io::Error as IoError;
+use std::io::ErrorKind;
+
+use async_trait::async_trait;
+use futures_util::io::AsyncReadExt;
+use tracing::debug;
+
+use fluvio_future::net::{
+    AsConnectionFd, BoxReadConnection, BoxWriteConnection, ConnectionFd, DefaultDomainConnector,
+    DomainConnector, TcpDomainConnector, TcpStream,
+};
+use fluvio_future::openssl::{SslVerifyMode, TlsAcceptor, TlsConnector};
+use fluvio_auth::sasl::{SaslAuthenticator, DEFAULT_TOKEN_ALGORITHM};
+use fluvio_auth::x509::{X509Authenticator, X509Identity};
 
 // defaults values
 use fluvio_types::defaults::SPU_PUBLIC_PORT;
@@ -25,9 +39,12 @@ use fluvio_types::defaults::SPU_RETRY_SC_TIMEOUT_MS;
 // environment variables
 
 use fluvio_types::defaults::SPU_MIN_IN_SYNC_REPLICAS;
+use fluvio_types::defaults::SPU_REPLICA_LAG_TIME_MAX_MS;
 use fluvio_types::defaults::FLV_LOG_BASE_DIR;
 use fluvio_types::defaults::FLV_LOG_SIZE;
 use fluvio_types::SpuId;
+use fluvio_controlplane_metadata::topic::TopicConfig;
+use fluvio_storage::encryption::SegmentCipher;
 use fluvio_storage::config::{
     ConfigOption, DEFAULT_FLUSH_WRITE_COUNT, DEFAULT_FLUSH_IDLE_MSEC, DEFAULT_MAX_BATCH_SIZE,
 };
@@ -35,12 +52,15 @@ use fluvio_storage::config::{
 #[derive(Debug, PartialEq, Clone)]
 pub struct ReplicationConfig {
     pub min_in_sync_replicas: u16,
+    /// follower which has not caught up with leader within this time is considered out of sync
+    pub replica_lag_time_max_ms: u64,
 }
 
 impl Default for ReplicationConfig {
     fn default() -> Self {
         Self {
             min_in_sync_replicas: SPU_MIN_IN_SYNC_REPLICAS,
+            replica_lag_time_max_ms: SPU_REPLICA_LAG_TIME_MAX_MS,
         }
     }
 }
@@ -55,6 +75,8 @@ pub struct Log {
     pub flush_write_count: u32,
     pub flush_idle_msec: u32,
     pub max_batch_size: u32,
+    /// if set, log segments are encrypted
+    pub encryption: Option<SegmentCipher>,
 }
 
 impl Default for Log {
@@ -70,10 +92,155 @@ impl Default for Log {
             flush_write_count: DEFAULT_FLUSH_WRITE_COUNT,
             flush_idle_msec: DEFAULT_FLUSH_IDLE_MSEC,
             max_batch_size: DEFAULT_MAX_BATCH_SIZE,
+            encryption: None,
         }
     }
 }
 
+/// mutual TLS for traffic between SC and SPUs
+#[derive(Debug, PartialEq, Clone)]
+pub struct PrivateTlsConfig {
+    /// address of TLS proxy in front of private service
+    pub proxy_endpoint: String,
+    /// certificate of this SPU, common name must be `<id>` or `spu-<id>`
+    pub cert: PathBuf,
+    pub key: PathBuf,
+    /// CA which signed certificates of SC and SPUs
+    pub ca_cert: PathBuf,
+    /// common name of SC certificate, if not set any certificate
+    /// signed by CA which doesn't belong to SPU is accepted as SC
+    pub sc_name: Option<String>,
+}
+
+impl PrivateTlsConfig {
+    /// acceptor for private proxy, peers must present certificate signed by CA
+    pub fn try_build_tls_acceptor(&self) -> Result<TlsAcceptor, IoError> {
+        let builder = TlsAcceptor::builder()
+            .map_err(|err| err.into_io_error())?
+            .with_ssl_verify_mode(SslVerifyMode::PEER)
+            .with_ca_from_pem_file(&self.ca_cert)
+            .map_err(|err| err.into_io_error())?
+            .with_certifiate_and_key_from_pem_files(&self.cert, &self.key)
+            .map_err(|err| err.into_io_error())?;
+
+        Ok(builder.build())
+    }
+
+    /// connector to SC or leader, presents certificate of this SPU.
+    /// peers are addressed by ip, so instead of hostname common name of peer is verified
+    pub fn try_build_connector(
+        &self,
+        endpoint: &str,
+        peer: PrivatePeer,
+    ) -> Result<DomainConnector, IoError> {
+        use fluvio_future::openssl::certs::{IdentityBuilder, X509PemBuilder, PrivateKeyBuilder};
+        use fluvio_future::net::certs::CertBuilder;
+
+        let builder = TlsConnector::builder()
+            .map_err(|err| IoError::new(ErrorKind::InvalidData, err))?
+            .with_identity(IdentityBuilder::from_x509(
+                X509PemBuilder::from_path(&self.cert)?,
+                PrivateKeyBuilder::from_path(&self.key)?,
+            )?)
+            .map_err(|err| IoError::new(ErrorKind::InvalidData, err))?
+            .add_root_certificate(X509PemBuilder::from_path(&self.ca_cert)?.build()?)
+            .map_err(|err| IoError::new(ErrorKind::InvalidData, err))?
+            .with_hostname_vertification_disabled()
+            .map_err(|err| IoError::new(ErrorKind::InvalidData, err))?;
+
+        let domain = endpoint.split(':').next().unwrap_or(endpoint).to_owned();
+        Ok(Box::new(PeerTlsConnector {
+            connector: builder.build(),
+            domain,
+            peer,
+            sc_name: self.sc_name.clone(),
+        }))
+    }
+}
+
+/// expected peer of private connection
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum PrivatePeer {
+    Sc,
+    Spu(SpuId),
+}
+
+impl PrivatePeer {
+    /// check common name of certificate presented by peer
+    pub fn verify(&self, common_name: &str, sc_name: Option<&str>) -> Result<(), IoError> {
+        let identity = X509Identity::new(common_name.to_owned(), vec![]);
+        let verified = match (self, sc_name) {
+            (Self::Spu(id), _) => identity.is_spu(*id),
+            (Self::Sc, Some(sc_name)) => common_name == sc_name,
+            // certificate of SPU can't be used to impersonate SC
+            (Self::Sc, None) => common_name
+                .strip_prefix("spu-")
+                .unwrap_or(common_name)
+                .parse::<SpuId>()
+                .is_err(),
+        };
+
+        if verified {
+            Ok(())
+        } else {
+            Err(IoError::new(
+                ErrorKind::PermissionDenied,
+                format!(
+                    "peer certificate {} doesn't belong to {:?}",
+                    common_name, self
+                ),
+            ))
+        }
+    }
+}
+
+/// TLS connector which verifies common name of peer after handshake
+#[derive(Clone)]
+struct PeerTlsConnector {
+    connector: TlsConnector,
+    domain: String,
+    peer: PrivatePeer,
+    sc_name: Option<String>,
+}
+
+#[async_trait]
+impl TcpDomainConnector for PeerTlsConnector {
+    async fn connect(
+        &self,
+        addr: &str,
+    ) -> Result<(BoxWriteConnection, BoxReadConnection, ConnectionFd), IoError> {
+        let tcp_stream = TcpStream::connect(addr).await?;
+        let fd = tcp_stream.as_connection_fd();
+        let tls_stream = self
+            .connector
+            .connect(&self.domain, tcp_stream)
+            .await
+            .map_err(|err| err.into_io_error())?;
+
+        let certificate = tls_stream
+            .peer_certificate()
+            .ok_or_else(|| IoError::new(ErrorKind::NotFound, "peer has no certificate"))?
+            .to_der()
+            .map_err(|err| err.into_io_error())?;
+        let common_name = X509Authenticator::principal_from_raw_certificate(&certificate)?;
+        self.peer.verify(&common_name, self.sc_name.as_deref())?;
+        debug!(%common_name, peer = ?self.peer, "verified private peer");
+
+        let (read, write) = tls_stream.split();
+        Ok((Box::new(write), Box::new(read), fd))
+    }
+
+    fn new_domain(&self, domain: String) -> DomainConnector {
+        let mut connector = self.clone();
+        connector.domain = domain;
+        Box::new(connector)
+    }
+
+    fn domain(&self) -> &str {
+        &self.domain
+    }
+}
+
 /// streaming processing unit configuration file
 #[derive(Debug, PartialEq, Clone)]
 pub struct SpuConfig {
@@ -94,6 +261,25 @@ pub struct SpuConfig {
     pub log: Log,
 
     pub peer_max_bytes: u32,
+
+    /// max size of client request, connection is closed on larger request
+    pub max_frame_size: usize,
+
+    /// scopes of x509 identities, if set produce and consume are authorized
+    pub x509_auth_scopes: Option<PathBuf>,
+
+    /// SASL credentials file, if set clients are authenticated with PLAIN or SCRAM-SHA-256
+    pub sasl_credentials: Option<PathBuf>,
+    /// public key to verify bearer tokens, if set clients are authenticated with OAUTHBEARER
+    pub token_public_key: Option<PathBuf>,
+    pub token_algorithm: String,
+    /// if set, bearer token `aud` claim must contain it
+    pub token_audience: Option<String>,
+    /// if set, bearer token `iss` claim must match it
+    pub token_issuer: Option<String>,
+
+    /// if set, SC and peer SPUs are connected with mutual TLS
+    pub private_tls: Option<PrivateTlsConfig>,
 }
 
 impl Default for SpuConfig {
@@ -108,6 +294,14 @@ impl Default for SpuConfig {
             sc_retry_ms: SPU_RETRY_SC_TIMEOUT_MS,
             log: Log::default(),
             peer_max_bytes: fluvio_storage::FileReplica::PREFER_MAX_LEN,
+            max_frame_size: fluvio_service::DEFAULT_MAX_FRAME_SIZE,
+            x509_auth_scopes: None,
+            sasl_credentials: None,
+            token_public_key: None,
+            token_algorithm: DEFAULT_TOKEN_ALGORITHM.to_owned(),
+            token_audience: None,
+            token_issuer: None,
+            private_tls: None,
         }
     }
 }
@@ -117,6 +311,38 @@ impl SpuConfig {
         self.id
     }
 
+    /// SASL authenticator, None if neither credentials nor token public key is configured.
+    /// SASL can't be used with authorization scopes, TLS proxy would send identity
+    /// of client certificate instead of SASL handshake
+    pub fn sasl_authenticator(&self) -> Result<Option<SaslAuthenticator>, IoError> {
+        let authenticator = SaslAuthenticator::load(
+            self.sasl_credentials.as_deref(),
+            self.token_public_key.as_deref(),
+            &self.token_algorithm,
+            self.token_audience.as_deref(),
+            self.token_issuer.as_deref(),
+        )?;
+        if authenticator.is_some() && self.x509_auth_scopes.is_some() {
+            return Err(IoError::new(
+                ErrorKind::InvalidInput,
+                "sasl authentication can't be used with authorization scopes",
+            ));
+        }
+        Ok(authenticator)
+    }
+
+    /// connector to private service of SC or peer SPU
+    pub fn private_connector(
+        &self,
+        endpoint: &str,
+        peer: PrivatePeer,
+    ) -> Result<DomainConnector, IoError> {
+        match &self.private_tls {
+            Some(tls) => tls.try_build_connector(endpoint, peer),
+            None => Ok(Box::new(DefaultDomainConnector::new())),
+        }
+    }
+
     #[allow(unused)]
     pub fn rack(&self) -> &Option<String> {
         &self.rack
@@ -156,12 +382,120 @@ impl From<&SpuConfig> for ConfigOption {
             .flush_write_count(log.flush_write_count)
             .flush_idle_msec(log.flush_idle_msec)
             .max_batch_size(log.max_batch_size)
+            .encryption(log.encryption.clone())
             .build()
     }
 }
 
+/// storage configuration which can be overridden by topic configuration
+pub trait TopicStorageConfig {
+    fn update_from_topic_config(&mut self, config: &TopicConfig);
+}
+
+impl TopicStorageConfig for ConfigOption {
+    fn update_from_topic_config(&mut self, config: &TopicConfig) {
+        if let Some(segment_max_bytes) = config.segment_max_bytes() {
+            self.segment_max_bytes = segment_max_bytes;
+        }
+        if let Some(index_max_bytes) = config.index_max_bytes() {
+            self.index_max_bytes = index_max_bytes;
+        }
+        if let Some(index_max_interval_bytes) = config.index_max_interval_bytes() {
+            self.index_max_interval_bytes = index_max_interval_bytes;
+        }
+        if let Some(flush_write_count) = config.flush_write_count() {
+            self.flush_write_count = flush_write_count;
+        }
+        if let Some(flush_idle_msec) = config.flush_idle_msec() {
+            self.flush_idle_msec = flush_idle_msec;
+        }
+        if let Some(max_batch_size) = config.max_batch_size() {
+            self.max_batch_size = max_batch_size;
+        }
+    }
+}
+
 impl From<&SpuConfig> for ReplicationConfig {
     fn from(config: &SpuConfig) -> ReplicationConfig {
         config.replication.clone()
     }
 }
+
+#[cfg(test)]
+mod test {
+
+    use std::io::ErrorKind;
+
+    use fluvio_controlplane_metadata::topic::{
+        TopicConfig, SEGMENT_MAX_BYTES, FLUSH_IDLE_MSEC, MAX_BATCH_SIZE,
+    };
+    use fluvio_storage::config::{ConfigOption, DEFAULT_MAX_BATCH_SIZE};
+
+    use super::{TopicStorageConfig, PrivatePeer};
+
+    #[test]
+    fn test_topic_storage_config_override() {
+        let mut config = TopicConfig::default();
+        config.insert(SEGMENT_MAX_BYTES, "1000");
+        config.insert(FLUSH_IDLE_MSEC, "50");
+
+        let default_option = ConfigOption::default();
+        let mut option = ConfigOption::default();
+        option.update_from_topic_config(&config);
+
+        assert_eq!(option.segment_max_bytes, 1000);
+        assert_eq!(option.flush_idle_msec, 50);
+        // keys not in topic config keep spu values
+        assert_eq!(option.index_max_bytes, default_option.index_max_bytes);
+        assert_eq!(
+            option.index_max_interval_bytes,
+            default_option.index_max_interval_bytes
+        );
+        assert_eq!(option.flush_write_count, default_option.flush_write_count);
+        assert_eq!(option.max_batch_size, DEFAULT_MAX_BATCH_SIZE);
+    }
+
+    #[test]
+    fn test_topic_storage_config_empty() {
+        let mut option = ConfigOption {
+            segment_max_bytes: 2000,
+            ..Default::default()
+        };
+        option.update_from_topic_config(&TopicConfig::default());
+        assert_eq!(option.segment_max_bytes, 2000);
+
+        // invalid values are ignored
+        let mut config = TopicConfig::default();
+        config.insert(MAX_BATCH_SIZE, "big");
+        option.update_from_topic_config(&config);
+        assert_eq!(option.max_batch_size, DEFAULT_MAX_BATCH_SIZE);
+    }
+
+    #[test]
+    fn test_private_peer_accepted() {
+        assert!(PrivatePeer::Spu(5001).verify("spu-5001", None).is_ok());
+        assert!(PrivatePeer::Spu(5001).verify("5001", None).is_ok());
+        assert!(PrivatePeer::Sc
+            .verify("fluvio-sc", Some("fluvio-sc"))
+            .is_ok());
+        assert!(PrivatePeer::Sc.verify("fluvio.io", None).is_ok());
+    }
+
+    #[test]
+    fn test_private_peer_rejected() {
+        // other SPU can't act as leader
+        assert!(PrivatePeer::Spu(5001).verify("spu-5002", None).is_err());
+        assert!(PrivatePeer::Spu(5001).verify("fluvio-sc", None).is_err());
+        // SPU can't act as SC
+        assert!(PrivatePeer::Sc.verify("spu-5001", None).is_err());
+        assert!(PrivatePeer::Sc.verify("5001", None).is_err());
+        assert!(PrivatePeer::Sc.verify("other", Some("fluvio-sc")).is_err());
+        assert_eq!(
+            PrivatePeer::Sc
+                .verify("spu-5001", Some("fluvio-sc"))
+                .unwrap_err()
+                .kind(),
+            ErrorKind::PermissionDenied
+        );
+    }
+}
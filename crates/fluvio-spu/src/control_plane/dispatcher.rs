@@ -14,11 +14,18 @@ use fluvio_controlplane::InternalSpuRequest;
 use fluvio_controlplane::RegisterSpuRequest;
 use fluvio_controlplane::{UpdateSpuRequest, UpdateLrsRequest};
 use fluvio_controlplane::UpdateReplicaRequest;
+use fluvio_controlplane::UpdateAuthPolicyRequest;
+use fluvio_controlplane::UpdateQuotaRequest;
+use fluvio_controlplane::UpdateSchemaRequest;
+use fluvio_controlplane::{WriteTxnMarkersRequest, TxnMarkersWrittenRequest};
+use fluvio_auth::basic::BasicRbacPolicy;
 use dataplane::api::RequestMessage;
 use fluvio_socket::{FluvioSocket, SocketError, FluvioSink};
 use fluvio_storage::FileReplica;
 use crate::core::SharedGlobalContext;
+use crate::config::PrivatePeer;
 use crate::InternalServerError;
+use crate::services::transaction::write_markers;
 
 use super::message_sink::{SharedStatusUpdate};
 
@@ -150,6 +157,21 @@ impl ScDispatcher<FileReplica> {
                                 break;
                             }
                         },
+                        Some(Ok(InternalSpuRequest::UpdateAuthPolicyRequest(request))) => {
+                            if let Err(err) = self.handle_update_auth_policy_request(request).await {
+                                error!("error handling update auth policy request: {}", err);
+                                break;
+                            }
+                        },
+                        Some(Ok(InternalSpuRequest::UpdateQuotaRequest(request))) => {
+                            self.handle_update_quota_request(request);
+                        },
+                        Some(Ok(InternalSpuRequest::UpdateSchemaRequest(request))) => {
+                            self.handle_update_schema_request(request);
+                        },
+                        Some(Ok(InternalSpuRequest::WriteTxnMarkersRequest(request))) => {
+                            self.handle_write_txn_markers_request(request, &mut sink).await?;
+                        },
                         Some(_) => {
                             debug!("no more sc msg content, end");
                             break;
@@ -243,7 +265,17 @@ impl ScDispatcher<FileReplica> {
                 "trying to create socket to sc",
 
             );
-            match FluvioSocket::connect(&sc_endpoint).await {
+            let result = match self
+                .ctx
+                .config()
+                .private_connector(&sc_endpoint, PrivatePeer::Sc)
+            {
+                Ok(connector) => {
+                    FluvioSocket::connect_with_connector(&sc_endpoint, connector.as_ref()).await
+                }
+                Err(err) => Err(err.into()),
+            };
+            match result {
                 Ok(socket) => {
                     info!(spu_id, "connected to sc for spu");
                     self.counter.reconnect += 1;
@@ -324,4 +356,70 @@ impl ScDispatcher<FileReplica> {
 
         Ok(())
     }
+
+    /// Authorization policy sent by SC
+    #[instrument(skip(self, req_msg), name = "update_auth_policy_request")]
+    async fn handle_update_auth_policy_request(
+        &mut self,
+        req_msg: RequestMessage<UpdateAuthPolicyRequest>,
+    ) -> Result<(), IoError> {
+        let (_, request) = req_msg.get_header_request();
+
+        let policy: Option<BasicRbacPolicy> = match request.policy {
+            Some(policy) => Some(serde_json::from_str(&policy)?),
+            None => None,
+        };
+
+        debug!(policy = policy.is_some(), "received auth policy");
+
+        *self.ctx.auth_policy().write().await = policy.into();
+
+        Ok(())
+    }
+
+    /// Quotas sent by SC
+    #[instrument(skip(self, req_msg), name = "update_quota_request")]
+    fn handle_update_quota_request(&mut self, req_msg: RequestMessage<UpdateQuotaRequest>) {
+        let (_, request) = req_msg.get_header_request();
+
+        debug!(quotas = request.quotas.len(), "received quotas");
+
+        self.ctx.quotas().update(request.quotas);
+    }
+
+    /// Schema subjects sent by SC
+    #[instrument(skip(self, req_msg), name = "update_schema_request")]
+    fn handle_update_schema_request(&mut self, req_msg: RequestMessage<UpdateSchemaRequest>) {
+        let (_, request) = req_msg.get_header_request();
+
+        debug!(
+            subjects = request.subjects.len(),
+            "received schema subjects"
+        );
+
+        self.ctx.schemas().update(request.subjects);
+    }
+
+    /// Transaction markers sent by SC, written markers are confirmed to SC
+    #[instrument(skip(self, req_msg, sc_sink), name = "write_txn_markers_request")]
+    async fn handle_write_txn_markers_request(
+        &mut self,
+        req_msg: RequestMessage<WriteTxnMarkersRequest>,
+        sc_sink: &mut FluvioSink,
+    ) -> Result<(), SocketError> {
+        let (_, request) = req_msg.get_header_request();
+
+        debug!(
+            markers = request.markers.len(),
+            "received transaction markers"
+        );
+
+        let written = write_markers(&self.ctx, &request.markers).await;
+        if written.is_empty() {
+            return Ok(());
+        }
+
+        let message = RequestMessage::new_request(TxnMarkersWrittenRequest::new(written));
+        sc_sink.send_request(&message).await
+    }
 }
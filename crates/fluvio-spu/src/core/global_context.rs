@@ -20,6 +20,11 @@ use crate::replication::leader::{
 use crate::services::public::StreamPublishers;
 use crate::control_plane::{StatusMessageSink, SharedStatusUpdate};
 use crate::smartstream::SmartStreamEngine;
+use crate::services::auth::SharedAuthPolicy;
+use crate::services::quota::QuotaManager;
+use crate::services::schema::SchemaValidator;
+use crate::services::transaction::TransactionState;
+use fluvio_auth::sasl::SaslAuthenticator;
 
 use super::spus::SharedSpuLocalStore;
 use super::SharedReplicaLocalStore;
@@ -40,6 +45,11 @@ pub struct GlobalContext<S> {
     spu_followers: SharedSpuUpdates,
     status_update: SharedStatusUpdate,
     sm_engine: SmartStreamEngine,
+    auth_policy: SharedAuthPolicy,
+    sasl_authenticator: Option<Arc<SaslAuthenticator>>,
+    quotas: Arc<QuotaManager>,
+    schemas: Arc<SchemaValidator>,
+    transactions: Arc<TransactionState>,
 }
 
 // -----------------------------------
@@ -55,6 +65,10 @@ where
     }
 
     pub fn new(spu_config: SpuConfig) -> Self {
+        let sasl_authenticator = spu_config
+            .sasl_authenticator()
+            .expect("sasl authenticator")
+            .map(Arc::new);
         GlobalContext {
             spu_localstore: SpuLocalStore::new_shared(),
             replica_localstore: ReplicaStore::new_shared(),
@@ -65,6 +79,11 @@ where
             spu_followers: FollowerNotifier::shared(),
             status_update: StatusMessageSink::shared(),
             sm_engine: SmartStreamEngine::default(),
+            auth_policy: SharedAuthPolicy::default(),
+            sasl_authenticator,
+            quotas: Arc::new(QuotaManager::default()),
+            schemas: Arc::new(SchemaValidator::default()),
+            transactions: Arc::new(TransactionState::default()),
         }
     }
 
@@ -133,6 +152,31 @@ where
     pub fn smartstream_owned(&self) -> SmartStreamEngine {
         self.sm_engine.clone()
     }
+
+    /// authorization policy received from SC
+    pub fn auth_policy(&self) -> &SharedAuthPolicy {
+        &self.auth_policy
+    }
+
+    /// SASL authenticator for client connections, if configured
+    pub fn sasl_authenticator(&self) -> &Option<Arc<SaslAuthenticator>> {
+        &self.sasl_authenticator
+    }
+
+    /// throughput quotas received from SC
+    pub fn quotas(&self) -> &QuotaManager {
+        &self.quotas
+    }
+
+    /// schema subjects received from SC, validated on produce
+    pub fn schemas(&self) -> &SchemaValidator {
+        &self.schemas
+    }
+
+    /// epochs of transactional producers and markers written for SC
+    pub fn transactions(&self) -> &TransactionState {
+        &self.transactions
+    }
 }
 
 mod file_replica {
@@ -176,7 +220,8 @@ mod file_replica {
                     "old follower replica exists, promoting to leader"
                 );
 
-                self.leaders_state()
+                let leader = self
+                    .leaders_state()
                     .promote_follower(
                         self.config().into(),
                         follower_replica,
@@ -184,6 +229,8 @@ mod file_replica {
                         self.status_update_owned(),
                     )
                     .await;
+                // markers and epochs were replicated from previous leader
+                self.transactions().load_replica(&leader).await;
             } else {
                 error!("follower replica {} didn't exists!", old_replica.id);
             }
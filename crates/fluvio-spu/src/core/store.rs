@@ -105,7 +105,7 @@ where
 
 impl<S> LocalStore<S>
 where
-    S: Spec + Clone + PartialEq + Debug + Encoder + Decoder,
+    S: Spec + Clone + PartialEq + Debug + Encoder + Decoder + Default,
     S::Key: Display,
 {
     /// apply either all or changes, all takes precedent
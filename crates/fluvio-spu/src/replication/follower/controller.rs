@@ -7,6 +7,7 @@ use adaptive_backoff::prelude::*;
 use fluvio_types::SpuId;
 use fluvio_types::event::offsets::OffsetPublisher;
 use crate::core::{FileGlobalContext};
+use crate::config::PrivatePeer;
 
 use super::{FollowersState};
 use super::state::{SharedFollowersState, FollowerReplicaState};
@@ -325,7 +326,17 @@ mod inner {
                     "trying connect to leader",
                 );
 
-                match FluvioSocket::connect(&leader_endpoint).await {
+                let result = match self
+                    .config
+                    .private_connector(&leader_endpoint, PrivatePeer::Spu(self.leader))
+                {
+                    Ok(connector) => {
+                        FluvioSocket::connect_with_connector(&leader_endpoint, connector.as_ref())
+                            .await
+                    }
+                    Err(err) => Err(err.into()),
+                };
+                match result {
                     Ok(socket) => {
                         debug!("connected to leader");
                         return socket;
@@ -10,7 +10,9 @@ use fluvio_controlplane_metadata::partition::{Replica, ReplicaKey};
 use dataplane::record::RecordSet;
 use dataplane::Offset;
 use fluvio_storage::{FileReplica, StorageError, ReplicaStorage};
+use fluvio_storage::config::ConfigOption;
 use fluvio_types::SpuId;
+use crate::config::TopicStorageConfig;
 use crate::replication::leader::ReplicaOffsetRequest;
 use crate::core::{FileGlobalContext};
 use crate::storage::SharableReplicaStorage;
@@ -90,8 +92,10 @@ impl FollowersState<FileReplica> {
                     "creating new follower state"
                 );
 
+                let mut storage_config: ConfigOption = ctx.config().into();
+                storage_config.update_from_topic_config(&replica.config);
                 let replica_state =
-                    FollowerReplicaState::create(leader, replica.id, ctx.config().into()).await?;
+                    FollowerReplicaState::create(leader, replica.id, storage_config).await?;
 
                 entry.insert(replica_state.clone());
                 self.groups.check_new(ctx, leader).await;
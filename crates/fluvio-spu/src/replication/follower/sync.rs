@@ -6,7 +6,7 @@ use std::fmt;
 use std::io::Error as IoError;
 use std::marker::PhantomData;
 
-use bytes::BytesMut;
+use bytes::{Bytes, BytesMut};
 use tracing::trace;
 
 use dataplane::core::{Encoder, Decoder, Version};
@@ -30,14 +30,14 @@ pub type PeerFileTopicResponse = PeerFetchableTopicResponse<FileRecordSet>;
 #[derive(Default, Encoder, Decoder, Debug)]
 pub struct SyncRequest<R>
 where
-    R: Encoder + Decoder + Debug,
+    R: Encoder + Decoder + Default + Debug,
 {
     pub topics: Vec<PeerFetchableTopicResponse<R>>,
 }
 
 impl<R> fmt::Display for SyncRequest<R>
 where
-    R: Encoder + Decoder + Debug + Display,
+    R: Encoder + Decoder + Default + Debug + Display,
 {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "[")?;
@@ -53,7 +53,7 @@ where
 // TODO: come up with unify encoding
 impl<R> Request for SyncRequest<R>
 where
-    R: Encoder + Decoder + Debug,
+    R: Encoder + Decoder + Default + Debug,
 {
     const API_KEY: u16 = FollowerPeerApiEnum::SyncRecords as u16;
     const DEFAULT_API_VERSION: i16 = 7;
@@ -165,6 +165,10 @@ impl SlicePartitionResponse for PeerFilePartitionResponse {
         self.records = slice.into();
     }
 
+    fn set_decrypted_slice(&mut self, slice: AsyncFileSlice, records: Bytes) {
+        self.records = FileRecordSet::from_memory(slice, records);
+    }
+
     fn set_error_code(&mut self, error: ErrorCode) {
         self.error = error;
     }
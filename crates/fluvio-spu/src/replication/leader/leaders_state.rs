@@ -82,6 +82,7 @@ impl ReplicaLeadersState<FileReplica> {
 
         let leader_replica =
             LeaderReplicaState::create(replica, ctx.config(), status_update).await?;
+        ctx.transactions().load_replica(&leader_replica).await;
         self.insert_leader(replica_id, leader_replica.clone()).await;
         Ok(leader_replica)
     }
@@ -3,6 +3,7 @@ use std::{
     collections::{BTreeMap, HashSet},
     ops::{Deref, DerefMut},
     sync::Arc,
+    time::{Duration, Instant},
 };
 use std::iter::FromIterator;
 use std::fmt;
@@ -19,7 +20,7 @@ use fluvio_storage::{FileReplica, StorageError, ReplicaStorage, OffsetInfo};
 use fluvio_types::{SpuId};
 
 use crate::{
-    config::{ReplicationConfig},
+    config::{ReplicationConfig, TopicStorageConfig},
     control_plane::SharedStatusUpdate,
 };
 use crate::replication::follower::sync::{PeerFileTopicResponse, PeerFilePartitionResponse};
@@ -37,6 +38,7 @@ pub struct LeaderReplicaState<S> {
     storage: SharableReplicaStorage<S>,
     config: ReplicationConfig,
     followers: Arc<RwLock<BTreeMap<SpuId, OffsetInfo>>>,
+    follower_sync_times: Arc<RwLock<BTreeMap<SpuId, Instant>>>,
     status_update: SharedStatusUpdate,
 }
 
@@ -47,6 +49,7 @@ impl<S> Clone for LeaderReplicaState<S> {
             storage: self.storage.clone(),
             config: self.config.clone(),
             followers: self.followers.clone(),
+            follower_sync_times: self.follower_sync_times.clone(),
             in_sync_replica: self.in_sync_replica,
             status_update: self.status_update.clone(),
         }
@@ -115,6 +118,7 @@ where
             storage: inner,
             config,
             followers: Arc::new(RwLock::new(followers)),
+            follower_sync_times: Arc::new(RwLock::new(BTreeMap::new())),
             in_sync_replica,
             status_update,
         }
@@ -128,9 +132,11 @@ where
     ) -> Result<LeaderReplicaState<S>, StorageError>
     where
         ReplicationConfig: From<&'a C>,
-        S::Config: From<&'a C>,
+        S::Config: From<&'a C> + TopicStorageConfig,
     {
-        let inner = SharableReplicaStorage::create(replica.id.clone(), config.into()).await?;
+        let mut storage_config: S::Config = config.into();
+        storage_config.update_from_topic_config(&replica.config);
+        let inner = SharableReplicaStorage::create(replica.id.clone(), storage_config).await?;
 
         let leader_replica = Self::new(replica, config.into(), status_update, inner);
         leader_replica.update_status().await;
@@ -177,6 +183,12 @@ where
         let mut followers = self.followers.write().await;
         let update = if let Some(current_follow_info) = followers.get_mut(&follower_id) {
             if current_follow_info.update(&follower_pos) {
+                if follower_pos.leo == leader_pos.leo {
+                    self.follower_sync_times
+                        .write()
+                        .await
+                        .insert(follower_id, Instant::now());
+                }
                 // if our leo and hw is same there is no need to recompute hw
                 if !leader_pos.is_committed() {
                     if let Some(hw) = compute_hw(&leader_pos, self.in_sync_replica, &followers) {
@@ -210,6 +222,39 @@ where
         update
     }
 
+    /// min in sync replicas required by topic
+    pub fn min_in_sync_replicas(&self) -> u16 {
+        self.replica.replication_policy.min_in_sync_replicas
+    }
+
+    /// number of in sync replicas including leader.
+    /// follower is in sync if it has same leo as leader or it has caught up within max lag time
+    pub async fn in_sync_replica_count(&self) -> u16 {
+        let leader_leo = self.leo();
+        let max_lag = Duration::from_millis(self.config.replica_lag_time_max_ms);
+        let now = Instant::now();
+
+        let followers = self.followers.read().await;
+        let mut sync_times = self.follower_sync_times.write().await;
+        let mut count = 1;
+        for (follower_id, follower_info) in followers.iter() {
+            if follower_info.is_valid() && follower_info.leo == leader_leo {
+                sync_times.insert(*follower_id, now);
+                count += 1;
+            } else if let Some(sync_time) = sync_times.get(follower_id) {
+                if now.duration_since(*sync_time) <= max_lag {
+                    count += 1;
+                }
+            }
+        }
+        count
+    }
+
+    /// check if there are enough in sync replicas to accept new records
+    pub async fn has_min_in_sync_replicas(&self) -> bool {
+        self.in_sync_replica_count().await >= self.min_in_sync_replicas()
+    }
+
     /// compute follower that needs to be updated
     /// based on leader's state
     pub async fn follower_updates(
@@ -670,6 +715,7 @@ mod test_leader {
     use async_trait::async_trait;
 
     use fluvio_controlplane_metadata::partition::{ReplicaKey, Replica};
+    use fluvio_controlplane_metadata::topic::TopicConfig;
     use fluvio_storage::{ReplicaStorage, ReplicaStorageConfig, OffsetInfo};
     use dataplane::Offset;
     use dataplane::fixture::{create_recordset};
@@ -688,6 +734,10 @@ mod test_leader {
 
     impl ReplicaStorageConfig for MockConfig {}
 
+    impl TopicStorageConfig for MockConfig {
+        fn update_from_topic_config(&mut self, _config: &TopicConfig) {}
+    }
+
     #[derive(Default)]
     struct MockStorage {
         pos: OffsetInfo,
@@ -783,6 +833,43 @@ mod test_leader {
         assert_eq!(state.in_sync_replica, 1);
     }
 
+    #[fluvio_future::test]
+    async fn test_leader_min_in_sync_replicas() {
+        let leader_config = SpuConfig {
+            id: 5000,
+            ..Default::default()
+        };
+
+        let notifier = FollowerNotifier::shared();
+
+        let mut replica = Replica::new(("test", 1), 5000, vec![5000, 5001, 5002]);
+        replica.replication_policy.min_in_sync_replicas = 2;
+        let state: LeaderReplicaState<MockStorage> =
+            LeaderReplicaState::create(replica, &leader_config, StatusMessageSink::shared())
+                .await
+                .expect("state");
+
+        // followers have not reported yet, only leader is in sync
+        assert_eq!(state.in_sync_replica_count().await, 1);
+        assert!(!state.has_min_in_sync_replicas().await);
+
+        // 5001 caught up with leader
+        assert!(
+            state
+                .update_states_from_followers(5001, OffsetInfo { leo: 0, hw: 0 }, &notifier)
+                .await
+        );
+        assert_eq!(state.in_sync_replica_count().await, 2);
+        assert!(state.has_min_in_sync_replicas().await);
+
+        // 5001 is still in sync right after leader has written new records
+        state
+            .write_record_set(&mut create_recordset(10), &notifier)
+            .await
+            .expect("write");
+        assert_eq!(state.in_sync_replica_count().await, 2);
+    }
+
     #[fluvio_future::test]
     async fn test_follower_update() {
         let leader_config = SpuConfig {
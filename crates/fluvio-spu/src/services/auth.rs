@@ -0,0 +1,277 @@
+//!
+//! # SPU Authorization
+//!
+//! Authorize produce and consume requests.
+//! Identity of client is sent by TLS proxy or established by SASL handshake,
+//! policy is received from SC.
+//! When authorization is enabled, requests are denied until SC has sent policy.
+//! If SC has no policy, every request is allowed.
+//!
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use async_lock::RwLock;
+use tracing::{trace, instrument};
+
+use fluvio_auth::{AuthContext, Authorization, TypeAction, InstanceAction, AuthError, Identity};
+use fluvio_auth::basic::{Action, BasicRbacPolicy};
+use fluvio_auth::x509::X509Identity;
+use fluvio_auth::sasl::SaslAuthenticator;
+use fluvio_controlplane_metadata::extended::ObjectType;
+use fluvio_socket::FluvioSocket;
+
+/// policy distributed by SC
+pub type SharedAuthPolicy = Arc<RwLock<ReceivedPolicy>>;
+
+/// state of policy received from SC
+#[derive(Debug, Clone)]
+pub enum ReceivedPolicy {
+    /// SC has not sent policy yet, every request is denied
+    Pending,
+    /// SC has no policy, every request is allowed
+    None,
+    Policy(BasicRbacPolicy),
+}
+
+impl Default for ReceivedPolicy {
+    fn default() -> Self {
+        Self::Pending
+    }
+}
+
+impl From<Option<BasicRbacPolicy>> for ReceivedPolicy {
+    fn from(policy: Option<BasicRbacPolicy>) -> Self {
+        match policy {
+            Some(policy) => Self::Policy(policy),
+            None => Self::None,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct SpuAuthorization {
+    policy: SharedAuthPolicy,
+    x509: bool,
+    sasl: Option<Arc<SaslAuthenticator>>,
+}
+
+impl SpuAuthorization {
+    /// authorization is enabled if identity is established by x509 or SASL
+    pub fn is_enabled(&self) -> bool {
+        self.x509 || self.sasl.is_some()
+    }
+
+    /// if sasl is set, identity is established by SASL handshake,
+    /// otherwise if x509 is set, identity is read from connection.
+    /// `SpuConfig` rejects using both
+    pub fn new(policy: SharedAuthPolicy, x509: bool, sasl: Option<Arc<SaslAuthenticator>>) -> Self {
+        Self { policy, x509, sasl }
+    }
+}
+
+#[async_trait]
+impl Authorization for SpuAuthorization {
+    type Context = SpuAuthContext;
+
+    #[instrument(level = "trace", skip(self, socket))]
+    async fn create_auth_context(
+        &self,
+        socket: &mut FluvioSocket,
+    ) -> Result<Self::Context, AuthError> {
+        let identity: Option<Box<dyn Identity>> = if let Some(sasl) = &self.sasl {
+            Some(Box::new(sasl.authenticate(socket).await?))
+        } else if self.x509 {
+            Some(Box::new(
+                X509Identity::create_from_connection(socket).await?,
+            ))
+        } else {
+            None
+        };
+
+        Ok(SpuAuthContext {
+            enabled: self.is_enabled(),
+            identity,
+            policy: self.policy.clone(),
+        })
+    }
+}
+
+/// Authorization context of a client connection
+#[derive(Debug)]
+pub struct SpuAuthContext {
+    enabled: bool,
+    identity: Option<Box<dyn Identity>>,
+    policy: SharedAuthPolicy,
+}
+
+impl SpuAuthContext {
+    async fn evaluate(
+        &self,
+        action: Action,
+        ty: ObjectType,
+        instance: Option<&str>,
+    ) -> Result<bool, AuthError> {
+        if !self.enabled {
+            return Ok(true);
+        }
+
+        let identity = match &self.identity {
+            Some(identity) => identity.as_ref(),
+            None => {
+                trace!("no identity, denied");
+                return Ok(false);
+            }
+        };
+
+        match &*self.policy.read().await {
+            ReceivedPolicy::Policy(policy) => {
+                let allowed = policy.evaluate(action, ty, instance, identity).await?;
+                trace!(
+                    principal = identity.principal(),
+                    allowed,
+                    "evaluated policy"
+                );
+                Ok(allowed)
+            }
+            ReceivedPolicy::None => {
+                trace!(
+                    principal = identity.principal(),
+                    "SC has no policy, allowed"
+                );
+                Ok(true)
+            }
+            ReceivedPolicy::Pending => {
+                trace!(
+                    principal = identity.principal(),
+                    "no policy from SC yet, denied"
+                );
+                Ok(false)
+            }
+        }
+    }
+}
+
+#[async_trait]
+impl AuthContext for SpuAuthContext {
+    async fn allow_type_action(
+        &self,
+        ty: ObjectType,
+        action: TypeAction,
+    ) -> Result<bool, AuthError> {
+        self.evaluate(action.into(), ty, None).await
+    }
+
+    async fn allow_instance_action(
+        &self,
+        ty: ObjectType,
+        action: InstanceAction,
+        key: &str,
+    ) -> Result<bool, AuthError> {
+        self.evaluate(action.into(), ty, Some(key)).await
+    }
+
+    fn principal(&self) -> Option<&str> {
+        self.identity.as_ref().map(|identity| identity.principal())
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    use std::collections::HashMap;
+
+    use super::*;
+
+    fn auth_context(identity: Option<X509Identity>, policy: ReceivedPolicy) -> SpuAuthContext {
+        SpuAuthContext {
+            enabled: true,
+            identity: identity.map(|identity| Box::new(identity) as Box<dyn Identity>),
+            policy: Arc::new(RwLock::new(policy)),
+        }
+    }
+
+    /// policy where "Consumer" role can only read topics
+    fn consumer_policy() -> BasicRbacPolicy {
+        let mut consumer_role = HashMap::new();
+        consumer_role.insert(ObjectType::Topic, vec![Action::Read.into()]);
+        let mut policy = BasicRbacPolicy::default();
+        policy.0.insert(String::from("Consumer"), consumer_role);
+        policy
+    }
+
+    fn consumer_identity() -> X509Identity {
+        X509Identity::new("User".to_owned(), vec!["Consumer".to_owned()])
+    }
+
+    #[fluvio_future::test]
+    async fn test_spu_auth_context() {
+        let identity = consumer_identity();
+        let policy = consumer_policy();
+
+        // authorization not enabled
+        let ctx = SpuAuthContext {
+            enabled: false,
+            identity: None,
+            policy: SharedAuthPolicy::default(),
+        };
+        assert!(ctx
+            .allow_instance_action(ObjectType::Topic, InstanceAction::Write, "test")
+            .await
+            .expect("eval"));
+
+        // no identity
+        let ctx = auth_context(None, ReceivedPolicy::Policy(policy.clone()));
+        assert!(!ctx
+            .allow_instance_action(ObjectType::Topic, InstanceAction::Read, "test")
+            .await
+            .expect("eval"));
+
+        // no policy from SC yet
+        let ctx = auth_context(Some(identity.clone()), ReceivedPolicy::Pending);
+        assert!(!ctx
+            .allow_instance_action(ObjectType::Topic, InstanceAction::Read, "test")
+            .await
+            .expect("eval"));
+
+        // SC has no policy
+        let ctx = auth_context(Some(identity.clone()), ReceivedPolicy::None);
+        assert!(ctx
+            .allow_instance_action(ObjectType::Topic, InstanceAction::Write, "test")
+            .await
+            .expect("eval"));
+
+        let ctx = auth_context(Some(identity), ReceivedPolicy::Policy(policy));
+        assert!(ctx
+            .allow_instance_action(ObjectType::Topic, InstanceAction::Read, "test")
+            .await
+            .expect("eval"));
+        assert!(!ctx
+            .allow_instance_action(ObjectType::Topic, InstanceAction::Write, "test")
+            .await
+            .expect("eval"));
+    }
+
+    #[fluvio_future::test]
+    async fn test_spu_auth_policy_update() {
+        let policy = SharedAuthPolicy::default();
+        let authorization = SpuAuthorization::new(policy.clone(), true, None);
+        assert!(authorization.is_enabled());
+
+        let ctx = SpuAuthContext {
+            enabled: authorization.is_enabled(),
+            identity: Some(Box::new(consumer_identity())),
+            policy: policy.clone(),
+        };
+        assert!(!ctx
+            .allow_instance_action(ObjectType::Topic, InstanceAction::Read, "test")
+            .await
+            .expect("eval"));
+
+        // policy sent by SC applies to existing connections
+        *policy.write().await = ReceivedPolicy::Policy(consumer_policy());
+        assert!(ctx
+            .allow_instance_action(ObjectType::Topic, InstanceAction::Read, "test")
+            .await
+            .expect("eval"));
+    }
+}
@@ -5,6 +5,7 @@ use fluvio_service::ConnectInfo;
 use tracing::instrument;
 use tracing::{debug, warn};
 
+use fluvio_auth::x509::X509Identity;
 use fluvio_service::{wait_for_request, FluvioService};
 use fluvio_socket::{FluvioSocket, SocketError};
 
@@ -32,9 +33,16 @@ impl FluvioService for InternalService {
     async fn respond(
         self: Arc<Self>,
         ctx: DefaultSharedGlobalContext,
-        socket: FluvioSocket,
+        mut socket: FluvioSocket,
         _connection: ConnectInfo,
     ) -> Result<(), SocketError> {
+        // with private tls, identity of follower certificate is forwarded by proxy
+        let identity = if ctx.config().private_tls.is_some() {
+            Some(X509Identity::create_from_connection(&mut socket).await?)
+        } else {
+            None
+        };
+
         let (mut sink, mut stream) = socket.split();
         let mut api_stream = stream.api_stream::<SpuPeerRequest, SPUPeerApiEnum>();
 
@@ -50,6 +58,10 @@ impl FluvioService for InternalService {
                     follower_id,
                     "received fetch stream"
                 );
+                if let Some(identity) = identity.as_ref().filter(|identity| !identity.is_spu(follower_id)) {
+                    warn!(follower_id, principal = %identity.principal, "spu certificate doesn't match spu id, dropping connection");
+                    return Ok(())
+                }
                 // check if follower_id is valid
                 if let Some(spu_update) = ctx.follower_notifier().get(&follower_id).await {
                     let response = FetchStreamResponse::new(follower_id);
@@ -1,4 +1,8 @@
 pub(crate) mod public;
+pub(crate) mod auth;
+pub(crate) mod quota;
+pub(crate) mod schema;
+pub(crate) mod transaction;
 
 pub mod internal;
 
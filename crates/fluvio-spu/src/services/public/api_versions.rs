@@ -7,6 +7,7 @@ use dataplane::fetch::DefaultFetchRequest;
 use dataplane::versions::ApiVersionKey;
 use fluvio_spu_schema::server::SpuServerApiKey;
 use fluvio_spu_schema::server::fetch_offset::FetchOffsetsRequest;
+use fluvio_spu_schema::server::stream_control::StreamControlRequest;
 use fluvio_spu_schema::server::stream_fetch::DefaultStreamFetchRequest;
 use fluvio_spu_schema::server::update_offset::UpdateOffsetsRequest;
 use fluvio_spu_schema::{ApiVersionsRequest, ApiVersionsResponse};
@@ -28,7 +29,7 @@ pub async fn handle_api_version_request(
     ));
     response.api_keys.push(make_version_key(
         SpuServerApiKey::FetchOffsets,
-        FetchOffsetsRequest::DEFAULT_API_VERSION,
+        0,
         FetchOffsetsRequest::DEFAULT_API_VERSION,
     ));
     response.api_keys.push(make_version_key(
@@ -41,6 +42,11 @@ pub async fn handle_api_version_request(
         0,
         UpdateOffsetsRequest::DEFAULT_API_VERSION,
     ));
+    response.api_keys.push(make_version_key(
+        SpuServerApiKey::StreamControl,
+        0,
+        StreamControlRequest::DEFAULT_API_VERSION,
+    ));
 
     trace!("Returning ApiVersionsResponse: {:#?}", &response);
     Ok(request.new_response(response))
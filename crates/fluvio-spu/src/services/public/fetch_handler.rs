@@ -1,5 +1,9 @@
+use std::io::{Error as IoError, ErrorKind};
+
 use tracing::{debug, trace, instrument};
 
+use fluvio_future::timer::sleep;
+
 use fluvio_socket::ExclusiveFlvSink;
 use fluvio_socket::SocketError;
 use dataplane::{ErrorCode, api::RequestMessage};
@@ -8,29 +12,45 @@ use dataplane::fetch::{
     FetchablePartitionResponse, FetchPartition, FetchableTopic, FetchableTopicResponse,
 };
 use fluvio_controlplane_metadata::partition::ReplicaKey;
+use fluvio_controlplane_metadata::extended::ObjectType;
+use fluvio_auth::{AuthContext, InstanceAction};
 
 use crate::core::DefaultSharedGlobalContext;
+use crate::services::quota::QuotaUsage;
 use dataplane::record::FileRecordSet;
 
 /// perform log fetch request using zero copy write
 #[instrument(
-    skip(request, ctx, sink),
+    skip(request, ctx, sink, auth_ctx),
     fields(
         max_bytes = request.request.max_bytes,
     ),
 )]
-pub async fn handle_fetch_request(
+pub async fn handle_fetch_request<AC: AuthContext>(
     request: RequestMessage<FileFetchRequest>,
     ctx: DefaultSharedGlobalContext,
     sink: ExclusiveFlvSink,
+    auth_ctx: &AC,
 ) -> Result<(), SocketError> {
     let (header, fetch_request) = request.get_header_request();
     trace!("Handling FileFetchRequest: {:#?}", fetch_request);
-    let mut fetch_response = FileFetchResponse::default();
+    let mut fetch_response = fetch_records(&ctx, &fetch_request, auth_ctx).await?;
 
-    for topic_request in &fetch_request.topics {
-        let topic_response = handle_fetch_topic(&ctx, &fetch_request, topic_request).await?;
-        fetch_response.topics.push(topic_response);
+    let fetched_bytes: usize = fetch_response
+        .topics
+        .iter()
+        .flat_map(|topic| topic.partitions.iter())
+        .map(|partition| partition.records.len())
+        .sum();
+    let throttle = ctx.quotas().record(
+        auth_ctx.principal(),
+        header.client_id(),
+        QuotaUsage::Fetch(fetched_bytes as u64),
+    );
+    if !throttle.is_zero() {
+        debug!(?throttle, "fetch quota exceeded, throttling");
+        fetch_response.throttle_time_ms = throttle.as_millis() as i32;
+        sleep(throttle).await;
     }
 
     let response =
@@ -47,14 +67,32 @@ pub async fn handle_fetch_request(
     Ok(())
 }
 
+/// read records of every requested partition, partitions of topics which can't be read are rejected
+async fn fetch_records<AC: AuthContext>(
+    ctx: &DefaultSharedGlobalContext,
+    fetch_request: &FileFetchRequest,
+    auth_ctx: &AC,
+) -> Result<FileFetchResponse, SocketError> {
+    let mut fetch_response = FileFetchResponse::default();
+
+    for topic_request in &fetch_request.topics {
+        let topic_response =
+            handle_fetch_topic(ctx, fetch_request, topic_request, auth_ctx).await?;
+        fetch_response.topics.push(topic_response);
+    }
+
+    Ok(fetch_response)
+}
+
 #[instrument(
-    skip(ctx, fetch_request, topic_request),
+    skip(ctx, fetch_request, topic_request, auth_ctx),
     fields(topic = %topic_request.name),
 )]
-async fn handle_fetch_topic(
+async fn handle_fetch_topic<AC: AuthContext>(
     ctx: &DefaultSharedGlobalContext,
     fetch_request: &FileFetchRequest,
     topic_request: &FetchableTopic,
+    auth_ctx: &AC,
 ) -> Result<FetchableTopicResponse<FileRecordSet>, SocketError> {
     let topic = &topic_request.name;
 
@@ -63,6 +101,22 @@ async fn handle_fetch_topic(
         ..Default::default()
     };
 
+    let authorized = auth_ctx
+        .allow_instance_action(ObjectType::Topic, InstanceAction::Read, topic)
+        .await
+        .map_err(|_| IoError::new(ErrorKind::Interrupted, "authorization io error"))?;
+    if !authorized {
+        debug!("authorization failed");
+        for partition_request in &topic_request.fetch_partitions {
+            topic_response.partitions.push(FilePartitionResponse {
+                partition_index: partition_request.partition_index,
+                error_code: ErrorCode::PermissionDenied,
+                ..Default::default()
+            });
+        }
+        return Ok(topic_response);
+    }
+
     for partition_request in &topic_request.fetch_partitions {
         let replica_id = ReplicaKey::new(topic.clone(), partition_request.partition_index);
         let partition_response =
@@ -111,3 +165,143 @@ async fn handle_fetch_partition(
 
     Ok(partition_response)
 }
+
+#[cfg(test)]
+mod test {
+
+    use std::env::temp_dir;
+
+    use async_trait::async_trait;
+
+    use flv_util::fixture::ensure_clean_dir;
+    use fluvio_auth::{AuthContext, AuthError, TypeAction, InstanceAction};
+    use fluvio_controlplane_metadata::extended::ObjectType;
+    use dataplane::ErrorCode;
+    use dataplane::fetch::{FetchPartition, FetchableTopic, FileFetchRequest};
+    use dataplane::produce::{DefaultProduceRequest, TopicProduceData, PartitionProduceData};
+    use dataplane::api::RequestMessage;
+
+    use crate::config::SpuConfig;
+    use crate::core::GlobalContext;
+    use crate::services::public::produce_handler::handle_produce_request;
+
+    use super::fetch_records;
+
+    /// allows reading topics if `read` is set, denies everything else
+    struct ConsumerAuthContext {
+        read: bool,
+    }
+
+    #[async_trait]
+    impl AuthContext for ConsumerAuthContext {
+        async fn allow_type_action(
+            &self,
+            _ty: ObjectType,
+            _action: TypeAction,
+        ) -> Result<bool, AuthError> {
+            Ok(false)
+        }
+
+        async fn allow_instance_action(
+            &self,
+            ty: ObjectType,
+            action: InstanceAction,
+            _key: &str,
+        ) -> Result<bool, AuthError> {
+            Ok(self.read && ty == ObjectType::Topic && action == InstanceAction::Read)
+        }
+    }
+
+    fn fetch_request(topic: &str) -> FileFetchRequest {
+        FileFetchRequest {
+            topics: vec![FetchableTopic {
+                name: topic.to_owned(),
+                fetch_partitions: vec![FetchPartition {
+                    partition_index: 0,
+                    ..Default::default()
+                }],
+                ..Default::default()
+            }],
+            ..Default::default()
+        }
+    }
+
+    fn produce_request(topic: &str) -> DefaultProduceRequest {
+        DefaultProduceRequest {
+            topics: vec![TopicProduceData {
+                name: topic.to_owned(),
+                partitions: vec![PartitionProduceData {
+                    partition_index: 0,
+                    ..Default::default()
+                }],
+                ..Default::default()
+            }],
+            ..Default::default()
+        }
+    }
+
+    #[fluvio_future::test]
+    async fn test_reject_unauthorized() {
+        let test_path = temp_dir().join("test_reject_unauthorized");
+        ensure_clean_dir(&test_path);
+        let mut spu_config = SpuConfig::default();
+        spu_config.log.base_dir = test_path;
+        let ctx = GlobalContext::new_shared_context(spu_config);
+
+        // neither read nor write is allowed
+        let auth_ctx = ConsumerAuthContext { read: false };
+
+        let response = fetch_records(&ctx, &fetch_request("test"), &auth_ctx)
+            .await
+            .expect("fetch");
+        assert_eq!(
+            response.topics[0].partitions[0].error_code,
+            ErrorCode::PermissionDenied
+        );
+
+        let response = handle_produce_request(
+            RequestMessage::new_request(produce_request("test")),
+            ctx.clone(),
+            &auth_ctx,
+        )
+        .await
+        .expect("produce");
+        assert_eq!(
+            response.response.responses[0].partitions[0].error_code,
+            ErrorCode::PermissionDenied
+        );
+    }
+
+    #[fluvio_future::test]
+    async fn test_fetch_authorization() {
+        let test_path = temp_dir().join("test_fetch_authorization");
+        ensure_clean_dir(&test_path);
+        let mut spu_config = SpuConfig::default();
+        spu_config.log.base_dir = test_path;
+        let ctx = GlobalContext::new_shared_context(spu_config);
+
+        // consumer can read, but not write
+        let auth_ctx = ConsumerAuthContext { read: true };
+
+        let response = fetch_records(&ctx, &fetch_request("test"), &auth_ctx)
+            .await
+            .expect("fetch");
+        // authorized, but this spu is not leader
+        assert_eq!(
+            response.topics[0].partitions[0].error_code,
+            ErrorCode::NotLeaderForPartition
+        );
+
+        let response = handle_produce_request(
+            RequestMessage::new_request(produce_request("test")),
+            ctx.clone(),
+            &auth_ctx,
+        )
+        .await
+        .expect("produce");
+        assert_eq!(
+            response.response.responses[0].partitions[0].error_code,
+            ErrorCode::PermissionDenied
+        );
+    }
+}
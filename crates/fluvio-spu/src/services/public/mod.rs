@@ -3,9 +3,12 @@ mod produce_handler;
 mod fetch_handler;
 mod offset_request;
 mod offset_update;
+mod stream_control;
 mod stream_fetch;
 
 use std::sync::Arc;
+use std::io::Error as IoError;
+
 use async_trait::async_trait;
 use tracing::{info, debug, trace, instrument};
 use futures_util::StreamExt;
@@ -15,13 +18,16 @@ use fluvio_service::{FluvioApiServer, FluvioService, ConnectInfo, call_service};
 use fluvio_spu_schema::server::SpuServerRequest;
 use fluvio_spu_schema::server::SpuServerApiKey;
 use fluvio_types::event::StickyEvent;
+use fluvio_auth::{Authorization, AuthContext};
 
 use crate::core::DefaultSharedGlobalContext;
+use crate::services::auth::SpuAuthorization;
 use self::api_versions::handle_api_version_request;
 use self::produce_handler::handle_produce_request;
 use self::fetch_handler::handle_fetch_request;
 use self::offset_request::handle_offset_request;
 use self::offset_update::handle_offset_update;
+use self::stream_control::handle_stream_control;
 use self::stream_fetch::StreamFetchHandler;
 pub use stream_fetch::publishers::StreamPublishers;
 
@@ -35,7 +41,8 @@ pub fn create_public_server(addr: String, ctx: DefaultSharedGlobalContext) -> Sp
         "Starting SPU public service:",
     );
 
-    FluvioApiServer::new(addr, ctx, PublicService::new())
+    let max_frame_size = ctx.config().max_frame_size;
+    FluvioApiServer::new(addr, ctx, PublicService::new()).with_max_frame_size(max_frame_size)
 }
 
 #[derive(Debug)]
@@ -58,9 +65,19 @@ impl FluvioService for PublicService {
     async fn respond(
         self: Arc<Self>,
         context: DefaultSharedGlobalContext,
-        socket: FluvioSocket,
+        mut socket: FluvioSocket,
         _connection: ConnectInfo,
     ) -> Result<(), SocketError> {
+        let auth = SpuAuthorization::new(
+            context.auth_policy().clone(),
+            context.config().x509_auth_scopes.is_some(),
+            context.sasl_authenticator().clone(),
+        );
+        let auth_ctx = auth.create_auth_context(&mut socket).await.map_err(|err| {
+            let io_error: IoError = err.into();
+            io_error
+        })?;
+
         let (sink, mut stream) = socket.split();
 
         let mut shared_sink = sink.as_shared();
@@ -87,17 +104,22 @@ impl FluvioService for PublicService {
                         ),
                         SpuServerRequest::ProduceRequest(request) => call_service!(
                             request,
-                            handle_produce_request(request, context.clone()),
+                            handle_produce_request(request, context.clone(), &auth_ctx),
                             shared_sink,
                             "ProduceRequest"
                         ),
                         SpuServerRequest::FileFetchRequest(request) => {
-                            handle_fetch_request(request, context.clone(), shared_sink.clone())
-                                .await?
+                            handle_fetch_request(
+                                request,
+                                context.clone(),
+                                shared_sink.clone(),
+                                &auth_ctx,
+                            )
+                            .await?
                         }
                         SpuServerRequest::FetchOffsetsRequest(request) => call_service!(
                             request,
-                            handle_offset_request(request, context.clone()),
+                            handle_offset_request(request, context.clone(), &auth_ctx),
                             shared_sink,
                             "FetchOffsetsRequest"
                         ),
@@ -105,6 +127,7 @@ impl FluvioService for PublicService {
                             StreamFetchHandler::start(
                                 request,
                                 context.clone(),
+                                &auth_ctx,
                                 shared_sink.clone(),
                                 shutdown.clone(),
                             )
@@ -112,10 +135,16 @@ impl FluvioService for PublicService {
                         }
                         SpuServerRequest::UpdateOffsetsRequest(request) => call_service!(
                             request,
-                            handle_offset_update(&context, request),
+                            handle_offset_update(&context, request, &auth_ctx),
                             shared_sink,
                             "UpdateOffsetsRequest"
                         ),
+                        SpuServerRequest::StreamControlRequest(request) => call_service!(
+                            request,
+                            handle_stream_control(&context, request, auth_ctx.principal()),
+                            shared_sink,
+                            "StreamControlRequest"
+                        ),
                     }
                 }
                 Some(Err(e)) => {
@@ -1,6 +1,6 @@
-use std::io::Error as IoError;
+use std::io::{Error as IoError, ErrorKind};
 
-use tracing::{trace, instrument};
+use tracing::{debug, trace, instrument};
 
 use dataplane::api::{RequestMessage, ResponseMessage};
 use fluvio_spu_schema::server::fetch_offset::FetchOffsetsRequest;
@@ -8,14 +8,17 @@ use fluvio_spu_schema::server::fetch_offset::FetchOffsetTopicResponse;
 use fluvio_spu_schema::server::fetch_offset::FetchOffsetsResponse;
 use fluvio_spu_schema::server::fetch_offset::FetchOffsetPartitionResponse;
 use fluvio_controlplane_metadata::partition::ReplicaKey;
+use fluvio_controlplane_metadata::extended::ObjectType;
+use fluvio_auth::{AuthContext, InstanceAction};
 use dataplane::ErrorCode;
 
 use crate::core::DefaultSharedGlobalContext;
 
-#[instrument(skip(req_msg, ctx))]
-pub async fn handle_offset_request(
+#[instrument(skip(req_msg, ctx, auth_ctx))]
+pub async fn handle_offset_request<AC: AuthContext>(
     req_msg: RequestMessage<FetchOffsetsRequest>,
     ctx: DefaultSharedGlobalContext,
+    auth_ctx: &AC,
 ) -> Result<ResponseMessage<FetchOffsetsResponse>, IoError> {
     let request = req_msg.request();
     trace!("handling flv fetch request: {:#?}", request);
@@ -30,6 +33,32 @@ pub async fn handle_offset_request(
             ..Default::default()
         };
 
+        let authorized = auth_ctx
+            .allow_instance_action(ObjectType::Topic, InstanceAction::Read, topic)
+            .await
+            .map_err(|_| IoError::new(ErrorKind::Interrupted, "authorization io error"))?;
+        if !authorized {
+            debug!(%topic, "authorization failed");
+            for partition_req in &topic_request.partitions {
+                topic_response
+                    .partitions
+                    .push(FetchOffsetPartitionResponse {
+                        partition_index: partition_req.partition_index,
+                        error_code: ErrorCode::PermissionDenied,
+                        ..Default::default()
+                    });
+            }
+            response.topics.push(topic_response);
+            continue;
+        }
+
+        // client ids of other consumers are only visible to topic admin
+        let all_sessions = request.include_sessions
+            && auth_ctx
+                .allow_instance_action(ObjectType::Topic, InstanceAction::Update, topic)
+                .await
+                .map_err(|_| IoError::new(ErrorKind::Interrupted, "authorization io error"))?;
+
         for partition_req in &topic_request.partitions {
             let partition = &partition_req.partition_index;
             let mut partition_response = FetchOffsetPartitionResponse {
@@ -43,6 +72,15 @@ pub async fn handle_offset_request(
                 partition_response.error_code = ErrorCode::None;
                 partition_response.start_offset = start_offset;
                 partition_response.last_stable_offset = hw;
+                partition_response.leo = replica.leo();
+                if request.include_sessions {
+                    partition_response.sessions = ctx
+                        .stream_publishers()
+                        .sessions(&rep_id, |session| {
+                            all_sessions || session.principal.as_deref() == auth_ctx.principal()
+                        })
+                        .await;
+                }
             } else {
                 trace!("offset fetch request is not found: {}", rep_id);
                 partition_response.error_code = ErrorCode::PartitionNotLeader;
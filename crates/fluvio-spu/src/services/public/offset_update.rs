@@ -1,6 +1,8 @@
-use std::{io::Error as IoError};
+use std::io::{Error as IoError, ErrorKind};
 
 use tracing::{debug, error, instrument};
+use fluvio_auth::{AuthContext, InstanceAction};
+use fluvio_controlplane_metadata::extended::ObjectType;
 use fluvio_spu_schema::server::update_offset::{
     OffsetUpdateStatus, UpdateOffsetsRequest, UpdateOffsetsResponse,
 };
@@ -8,10 +10,11 @@ use dataplane::ErrorCode;
 use dataplane::api::{ResponseMessage, RequestMessage};
 use crate::core::DefaultSharedGlobalContext;
 
-#[instrument(skip(ctx, request))]
-pub async fn handle_offset_update(
+#[instrument(skip(ctx, request, auth_ctx))]
+pub async fn handle_offset_update<AC: AuthContext>(
     ctx: &DefaultSharedGlobalContext,
     request: RequestMessage<UpdateOffsetsRequest>,
+    auth_ctx: &AC,
 ) -> Result<ResponseMessage<UpdateOffsetsResponse>, IoError> {
     debug!("received stream updates");
     let (header, updates) = request.get_header_request();
@@ -19,18 +22,40 @@ pub async fn handle_offset_update(
     let mut status_list = vec![];
 
     for update in updates.offsets {
-        let maybe_publisher = publishers.get_publisher(update.session_id).await;
-        let status = match maybe_publisher {
-            Some(publisher) => {
-                debug!(
-                    offset_update = update.offset,
-                    session_id = update.session_id,
-                    "published offsets"
-                );
-                publisher.update(update.offset);
-                OffsetUpdateStatus {
-                    session_id: update.session_id,
-                    error: ErrorCode::None,
+        let maybe_session = publishers.get_session(update.session_id).await;
+        let status = match maybe_session {
+            Some(session) => {
+                let authorized = session.principal.as_deref() == auth_ctx.principal()
+                    && auth_ctx
+                        .allow_instance_action(
+                            ObjectType::Topic,
+                            InstanceAction::Read,
+                            &session.replica.topic,
+                        )
+                        .await
+                        .map_err(|_| {
+                            IoError::new(ErrorKind::Interrupted, "authorization io error")
+                        })?;
+                if authorized {
+                    debug!(
+                        offset_update = update.offset,
+                        session_id = update.session_id,
+                        "published offsets"
+                    );
+                    session.consumer_offset.update(update.offset);
+                    OffsetUpdateStatus {
+                        session_id: update.session_id,
+                        error: ErrorCode::None,
+                    }
+                } else {
+                    error!(
+                        session_id = update.session_id,
+                        "offset update not authorized"
+                    );
+                    OffsetUpdateStatus {
+                        session_id: update.session_id,
+                        error: ErrorCode::PermissionDenied,
+                    }
                 }
             }
             None => {
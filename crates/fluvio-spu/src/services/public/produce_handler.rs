@@ -1,9 +1,11 @@
-use std::io::Error;
+use std::io::{Error, ErrorKind};
 
 use fluvio_storage::StorageError;
 use tracing::{debug, trace, error};
 use tracing::instrument;
 
+use fluvio_future::timer::sleep;
+
 use dataplane::ErrorCode;
 use dataplane::produce::{
     DefaultProduceRequest, ProduceResponse, TopicProduceResponse, PartitionProduceResponse,
@@ -12,41 +14,68 @@ use dataplane::produce::{
 use dataplane::api::RequestMessage;
 use dataplane::api::ResponseMessage;
 use dataplane::record::RecordSet;
+use dataplane::core::Encoder;
 use fluvio_controlplane_metadata::partition::ReplicaKey;
+use fluvio_controlplane_metadata::extended::ObjectType;
+use fluvio_auth::{AuthContext, InstanceAction};
 
 use crate::core::DefaultSharedGlobalContext;
+use crate::services::quota::QuotaUsage;
 
 #[instrument(
-    skip(request,ctx),
+    skip(request,ctx,auth_ctx),
     fields(
         id = request.header.correlation_id(),
         client = %request.header.client_id()
     )
 )]
-pub async fn handle_produce_request(
+pub async fn handle_produce_request<AC: AuthContext>(
     request: RequestMessage<DefaultProduceRequest>,
     ctx: DefaultSharedGlobalContext,
+    auth_ctx: &AC,
 ) -> Result<ResponseMessage<ProduceResponse>, Error> {
     let (header, produce_request) = request.get_header_request();
     trace!("Handling ProduceRequest: {:#?}", produce_request);
 
+    let produced_bytes: usize = produce_request
+        .topics
+        .iter()
+        .flat_map(|topic| topic.partitions.iter())
+        .map(|partition| partition.records.write_size(header.api_version()))
+        .sum();
+
+    let transactional = produce_request.transactional_id.is_some();
     let mut response = ProduceResponse::default();
     for topic_request in produce_request.topics.into_iter() {
-        let topic_response = handle_produce_topic(&ctx, topic_request).await?;
+        let topic_response =
+            handle_produce_topic(&ctx, auth_ctx, topic_request, transactional).await?;
         response.responses.push(topic_response);
     }
 
+    let throttle = ctx.quotas().record(
+        auth_ctx.principal(),
+        header.client_id(),
+        QuotaUsage::Produce(produced_bytes as u64),
+    );
+    if !throttle.is_zero() {
+        debug!(?throttle, "produce quota exceeded, throttling");
+        response.throttle_time_ms = throttle.as_millis() as i32;
+        sleep(throttle).await;
+    }
+
     trace!("Returning ProduceResponse: {:#?}", &response);
     Ok(RequestMessage::<DefaultProduceRequest>::response_with_header(&header, response))
 }
 
 #[instrument(
-    skip(ctx, topic_request),
+    skip(ctx, auth_ctx, topic_request),
     fields(topic = %topic_request.name),
 )]
-async fn handle_produce_topic(
+async fn handle_produce_topic<AC: AuthContext>(
     ctx: &DefaultSharedGlobalContext,
+    auth_ctx: &AC,
     topic_request: TopicProduceData<RecordSet>,
+    transactional: bool,
 ) -> Result<TopicProduceResponse, Error> {
     trace!("Handling produce request for topic:");
     let topic = &topic_request.name;
@@ -56,10 +85,26 @@ async fn handle_produce_topic(
         ..Default::default()
     };
 
+    let authorized = auth_ctx
+        .allow_instance_action(ObjectType::Topic, InstanceAction::Write, topic)
+        .await
+        .map_err(|_| Error::new(ErrorKind::Interrupted, "authorization io error"))?;
+    if !authorized {
+        debug!("authorization failed");
+        for partition_request in topic_request.partitions.into_iter() {
+            topic_response.partitions.push(PartitionProduceResponse {
+                partition_index: partition_request.partition_index,
+                error_code: ErrorCode::PermissionDenied,
+                ..Default::default()
+            });
+        }
+        return Ok(topic_response);
+    }
+
     for partition_request in topic_request.partitions.into_iter() {
         let replica_id = ReplicaKey::new(topic.to_string(), partition_request.partition_index);
         let partition_response =
-            handle_produce_partition(ctx, replica_id, partition_request).await?;
+            handle_produce_partition(ctx, replica_id, partition_request, transactional).await?;
         topic_response.partitions.push(partition_response);
     }
 
@@ -74,6 +119,7 @@ async fn handle_produce_partition(
     ctx: &DefaultSharedGlobalContext,
     replica_id: ReplicaKey,
     mut partition_request: PartitionProduceData<RecordSet>,
+    transactional: bool,
 ) -> Result<PartitionProduceResponse, Error> {
     trace!("Handling produce request for partition:");
 
@@ -91,6 +137,58 @@ async fn handle_produce_partition(
         }
     };
 
+    if !leader_state.has_min_in_sync_replicas().await {
+        debug!(
+            %replica_id,
+            min_in_sync_replicas = leader_state.min_in_sync_replicas(),
+            "not enough in sync replicas"
+        );
+        partition_response.error_code = ErrorCode::NotEnoughReplicas;
+        return Ok(partition_response);
+    }
+
+    // batches of transactions are written by transactional producers only,
+    // markers are written by SPU when SC ends transaction
+    let batches = &partition_request.records.batches;
+    if batches.iter().any(|batch| {
+        let header = batch.get_header();
+        header.is_control() || (!transactional && header.is_transactional())
+    }) {
+        debug!(%replica_id, "marker or transactional batch without transactional id");
+        partition_response.error_code = ErrorCode::InvalidTransactionState;
+        return Ok(partition_response);
+    }
+
+    // producer is fenced when epoch is bumped by SC
+    if let Some(header) = batches
+        .iter()
+        .map(|batch| batch.get_header())
+        .find(|header| {
+            header.is_transactional()
+                && !ctx
+                    .transactions()
+                    .check_epoch(header.producer_id, header.producer_epoch)
+        })
+    {
+        debug!(
+            %replica_id,
+            producer_id = header.producer_id,
+            producer_epoch = header.producer_epoch,
+            "producer is fenced"
+        );
+        partition_response.error_code = ErrorCode::ProducerFenced;
+        return Ok(partition_response);
+    }
+
+    if let Err(err) = ctx
+        .schemas()
+        .validate(&replica_id.topic, &partition_request.records)
+    {
+        debug!(%replica_id, %err, "schema validation failed");
+        partition_response.error_code = ErrorCode::SchemaValidationFailed;
+        return Ok(partition_response);
+    }
+
     let write_result = leader_state
         .write_record_set(&mut partition_request.records, ctx.follower_notifier())
         .await;
@@ -111,3 +209,134 @@ async fn handle_produce_partition(
 
     Ok(partition_response)
 }
+
+#[cfg(test)]
+mod test {
+
+    use std::env::temp_dir;
+
+    use async_trait::async_trait;
+
+    use flv_util::fixture::ensure_clean_dir;
+    use fluvio_auth::{AuthError, TypeAction};
+    use fluvio_controlplane::TxnMarker;
+    use fluvio_controlplane_metadata::partition::Replica;
+    use dataplane::batch::Batch;
+    use dataplane::record::Record;
+    use dataplane::transaction::ControlType;
+
+    use crate::config::SpuConfig;
+    use crate::core::GlobalContext;
+    use crate::replication::leader::LeaderReplicaState;
+    use crate::services::transaction::write_markers;
+
+    use super::*;
+
+    /// allows everything
+    struct RootAuthContext {}
+
+    #[async_trait]
+    impl AuthContext for RootAuthContext {
+        async fn allow_type_action(
+            &self,
+            _ty: ObjectType,
+            _action: TypeAction,
+        ) -> Result<bool, AuthError> {
+            Ok(true)
+        }
+
+        async fn allow_instance_action(
+            &self,
+            _ty: ObjectType,
+            _action: InstanceAction,
+            _key: &str,
+        ) -> Result<bool, AuthError> {
+            Ok(true)
+        }
+    }
+
+    fn produce_request(batch: Batch, transactional: bool) -> RequestMessage<DefaultProduceRequest> {
+        let mut partition = PartitionProduceData {
+            partition_index: 0,
+            ..Default::default()
+        };
+        partition.records.batches.push(batch);
+        RequestMessage::new_request(DefaultProduceRequest {
+            transactional_id: transactional.then(|| "app".to_owned()),
+            topics: vec![TopicProduceData {
+                name: "txn".to_owned(),
+                partitions: vec![partition],
+                ..Default::default()
+            }],
+            ..Default::default()
+        })
+    }
+
+    fn transactional(producer_id: i64, producer_epoch: i16) -> Batch {
+        let mut batch = Batch::from(vec![Record::new("value")]);
+        batch
+            .get_mut_header()
+            .set_transactional(producer_id, producer_epoch);
+        batch
+    }
+
+    #[fluvio_future::test]
+    async fn test_produce_transactional() {
+        let test_path = temp_dir().join("test_produce_transactional");
+        ensure_clean_dir(&test_path);
+
+        let mut spu_config = SpuConfig::default();
+        spu_config.log.base_dir = test_path;
+        let ctx = GlobalContext::new_shared_context(spu_config);
+
+        let test = Replica::new(("txn", 0), 5001, vec![5001]);
+        let replica_id = test.id.clone();
+        let leader = LeaderReplicaState::create(test, ctx.config(), ctx.status_update_owned())
+            .await
+            .expect("replica");
+        ctx.leaders_state()
+            .insert(replica_id.clone(), leader.clone());
+
+        let auth_ctx = RootAuthContext {};
+        let produce = |batch: Batch, transactional: bool| {
+            let ctx = ctx.clone();
+            let auth_ctx = &auth_ctx;
+            async move {
+                handle_produce_request(produce_request(batch, transactional), ctx, auth_ctx)
+                    .await
+                    .expect("produce")
+                    .response
+                    .responses[0]
+                    .partitions[0]
+                    .error_code
+                    .clone()
+            }
+        };
+
+        assert_eq!(produce(transactional(1, 1), true).await, ErrorCode::None);
+        assert_eq!(
+            produce(transactional(1, 1), false).await,
+            ErrorCode::InvalidTransactionState
+        );
+        // markers are only written by SPU
+        assert_eq!(
+            produce(Batch::control_marker(1, 1, 0, ControlType::Commit), true).await,
+            ErrorCode::InvalidTransactionState
+        );
+        assert_eq!(
+            produce(transactional(1, 0), true).await,
+            ErrorCode::ProducerFenced
+        );
+        assert_eq!(leader.leo(), 1);
+
+        // abort marker of new epoch fences producer
+        let marker = TxnMarker::new(replica_id, 1, 2, 0, false);
+        assert_eq!(write_markers(&ctx, &[marker.clone()]).await, vec![marker]);
+        assert_eq!(
+            produce(transactional(1, 1), true).await,
+            ErrorCode::ProducerFenced
+        );
+        assert_eq!(produce(transactional(1, 2), true).await, ErrorCode::None);
+        assert_eq!(leader.leo(), 3);
+    }
+}
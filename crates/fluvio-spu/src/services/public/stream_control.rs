@@ -0,0 +1,184 @@
+use std::io::Error as IoError;
+
+use tracing::{debug, error, instrument};
+
+use dataplane::ErrorCode;
+use dataplane::api::{ResponseMessage, RequestMessage};
+use fluvio_spu_schema::server::stream_control::{
+    StreamControl, StreamControlRequest, StreamControlResponse,
+};
+
+use crate::core::DefaultSharedGlobalContext;
+
+#[instrument(skip(ctx, request))]
+pub async fn handle_stream_control(
+    ctx: &DefaultSharedGlobalContext,
+    request: RequestMessage<StreamControlRequest>,
+    principal: Option<&str>,
+) -> Result<ResponseMessage<StreamControlResponse>, IoError> {
+    let (header, request) = request.get_header_request();
+    let session_id = request.session_id;
+    debug!(session_id, control = ?request.control, "received stream control");
+
+    let error = control_session(ctx, request, principal).await;
+    let response = StreamControlResponse { session_id, error };
+    Ok(RequestMessage::<StreamControlRequest>::response_with_header(&header, response))
+}
+
+async fn control_session(
+    ctx: &DefaultSharedGlobalContext,
+    request: StreamControlRequest,
+    principal: Option<&str>,
+) -> ErrorCode {
+    let session = match ctx
+        .stream_publishers()
+        .get_session(request.session_id)
+        .await
+    {
+        Some(session) => session,
+        None => {
+            error!(session_id = request.session_id, "stream session not found");
+            return ErrorCode::FetchSessionNotFoud;
+        }
+    };
+
+    // only owner of session can control it
+    if session.principal.as_deref() != principal {
+        error!(
+            session_id = request.session_id,
+            "stream session is not owned by principal"
+        );
+        return ErrorCode::PermissionDenied;
+    }
+
+    if let StreamControl::Seek(offset) = request.control {
+        let leader_state = match ctx.leaders_state().get(&session.replica) {
+            Some(leader_state) => leader_state,
+            None => return ErrorCode::NotLeaderForPartition,
+        };
+        let (start_offset, _) = leader_state.start_offset_info().await;
+        let end_offset = leader_state.end_offset(&session.isolation);
+        if offset < start_offset || offset > end_offset {
+            debug!(
+                offset,
+                start_offset, end_offset, "seek offset is out of range"
+            );
+            return ErrorCode::OffsetOutOfRange;
+        }
+    }
+
+    // waits while stream handler has full queue of pending controls
+    if session.control.send(request.control).await.is_err() {
+        // handler terminated, session is being removed
+        return ErrorCode::FetchSessionNotFoud;
+    }
+
+    ErrorCode::None
+}
+
+#[cfg(test)]
+mod test {
+
+    use std::env::temp_dir;
+
+    use flv_util::fixture::ensure_clean_dir;
+    use fluvio_controlplane_metadata::partition::Replica;
+    use dataplane::{Isolation, ReplicaKey};
+    use dataplane::fixture::create_batch;
+    use dataplane::record::RecordSet;
+
+    use crate::config::SpuConfig;
+    use crate::core::GlobalContext;
+    use crate::replication::leader::LeaderReplicaState;
+
+    use super::*;
+
+    #[fluvio_future::test]
+    async fn test_stream_control_seek() {
+        let test_path = temp_dir().join("test_stream_control_seek");
+        ensure_clean_dir(&test_path);
+
+        let mut spu_config = SpuConfig::default();
+        spu_config.log.base_dir = test_path;
+        let ctx = GlobalContext::new_shared_context(spu_config);
+
+        // follower never catches up, so only uncommitted records are written
+        let test = Replica::new(("control", 0), 5001, vec![5001, 5002]);
+        let replica_id: ReplicaKey = test.id.clone();
+        let leader = LeaderReplicaState::create(test, ctx.config(), ctx.status_update_owned())
+            .await
+            .expect("replica");
+        ctx.leaders_state()
+            .insert(replica_id.clone(), leader.clone());
+        leader
+            .write_record_set(
+                &mut RecordSet::default().add(create_batch()),
+                ctx.follower_notifier(),
+            )
+            .await
+            .expect("write");
+        assert_eq!(leader.hw(), 0);
+        assert_eq!(leader.leo(), 2);
+
+        let seek = |session_id, offset| StreamControlRequest {
+            session_id,
+            control: StreamControl::Seek(offset),
+        };
+
+        assert_eq!(
+            control_session(&ctx, seek(100, 0), None).await,
+            ErrorCode::FetchSessionNotFoud
+        );
+
+        let (committed_id, _, _committed_control) = ctx
+            .stream_publishers()
+            .create_new_publisher(
+                replica_id.clone(),
+                "committed".to_owned(),
+                Some("alice".to_owned()),
+                Isolation::ReadCommitted,
+            )
+            .await;
+        assert_eq!(
+            control_session(&ctx, seek(committed_id, 0), Some("bob")).await,
+            ErrorCode::PermissionDenied
+        );
+        assert_eq!(
+            control_session(&ctx, seek(committed_id, 2), Some("alice")).await,
+            ErrorCode::OffsetOutOfRange
+        );
+        assert_eq!(
+            control_session(&ctx, seek(committed_id, 0), Some("alice")).await,
+            ErrorCode::None
+        );
+
+        let (uncommitted_id, _, uncommitted_control) = ctx
+            .stream_publishers()
+            .create_new_publisher(
+                replica_id,
+                "uncommitted".to_owned(),
+                None,
+                Isolation::ReadUncommitted,
+            )
+            .await;
+        assert_eq!(
+            control_session(&ctx, seek(uncommitted_id, 3), None).await,
+            ErrorCode::OffsetOutOfRange
+        );
+        assert_eq!(
+            control_session(&ctx, seek(uncommitted_id, 2), None).await,
+            ErrorCode::None
+        );
+        assert!(matches!(
+            uncommitted_control.try_recv(),
+            Ok(StreamControl::Seek(2))
+        ));
+
+        // session of terminated handler can't be controlled
+        drop(uncommitted_control);
+        assert_eq!(
+            control_session(&ctx, seek(uncommitted_id, 0), None).await,
+            ErrorCode::FetchSessionNotFoud
+        );
+    }
+}
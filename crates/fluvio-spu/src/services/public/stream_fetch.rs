@@ -3,12 +3,14 @@ use std::time::{Instant};
 use std::io::ErrorKind;
 use std::io::Error as IoError;
 
+use async_channel::Receiver;
 use tracing::{error, debug, trace, instrument};
 use tokio::select;
 
 use dataplane::record::FileRecordSet;
 use fluvio_types::event::{StickyEvent, offsets::OffsetPublisher};
 use fluvio_future::task::spawn;
+use fluvio_future::timer::sleep;
 use fluvio_socket::{ExclusiveFlvSink, SocketError};
 use dataplane::{
     ErrorCode,
@@ -17,15 +19,20 @@ use dataplane::{
     record::RecordSet,
     SmartStreamError,
 };
+use dataplane::core::Encoder;
 use dataplane::{Offset, Isolation, ReplicaKey};
 use dataplane::fetch::FilePartitionResponse;
 use fluvio_spu_schema::server::stream_fetch::{
     DefaultStreamFetchRequest, FileStreamFetchRequest, SmartStreamKind, StreamFetchRequest,
-    StreamFetchResponse,
+    StreamFetchResponse, STREAM_CONTROL_API,
 };
+use fluvio_spu_schema::server::stream_control::StreamControl;
 use fluvio_types::event::offsets::OffsetChangeListener;
+use fluvio_controlplane_metadata::extended::ObjectType;
+use fluvio_auth::{AuthContext, InstanceAction};
 
 use crate::core::DefaultSharedGlobalContext;
+use crate::services::quota::QuotaUsage;
 use crate::replication::leader::SharedFileLeaderState;
 use publishers::INIT_OFFSET;
 use crate::smartstream::{SmartStream};
@@ -44,25 +51,48 @@ pub struct StreamFetchHandler {
     sink: ExclusiveFlvSink,
     end_event: Arc<StickyEvent>,
     consumer_offset_listener: OffsetChangeListener,
+    delivered_offset: Arc<OffsetPublisher>,
+    control_receiver: Receiver<StreamControl>,
     leader_state: SharedFileLeaderState,
     stream_id: u32,
+    principal: Option<String>,
 }
 
 impl StreamFetchHandler {
     /// handle fluvio continuous fetch request
-    pub async fn start(
+    pub async fn start<AC: AuthContext>(
         request: RequestMessage<FileStreamFetchRequest>,
         ctx: DefaultSharedGlobalContext,
+        auth_ctx: &AC,
         sink: ExclusiveFlvSink,
         end_event: Arc<StickyEvent>,
     ) -> Result<(), SocketError> {
         let (header, msg) = request.get_header_request();
         let replica = ReplicaKey::new(msg.topic.clone(), msg.partition);
 
+        let authorized = auth_ctx
+            .allow_instance_action(ObjectType::Topic, InstanceAction::Read, &replica.topic)
+            .await
+            .map_err(|_| IoError::new(ErrorKind::Interrupted, "authorization io error"))?;
+
+        if !authorized {
+            debug!(topic = %replica.topic, "authorization failed");
+            return Self::send_error_response(replica, header, sink, ErrorCode::PermissionDenied)
+                .await;
+        }
+
         if let Some(leader_state) = ctx.leaders_state().get(&replica) {
-            let (stream_id, offset_publisher) =
-                ctx.stream_publishers().create_new_publisher().await;
-            let consumer_offset_listener = offset_publisher.change_listner();
+            let principal = auth_ctx.principal().map(|principal| principal.to_owned());
+            let (stream_id, session, control_receiver) = ctx
+                .stream_publishers()
+                .create_new_publisher(
+                    replica.clone(),
+                    header.client_id().to_owned(),
+                    principal.clone(),
+                    msg.isolation.clone(),
+                )
+                .await;
+            let consumer_offset_listener = session.consumer_offset.change_listner();
 
             spawn(async move {
                 if let Err(err) = StreamFetchHandler::fetch(
@@ -74,7 +104,10 @@ impl StreamFetchHandler {
                     header,
                     replica,
                     consumer_offset_listener,
+                    session.delivered_offset,
+                    control_receiver,
                     msg,
+                    principal,
                 )
                 .await
                 {
@@ -84,33 +117,46 @@ impl StreamFetchHandler {
             });
         } else {
             debug!(topic = %replica.topic," no leader founded, returning");
-            let response = StreamFetchResponse {
-                topic: replica.topic,
-                stream_id: 0,
-                partition: FilePartitionResponse {
-                    partition_index: replica.partition,
-                    error_code: ErrorCode::NotLeaderForPartition,
-                    ..Default::default()
-                },
-            };
+            Self::send_error_response(replica, header, sink, ErrorCode::NotLeaderForPartition)
+                .await?;
+        }
+
+        Ok(())
+    }
 
-            let response_msg =
-                RequestMessage::<FileStreamFetchRequest>::response_with_header(&header, response);
+    /// send back response with error, stream is not started
+    async fn send_error_response(
+        replica: ReplicaKey,
+        header: RequestHeader,
+        sink: ExclusiveFlvSink,
+        error_code: ErrorCode,
+    ) -> Result<(), SocketError> {
+        let response = StreamFetchResponse {
+            topic: replica.topic,
+            stream_id: 0,
+            partition: FilePartitionResponse {
+                partition_index: replica.partition,
+                error_code,
+                ..Default::default()
+            },
+        };
 
-            trace!("sending back file fetch response msg: {:#?}", response_msg);
+        let response_msg =
+            RequestMessage::<FileStreamFetchRequest>::response_with_header(&header, response);
 
-            let mut inner_sink = sink.lock().await;
-            inner_sink
-                .send_response(&response_msg, header.api_version())
-                .await?;
-        }
+        trace!("sending back file fetch response msg: {:#?}", response_msg);
+
+        let mut inner_sink = sink.lock().await;
+        inner_sink
+            .send_response(&response_msg, header.api_version())
+            .await?;
 
         Ok(())
     }
 
     #[allow(clippy::too_many_arguments)]
     #[instrument(
-        skip(ctx,replica,end_event,leader_state,header,msg,consumer_offset_listener),
+        skip(ctx,replica,end_event,leader_state,header,msg,consumer_offset_listener,delivered_offset,control_receiver,principal),
         fields(
             replica = %replica,
             sink = sink.id()
@@ -125,7 +171,10 @@ impl StreamFetchHandler {
         header: RequestHeader,
         replica: ReplicaKey,
         consumer_offset_listener: OffsetChangeListener,
+        delivered_offset: Arc<OffsetPublisher>,
+        control_receiver: Receiver<StreamControl>,
         msg: StreamFetchRequest<FileRecordSet>,
+        principal: Option<String>,
     ) -> Result<(), SocketError> {
         let max_bytes = msg.max_bytes as u32;
         let sm_engine = ctx.smartstream_owned();
@@ -236,9 +285,12 @@ impl StreamFetchHandler {
             end_event,
             header,
             consumer_offset_listener,
+            delivered_offset,
+            control_receiver,
             stream_id,
             leader_state,
             max_fetch_bytes,
+            principal,
         };
 
         handler.process(starting_offset, smartstream).await
@@ -252,12 +304,20 @@ impl StreamFetchHandler {
         let (mut last_partition_offset, consumer_wait) = self
             .send_back_records(starting_offset, smartstream.as_mut())
             .await?;
+        self.delivered_offset.update(last_partition_offset);
+        if !consumer_wait && self.header.api_version() >= STREAM_CONTROL_API {
+            self.send_session_start().await?;
+        }
 
         let mut leader_offset_receiver = self.leader_state.offset_listener(&self.isolation);
         let mut counter: i32 = 0;
         // since we don't need to wait for consumer, can move consumer to same offset as last read
         let mut last_known_consumer_offset: Option<Offset> =
             (!consumer_wait).then(|| last_partition_offset);
+        // records are not sent while paused, consumer and leader offsets are still tracked
+        let mut paused = false;
+        // consumer offsets before last seek are acknowledgements of records sent before it
+        let mut seek_offset = starting_offset;
 
         loop {
             counter += 1;
@@ -280,6 +340,22 @@ impl StreamFetchHandler {
                         continue;
                     }
 
+                    // acknowledgement of records sent before seek
+                    if consumer_offset_update < seek_offset || consumer_offset_update > last_partition_offset {
+                        debug!(
+                            consumer_offset_update,
+                            seek_offset,
+                            last_partition_offset,
+                            "Consumer offset is outside of stream position, ignoring",
+                        );
+                        continue;
+                    }
+
+                    if paused {
+                        last_known_consumer_offset = Some(consumer_offset_update);
+                        continue;
+                    }
+
                     // If the consumer offset is not behind, there is no need to send records
                     if !(consumer_offset_update < last_partition_offset) {
                         debug!(
@@ -299,6 +375,7 @@ impl StreamFetchHandler {
                         "Consumer offset updated and is behind, need to send records",
                     );
                     let (offset, wait) = self.send_back_records(consumer_offset_update, smartstream.as_mut()).await?;
+                    self.delivered_offset.update(offset);
                     last_partition_offset = offset;
                     if wait {
                         last_known_consumer_offset = None;
@@ -316,6 +393,11 @@ impl StreamFetchHandler {
                 partition_offset_update = leader_offset_receiver.listen() => {
                     debug!(partition_offset_update, "Received leader update:");
 
+                    if paused {
+                        debug!(partition_offset_update, "Stream is paused, not sending records");
+                        continue;
+                    }
+
                     let last_consumer_offset = match last_known_consumer_offset {
                         Some(last_consumer_offset) => last_consumer_offset,
                         None => {
@@ -340,6 +422,7 @@ impl StreamFetchHandler {
                     // We need to send the consumer all records since the last consumer offset
                     debug!(partition_offset_update, last_consumer_offset, "reading offset event");
                     let (offset, wait) = self.send_back_records(last_consumer_offset, smartstream.as_mut()).await?;
+                    self.delivered_offset.update(offset);
                     last_partition_offset = offset;
                     if wait {
                         last_known_consumer_offset = None;
@@ -353,6 +436,53 @@ impl StreamFetchHandler {
                         debug!(?last_known_consumer_offset, "Finished handling partition_offset_update, not waiting for consumer");
                     }
                 },
+
+                // Received control from consumer through StreamControl request
+                control = self.control_receiver.recv() => {
+                    let control = match control {
+                        Ok(control) => control,
+                        Err(_) => {
+                            debug!("control channel is closed, terminating");
+                            break;
+                        }
+                    };
+                    debug!(?control, "Received stream control");
+
+                    let resume_offset = match control {
+                        StreamControl::Pause => {
+                            paused = true;
+                            continue;
+                        }
+                        StreamControl::Resume => {
+                            paused = false;
+                            match last_known_consumer_offset {
+                                Some(offset) => offset,
+                                // records are in flight, consumer acknowledgement sends the rest
+                                None => continue,
+                            }
+                        }
+                        StreamControl::Seek(offset) => {
+                            seek_offset = offset;
+                            if paused {
+                                last_partition_offset = offset;
+                                last_known_consumer_offset = Some(offset);
+                                self.delivered_offset.update(offset);
+                                continue;
+                            }
+                            offset
+                        }
+                    };
+
+                    let (offset, wait) = self.send_back_records(resume_offset, smartstream.as_mut()).await?;
+                    self.delivered_offset.update(offset);
+                    last_partition_offset = offset;
+                    if wait {
+                        last_known_consumer_offset = None;
+                    } else {
+                        last_known_consumer_offset = Some(last_partition_offset);
+                    }
+                    debug!(last_partition_offset, ?last_known_consumer_offset, "Finished handling stream control");
+                },
             }
         }
 
@@ -365,6 +495,30 @@ impl StreamFetchHandler {
         Ok(())
     }
 
+    /// send back empty response, so consumer knows session id before any record is available
+    async fn send_session_start(&self) -> Result<(), SocketError> {
+        let response = StreamFetchResponse {
+            topic: self.replica.topic.clone(),
+            stream_id: self.stream_id,
+            partition: FilePartitionResponse {
+                partition_index: self.replica.partition,
+                high_watermark: self.leader_state.hw(),
+                ..Default::default()
+            },
+        };
+
+        let response_msg =
+            RequestMessage::<FileStreamFetchRequest>::response_with_header(&self.header, response);
+
+        debug!(stream_id = self.stream_id, "sending session start");
+        let mut inner_sink = self.sink.lock().await;
+        inner_sink
+            .send_response(&response_msg, self.header.api_version())
+            .await?;
+
+        Ok(())
+    }
+
     /// send back records back to consumer
     /// return (next offset, consumer wait)
     //  consumer wait flag tells that there are records send back to consumer
@@ -415,28 +569,32 @@ impl StreamFetchHandler {
         }
 
         let records = &file_partition_response.records;
-        let mut file_batch_iterator = FileBatchIterator::from_raw_slice(records.raw_slice());
+        let mut file_batch_iterator = FileBatchIterator::from_records(records);
 
         // If a smartstream module is provided, we need to read records from file to memory
         // In-memory records are then processed by smartstream and returned to consumer
-        let output = match smartstream {
+        let (output, sent_bytes) = match smartstream {
             Some(smartstream) => {
                 let (batch, smartstream_error) = smartstream
                     .process_batch(&mut file_batch_iterator, self.max_bytes as usize)
                     .map_err(|err| IoError::new(ErrorKind::Other, format!("filter err {}", err)))?;
-
-                self.send_processed_response(
-                    file_partition_response,
-                    next_offset,
-                    batch,
-                    smartstream_error,
-                )
-                .await?
+                let sent_bytes = batch.write_size(self.header.api_version());
+
+                let output = self
+                    .send_processed_response(
+                        file_partition_response,
+                        next_offset,
+                        batch,
+                        smartstream_error,
+                    )
+                    .await?;
+                (output, sent_bytes)
             }
             None => {
                 // If no smartstream is provided, respond using raw file records
                 debug!("No SmartStream, sending back entire log");
 
+                let sent_bytes = file_partition_response.records.len();
                 let response = StreamFetchResponse {
                     topic: self.replica.topic.clone(),
                     stream_id: self.stream_id,
@@ -459,9 +617,26 @@ impl StreamFetchHandler {
 
                 debug!(read_time_ms = %now.elapsed().as_millis(),"finish sending back records");
 
-                (read_end_offset.isolation(&self.isolation), true)
+                (
+                    (read_end_offset.isolation(&self.isolation), true),
+                    sent_bytes,
+                )
             }
         };
+
+        // records were sent, delay next batch if client is over quota
+        if output.1 {
+            let throttle = self.ctx.quotas().record(
+                self.principal.as_deref(),
+                self.header.client_id(),
+                QuotaUsage::Fetch(sent_bytes as u64),
+            );
+            if !throttle.is_zero() {
+                debug!(?throttle, "fetch quota exceeded, throttling stream");
+                sleep(throttle).await;
+            }
+        }
+
         Ok(output)
     }
 
@@ -545,15 +720,39 @@ pub mod publishers {
     use std::sync::atomic::Ordering::SeqCst;
     use std::fmt::Debug;
 
+    use async_channel::{Sender, Receiver};
     use async_lock::Mutex;
     use tracing::debug;
 
+    use dataplane::{Isolation, ReplicaKey};
+    use fluvio_spu_schema::server::fetch_offset::StreamSessionOffset;
+    use fluvio_spu_schema::server::stream_control::StreamControl;
+
     use super::OffsetPublisher;
 
     pub const INIT_OFFSET: i64 = -1;
 
+    /// pending controls per session, sender waits when stream handler falls behind
+    pub const CONTROL_QUEUE_SIZE: usize = 16;
+
+    /// consumer stream of replica
+    #[derive(Clone)]
+    pub struct StreamSession {
+        pub replica: ReplicaKey,
+        pub client_id: String,
+        pub principal: Option<String>,
+        /// isolation requested by stream, bounds offsets it can seek to
+        pub isolation: Isolation,
+        /// controls of session received through StreamControl
+        pub control: Sender<StreamControl>,
+        /// offset acknowledged by consumer through UpdateOffsets
+        pub consumer_offset: Arc<OffsetPublisher>,
+        /// offset after last record read by stream
+        pub delivered_offset: Arc<OffsetPublisher>,
+    }
+
     pub struct StreamPublishers {
-        publishers: Mutex<HashMap<u32, Arc<OffsetPublisher>>>,
+        publishers: Mutex<HashMap<u32, StreamSession>>,
         stream_id: AtomicU32,
     }
 
@@ -576,20 +775,59 @@ pub mod publishers {
             self.stream_id.fetch_add(1, SeqCst)
         }
 
-        pub async fn create_new_publisher(&self) -> (u32, Arc<OffsetPublisher>) {
+        pub async fn create_new_publisher(
+            &self,
+            replica: ReplicaKey,
+            client_id: String,
+            principal: Option<String>,
+            isolation: Isolation,
+        ) -> (u32, StreamSession, Receiver<StreamControl>) {
             let stream_id = self.next_stream_id();
-            let offset_publisher = OffsetPublisher::shared(INIT_OFFSET);
+            let (control, control_receiver) = async_channel::bounded(CONTROL_QUEUE_SIZE);
+            let session = StreamSession {
+                replica,
+                client_id,
+                principal,
+                isolation,
+                control,
+                consumer_offset: OffsetPublisher::shared(INIT_OFFSET),
+                delivered_offset: OffsetPublisher::shared(INIT_OFFSET),
+            };
             let mut publisher_lock = self.publishers.lock().await;
-            publisher_lock.insert(stream_id, offset_publisher.clone());
-            (stream_id, offset_publisher)
+            publisher_lock.insert(stream_id, session.clone());
+            (stream_id, session, control_receiver)
         }
 
-        /// get publisher with stream id
-        pub async fn get_publisher(&self, stream_id: u32) -> Option<Arc<OffsetPublisher>> {
+        /// get session with stream id
+        pub async fn get_session(&self, stream_id: u32) -> Option<StreamSession> {
             let publisher_lock = self.publishers.lock().await;
             publisher_lock.get(&stream_id).cloned()
         }
 
+        /// offsets of active stream sessions of replica which are visible to caller
+        pub async fn sessions<F>(
+            &self,
+            replica: &ReplicaKey,
+            visible: F,
+        ) -> Vec<StreamSessionOffset>
+        where
+            F: Fn(&StreamSession) -> bool,
+        {
+            let publisher_lock = self.publishers.lock().await;
+            let mut sessions: Vec<StreamSessionOffset> = publisher_lock
+                .iter()
+                .filter(|(_, session)| &session.replica == replica && visible(session))
+                .map(|(stream_id, session)| StreamSessionOffset {
+                    session_id: *stream_id,
+                    client_id: session.client_id.clone(),
+                    delivered_offset: session.delivered_offset.current_value(),
+                    committed_offset: session.consumer_offset.current_value(),
+                })
+                .collect();
+            sessions.sort_by_key(|session| session.session_id);
+            sessions
+        }
+
         pub async fn remove_publisher(&self, stream_id: u32) {
             let mut publisher_lock = self.publishers.lock().await;
             if publisher_lock.remove(&stream_id).is_some() {
@@ -633,6 +871,60 @@ mod test {
     use crate::replication::leader::LeaderReplicaState;
     use crate::services::public::create_public_server;
 
+    #[fluvio_future::test]
+    async fn test_stream_session_bookkeeping() {
+        let publishers = publishers::StreamPublishers::new();
+        let replica = ReplicaKey::new("test", 0);
+
+        let (first, first_session, _first_control) = publishers
+            .create_new_publisher(
+                replica.clone(),
+                "consumer-a".to_owned(),
+                Some("alice".to_owned()),
+                Isolation::ReadUncommitted,
+            )
+            .await;
+        let (second, _, _second_control) = publishers
+            .create_new_publisher(
+                replica.clone(),
+                "consumer-b".to_owned(),
+                Some("bob".to_owned()),
+                Isolation::ReadCommitted,
+            )
+            .await;
+        let _other = publishers
+            .create_new_publisher(
+                ReplicaKey::new("other", 0),
+                "consumer-c".to_owned(),
+                None,
+                Isolation::ReadUncommitted,
+            )
+            .await;
+        first_session.delivered_offset.update(10);
+        first_session.consumer_offset.update(5);
+
+        let sessions = publishers.sessions(&replica, |_| true).await;
+        assert_eq!(sessions.len(), 2);
+        assert_eq!(sessions[0].session_id, first);
+        assert_eq!(sessions[0].client_id, "consumer-a");
+        assert_eq!(sessions[0].delivered_offset, 10);
+        assert_eq!(sessions[0].committed_offset, 5);
+        assert_eq!(sessions[1].session_id, second);
+        assert_eq!(sessions[1].delivered_offset, publishers::INIT_OFFSET);
+
+        let own = publishers
+            .sessions(&replica, |session| {
+                session.principal.as_deref() == Some("bob")
+            })
+            .await;
+        assert_eq!(own.len(), 1);
+        assert_eq!(own[0].client_id, "consumer-b");
+
+        publishers.remove_publisher(first).await;
+        assert!(publishers.get_session(first).await.is_none());
+        assert_eq!(publishers.sessions(&replica, |_| true).await.len(), 1);
+    }
+
     #[fluvio_future::test(ignore)]
     async fn test_stream_fetch() {
         let test_path = temp_dir().join("test_stream_fetch");
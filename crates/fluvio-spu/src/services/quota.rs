@@ -0,0 +1,364 @@
+//!
+//! # SPU Quotas
+//!
+//! Throughput limits of produce and fetch requests, received from SC.
+//! Like Kafka, client is not rejected when exceeding quota,
+//! instead response is delayed by throttle time which brings client back under quota.
+//!
+use std::collections::{HashMap, VecDeque};
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+use tracing::{debug, trace};
+
+use fluvio_controlplane::Quota;
+
+/// rate is measured over this window
+const WINDOW: Duration = Duration::from_secs(10);
+/// usage within single sample is aggregated
+const SAMPLE: Duration = Duration::from_secs(1);
+
+/// usage of single produce or fetch response
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum QuotaUsage {
+    Produce(u64),
+    Fetch(u64),
+}
+
+#[derive(Debug, Default)]
+pub struct QuotaManager {
+    inner: Mutex<QuotaState>,
+}
+
+#[derive(Debug)]
+struct QuotaState {
+    /// sorted by precedence, then by name
+    quotas: Vec<Quota>,
+    usage: HashMap<UsageKey, ClientUsage>,
+    last_purge: Instant,
+}
+
+impl Default for QuotaState {
+    fn default() -> Self {
+        Self {
+            quotas: vec![],
+            usage: HashMap::new(),
+            last_purge: Instant::now(),
+        }
+    }
+}
+
+/// usage is shared by all clients matching quota, except default quota
+/// where each principal and client id pair is tracked separately
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+struct UsageKey {
+    quota: String,
+    principal: Option<String>,
+    client_id: Option<String>,
+}
+
+impl QuotaManager {
+    /// replace all quotas, usage is kept for quotas which still exist
+    /// so changing limit doesn't reset clients which are over it
+    pub fn update(&self, mut quotas: Vec<Quota>) {
+        quotas.sort_by(|a, b| {
+            b.spec
+                .precedence()
+                .cmp(&a.spec.precedence())
+                .then_with(|| a.name.cmp(&b.name))
+        });
+        debug!(quotas = quotas.len(), "updating quotas");
+        let mut state = self.inner.lock().unwrap();
+        state
+            .usage
+            .retain(|key, _| quotas.iter().any(|quota| quota.name == key.quota));
+        state.quotas = quotas;
+    }
+
+    /// record usage of client, returns time which response should be delayed
+    pub fn record(&self, principal: Option<&str>, client_id: &str, usage: QuotaUsage) -> Duration {
+        self.record_at(principal, client_id, usage, Instant::now())
+    }
+
+    fn record_at(
+        &self,
+        principal: Option<&str>,
+        client_id: &str,
+        usage: QuotaUsage,
+        now: Instant,
+    ) -> Duration {
+        let mut state = self.inner.lock().unwrap();
+
+        if now.saturating_duration_since(state.last_purge) > WINDOW {
+            state.usage.retain(|_, client| !client.is_idle(now));
+            state.last_purge = now;
+        }
+
+        let quota = match state
+            .quotas
+            .iter()
+            .find(|quota| quota.spec.matches(principal, client_id))
+        {
+            Some(quota) => quota.clone(),
+            None => return Duration::ZERO,
+        };
+
+        let key = if quota.spec.is_default() {
+            UsageKey {
+                quota: quota.name.clone(),
+                principal: principal.map(|p| p.to_owned()),
+                client_id: Some(client_id.to_owned()),
+            }
+        } else {
+            UsageKey {
+                quota: quota.name.clone(),
+                principal: None,
+                client_id: None,
+            }
+        };
+
+        let client = state
+            .usage
+            .entry(key)
+            .or_insert_with(|| ClientUsage::new(now));
+
+        let throttle = match usage {
+            QuotaUsage::Produce(bytes) => {
+                client.produce.record(now, bytes as f64);
+                client.produce.throttle(now, quota.spec.produce_byte_rate)
+            }
+            QuotaUsage::Fetch(bytes) => {
+                client.fetch.record(now, bytes as f64);
+                client.fetch.throttle(now, quota.spec.fetch_byte_rate)
+            }
+        };
+        client.requests.record(now, 1.0);
+        let request_throttle = client
+            .requests
+            .throttle(now, quota.spec.request_rate.map(|rate| rate as u64));
+
+        let throttle = throttle.max(request_throttle);
+        trace!(quota = %quota.name, ?usage, ?throttle, "recorded usage");
+        throttle
+    }
+}
+
+#[derive(Debug)]
+struct ClientUsage {
+    produce: Rate,
+    fetch: Rate,
+    requests: Rate,
+}
+
+impl ClientUsage {
+    fn new(now: Instant) -> Self {
+        Self {
+            produce: Rate::new(now),
+            fetch: Rate::new(now),
+            requests: Rate::new(now),
+        }
+    }
+
+    fn is_idle(&self, now: Instant) -> bool {
+        self.produce.is_idle(now) && self.fetch.is_idle(now) && self.requests.is_idle(now)
+    }
+}
+
+/// rate over sliding window, made of samples
+#[derive(Debug)]
+struct Rate {
+    created: Instant,
+    samples: VecDeque<(Instant, f64)>,
+}
+
+impl Rate {
+    fn new(now: Instant) -> Self {
+        Self {
+            created: now,
+            samples: VecDeque::new(),
+        }
+    }
+
+    fn expire(&mut self, now: Instant) {
+        while let Some((start, _)) = self.samples.front() {
+            if now.saturating_duration_since(*start) > WINDOW {
+                self.samples.pop_front();
+            } else {
+                break;
+            }
+        }
+    }
+
+    fn record(&mut self, now: Instant, value: f64) {
+        self.expire(now);
+        match self.samples.back_mut() {
+            Some((start, total)) if now.saturating_duration_since(*start) < SAMPLE => {
+                *total += value;
+            }
+            _ => self.samples.push_back((now, value)),
+        }
+    }
+
+    /// elapsed time of window, short windows are extended to sample
+    /// so single burst of new client is not over estimated
+    fn elapsed(&self, now: Instant) -> Duration {
+        let start = match self.samples.front() {
+            Some((start, _)) if *start > self.created => *start,
+            _ => self.created,
+        };
+        now.saturating_duration_since(start).clamp(SAMPLE, WINDOW)
+    }
+
+    fn throttle(&mut self, now: Instant, quota: Option<u64>) -> Duration {
+        let quota = match quota {
+            Some(quota) if quota > 0 => quota as f64,
+            _ => return Duration::ZERO,
+        };
+        self.expire(now);
+        let elapsed = self.elapsed(now);
+        let total: f64 = self.samples.iter().map(|(_, value)| value).sum();
+        let rate = total / elapsed.as_secs_f64();
+        if rate <= quota {
+            return Duration::ZERO;
+        }
+        let throttle = elapsed.as_secs_f64() * (rate - quota) / quota;
+        Duration::from_secs_f64(throttle).min(WINDOW)
+    }
+
+    fn is_idle(&self, now: Instant) -> bool {
+        match self.samples.back() {
+            Some((start, _)) => now.saturating_duration_since(*start) > WINDOW,
+            None => true,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    use std::time::{Duration, Instant};
+
+    use fluvio_controlplane::Quota;
+    use fluvio_controlplane_metadata::quota::QuotaSpec;
+
+    use super::{QuotaManager, QuotaUsage};
+
+    fn quota(name: &str, principal: Option<&str>, client_id: Option<&str>) -> Quota {
+        Quota::new(
+            name.to_owned(),
+            QuotaSpec {
+                principal: principal.map(|p| p.to_owned()),
+                client_id: client_id.map(|c| c.to_owned()),
+                produce_byte_rate: Some(1000),
+                fetch_byte_rate: Some(2000),
+                request_rate: None,
+            },
+        )
+    }
+
+    #[test]
+    fn test_no_quota() {
+        let manager = QuotaManager::default();
+        let throttle = manager.record(Some("alice"), "client", QuotaUsage::Produce(u64::MAX));
+        assert_eq!(throttle, Duration::ZERO);
+    }
+
+    #[test]
+    fn test_throttle_over_quota() {
+        let manager = QuotaManager::default();
+        manager.update(vec![quota("alice", Some("alice"), None)]);
+        let now = Instant::now();
+
+        // within quota
+        let throttle = manager.record_at(Some("alice"), "c1", QuotaUsage::Produce(500), now);
+        assert_eq!(throttle, Duration::ZERO);
+
+        // 3000 bytes in 1 sec, client waits 2 more secs to get back to 1000 bytes/sec
+        let throttle = manager.record_at(Some("alice"), "c2", QuotaUsage::Produce(2500), now);
+        assert_eq!(throttle, Duration::from_secs(2));
+
+        // fetch is tracked separately
+        let throttle = manager.record_at(Some("alice"), "c1", QuotaUsage::Fetch(2000), now);
+        assert_eq!(throttle, Duration::ZERO);
+
+        // other principal is not limited
+        let throttle = manager.record_at(Some("bob"), "c1", QuotaUsage::Produce(5000), now);
+        assert_eq!(throttle, Duration::ZERO);
+
+        // rate drops as time passes
+        let later = now + Duration::from_secs(3);
+        let throttle = manager.record_at(Some("alice"), "c1", QuotaUsage::Produce(0), later);
+        assert_eq!(throttle, Duration::ZERO);
+    }
+
+    #[test]
+    fn test_most_specific_quota() {
+        let manager = QuotaManager::default();
+        manager.update(vec![
+            quota("default", None, None),
+            quota("alice", Some("alice"), None),
+            quota("alice-batch", Some("alice"), Some("batch")),
+        ]);
+        let now = Instant::now();
+
+        // alice-batch usage doesn't count against alice
+        let throttle = manager.record_at(Some("alice"), "batch", QuotaUsage::Produce(1000), now);
+        assert_eq!(throttle, Duration::ZERO);
+        let throttle = manager.record_at(Some("alice"), "other", QuotaUsage::Produce(1000), now);
+        assert_eq!(throttle, Duration::ZERO);
+
+        // default quota is tracked per client
+        let throttle = manager.record_at(Some("bob"), "c1", QuotaUsage::Produce(1000), now);
+        assert_eq!(throttle, Duration::ZERO);
+        let throttle = manager.record_at(Some("bob"), "c2", QuotaUsage::Produce(1000), now);
+        assert_eq!(throttle, Duration::ZERO);
+        let throttle = manager.record_at(Some("bob"), "c2", QuotaUsage::Produce(1000), now);
+        assert_eq!(throttle, Duration::from_secs(1));
+    }
+
+    #[test]
+    fn test_request_rate() {
+        let manager = QuotaManager::default();
+        manager.update(vec![Quota::new(
+            "slow".to_owned(),
+            QuotaSpec {
+                client_id: Some("slow".to_owned()),
+                request_rate: Some(2),
+                ..Default::default()
+            },
+        )]);
+        let now = Instant::now();
+        for _ in 0..2 {
+            let throttle = manager.record_at(None, "slow", QuotaUsage::Fetch(10), now);
+            assert_eq!(throttle, Duration::ZERO);
+        }
+        let throttle = manager.record_at(None, "slow", QuotaUsage::Fetch(10), now);
+        assert_eq!(throttle, Duration::from_millis(500));
+    }
+
+    #[test]
+    fn test_update_keeps_usage() {
+        let manager = QuotaManager::default();
+        manager.update(vec![
+            quota("alice", Some("alice"), None),
+            quota("bob", Some("bob"), None),
+        ]);
+        let now = Instant::now();
+        manager.record_at(Some("alice"), "c1", QuotaUsage::Produce(3000), now);
+        manager.record_at(Some("bob"), "c1", QuotaUsage::Produce(3000), now);
+
+        // alice limit is raised, bob quota is removed and added back
+        let mut raised = quota("alice", Some("alice"), None);
+        raised.spec.produce_byte_rate = Some(1500);
+        manager.update(vec![raised.clone()]);
+        manager.update(vec![raised, quota("bob", Some("bob"), None)]);
+
+        // usage of alice is kept, client waits 1 more sec to get back to 1500 bytes/sec
+        let throttle = manager.record_at(Some("alice"), "c1", QuotaUsage::Produce(0), now);
+        assert_eq!(throttle, Duration::from_secs(1));
+
+        // usage of removed quota is discarded
+        let throttle = manager.record_at(Some("bob"), "c1", QuotaUsage::Produce(0), now);
+        assert_eq!(throttle, Duration::ZERO);
+    }
+}
@@ -0,0 +1,115 @@
+//!
+//! # SPU Schema Validation
+//!
+//! Schema subjects with validation enabled, received from SC.
+//! Values produced to topic with same name as subject must be framed with
+//! schema id of subject and match schema.
+//!
+use std::collections::HashMap;
+use std::sync::{Arc, RwLock};
+
+use tracing::{debug, error};
+
+use dataplane::record::RecordSet;
+use fluvio_controlplane::SchemaSubject;
+use fluvio_controlplane_metadata::schema::ValueValidator;
+
+#[derive(Debug, Default)]
+pub struct SchemaValidator {
+    subjects: RwLock<HashMap<String, Arc<ValueValidator>>>,
+}
+
+impl SchemaValidator {
+    /// replace all subjects
+    pub fn update(&self, subjects: Vec<SchemaSubject>) {
+        let subjects: HashMap<String, Arc<ValueValidator>> = subjects
+            .into_iter()
+            .filter_map(|subject| match ValueValidator::new(&subject.spec) {
+                Ok(validator) => Some((subject.name, Arc::new(validator))),
+                Err(err) => {
+                    error!(subject = %subject.name, %err, "invalid schema, skipping validation");
+                    None
+                }
+            })
+            .collect();
+        debug!(subjects = subjects.len(), "updating schema subjects");
+        *self.subjects.write().unwrap() = subjects;
+    }
+
+    /// check values of all records produced to topic, transaction markers are not checked
+    pub fn validate(&self, topic: &str, records: &RecordSet) -> Result<(), String> {
+        let validator = match self.subjects.read().unwrap().get(topic) {
+            Some(validator) => validator.clone(),
+            None => return Ok(()),
+        };
+
+        for (index, record) in records
+            .batches
+            .iter()
+            .filter(|batch| !batch.get_header().is_control())
+            .flat_map(|batch| batch.records().iter())
+            .enumerate()
+        {
+            validator
+                .validate(record.value().as_ref())
+                .map_err(|err| format!("record {}: {}", index, err))?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    use dataplane::batch::Batch;
+    use dataplane::record::{Record, RecordSet};
+    use dataplane::transaction::ControlType;
+    use fluvio_controlplane::SchemaSubject;
+    use fluvio_controlplane_metadata::schema::{
+        SchemaSpec, SchemaType, SchemaVersion, encode_schema_id,
+    };
+
+    use super::SchemaValidator;
+
+    fn records(values: Vec<Vec<u8>>) -> RecordSet {
+        let mut batch = Batch::default();
+        for value in values {
+            batch.add_record(Record::new(value));
+        }
+        RecordSet::default().add(batch)
+    }
+
+    #[test]
+    fn test_validate_subject_of_topic() {
+        let validator = SchemaValidator::default();
+        validator.update(vec![SchemaSubject::new(
+            "users".to_owned(),
+            SchemaSpec {
+                validate: true,
+                versions: vec![SchemaVersion {
+                    version: 1,
+                    id: 1,
+                    schema_type: SchemaType::JsonSchema,
+                    definition: r#"{"type": "object", "required": ["name"]}"#.to_owned(),
+                }],
+                ..Default::default()
+            },
+        )]);
+
+        let valid = records(vec![encode_schema_id(1, br#"{"name": "bob"}"#)]);
+        let invalid = records(vec![
+            encode_schema_id(1, br#"{"name": "bob"}"#),
+            br#"{"name": "alice"}"#.to_vec(),
+        ]);
+
+        assert!(validator.validate("users", &valid).is_ok());
+        assert!(validator.validate("users", &invalid).is_err());
+        assert!(validator.validate("other", &invalid).is_ok());
+
+        let marker = RecordSet::default().add(Batch::control_marker(1, 0, 0, ControlType::Commit));
+        assert!(validator.validate("users", &marker).is_ok());
+
+        validator.update(vec![]);
+        assert!(validator.validate("users", &invalid).is_ok());
+    }
+}
@@ -0,0 +1,254 @@
+//!
+//! # SPU Transactions
+//!
+//! Latest epoch of transactional producers, batches of older epochs are from
+//! fenced producers and are rejected. Markers of transactions are sent by SC,
+//! each marker is written once. Sequence of transactions of producer increases,
+//! so marker is written if marker with same or higher sequence is in replica.
+//!
+//! Epochs and markers are written to log and replicated with other batches,
+//! state is rebuilt from log when replica becomes led by this SPU.
+//!
+use std::collections::HashMap;
+use std::sync::Mutex;
+
+use tracing::{debug, error, trace, instrument};
+
+use dataplane::batch::{Batch, BatchHeader};
+use dataplane::record::RecordSet;
+use dataplane::transaction::ControlType;
+use dataplane::ReplicaKey;
+use fluvio_controlplane::TxnMarker;
+use fluvio_storage::FileReplica;
+
+use crate::core::DefaultSharedGlobalContext;
+use crate::replication::leader::LeaderReplicaState;
+
+#[derive(Debug, Default)]
+pub struct TransactionState {
+    epochs: Mutex<HashMap<i64, i16>>,
+    /// sequence of last written marker by replica and producer id
+    markers: Mutex<HashMap<(ReplicaKey, i64), i32>>,
+}
+
+impl TransactionState {
+    /// check epoch is not older than latest epoch of producer, which is then recorded
+    pub fn check_epoch(&self, producer_id: i64, producer_epoch: i16) -> bool {
+        let mut epochs = self.epochs.lock().unwrap();
+        let epoch = epochs.entry(producer_id).or_insert(producer_epoch);
+        if producer_epoch < *epoch {
+            false
+        } else {
+            *epoch = producer_epoch;
+            true
+        }
+    }
+
+    fn is_written(&self, marker: &TxnMarker) -> bool {
+        let markers = self.markers.lock().unwrap();
+        matches!(
+            markers.get(&(marker.replica.clone(), marker.producer_id)),
+            Some(sequence) if *sequence >= marker.txn_sequence
+        )
+    }
+
+    /// markers which are not written yet
+    pub fn pending_markers(&self, markers: &[TxnMarker]) -> Vec<TxnMarker> {
+        markers
+            .iter()
+            .filter(|marker| !self.is_written(marker))
+            .cloned()
+            .collect()
+    }
+
+    pub fn marker_written(&self, replica: &ReplicaKey, producer_id: i64, txn_sequence: i32) {
+        let mut markers = self.markers.lock().unwrap();
+        let sequence = markers
+            .entry((replica.clone(), producer_id))
+            .or_insert(txn_sequence);
+        *sequence = txn_sequence.max(*sequence);
+    }
+
+    /// markers which are written, including ones written before
+    pub fn written_markers(&self, markers: &[TxnMarker]) -> Vec<TxnMarker> {
+        markers
+            .iter()
+            .filter(|marker| self.is_written(marker))
+            .cloned()
+            .collect()
+    }
+
+    /// record epoch and marker of batch which is in log of replica
+    fn load_batch(&self, replica: &ReplicaKey, header: &BatchHeader) {
+        self.check_epoch(header.producer_id, header.producer_epoch);
+        if header.is_control() {
+            self.marker_written(replica, header.producer_id, header.first_sequence);
+        }
+    }
+
+    /// rebuild epochs and written markers from log of replica led by this SPU
+    #[instrument(skip(self, leader), fields(replica = %leader.id()))]
+    pub async fn load_replica(&self, leader: &LeaderReplicaState<FileReplica>) {
+        let headers = leader.read().await.read_transactional_headers().await;
+        match headers {
+            Ok(headers) => {
+                debug!(count = headers.len(), "loading transactional batches");
+                for header in headers {
+                    self.load_batch(leader.id(), &header);
+                }
+            }
+            Err(err) => {
+                error!("error reading transactional batches: {:#?}", err);
+            }
+        }
+    }
+}
+
+/// write markers sent by SC to replicas led by this SPU, markers which are written are returned.
+/// Epoch of marker fences older epochs of producer before marker is written
+#[instrument(skip(ctx, markers))]
+pub async fn write_markers(
+    ctx: &DefaultSharedGlobalContext,
+    markers: &[TxnMarker],
+) -> Vec<TxnMarker> {
+    let transactions = ctx.transactions();
+    for marker in transactions.pending_markers(markers) {
+        let leader_state = match ctx.leaders_state().get(&marker.replica) {
+            Some(leader_state) => leader_state,
+            None => {
+                trace!(replica = %marker.replica, "not leader, skipping marker");
+                continue;
+            }
+        };
+
+        transactions.check_epoch(marker.producer_id, marker.producer_epoch);
+        let control = if marker.commit {
+            ControlType::Commit
+        } else {
+            ControlType::Abort
+        };
+        let mut records = RecordSet::default().add(Batch::control_marker(
+            marker.producer_id,
+            marker.producer_epoch,
+            marker.txn_sequence,
+            control,
+        ));
+        match leader_state
+            .write_record_set(&mut records, ctx.follower_notifier())
+            .await
+        {
+            Ok(_) => {
+                debug!(replica = %marker.replica, ?control, "transaction marker written");
+                transactions.marker_written(
+                    &marker.replica,
+                    marker.producer_id,
+                    marker.txn_sequence,
+                );
+            }
+            Err(err) => {
+                error!(replica = %marker.replica, "error writing transaction marker: {:#?}", err);
+            }
+        }
+    }
+    transactions.written_markers(markers)
+}
+
+#[cfg(test)]
+mod test {
+
+    use std::env::temp_dir;
+
+    use flv_util::fixture::ensure_clean_dir;
+    use fluvio_controlplane::TxnMarker;
+    use fluvio_controlplane_metadata::partition::{Replica, ReplicaKey};
+
+    use crate::config::SpuConfig;
+    use crate::core::GlobalContext;
+    use crate::replication::leader::LeaderReplicaState;
+
+    use super::{TransactionState, write_markers};
+
+    #[test]
+    fn test_fence_epoch() {
+        let state = TransactionState::default();
+        assert!(state.check_epoch(1, 0));
+        assert!(state.check_epoch(1, 2));
+        assert!(!state.check_epoch(1, 1));
+        assert!(state.check_epoch(1, 2));
+        assert!(state.check_epoch(2, 0));
+    }
+
+    #[test]
+    fn test_pending_markers() {
+        let state = TransactionState::default();
+        let first = TxnMarker::new(ReplicaKey::new("out", 0), 1, 0, 0, true);
+        let second = TxnMarker::new(ReplicaKey::new("out", 1), 1, 0, 0, true);
+        let markers = vec![first.clone(), second.clone()];
+
+        assert_eq!(state.pending_markers(&markers), markers);
+        state.marker_written(&first.replica, 1, 0);
+        assert_eq!(state.pending_markers(&markers), vec![second.clone()]);
+        assert_eq!(state.written_markers(&markers), vec![first.clone()]);
+
+        // next transaction with same epoch is not merged with previous one
+        let next = TxnMarker::new(ReplicaKey::new("out", 0), 1, 0, 1, true);
+        assert_eq!(state.pending_markers(&[next.clone()]), vec![next.clone()]);
+        state.marker_written(&next.replica, 1, 1);
+        assert_eq!(
+            state.written_markers(&[first.clone(), next.clone()]),
+            vec![first, next]
+        );
+        assert_eq!(state.pending_markers(&[second.clone()]), vec![second]);
+    }
+
+    #[fluvio_future::test]
+    async fn test_write_markers() {
+        let test_path = temp_dir().join("test_write_txn_markers");
+        ensure_clean_dir(&test_path);
+
+        let mut spu_config = SpuConfig::default();
+        spu_config.log.base_dir = test_path;
+        let ctx = GlobalContext::new_shared_context(spu_config.clone());
+
+        let test = Replica::new(("txn", 0), 5001, vec![5001]);
+        let replica_id: ReplicaKey = test.id.clone();
+        let leader =
+            LeaderReplicaState::create(test.clone(), ctx.config(), ctx.status_update_owned())
+                .await
+                .expect("replica");
+        ctx.leaders_state()
+            .insert(replica_id.clone(), leader.clone());
+
+        let marker = TxnMarker::new(replica_id.clone(), 1, 3, 0, false);
+        // replica of other SPU is skipped
+        let other = TxnMarker::new(ReplicaKey::new("txn", 1), 1, 3, 0, false);
+        let markers = vec![marker.clone(), other];
+
+        assert_eq!(write_markers(&ctx, &markers).await, vec![marker.clone()]);
+        assert_eq!(leader.leo(), 1);
+        assert!(!ctx.transactions().check_epoch(1, 2));
+
+        // marker is confirmed again, but written once
+        assert_eq!(write_markers(&ctx, &markers).await, vec![marker.clone()]);
+        assert_eq!(leader.leo(), 1);
+        drop(leader);
+        drop(ctx);
+
+        // state is rebuilt from log after restart
+        let ctx = GlobalContext::new_shared_context(spu_config);
+        let leader = LeaderReplicaState::create(test, ctx.config(), ctx.status_update_owned())
+            .await
+            .expect("replica");
+        ctx.leaders_state()
+            .insert(replica_id.clone(), leader.clone());
+        ctx.transactions().load_replica(&leader).await;
+
+        assert!(!ctx.transactions().check_epoch(1, 2));
+        assert_eq!(write_markers(&ctx, &[marker.clone()]).await, vec![marker]);
+        assert_eq!(leader.leo(), 1);
+
+        let next = TxnMarker::new(replica_id, 1, 3, 1, true);
+        assert_eq!(write_markers(&ctx, &[next.clone()]).await, vec![next]);
+        assert_eq!(leader.leo(), 2);
+    }
+}
@@ -5,6 +5,8 @@ use tracing::{warn, debug};
 use std::os::unix::io::RawFd;
 use nix::sys::uio::pread;
 use fluvio_future::file_slice::AsyncFileSlice;
+use bytes::Bytes;
+use dataplane::record::FileRecordSet;
 
 // only encode information necessary to decode batches efficiently
 pub struct FileBatch {
@@ -27,6 +29,8 @@ pub struct FileBatchIterator {
     fd: RawFd,
     offset: i64,
     end: i64,
+    /// batches already read into memory, offset is relative to start of memory
+    memory: Option<Bytes>,
 }
 
 impl FileBatchIterator {
@@ -36,6 +40,7 @@ impl FileBatchIterator {
             fd,
             offset,
             end: offset + len,
+            memory: None,
         }
     }
 
@@ -46,6 +51,33 @@ impl FileBatchIterator {
             fd: slice.as_raw_fd(),
             offset,
             end: offset + slice.len() as i64,
+            memory: None,
+        }
+    }
+
+    /// iterate over records in memory if set, otherwise read them from file slice
+    pub fn from_records(records: &FileRecordSet) -> Self {
+        match records.memory() {
+            Some(memory) => Self {
+                fd: records.raw_slice().fd(),
+                offset: 0,
+                end: memory.len() as i64,
+                memory: Some(memory.clone()),
+            },
+            None => Self::from_raw_slice(records.raw_slice()),
+        }
+    }
+
+    fn read_at(&self, buf: &mut [u8], offset: i64) -> Result<usize, IoError> {
+        match &self.memory {
+            Some(memory) => {
+                let start = (offset as usize).min(memory.len());
+                let len = buf.len().min(memory.len() - start);
+                buf[..len].copy_from_slice(&memory[start..start + len]);
+                Ok(len)
+            }
+            None => pread(self.fd, buf, offset)
+                .map_err(|err| IoError::new(ErrorKind::Other, format!("pread error {}", err))),
         }
     }
 }
@@ -59,9 +91,7 @@ impl Iterator for FileBatchIterator {
         }
 
         let mut header = vec![0u8; BATCH_FILE_HEADER_SIZE];
-        let bytes_read = match pread(self.fd, &mut header, self.offset)
-            .map_err(|err| IoError::new(ErrorKind::Other, format!("pread error {}", err)))
-        {
+        let bytes_read = match self.read_at(&mut header, self.offset) {
             Ok(bytes) => bytes,
             Err(err) => return Some(Err(err)),
         };
@@ -98,9 +128,7 @@ impl Iterator for FileBatchIterator {
 
         self.offset += BATCH_FILE_HEADER_SIZE as i64;
 
-        let bytes_read = match pread(self.fd, &mut records, self.offset)
-            .map_err(|err| IoError::new(ErrorKind::Other, format!("pread error {}", err)))
-        {
+        let bytes_read = match self.read_at(&mut records, self.offset) {
             Ok(bytes) => bytes,
             Err(err) => return Some(Err(err)),
         };
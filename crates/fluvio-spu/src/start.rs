@@ -44,6 +44,10 @@ pub fn main_loop(opt: SpuOpt) {
         let _public_shutdown = internal_server.unwrap().run();
         let _private_shutdown = public_server.unwrap().run();
 
+        if let Some(private_tls) = spu_config.private_tls.clone() {
+            proxy::start_private_proxy(spu_config.private_endpoint.clone(), private_tls).await;
+        }
+
         if let Some(tls_config) = tls_acceptor_option {
             proxy::start_proxy(spu_config, tls_config).await;
         }
@@ -98,15 +102,25 @@ mod proxy {
 
     use flv_util::print_cli_err;
     use fluvio_future::openssl::TlsAcceptor;
-    use crate::config::SpuConfig;
-    use flv_tls_proxy::start as proxy_start;
+    use crate::config::{SpuConfig, PrivateTlsConfig};
+    use fluvio_auth::x509::X509Authenticator;
+    use flv_tls_proxy::{
+        start as proxy_start, start_with_authenticator as proxy_start_with_authenticator,
+    };
 
     pub async fn start_proxy(config: SpuConfig, acceptor: (TlsAcceptor, String)) {
         let (tls_acceptor, proxy_addr) = acceptor;
         let target = config.public_endpoint;
         info!("starting TLS proxy: {}", proxy_addr);
 
-        if let Err(err) = proxy_start(&proxy_addr, tls_acceptor, target).await {
+        let result = if let Some(x509_auth_scopes) = config.x509_auth_scopes {
+            let authenticator = Box::new(X509Authenticator::new(&x509_auth_scopes));
+            proxy_start_with_authenticator(&proxy_addr, tls_acceptor, target, authenticator).await
+        } else {
+            proxy_start(&proxy_addr, tls_acceptor, target).await
+        };
+
+        if let Err(err) = result {
             print_cli_err!(err);
             process::exit(-1);
         } else {
@@ -114,4 +128,28 @@ mod proxy {
             println!("TLS proxy started");
         }
     }
+
+    /// proxy for SC and peer SPUs, forwards identity of client cert to private service
+    pub async fn start_private_proxy(target: String, config: PrivateTlsConfig) {
+        info!("starting private TLS proxy: {}", config.proxy_endpoint);
+
+        let result = match config.try_build_tls_acceptor() {
+            Ok(tls_acceptor) => {
+                let authenticator = Box::new(X509Authenticator::identity_only());
+                proxy_start_with_authenticator(
+                    &config.proxy_endpoint,
+                    tls_acceptor,
+                    target,
+                    authenticator,
+                )
+                .await
+            }
+            Err(err) => Err(err),
+        };
+
+        if let Err(err) = result {
+            print_cli_err!(err);
+            process::exit(-1);
+        }
+    }
 }
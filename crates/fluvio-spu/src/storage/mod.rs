@@ -68,6 +68,14 @@ where
         self.hw.current_value()
     }
 
+    /// end of records readable with isolation
+    pub fn end_offset(&self, isolation: &Isolation) -> Offset {
+        match isolation {
+            Isolation::ReadCommitted => self.hw(),
+            Isolation::ReadUncommitted => self.leo(),
+        }
+    }
+
     pub fn as_offset(&self) -> OffsetInfo {
         OffsetInfo {
             hw: self.hw(),
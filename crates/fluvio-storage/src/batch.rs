@@ -44,6 +44,11 @@ where
         &self.inner
     }
 
+    #[inline(always)]
+    pub fn into_batch(self) -> Batch<R> {
+        self.inner
+    }
+
     #[inline(always)]
     pub fn get_pos(&self) -> Size {
         self.pos
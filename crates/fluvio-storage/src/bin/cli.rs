@@ -4,6 +4,9 @@ use structopt::StructOpt;
 
 use fluvio_future::task::run_block_on;
 use fluvio_storage::{LogIndex, StorageError, OffsetPosition, batch_header::BatchHeaderStream};
+use fluvio_storage::encryption::SegmentCipher;
+use dataplane::batch::Batch;
+use dataplane::core::Decoder;
 
 #[derive(Debug, StructOpt)]
 #[structopt(name = "storage", about = "Flavio Storage CLI")]
@@ -12,6 +15,8 @@ enum Main {
     Log(LogOpt),
     #[structopt(name = "index")]
     Index(IndexOpt),
+    #[structopt(name = "decrypt")]
+    Decrypt(DecryptOpt),
 }
 
 fn main() {
@@ -22,6 +27,11 @@ fn main() {
     match opt {
         Main::Log(opt) => dump_log(opt),
         Main::Index(opt) => dump_index(opt),
+        Main::Decrypt(opt) => {
+            if let Err(err) = decrypt_log(opt) {
+                println!("error decrypting: {:#?}", err)
+            }
+        }
     }
 }
 
@@ -104,3 +114,54 @@ async fn print_index(path: PathBuf) -> Result<(), StorageError> {
 
     Ok(())
 }
+
+#[derive(Debug, StructOpt)]
+pub(crate) struct DecryptOpt {
+    #[structopt(parse(from_os_str))]
+    file_name: PathBuf,
+
+    /// key used to encrypt segment
+    #[structopt(long, parse(from_os_str), conflicts_with = "key_dir")]
+    key_file: Option<PathBuf>,
+
+    /// directory of `<id>.key` files
+    #[structopt(long, parse(from_os_str), required_unless = "key_file")]
+    key_dir: Option<PathBuf>,
+
+    /// write decrypted segment to this file
+    #[structopt(long, parse(from_os_str))]
+    output: Option<PathBuf>,
+}
+
+pub(crate) fn decrypt_log(opt: DecryptOpt) -> Result<(), StorageError> {
+    let cipher = match (opt.key_file, opt.key_dir) {
+        (Some(key_file), _) => SegmentCipher::from_key_file(key_file)?,
+        (None, Some(key_dir)) => SegmentCipher::from_key_dir(key_dir)?,
+        (None, None) => unreachable!("key is required"),
+    };
+
+    println!("decrypting batch: {:#?}", opt.file_name);
+    let bytes = std::fs::read(&opt.file_name)?;
+    let decrypted = cipher.decrypt_batches(&bytes)?;
+
+    let mut src = std::io::Cursor::new(decrypted.as_ref());
+    while (src.position() as usize) < decrypted.len() {
+        let batch: Batch = Batch::decode_from(&mut src, 0)?;
+        println!(
+            "batch offset: {}, records: {}",
+            batch.get_base_offset(),
+            batch.records().len()
+        );
+        for record in batch.records() {
+            println!("  {}", record.value().describe());
+        }
+    }
+
+    if let Some(output) = opt.output {
+        std::fs::write(&output, &decrypted)?;
+        println!("decrypted segment written to: {:#?}", output);
+    }
+    println!("done");
+
+    Ok(())
+}
@@ -8,13 +8,17 @@ use serde::Deserialize;
 use fluvio_types::defaults::{SPU_LOG_INDEX_MAX_BYTES, SPU_LOG_BASE_DIR};
 use fluvio_types::defaults::SPU_LOG_INDEX_MAX_INTERVAL_BYTES;
 use fluvio_types::defaults::SPU_LOG_SEGMENT_MAX_BYTES;
+use fluvio_types::defaults::{
+    SPU_LOG_FLUSH_WRITE_COUNT, SPU_LOG_FLUSH_IDLE_MSEC, SPU_LOG_MAX_BATCH_SIZE,
+};
 use dataplane::Size;
 
 use crate::ReplicaStorageConfig;
+use crate::encryption::SegmentCipher;
 
-pub const DEFAULT_FLUSH_WRITE_COUNT: u32 = 1;
-pub const DEFAULT_FLUSH_IDLE_MSEC: u32 = 0;
-pub const DEFAULT_MAX_BATCH_SIZE: u32 = 1048588;
+pub const DEFAULT_FLUSH_WRITE_COUNT: u32 = SPU_LOG_FLUSH_WRITE_COUNT;
+pub const DEFAULT_FLUSH_IDLE_MSEC: u32 = SPU_LOG_FLUSH_IDLE_MSEC;
+pub const DEFAULT_MAX_BATCH_SIZE: u32 = SPU_LOG_MAX_BATCH_SIZE;
 
 // common option
 #[derive(Builder, Debug, Clone, PartialEq, Deserialize)]
@@ -44,6 +48,10 @@ pub struct ConfigOption {
     #[builder(default = "default_update_hw()")]
     #[serde(default = "default_update_hw")]
     pub update_hw: bool, // if true, enable hw update
+    /// if set, records are encrypted before written to segment
+    #[builder(default)]
+    #[serde(skip)]
+    pub encryption: Option<SegmentCipher>,
 }
 
 impl fmt::Display for ConfigOption {
@@ -104,6 +112,7 @@ impl Default for ConfigOption {
             flush_idle_msec: default_flush_idle_msec(),
             max_batch_size: default_max_batch_size(),
             update_hw: true,
+            encryption: None,
         }
     }
 }
@@ -0,0 +1,440 @@
+//!
+//! # Segment Encryption
+//!
+//! Records of batch are encrypted with AES-256-GCM before batch is written to log segment.
+//! Preamble and header of batch are kept in plain text, so index and log validation
+//! work without key. Header is authenticated together with records.
+//!
+//! Encrypted records are stored as: key id (u32) | nonce (12 bytes) | cipher text and tag
+//!
+//! Records of encrypted replica are decrypted in memory when fetched,
+//! so they are not sent with zero copy like records of plain replica.
+//!
+use std::collections::BTreeMap;
+use std::fmt;
+use std::fs;
+use std::io::{Error as IoError, ErrorKind};
+use std::os::unix::io::RawFd;
+use std::path::Path;
+use std::sync::Arc;
+
+use aes_gcm::{Aes256Gcm, Key, Nonce};
+use aes_gcm::aead::{Aead, NewAead, Payload};
+use bytes::{BufMut, Bytes, BytesMut};
+use nix::sys::uio::pread;
+use rand::RngCore;
+use tracing::{debug, trace};
+
+use fluvio_future::file_slice::AsyncFileSlice;
+use dataplane::batch::{BATCH_FILE_HEADER_SIZE, BATCH_PREAMBLE_SIZE};
+
+/// set in batch attributes when records are encrypted
+pub const ENCRYPTED_ATTRIBUTE: i16 = 0x0100;
+
+pub const KEY_EXTENSION: &str = "key";
+
+const KEY_LEN: usize = 32;
+const KEY_ID_LEN: usize = 4;
+const NONCE_LEN: usize = 12;
+const TAG_LEN: usize = 16;
+
+// position of header fields in encoded batch
+const BATCH_LEN_POS: usize = 8;
+const CRC_POS: usize = 17;
+const ATTRIBUTES_POS: usize = 21;
+
+/// Keys to encrypt and decrypt log segments.
+/// New batches are encrypted with current key,
+/// previous keys are kept to decrypt batches written before key rotation.
+#[derive(Clone)]
+pub struct SegmentCipher {
+    current: u32,
+    keys: Arc<BTreeMap<u32, Aes256Gcm>>,
+}
+
+impl fmt::Debug for SegmentCipher {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "SegmentCipher(current key: {})", self.current)
+    }
+}
+
+impl PartialEq for SegmentCipher {
+    fn eq(&self, other: &Self) -> bool {
+        self.current == other.current && self.keys.keys().eq(other.keys.keys())
+    }
+}
+
+impl SegmentCipher {
+    /// keys by id, key with highest id is current
+    pub fn new(keys: BTreeMap<u32, [u8; KEY_LEN]>) -> Result<Self, IoError> {
+        let current = *keys
+            .keys()
+            .next_back()
+            .ok_or_else(|| IoError::new(ErrorKind::InvalidInput, "no encryption keys"))?;
+        let keys = keys
+            .into_iter()
+            .map(|(id, key)| (id, Aes256Gcm::new(Key::from_slice(&key))))
+            .collect();
+        Ok(Self {
+            current,
+            keys: Arc::new(keys),
+        })
+    }
+
+    /// single key with id 0
+    pub fn from_key_file<P: AsRef<Path>>(path: P) -> Result<Self, IoError> {
+        let mut keys = BTreeMap::new();
+        keys.insert(0, read_key(path.as_ref())?);
+        Self::new(keys)
+    }
+
+    /// local key provider, directory of `<id>.key` files.
+    /// keys are rotated by adding key with higher id
+    pub fn from_key_dir<P: AsRef<Path>>(dir: P) -> Result<Self, IoError> {
+        let mut keys = BTreeMap::new();
+        for entry in fs::read_dir(dir.as_ref())? {
+            let path = entry?.path();
+            if path.extension().and_then(|ext| ext.to_str()) != Some(KEY_EXTENSION) {
+                continue;
+            }
+            let id = path
+                .file_stem()
+                .and_then(|stem| stem.to_str())
+                .and_then(|stem| stem.parse::<u32>().ok())
+                .ok_or_else(|| {
+                    IoError::new(
+                        ErrorKind::InvalidInput,
+                        format!("key file name must be numeric id: {}", path.display()),
+                    )
+                })?;
+            keys.insert(id, read_key(&path)?);
+        }
+        debug!(keys = keys.len(), "loaded encryption keys");
+        Self::new(keys)
+    }
+
+    pub fn current_key_id(&self) -> u32 {
+        self.current
+    }
+
+    /// encrypt records of encoded batch in place, batch which is already encrypted is not changed
+    pub fn encrypt_batch(&self, batch: &mut Vec<u8>) -> Result<(), IoError> {
+        let attributes = attributes(batch)?;
+        if attributes & ENCRYPTED_ATTRIBUTE != 0 {
+            return Ok(());
+        }
+
+        let cipher = &self.keys[&self.current];
+        let mut nonce = [0u8; NONCE_LEN];
+        rand::thread_rng().fill_bytes(&mut nonce);
+
+        let sealed = cipher
+            .encrypt(
+                Nonce::from_slice(&nonce),
+                Payload {
+                    msg: &batch[BATCH_FILE_HEADER_SIZE..],
+                    aad: &header_aad(batch, attributes | ENCRYPTED_ATTRIBUTE),
+                },
+            )
+            .map_err(|_| IoError::new(ErrorKind::Other, "batch encryption failed"))?;
+
+        batch.truncate(BATCH_FILE_HEADER_SIZE);
+        batch.extend_from_slice(&self.current.to_be_bytes());
+        batch.extend_from_slice(&nonce);
+        batch.extend_from_slice(&sealed);
+        update_header(batch, attributes | ENCRYPTED_ATTRIBUTE);
+        Ok(())
+    }
+
+    /// decrypt encoded batch into dest, batch which is not encrypted is copied as is
+    pub fn decrypt_batch(&self, batch: &[u8], dest: &mut BytesMut) -> Result<(), IoError> {
+        let attributes = attributes(batch)?;
+        if attributes & ENCRYPTED_ATTRIBUTE == 0 {
+            dest.put_slice(batch);
+            return Ok(());
+        }
+
+        let sealed = &batch[BATCH_FILE_HEADER_SIZE..];
+        if sealed.len() < KEY_ID_LEN + NONCE_LEN + TAG_LEN {
+            return Err(IoError::new(
+                ErrorKind::InvalidData,
+                "encrypted records are too short",
+            ));
+        }
+
+        let mut key_id = [0u8; KEY_ID_LEN];
+        key_id.copy_from_slice(&sealed[..KEY_ID_LEN]);
+        let key_id = u32::from_be_bytes(key_id);
+        let cipher = self.keys.get(&key_id).ok_or_else(|| {
+            IoError::new(
+                ErrorKind::InvalidData,
+                format!("encryption key {} not found", key_id),
+            )
+        })?;
+
+        let nonce = &sealed[KEY_ID_LEN..KEY_ID_LEN + NONCE_LEN];
+        let records = cipher
+            .decrypt(
+                Nonce::from_slice(nonce),
+                Payload {
+                    msg: &sealed[KEY_ID_LEN + NONCE_LEN..],
+                    aad: &header_aad(batch, attributes),
+                },
+            )
+            .map_err(|_| IoError::new(ErrorKind::InvalidData, "batch decryption failed"))?;
+
+        let mut plain = Vec::with_capacity(BATCH_FILE_HEADER_SIZE + records.len());
+        plain.extend_from_slice(&batch[..BATCH_FILE_HEADER_SIZE]);
+        plain.extend_from_slice(&records);
+        update_header(&mut plain, attributes & !ENCRYPTED_ATTRIBUTE);
+        dest.put_slice(&plain);
+        Ok(())
+    }
+
+    /// decrypt sequence of encoded batches, incomplete batch at the end is dropped
+    pub fn decrypt_batches(&self, mut bytes: &[u8]) -> Result<BytesMut, IoError> {
+        let mut dest = BytesMut::with_capacity(bytes.len());
+        while bytes.len() >= BATCH_FILE_HEADER_SIZE {
+            let total_len = total_batch_len(bytes)?;
+            if bytes.len() < total_len {
+                break;
+            }
+            self.decrypt_batch(&bytes[..total_len], &mut dest)?;
+            bytes = &bytes[total_len..];
+        }
+        Ok(dest)
+    }
+
+    /// read complete batches from slice and decrypt them.
+    /// batches are read until max_len is reached, but at least one batch is read.
+    /// this does blocking io, so it must not be called on executor thread
+    pub fn read_slice(&self, slice: &AsyncFileSlice, max_len: u32) -> Result<Bytes, IoError> {
+        let fd = slice.fd();
+        let end = slice.position() + slice.len();
+        let mut pos = slice.position();
+        let mut read_len: u64 = 0;
+        let mut dest = BytesMut::new();
+
+        while pos + BATCH_FILE_HEADER_SIZE as u64 <= end {
+            let mut preamble = [0u8; BATCH_PREAMBLE_SIZE];
+            pread_exact(fd, &mut preamble, pos)?;
+            let total_len = total_batch_len(&preamble)? as u64;
+            if pos + total_len > end {
+                break;
+            }
+            if read_len > 0 && read_len + total_len > max_len as u64 {
+                break;
+            }
+
+            let mut batch = vec![0u8; total_len as usize];
+            pread_exact(fd, &mut batch, pos)?;
+            self.decrypt_batch(&batch, &mut dest)?;
+
+            pos += total_len;
+            read_len += total_len;
+        }
+
+        trace!(read_len, decrypted_len = dest.len(), "decrypted slice");
+        Ok(dest.freeze())
+    }
+}
+
+/// key is either 32 raw bytes or 64 hex characters
+fn read_key(path: &Path) -> Result<[u8; KEY_LEN], IoError> {
+    let content = fs::read(path)?;
+    let mut key = [0u8; KEY_LEN];
+    if content.len() == KEY_LEN {
+        key.copy_from_slice(&content);
+        return Ok(key);
+    }
+
+    let text = String::from_utf8_lossy(&content);
+    let decoded = hex::decode(text.trim())
+        .ok()
+        .filter(|bytes| bytes.len() == KEY_LEN);
+    match decoded {
+        Some(bytes) => {
+            key.copy_from_slice(&bytes);
+            Ok(key)
+        }
+        None => Err(IoError::new(
+            ErrorKind::InvalidData,
+            format!(
+                "key must be 32 bytes or 64 hex characters: {}",
+                path.display()
+            ),
+        )),
+    }
+}
+
+fn attributes(batch: &[u8]) -> Result<i16, IoError> {
+    if batch.len() < BATCH_FILE_HEADER_SIZE {
+        return Err(IoError::new(
+            ErrorKind::UnexpectedEof,
+            "not enough bytes for batch header",
+        ));
+    }
+    Ok(i16::from_be_bytes([
+        batch[ATTRIBUTES_POS],
+        batch[ATTRIBUTES_POS + 1],
+    ]))
+}
+
+/// length of batch including preamble
+fn total_batch_len(batch: &[u8]) -> Result<usize, IoError> {
+    let mut batch_len = [0u8; 4];
+    batch_len.copy_from_slice(&batch[BATCH_LEN_POS..BATCH_PREAMBLE_SIZE]);
+    let batch_len = i32::from_be_bytes(batch_len);
+    if batch_len < 0 {
+        return Err(IoError::new(ErrorKind::InvalidData, "negative batch len"));
+    }
+    Ok(BATCH_PREAMBLE_SIZE + batch_len as usize)
+}
+
+/// batch header as authenticated data, header is stored in plain text so
+/// it is bound to records. batch len and crc depend on cipher text, so they are excluded
+fn header_aad(batch: &[u8], attributes: i16) -> [u8; BATCH_FILE_HEADER_SIZE] {
+    let mut aad = [0u8; BATCH_FILE_HEADER_SIZE];
+    aad.copy_from_slice(&batch[..BATCH_FILE_HEADER_SIZE]);
+    aad[BATCH_LEN_POS..BATCH_PREAMBLE_SIZE].fill(0);
+    aad[CRC_POS..CRC_POS + 4].fill(0);
+    aad[ATTRIBUTES_POS..ATTRIBUTES_POS + 2].copy_from_slice(&attributes.to_be_bytes());
+    aad
+}
+
+/// set attributes, then batch len and crc to match content
+fn update_header(batch: &mut [u8], attributes: i16) {
+    batch[ATTRIBUTES_POS..ATTRIBUTES_POS + 2].copy_from_slice(&attributes.to_be_bytes());
+    let batch_len = (batch.len() - BATCH_PREAMBLE_SIZE) as i32;
+    batch[BATCH_LEN_POS..BATCH_PREAMBLE_SIZE].copy_from_slice(&batch_len.to_be_bytes());
+    let crc = crc32c::crc32c(&batch[ATTRIBUTES_POS..]);
+    batch[CRC_POS..CRC_POS + 4].copy_from_slice(&crc.to_be_bytes());
+}
+
+fn pread_exact(fd: RawFd, buf: &mut [u8], offset: u64) -> Result<(), IoError> {
+    let mut done = 0;
+    while done < buf.len() {
+        let read =
+            pread(fd, &mut buf[done..], (offset + done as u64) as i64).map_err(IoError::from)?;
+        if read == 0 {
+            return Err(IoError::new(
+                ErrorKind::UnexpectedEof,
+                "not enough bytes for batch",
+            ));
+        }
+        done += read;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+
+    use std::collections::BTreeMap;
+    use std::io::Cursor;
+
+    use bytes::BytesMut;
+
+    use dataplane::batch::Batch;
+    use dataplane::core::{Decoder, Encoder};
+    use dataplane::fixture::create_batch;
+
+    use super::{SegmentCipher, ENCRYPTED_ATTRIBUTE};
+
+    fn cipher(ids: &[u32]) -> SegmentCipher {
+        let keys: BTreeMap<u32, [u8; 32]> = ids.iter().map(|id| (*id, [*id as u8; 32])).collect();
+        SegmentCipher::new(keys).expect("cipher")
+    }
+
+    fn encoded_batch(base_offset: i64) -> Vec<u8> {
+        let mut batch = create_batch();
+        batch.base_offset = base_offset;
+        let mut bytes = vec![];
+        batch.encode(&mut bytes, 0).expect("encode");
+        bytes
+    }
+
+    #[test]
+    fn test_encrypt_decrypt_batch() {
+        let cipher = cipher(&[0]);
+        let plain = encoded_batch(10);
+        let mut encrypted = plain.clone();
+        cipher.encrypt_batch(&mut encrypted).expect("encrypt");
+
+        assert_ne!(encrypted, plain);
+        // header is readable without key
+        let mut header: Batch = Batch::default();
+        header
+            .decode_from_file_buf(&mut Cursor::new(&encrypted), 0)
+            .expect("header");
+        assert_eq!(header.base_offset, 10);
+        assert_eq!(header.batch_len as usize + 12, encrypted.len());
+        assert!(header.header.attributes & ENCRYPTED_ATTRIBUTE != 0);
+
+        // encrypting again doesn't change batch
+        let mut twice = encrypted.clone();
+        cipher.encrypt_batch(&mut twice).expect("encrypt");
+        assert_eq!(twice, encrypted);
+
+        let mut decrypted = BytesMut::new();
+        cipher
+            .decrypt_batch(&encrypted, &mut decrypted)
+            .expect("decrypt");
+        assert_eq!(decrypted.as_ref(), plain.as_slice());
+
+        let batch: Batch = Batch::decode_from(&mut Cursor::new(decrypted), 0).expect("decode");
+        assert_eq!(batch.records().len(), 2);
+    }
+
+    #[test]
+    fn test_batch_bound_to_offset() {
+        let cipher = cipher(&[0]);
+        let mut encrypted = encoded_batch(10);
+        cipher.encrypt_batch(&mut encrypted).expect("encrypt");
+        encrypted[7] = 11;
+        assert!(cipher
+            .decrypt_batch(&encrypted, &mut BytesMut::new())
+            .is_err());
+    }
+
+    #[test]
+    fn test_batch_bound_to_header() {
+        let cipher = cipher(&[0]);
+        let mut encrypted = encoded_batch(10);
+        cipher.encrypt_batch(&mut encrypted).expect("encrypt");
+
+        // producer id, last offset delta and attributes can't be altered
+        for pos in [50, 26, 22] {
+            let mut altered = encrypted.clone();
+            altered[pos] ^= 1;
+            assert!(cipher
+                .decrypt_batch(&altered, &mut BytesMut::new())
+                .is_err());
+        }
+    }
+
+    #[test]
+    fn test_key_rotation() {
+        let old = cipher(&[1]);
+        let mut old_batch = encoded_batch(0);
+        old.encrypt_batch(&mut old_batch).expect("encrypt");
+
+        let rotated = cipher(&[1, 2]);
+        assert_eq!(rotated.current_key_id(), 2);
+        let mut new_batch = encoded_batch(2);
+        rotated.encrypt_batch(&mut new_batch).expect("encrypt");
+
+        let mut segment = old_batch.clone();
+        segment.extend_from_slice(&new_batch);
+        // incomplete batch at end is dropped
+        segment.extend_from_slice(&new_batch[..20]);
+
+        let decrypted = rotated.decrypt_batches(&segment).expect("decrypt");
+        let mut expected = encoded_batch(0);
+        expected.extend_from_slice(&encoded_batch(2));
+        assert_eq!(decrypted.as_ref(), expected.as_slice());
+
+        // old key alone can't read new batch
+        assert!(old.decrypt_batches(&segment).is_err());
+    }
+}
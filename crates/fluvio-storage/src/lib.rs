@@ -10,6 +10,7 @@ mod range_map;
 mod replica;
 pub mod segment;
 mod util;
+pub mod encryption;
 mod validator;
 pub mod config;
 
@@ -28,7 +29,9 @@ mod inner {
 
     use dataplane::{ErrorCode, Isolation, Offset, ReplicaKey};
     use dataplane::fetch::FilePartitionResponse;
-    use dataplane::record::RecordSet;
+    use bytes::Bytes;
+
+    use dataplane::record::{FileRecordSet, RecordSet};
     use fluvio_future::file_slice::AsyncFileSlice;
 
     #[derive(Debug, Clone, PartialEq)]
@@ -111,6 +114,9 @@ mod inner {
 
         fn set_slice(&mut self, slice: AsyncFileSlice);
 
+        /// records read from slice were decrypted
+        fn set_decrypted_slice(&mut self, slice: AsyncFileSlice, records: Bytes);
+
         fn set_error_code(&mut self, error: ErrorCode);
     }
 
@@ -127,6 +133,10 @@ mod inner {
             self.records = slice.into();
         }
 
+        fn set_decrypted_slice(&mut self, slice: AsyncFileSlice, records: Bytes) {
+            self.records = FileRecordSet::from_memory(slice, records);
+        }
+
         fn set_error_code(&mut self, error: ErrorCode) {
             self.error_code = error;
         }
@@ -28,6 +28,7 @@ use crate::validator::LogValidationError;
 use crate::config::ConfigOption;
 use crate::StorageError;
 use crate::records::FileRecords;
+use crate::encryption::SegmentCipher;
 
 pub const MESSAGE_LOG_EXTENSION: &str = "log";
 
@@ -50,6 +51,7 @@ pub struct MutFileRecords {
     flush_count: Arc<AtomicU32>,
     path: PathBuf,
     flush_time_tx: Option<Sender<Instant>>,
+    cipher: Option<SegmentCipher>,
 }
 
 impl fmt::Debug for MutFileRecords {
@@ -102,6 +104,7 @@ impl MutFileRecords {
             item_last_offset_delta: 0,
             path: log_path.to_owned(),
             flush_time_tx: None,
+            cipher: option.encryption.clone(),
         })
     }
 
@@ -139,6 +142,9 @@ impl MutFileRecords {
         self.item_last_offset_delta = item.get_last_offset_delta();
         let mut buffer: Vec<u8> = vec![];
         item.encode(&mut buffer, 0)?;
+        if let Some(cipher) = &self.cipher {
+            cipher.encrypt_batch(&mut buffer)?;
+        }
         let mf_sink = self.f_sink.clone();
         let mut f_sink = mf_sink.lock().await;
 
@@ -103,6 +103,11 @@ impl SegmentList {
         self.segments.insert(segment.get_base_offset(), segment);
     }
 
+    /// segments ordered by base offset
+    pub fn segments(&self) -> impl Iterator<Item = &ReadSegment> {
+        self.segments.values()
+    }
+
     #[allow(dead_code)]
     pub fn get_segment(&self, offset: Offset) -> Option<&ReadSegment> {
         self.segments.get(&offset)
@@ -5,17 +5,31 @@ use tracing::{debug, trace, error, warn, instrument};
 use async_trait::async_trait;
 
 use fluvio_future::fs::{create_dir_all, remove_dir_all};
+use fluvio_future::task::spawn_blocking;
 use dataplane::{ErrorCode, Isolation, Offset, ReplicaKey, Size};
-use dataplane::batch::Batch;
+use dataplane::batch::{Batch, BatchHeader};
 use dataplane::record::RecordSet;
 
 use crate::{OffsetInfo, checkpoint::CheckPoint};
+use crate::batch_header::BatchHeaderStream;
 use crate::range_map::SegmentList;
 use crate::segment::MutableSegment;
 use crate::config::ConfigOption;
 use crate::{SegmentSlice};
 use crate::{StorageError, SlicePartitionResponse, ReplicaStorage};
 
+async fn collect_transactional_headers(
+    mut stream: BatchHeaderStream,
+    headers: &mut Vec<BatchHeader>,
+) {
+    while let Some(batch_pos) = stream.next().await {
+        let header = batch_pos.into_batch().header;
+        if header.is_transactional() {
+            headers.push(header);
+        }
+    }
+}
+
 /// Replica is public abstraction for commit log which are distributed.
 /// Internally it is stored as list of segments.  Each segment contains finite sets of record batches.
 ///
@@ -225,6 +239,24 @@ impl FileReplica {
         }
     }
 
+    /// headers of transactional batches and transaction markers, oldest first.
+    /// Headers are not encrypted, so they are read without key
+    #[instrument(skip(self))]
+    pub async fn read_transactional_headers(&self) -> Result<Vec<BatchHeader>, StorageError> {
+        let mut headers = vec![];
+        for segment in self.prev_segments.segments() {
+            let stream = segment.open_batch_header_stream(0).await?;
+            collect_transactional_headers(stream, &mut headers).await;
+        }
+        // empty segment can't be mapped
+        if self.active_segment.get_end_offset() > self.active_segment.get_base_offset() {
+            let stream = self.active_segment.open_batch_header_stream(0).await?;
+            collect_transactional_headers(stream, &mut headers).await;
+        }
+        debug!(count = headers.len(), "read transactional headers");
+        Ok(headers)
+    }
+
     /// update high watermark to end
     #[instrument(skip(self))]
     pub async fn update_high_watermark_to_end(&mut self) -> Result<bool, StorageError> {
@@ -340,8 +372,31 @@ impl FileReplica {
                                 slice
                             };
 
-                            // limit slice
-                            response.set_slice(limited_slice);
+                            match &self.option.encryption {
+                                // encrypted records are read and decrypted in memory instead of zero copy,
+                                // blocking read and decryption must not run on executor thread
+                                Some(cipher) => {
+                                    let cipher = cipher.clone();
+                                    let encrypted = AsyncFileSlice::new(
+                                        slice.fd(),
+                                        slice.position(),
+                                        slice.len(),
+                                    );
+                                    let decrypted = spawn_blocking(move || {
+                                        cipher.read_slice(&encrypted, max_len)
+                                    })
+                                    .await;
+                                    match decrypted {
+                                        Ok(records) => response.set_decrypted_slice(slice, records),
+                                        Err(err) => {
+                                            response.set_error_code(ErrorCode::UnknownServerError);
+                                            error!("error decrypting records: {:#?}", err);
+                                        }
+                                    }
+                                }
+                                // limit slice
+                                None => response.set_slice(limited_slice),
+                            }
                         }
                         None => {
                             debug!("records not found for: {}", start_offset);
@@ -845,4 +900,100 @@ mod tests {
             }
         }
     }
+
+    #[fluvio_future::test]
+    async fn test_replica_encrypted() {
+        use std::collections::BTreeMap;
+
+        use crate::encryption::{SegmentCipher, ENCRYPTED_ATTRIBUTE};
+
+        let mut keys = BTreeMap::new();
+        keys.insert(0, [7u8; 32]);
+        let option = ConfigOption {
+            encryption: Some(SegmentCipher::new(keys).expect("cipher")),
+            ..base_option("test_encrypted")
+        };
+
+        let mut replica = FileReplica::create_or_load("test", 0, START_OFFSET, option.clone())
+            .await
+            .expect("test replica");
+        let mut batch = create_batch();
+        let batch_len = batch.write_size(0);
+        replica.write_batch(&mut batch).await.expect("write");
+        assert_eq!(replica.get_leo(), START_OFFSET + 2);
+
+        // records on disk are encrypted, header is not
+        let test_file = option.base_dir.join("test-0").join(TEST_SEG_NAME);
+        let bytes = read_bytes_from_file(&test_file).expect("read");
+        let mut header = Batch::default();
+        header
+            .decode_from_file_buf(&mut Cursor::new(&bytes), 0)
+            .expect("header");
+        assert_eq!(header.get_base_offset(), START_OFFSET);
+        assert!(header.get_header().attributes & ENCRYPTED_ATTRIBUTE != 0);
+
+        // records are decrypted when read
+        let mut partition_response = FilePartitionResponse::default();
+        replica
+            .read_all_uncommitted_records(FileReplica::PREFER_MAX_LEN, &mut partition_response)
+            .await;
+        assert_eq!(partition_response.error_code, ErrorCode::None);
+        assert_eq!(partition_response.records.len(), batch_len);
+        let records = partition_response.records.memory().expect("decrypted");
+        let batch = Batch::<MemoryRecords>::decode_from(&mut Cursor::new(records.as_ref()), 0)
+            .expect("decode");
+        assert_eq!(batch.get_base_offset(), START_OFFSET);
+        assert_eq!(batch.records().len(), 2);
+
+        drop(replica);
+
+        // segment is validated without decrypting
+        let replica = FileReplica::create_or_load("test", 0, START_OFFSET, option)
+            .await
+            .expect("reload");
+        assert_eq!(replica.get_leo(), START_OFFSET + 2);
+    }
+
+    #[fluvio_future::test]
+    async fn test_replica_transactional_headers() {
+        use dataplane::transaction::ControlType;
+
+        let option = rollover_option("test_transactional_headers");
+        let mut replica = FileReplica::create_or_load("test", 0, START_OFFSET, option.clone())
+            .await
+            .expect("test replica");
+        assert!(replica
+            .read_transactional_headers()
+            .await
+            .expect("headers")
+            .is_empty());
+
+        let mut transactional = create_batch();
+        transactional.get_mut_header().set_transactional(1, 2);
+        replica
+            .write_batch(&mut transactional)
+            .await
+            .expect("write");
+        replica
+            .write_batch(&mut create_batch())
+            .await
+            .expect("write");
+        replica
+            .write_batch(&mut Batch::control_marker(1, 2, 5, ControlType::Commit))
+            .await
+            .expect("write");
+        drop(replica);
+
+        // headers of older segments are read after reload
+        let replica = FileReplica::create_or_load("test", 0, START_OFFSET, option)
+            .await
+            .expect("reload");
+        assert!(replica.prev_segments.len() > 0);
+        let headers = replica.read_transactional_headers().await.expect("headers");
+        assert_eq!(headers.len(), 2);
+        assert!(!headers[0].is_control());
+        assert!(headers[1].is_control());
+        assert_eq!(headers[1].producer_epoch, 2);
+        assert_eq!(headers[1].first_sequence, 5);
+    }
 }
@@ -6,6 +6,7 @@ pub mod producer;
 pub mod consumer;
 pub mod election;
 pub mod reconnection;
+pub mod transaction;
 
 use serde::{Serialize, Deserialize};
 use std::time::SystemTime;
@@ -0,0 +1,117 @@
+use std::any::Any;
+
+use futures_lite::stream::StreamExt;
+
+use fluvio::{ConsumerConfig, Offset};
+use structopt::StructOpt;
+
+use fluvio_test_derive::fluvio_test;
+use fluvio_test_util::test_meta::environment::EnvironmentSetup;
+use fluvio_test_util::test_meta::{TestOption, TestCase};
+
+const TRANSACTIONAL_ID: &str = "fluvio-test-transaction";
+
+#[derive(Debug, Clone)]
+pub struct TransactionTestCase {
+    pub environment: EnvironmentSetup,
+    pub option: TransactionTestOption,
+}
+
+impl From<TestCase> for TransactionTestCase {
+    fn from(test_case: TestCase) -> Self {
+        let transaction_option = test_case
+            .option
+            .as_any()
+            .downcast_ref::<TransactionTestOption>()
+            .expect("TransactionTestOption")
+            .to_owned();
+        Self {
+            environment: test_case.environment,
+            option: transaction_option,
+        }
+    }
+}
+
+#[derive(Debug, Clone, StructOpt, Default, PartialEq)]
+#[structopt(name = "Fluvio transaction Test")]
+pub struct TransactionTestOption {}
+
+impl TestOption for TransactionTestOption {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+#[fluvio_test(topic = "transaction", async)]
+pub async fn transaction(mut test_driver: TestDriver, mut test_case: TestCase) {
+    println!("Starting transaction test");
+
+    let topic_name = test_case.environment.topic_name();
+    let client = test_driver.client();
+
+    let mut producer = client
+        .transactional_producer(TRANSACTIONAL_ID)
+        .await
+        .expect("producer");
+
+    println!("committing and aborting transactions");
+    let transactions = [
+        ("committed-1", true),
+        ("aborted", false),
+        ("committed-2", true),
+    ];
+    for &(value, commit) in transactions.iter() {
+        producer.begin().expect("begin");
+        producer
+            .send(&topic_name, 0, "key", value)
+            .await
+            .expect("send");
+        if commit {
+            producer.commit().await.expect("commit");
+        } else {
+            producer.abort().await.expect("abort");
+        }
+    }
+
+    println!("fencing producer");
+    producer.begin().expect("begin");
+    producer
+        .send(&topic_name, 0, "key", "zombie")
+        .await
+        .expect("send");
+
+    // new instance aborts transaction of previous one, which is fenced by SPU
+    let mut new_producer = client
+        .transactional_producer(TRANSACTIONAL_ID)
+        .await
+        .expect("producer");
+    assert!(producer
+        .send(&topic_name, 0, "key", "fenced")
+        .await
+        .is_err());
+    assert!(producer.commit().await.is_err());
+
+    new_producer.begin().expect("begin");
+    new_producer
+        .send(&topic_name, 0, "key", "committed-3")
+        .await
+        .expect("send");
+    new_producer.commit().await.expect("commit");
+
+    println!("checking records of committed transactions");
+    let consumer = test_driver.get_consumer(&topic_name, 0).await;
+    let config = ConsumerConfig::builder()
+        .transactional(true)
+        .build()
+        .expect("config");
+    let stream = consumer
+        .stream_with_config(Offset::beginning(), config)
+        .await
+        .expect("stream");
+    let values: Vec<String> = stream
+        .take(3)
+        .map(|record| String::from_utf8_lossy(record.expect("record").value()).to_string())
+        .collect()
+        .await;
+    assert_eq!(values, vec!["committed-1", "committed-2", "committed-3"]);
+}
@@ -7,6 +7,11 @@ use dataplane::core::Encoder;
 use dataplane::core::Decoder;
 use fluvio_sc_schema::objects::{Metadata, AllCreatableSpec};
 use fluvio_sc_schema::AdminRequest;
+use fluvio_sc_schema::partition::ElectLeaderRequest;
+use fluvio_sc_schema::spu::{DrainSpuRequest, ReplicaMove};
+use fluvio_sc_schema::acl::{AclCheck, AclRule, DescribeAclRequest};
+use fluvio_sc_schema::schema::RegisterSchemaRequest;
+use fluvio_types::SpuId;
 use fluvio_socket::SocketError;
 use fluvio_socket::MultiplexerSocket;
 
@@ -112,7 +117,8 @@ impl FluvioAdmin {
         use fluvio_protocol::api::Request;
 
         let connector = DomainConnector::try_from(config.tls.clone())?;
-        let config = ClientConfig::new(&config.endpoint, connector, config.use_spu_local_address);
+        let config = ClientConfig::new(&config.endpoint, connector, config.use_spu_local_address)
+            .set_credentials(config.credentials.clone());
         let inner_client = config.connect().await?;
         debug!(addr = %inner_client.config().addr(), "connected to cluster");
 
@@ -169,11 +175,75 @@ impl FluvioAdmin {
         Ok(())
     }
 
+    /// elect new leader for partition
+    /// if leader is not specified, leadership is moved to preferred replica
+    #[instrument(skip(self, topic))]
+    pub async fn elect_leader<S: Into<String>>(
+        &self,
+        topic: S,
+        partition: i32,
+        leader: Option<SpuId>,
+    ) -> Result<(), FluvioError> {
+        let request = ElectLeaderRequest::new(topic, partition, leader);
+        self.send_receive(request).await?.as_result()?;
+        Ok(())
+    }
+
+    /// drain spu by moving its replicas and leaderships to other spus.
+    /// in dry run, spu is not changed and only planned moves are returned
+    #[instrument(skip(self))]
+    pub async fn drain_spu(
+        &self,
+        spu: SpuId,
+        dry_run: bool,
+    ) -> Result<Vec<ReplicaMove>, FluvioError> {
+        let request = DrainSpuRequest::new(spu, dry_run);
+        let response = self.send_receive(request).await?;
+        response.status.as_result()?;
+        Ok(response.moves)
+    }
+
+    /// stop draining spu so it can receive replicas again
+    #[instrument(skip(self))]
+    pub async fn undrain_spu(&self, spu: SpuId) -> Result<(), FluvioError> {
+        let request = DrainSpuRequest::undrain(spu);
+        let response = self.send_receive(request).await?;
+        response.status.as_result()?;
+        Ok(())
+    }
+
+    /// list policy rules which apply to principal with scopes.
+    /// if check is specified, result of evaluating it against policy is returned
+    #[instrument(skip(self))]
+    pub async fn describe_acl<S: Into<String> + std::fmt::Debug>(
+        &self,
+        principal: S,
+        scopes: Vec<String>,
+        check: Option<AclCheck>,
+    ) -> Result<(Vec<AclRule>, Option<bool>), FluvioError> {
+        let request = DescribeAclRequest::new(principal, scopes, check);
+        let response = self.send_receive(request).await?;
+        response.status.as_result()?;
+        Ok((response.rules, response.allowed))
+    }
+
+    /// register schema as new version of subject, returns schema id and version.
+    /// if definition is already registered to subject, its id and version are returned
+    #[instrument(skip(self, request), fields(subject = %request.subject))]
+    pub async fn register_schema(
+        &self,
+        request: RegisterSchemaRequest,
+    ) -> Result<(u32, u32), FluvioError> {
+        let response = self.send_receive(request).await?;
+        response.status.as_result()?;
+        Ok((response.id, response.version))
+    }
+
     #[instrument(skip(self, filters))]
     pub async fn list<S, F>(&self, filters: F) -> Result<Vec<Metadata<S>>, FluvioError>
     where
-        S: ListSpec + Encoder + Decoder,
-        S::Status: Encoder + Decoder,
+        S: ListSpec + Encoder + Decoder + Default,
+        S::Status: Encoder + Decoder + Default,
         F: Into<Vec<S::Filter>>,
         ListResponse: TryInto<Vec<Metadata<S>>>,
         <ListResponse as TryInto<Vec<Metadata<S>>>>::Error: Display,
@@ -0,0 +1,83 @@
+use std::convert::TryInto;
+use std::fmt::Display;
+use std::sync::Arc;
+
+use dataplane::core::{Decoder, Encoder};
+use fluvio_sc_schema::objects::{AllCreatableSpec, Metadata};
+
+use crate::{FluvioConfig, FluvioError};
+use crate::metadata::objects::{DeleteSpec, ListResponse, ListSpec};
+
+use super::Runtime;
+
+/// Blocking version of [`FluvioAdmin`](crate::FluvioAdmin)
+pub struct FluvioAdmin {
+    inner: Arc<crate::FluvioAdmin>,
+    runtime: Arc<Runtime>,
+}
+
+impl FluvioAdmin {
+    pub(crate) fn new(inner: crate::FluvioAdmin, runtime: Arc<Runtime>) -> Self {
+        Self {
+            inner: Arc::new(inner),
+            runtime,
+        }
+    }
+
+    /// Creates a new admin connection using the current profile from `~/.fluvio/config`
+    pub fn connect() -> Result<Self, FluvioError> {
+        let runtime = Runtime::start()?;
+        let inner = runtime.block_on(crate::FluvioAdmin::connect())?;
+        Ok(Self::new(inner, runtime))
+    }
+
+    /// Creates a new admin connection using custom configurations
+    pub fn connect_with_config(config: &FluvioConfig) -> Result<Self, FluvioError> {
+        let runtime = Runtime::start()?;
+        let config = config.clone();
+        let inner = runtime
+            .block_on(async move { crate::FluvioAdmin::connect_with_config(&config).await })?;
+        Ok(Self::new(inner, runtime))
+    }
+
+    /// create new object
+    pub fn create<S>(&self, name: String, dry_run: bool, spec: S) -> Result<(), FluvioError>
+    where
+        S: Into<AllCreatableSpec>,
+    {
+        let spec: AllCreatableSpec = spec.into();
+        let inner = self.inner.clone();
+        self.runtime
+            .block_on(async move { inner.create(name, dry_run, spec).await })
+    }
+
+    /// delete object by key
+    /// key is dependent on spec, most are string but some allow multiple types
+    pub fn delete<S, K>(&self, key: K) -> Result<(), FluvioError>
+    where
+        S: DeleteSpec + 'static,
+        K: Into<S::DeleteKey>,
+        S::DeleteKey: Send + 'static,
+    {
+        let key: S::DeleteKey = key.into();
+        let inner = self.inner.clone();
+        self.runtime
+            .block_on(async move { inner.delete::<S, S::DeleteKey>(key).await })
+    }
+
+    /// return all instances of this spec
+    pub fn list<S, F>(&self, filters: F) -> Result<Vec<Metadata<S>>, FluvioError>
+    where
+        S: ListSpec + Encoder + Decoder + Default + Send + 'static,
+        S::Status: Encoder + Decoder + Default + Send,
+        S::Filter: Send + 'static,
+        F: Into<Vec<S::Filter>>,
+        ListResponse: TryInto<Vec<Metadata<S>>>,
+        <ListResponse as TryInto<Vec<Metadata<S>>>>::Error: Display,
+    {
+        let filters: Vec<S::Filter> = filters.into();
+        let inner = self.inner.clone();
+        self.runtime
+            .block_on(async move { inner.list::<S, _>(filters).await })
+    }
+}
@@ -0,0 +1,209 @@
+use std::future::Future;
+use std::sync::Arc;
+
+use async_channel::{Receiver, Sender};
+use futures_util::stream::{Stream, StreamExt};
+
+use fluvio_future::task::{run_block_on, spawn_local};
+
+use crate::{ConsumerConfig, FluvioError, Offset};
+use crate::consumer::Record;
+
+use super::Runtime;
+
+/// records read ahead of iterator
+const RECORD_BUFFER: usize = 100;
+
+/// Blocking version of [`PartitionConsumer`](crate::PartitionConsumer)
+pub struct PartitionConsumer {
+    inner: Arc<crate::PartitionConsumer>,
+    runtime: Arc<Runtime>,
+}
+
+impl PartitionConsumer {
+    pub(crate) fn new(inner: crate::PartitionConsumer, runtime: Arc<Runtime>) -> Self {
+        Self {
+            inner: Arc::new(inner),
+            runtime,
+        }
+    }
+
+    /// Returns the name of the Topic that this consumer reads from
+    pub fn topic(&self) -> &str {
+        self.inner.topic()
+    }
+
+    /// Returns the ID of the partition that this consumer reads from
+    pub fn partition(&self) -> i32 {
+        self.inner.partition()
+    }
+
+    /// Continuously iterates over events from a particular offset in the consumer's partition
+    pub fn stream(&self, offset: Offset) -> Result<RecordIter, FluvioError> {
+        let config = ConsumerConfig::builder().build()?;
+        self.stream_with_config(offset, config)
+    }
+
+    /// Continuously iterates over events from a particular offset in the consumer's partition,
+    /// using custom [`ConsumerConfig`]
+    pub fn stream_with_config(
+        &self,
+        offset: Offset,
+        config: ConsumerConfig,
+    ) -> Result<RecordIter, FluvioError> {
+        let inner = self.inner.clone();
+        RecordIter::start(self.runtime.clone(), async move {
+            inner.stream_with_config(offset, config).await
+        })
+    }
+}
+
+/// read stream on runtime thread until it ends or iterator is dropped,
+/// end of stream is sent as `None`
+async fn forward<S>(stream: S, sender: Sender<Option<S::Item>>)
+where
+    S: Stream,
+{
+    let mut stream = Box::pin(stream);
+    while let Some(item) = stream.next().await {
+        if sender.send(Some(item)).await.is_err() {
+            return;
+        }
+    }
+    let _ = sender.send(None).await;
+}
+
+/// Iterator over records of consumer stream.
+/// Iterator ends when stream ends, stream is stopped when iterator is dropped.
+/// Runtime thread is kept running by iterator, so it can outlive client and consumer
+pub struct RecordIter<T = Record> {
+    receiver: Receiver<Option<Result<T, FluvioError>>>,
+    _runtime: Arc<Runtime>,
+    ended: bool,
+}
+
+impl<T> RecordIter<T>
+where
+    T: Send + 'static,
+{
+    /// read stream created by `create_stream` on runtime thread
+    fn start<F, S>(runtime: Arc<Runtime>, create_stream: F) -> Result<Self, FluvioError>
+    where
+        F: Future<Output = Result<S, FluvioError>> + Send + 'static,
+        S: Stream<Item = Result<T, FluvioError>> + 'static,
+    {
+        let (sender, receiver) = async_channel::bounded(RECORD_BUFFER);
+        runtime.block_on(async move {
+            let stream = create_stream.await?;
+            spawn_local(forward(stream, sender));
+            Ok(())
+        })?;
+        Ok(Self {
+            receiver,
+            _runtime: runtime,
+            ended: false,
+        })
+    }
+}
+
+impl<T> Iterator for RecordIter<T> {
+    type Item = Result<T, FluvioError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.ended {
+            return None;
+        }
+        // waiting on channel only, records are read by runtime thread
+        match run_block_on(self.receiver.recv()) {
+            Ok(Some(item)) => Some(item),
+            Ok(None) => {
+                self.ended = true;
+                None
+            }
+            // stream is dropped before it ended
+            Err(_) => {
+                self.ended = true;
+                Some(Err(Runtime::stopped()))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use std::time::Duration;
+
+    use futures_util::stream::{iter, StreamExt};
+
+    use fluvio_future::task::{run_block_on, spawn_local};
+    use fluvio_future::timer::sleep;
+
+    use crate::FluvioError;
+
+    use super::super::Runtime;
+    use super::{forward, RecordIter};
+
+    #[test]
+    fn test_forward_stream() {
+        let runtime = Runtime::start().expect("runtime");
+
+        let (sender, receiver) = async_channel::bounded(1);
+        runtime
+            .block_on(async move {
+                spawn_local(forward(iter(0..5), sender));
+                Ok(())
+            })
+            .expect("forward");
+
+        // stream is read as items are received
+        let received: Vec<i32> =
+            std::iter::from_fn(|| run_block_on(receiver.recv()).ok().flatten()).collect();
+        assert_eq!(received, vec![0, 1, 2, 3, 4]);
+        assert!(run_block_on(receiver.recv()).is_err());
+
+        // stream is stopped when receiver is dropped
+        let (sender, receiver) = async_channel::bounded(1);
+        drop(receiver);
+        runtime
+            .block_on(async move {
+                forward(iter(0..), sender).await;
+                Ok(())
+            })
+            .expect("stopped");
+    }
+
+    #[test]
+    fn test_iter_keeps_runtime() {
+        let runtime = Runtime::start().expect("runtime");
+
+        let records = RecordIter::start(runtime.clone(), async {
+            Ok(iter(0..5).then(|i| async move {
+                sleep(Duration::from_millis(10)).await;
+                Ok::<_, FluvioError>(i)
+            }))
+        })
+        .expect("start");
+
+        // client and consumer are dropped, records are still read
+        drop(runtime);
+        let received: Vec<i32> = records.map(|record| record.expect("record")).collect();
+        assert_eq!(received, vec![0, 1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn test_iter_runtime_stopped() {
+        let runtime = Runtime::start().expect("runtime");
+
+        // stream is dropped without end
+        let (sender, receiver) = async_channel::bounded(1);
+        drop(sender);
+        let mut records = RecordIter::<i32> {
+            receiver,
+            _runtime: runtime,
+            ended: false,
+        };
+        assert!(matches!(records.next(), Some(Err(FluvioError::Other(_)))));
+        assert!(records.next().is_none());
+    }
+}
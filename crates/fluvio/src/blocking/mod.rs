@@ -0,0 +1,189 @@
+//!
+//! # Blocking client
+//!
+//! Synchronous versions of [`Fluvio`], [`TopicProducer`], [`PartitionConsumer`]
+//! and [`FluvioAdmin`] for programs which don't use async runtime.
+//! Requests are run on thread owned by client, which is stopped when
+//! client and all producers, consumers and admins created from it are dropped.
+//! Records are consumed with iterator.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use fluvio::{FluvioError, Offset, RecordKey};
+//! use fluvio::blocking::Fluvio;
+//!
+//! fn main() -> Result<(), FluvioError> {
+//!     let fluvio = Fluvio::connect()?;
+//!     let producer = fluvio.topic_producer("echo")?;
+//!     producer.send(RecordKey::NULL, "Hello, Fluvio!")?;
+//!
+//!     let consumer = fluvio.partition_consumer("echo", 0)?;
+//!     for record in consumer.stream(Offset::beginning())? {
+//!         let record = record?;
+//!         println!("Got record: {}", String::from_utf8_lossy(record.value()));
+//!     }
+//!     Ok(())
+//! }
+//! ```
+//!
+//! [`Fluvio`]: crate::blocking::Fluvio
+//! [`TopicProducer`]: crate::blocking::TopicProducer
+//! [`PartitionConsumer`]: crate::blocking::PartitionConsumer
+//! [`FluvioAdmin`]: crate::blocking::FluvioAdmin
+mod admin;
+mod consumer;
+mod producer;
+
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::{Arc, mpsc};
+use std::thread;
+
+use async_channel::Sender;
+use tracing::debug;
+
+use fluvio_future::task::{run_block_on, spawn_local};
+
+use crate::{FluvioConfig, FluvioError};
+
+pub use admin::FluvioAdmin;
+pub use consumer::{PartitionConsumer, RecordIter};
+pub use producer::TopicProducer;
+
+type Task = Pin<Box<dyn Future<Output = ()> + Send>>;
+
+/// thread which runs requests of blocking clients
+#[derive(Debug)]
+pub(crate) struct Runtime {
+    tasks: Sender<Task>,
+}
+
+impl Runtime {
+    pub(crate) fn start() -> Result<Arc<Self>, FluvioError> {
+        let (tasks, receiver) = async_channel::unbounded::<Task>();
+        thread::Builder::new()
+            .name("fluvio-blocking".to_owned())
+            .spawn(move || {
+                run_block_on(async move {
+                    // runs until all clients sharing runtime are dropped
+                    while let Ok(task) = receiver.recv().await {
+                        spawn_local(task);
+                    }
+                    debug!("blocking runtime stopped");
+                })
+            })?;
+        Ok(Arc::new(Self { tasks }))
+    }
+
+    /// run future on runtime thread and wait for its output
+    pub(crate) fn block_on<F, T>(&self, future: F) -> Result<T, FluvioError>
+    where
+        F: Future<Output = Result<T, FluvioError>> + Send + 'static,
+        T: Send + 'static,
+    {
+        let (sender, receiver) = mpsc::sync_channel(1);
+        self.tasks
+            .try_send(Box::pin(async move {
+                let _ = sender.send(future.await);
+            }))
+            .map_err(|_| Self::stopped())?;
+        receiver.recv().map_err(|_| Self::stopped())?
+    }
+
+    fn stopped() -> FluvioError {
+        FluvioError::Other("blocking runtime is stopped".to_owned())
+    }
+}
+
+/// Blocking version of [`Fluvio`](crate::Fluvio)
+pub struct Fluvio {
+    inner: Arc<crate::Fluvio>,
+    runtime: Arc<Runtime>,
+}
+
+impl Fluvio {
+    /// Creates a new Fluvio client using the current profile from `~/.fluvio/config`
+    pub fn connect() -> Result<Self, FluvioError> {
+        let runtime = Runtime::start()?;
+        let inner = runtime.block_on(crate::Fluvio::connect())?;
+        Ok(Self {
+            inner: Arc::new(inner),
+            runtime,
+        })
+    }
+
+    /// Creates a new Fluvio client with the given configuration
+    pub fn connect_with_config(config: &FluvioConfig) -> Result<Self, FluvioError> {
+        let runtime = Runtime::start()?;
+        let config = config.clone();
+        let inner =
+            runtime.block_on(async move { crate::Fluvio::connect_with_config(&config).await })?;
+        Ok(Self {
+            inner: Arc::new(inner),
+            runtime,
+        })
+    }
+
+    /// Creates a new `TopicProducer` for the given topic name
+    pub fn topic_producer<S: Into<String>>(&self, topic: S) -> Result<TopicProducer, FluvioError> {
+        let topic = topic.into();
+        let inner = self.inner.clone();
+        let producer = self
+            .runtime
+            .block_on(async move { inner.topic_producer(topic).await })?;
+        Ok(TopicProducer::new(producer, self.runtime.clone()))
+    }
+
+    /// Creates a new `PartitionConsumer` for the given topic and partition
+    pub fn partition_consumer<S: Into<String>>(
+        &self,
+        topic: S,
+        partition: i32,
+    ) -> Result<PartitionConsumer, FluvioError> {
+        let topic = topic.into();
+        let inner = self.inner.clone();
+        let consumer = self
+            .runtime
+            .block_on(async move { inner.partition_consumer(topic, partition).await })?;
+        Ok(PartitionConsumer::new(consumer, self.runtime.clone()))
+    }
+
+    /// Provides an interface for managing a Fluvio cluster
+    pub fn admin(&self) -> Result<FluvioAdmin, FluvioError> {
+        let inner = self.inner.clone();
+        let admin = self
+            .runtime
+            .block_on(async move { Ok(inner.admin().await) })?;
+        Ok(FluvioAdmin::new(admin, self.runtime.clone()))
+    }
+
+    /// Reports the Platform Version of the connected cluster.
+    pub fn platform_version(&self) -> &semver::Version {
+        self.inner.platform_version()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use std::thread;
+
+    use crate::FluvioError;
+
+    use super::Runtime;
+
+    #[test]
+    fn test_runtime_block_on() {
+        let runtime = Runtime::start().expect("runtime");
+
+        let name = runtime
+            .block_on(async { Ok(thread::current().name().map(|name| name.to_owned())) })
+            .expect("name");
+        assert_eq!(name.as_deref(), Some("fluvio-blocking"));
+
+        let result: Result<(), FluvioError> =
+            runtime.block_on(async { Err(FluvioError::Other("failed".to_owned())) });
+        assert!(matches!(result, Err(FluvioError::Other(message)) if message == "failed"));
+    }
+}
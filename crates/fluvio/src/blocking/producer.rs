@@ -0,0 +1,48 @@
+use std::sync::Arc;
+
+use crate::FluvioError;
+use crate::producer::{RecordData, RecordKey};
+
+use super::Runtime;
+
+/// Blocking version of [`TopicProducer`](crate::TopicProducer)
+pub struct TopicProducer {
+    inner: Arc<crate::TopicProducer>,
+    runtime: Arc<Runtime>,
+}
+
+impl TopicProducer {
+    pub(crate) fn new(inner: crate::TopicProducer, runtime: Arc<Runtime>) -> Self {
+        Self {
+            inner: Arc::new(inner),
+            runtime,
+        }
+    }
+
+    /// Sends a key/value record to this producer's Topic.
+    ///
+    /// The partition that the record will be sent to is derived from the Key.
+    pub fn send<K, V>(&self, key: K, value: V) -> Result<(), FluvioError>
+    where
+        K: Into<RecordKey>,
+        V: Into<RecordData>,
+    {
+        self.send_all(Some((key, value)))
+    }
+
+    /// Sends key/value records to this producer's Topic.
+    pub fn send_all<K, V, I>(&self, records: I) -> Result<(), FluvioError>
+    where
+        K: Into<RecordKey>,
+        V: Into<RecordData>,
+        I: IntoIterator<Item = (K, V)>,
+    {
+        let records: Vec<(RecordKey, RecordData)> = records
+            .into_iter()
+            .map(|(key, value)| (key.into(), value.into()))
+            .collect();
+        let inner = self.inner.clone();
+        self.runtime
+            .block_on(async move { inner.send_all(records).await })
+    }
+}
@@ -3,9 +3,11 @@
 //!
 //! Stores configuration parameter retrieved from the default or custom profile file.
 //!
+use std::time::Duration;
+
 use serde::{Serialize, Deserialize};
 
-use crate::config::TlsPolicy;
+use crate::config::{TlsPolicy, Credentials};
 
 /// Public configuration for Fluvio.
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
@@ -25,6 +27,18 @@ pub struct FluvioConfig {
     // use the default of NoTls
     #[serde(default)]
     pub tls: TlsPolicy,
+
+    /// Credentials to authenticate with SASL handshake, if the cluster requires it
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub credentials: Option<Credentials>,
+
+    /// Time to wait for response of each request in milliseconds
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub request_timeout_ms: Option<u64>,
+
+    /// Interval of heartbeats to SC and SPUs in milliseconds, heartbeats are disabled if not set or 0
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub heartbeat_interval_ms: Option<u64>,
 }
 
 impl FluvioConfig {
@@ -34,6 +48,9 @@ impl FluvioConfig {
             endpoint: addr.into(),
             use_spu_local_address: false,
             tls: TlsPolicy::Disabled,
+            credentials: None,
+            request_timeout_ms: None,
+            heartbeat_interval_ms: None,
         }
     }
 
@@ -42,4 +59,35 @@ impl FluvioConfig {
         self.tls = tls.into();
         self
     }
+
+    /// Add credentials to authenticate with this cluster.
+    pub fn with_credentials(mut self, credentials: Credentials) -> Self {
+        self.credentials = Some(credentials);
+        self
+    }
+
+    /// Set time to wait for response of each request.
+    pub fn with_request_timeout(mut self, timeout: Duration) -> Self {
+        self.request_timeout_ms = Some(timeout.as_millis() as u64);
+        self
+    }
+
+    /// Enable heartbeats with given interval, connection is closed and re-established
+    /// if heartbeat is not answered within interval, but at most 10 seconds.
+    pub fn with_heartbeat_interval(mut self, interval: Duration) -> Self {
+        self.heartbeat_interval_ms = Some(interval.as_millis() as u64);
+        self
+    }
+
+    pub fn request_timeout(&self) -> Option<Duration> {
+        self.request_timeout_ms.map(Duration::from_millis)
+    }
+
+    /// None if heartbeats are disabled
+    pub fn heartbeat_interval(&self) -> Option<Duration> {
+        match self.heartbeat_interval_ms {
+            None | Some(0) => None,
+            Some(interval) => Some(Duration::from_millis(interval)),
+        }
+    }
 }
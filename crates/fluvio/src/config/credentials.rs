@@ -0,0 +1,85 @@
+//!
+//! # Client Credentials
+//!
+//! Credentials used to authenticate with SASL handshake, as alternative to client certificates.
+//!
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+/// Describes how the client authenticates with the cluster
+#[derive(Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "mechanism")]
+pub enum Credentials {
+    /// Username and password sent to the cluster, requires TLS to be secure
+    #[serde(rename = "plain")]
+    Plain { username: String, password: String },
+    /// Username and password proven with challenge response, password is never sent
+    #[serde(rename = "scram-sha-256")]
+    ScramSha256 { username: String, password: String },
+    /// Signed bearer token
+    #[serde(rename = "token")]
+    Token { token: String },
+}
+
+impl Credentials {
+    pub fn plain<U: Into<String>, P: Into<String>>(username: U, password: P) -> Self {
+        Self::Plain {
+            username: username.into(),
+            password: password.into(),
+        }
+    }
+
+    pub fn scram_sha_256<U: Into<String>, P: Into<String>>(username: U, password: P) -> Self {
+        Self::ScramSha256 {
+            username: username.into(),
+            password: password.into(),
+        }
+    }
+
+    pub fn token<T: Into<String>>(token: T) -> Self {
+        Self::Token {
+            token: token.into(),
+        }
+    }
+}
+
+/// secrets are never printed
+impl fmt::Debug for Credentials {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Plain { username, .. } => {
+                f.debug_struct("Plain").field("username", username).finish()
+            }
+            Self::ScramSha256 { username, .. } => f
+                .debug_struct("ScramSha256")
+                .field("username", username)
+                .finish(),
+            Self::Token { .. } => f.debug_struct("Token").finish(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    use super::*;
+
+    #[test]
+    fn test_credentials_toml() {
+        let credentials: Credentials = toml::from_str(
+            r#"
+mechanism = "scram-sha-256"
+username = "ci"
+password = "secret"
+"#,
+        )
+        .expect("parse");
+        assert_eq!(credentials, Credentials::scram_sha_256("ci", "secret"));
+        assert!(!format!("{:?}", credentials).contains("secret"));
+
+        let credentials: Credentials =
+            toml::from_str("mechanism = \"token\"\ntoken = \"abc\"").expect("parse");
+        assert_eq!(credentials, Credentials::token("abc"));
+    }
+}
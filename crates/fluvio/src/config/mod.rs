@@ -2,7 +2,9 @@
 mod config;
 mod tls;
 mod cluster;
+mod credentials;
 
 pub use config::*;
 pub use tls::*;
 pub use cluster::*;
+pub use credentials::*;
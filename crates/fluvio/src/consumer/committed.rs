@@ -0,0 +1,226 @@
+//!
+//! # Read committed
+//!
+//! Batches of transactions are held until marker of transaction is read,
+//! then delivered if transaction is committed or dropped if it's aborted.
+//! Batches after batch of open transaction are held as well, so batches are
+//! delivered in offset order. Markers are never delivered.
+//!
+//! Size of held batches is limited, since transaction may never be ended
+//! if its coordinator loses track of it.
+//!
+//! Known limitation: filtering is done by consumer only. SPU doesn't track last stable
+//! offset, so batches of open and aborted transactions are sent to every read committed
+//! consumer, and stream fails with error once held batches exceed limit, for example when
+//! transaction stays open for long time on busy partition.
+//!
+use std::collections::{HashMap, VecDeque};
+
+use tracing::trace;
+
+use dataplane::batch::Batch;
+use dataplane::core::Encoder;
+use dataplane::transaction::ControlType;
+
+use crate::FluvioError;
+
+#[derive(Debug)]
+struct HeldBatch {
+    batch: Batch,
+    size: usize,
+    /// producer of transaction which is not ended yet
+    open: Option<i64>,
+}
+
+#[derive(Debug)]
+pub(crate) struct ReadCommitted {
+    held: VecDeque<HeldBatch>,
+    held_bytes: usize,
+    max_held_bytes: usize,
+    /// epoch of last marker of producer, batches and markers from older epochs are from fenced producers
+    epochs: HashMap<i64, i16>,
+}
+
+impl ReadCommitted {
+    pub(crate) fn new(max_held_bytes: usize) -> Self {
+        Self {
+            held: VecDeque::new(),
+            held_bytes: 0,
+            max_held_bytes,
+            epochs: HashMap::new(),
+        }
+    }
+
+    /// batches which can be delivered after batch is read.
+    /// Batch is not held if held batches would exceed limit
+    pub(crate) fn process(&mut self, batch: Batch) -> Result<Vec<Batch>, FluvioError> {
+        let header = batch.get_header();
+        let producer_id = header.producer_id;
+        let producer_epoch = header.producer_epoch;
+        let fenced =
+            matches!(self.epochs.get(&producer_id), Some(epoch) if producer_epoch < *epoch);
+
+        if header.is_control() {
+            let control = batch.control_type();
+            if fenced {
+                trace!(
+                    producer_id,
+                    producer_epoch,
+                    "ignoring marker of fenced producer"
+                );
+            } else {
+                trace!(producer_id, ?control, "transaction marker");
+                self.end_transaction(producer_id, control);
+                self.epochs.insert(producer_id, producer_epoch);
+            }
+        } else if header.is_transactional() && fenced {
+            trace!(
+                producer_id,
+                producer_epoch,
+                "dropping batch of fenced producer"
+            );
+        } else {
+            let open = header.is_transactional().then(|| producer_id);
+            if open.is_some() || !self.held.is_empty() {
+                let size = batch.write_size(0);
+                if self.held_bytes + size > self.max_held_bytes {
+                    return Err(FluvioError::Transaction(format!(
+                        "batches held until transactions are ended exceed {} bytes",
+                        self.max_held_bytes
+                    )));
+                }
+                self.held_bytes += size;
+                self.held.push_back(HeldBatch { batch, size, open });
+            } else {
+                return Ok(vec![batch]);
+            }
+        }
+
+        let mut ready = vec![];
+        while matches!(self.held.front(), Some(held) if held.open.is_none()) {
+            if let Some(held) = self.held.pop_front() {
+                self.held_bytes -= held.size;
+                ready.push(held.batch);
+            }
+        }
+        Ok(ready)
+    }
+
+    /// release batches of committed transaction, drop batches of aborted one
+    fn end_transaction(&mut self, producer_id: i64, control: Option<ControlType>) {
+        let in_transaction = |held: &HeldBatch| held.open == Some(producer_id);
+        match control {
+            Some(ControlType::Commit) => {
+                for held in self.held.iter_mut().filter(|held| in_transaction(held)) {
+                    held.open = None;
+                }
+            }
+            // marker which can't be read is treated as abort
+            Some(ControlType::Abort) | None => {
+                let held_bytes = &mut self.held_bytes;
+                self.held.retain(|held| {
+                    if in_transaction(held) {
+                        *held_bytes -= held.size;
+                        false
+                    } else {
+                        true
+                    }
+                });
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use dataplane::batch::Batch;
+    use dataplane::record::Record;
+    use dataplane::transaction::ControlType;
+
+    use super::ReadCommitted;
+
+    fn batch(base_offset: i64, value: &str) -> Batch {
+        Batch::from(vec![Record::new(value)]).base_offset(base_offset)
+    }
+
+    fn transactional(base_offset: i64, producer_id: i64, epoch: i16, value: &str) -> Batch {
+        let mut batch = batch(base_offset, value);
+        batch.get_mut_header().set_transactional(producer_id, epoch);
+        batch
+    }
+
+    fn marker(base_offset: i64, producer_id: i64, epoch: i16, control: ControlType) -> Batch {
+        Batch::control_marker(producer_id, epoch, 0, control).base_offset(base_offset)
+    }
+
+    /// offsets of delivered batches
+    fn process(filter: &mut ReadCommitted, batch: Batch) -> Vec<i64> {
+        filter
+            .process(batch)
+            .expect("process")
+            .iter()
+            .map(|batch| batch.base_offset)
+            .collect()
+    }
+
+    #[test]
+    fn test_read_committed() {
+        let mut filter = ReadCommitted::new(usize::MAX);
+
+        assert_eq!(process(&mut filter, batch(0, "a")), vec![0]);
+
+        // transaction of producer 1 holds later batches
+        assert!(process(&mut filter, transactional(1, 1, 0, "b")).is_empty());
+        assert!(process(&mut filter, transactional(2, 2, 0, "c")).is_empty());
+        assert!(process(&mut filter, batch(3, "d")).is_empty());
+        assert!(process(&mut filter, transactional(4, 1, 0, "e")).is_empty());
+
+        // transaction of producer 2 is aborted, producer 1 is still open
+        assert!(process(&mut filter, marker(5, 2, 0, ControlType::Abort)).is_empty());
+        assert_eq!(
+            process(&mut filter, marker(6, 1, 0, ControlType::Commit)),
+            vec![1, 3, 4]
+        );
+
+        // producer 2 is fenced by new instance which aborts its transaction
+        assert!(process(&mut filter, transactional(7, 2, 1, "f")).is_empty());
+        assert!(process(&mut filter, marker(8, 2, 2, ControlType::Abort)).is_empty());
+        assert!(process(&mut filter, transactional(9, 2, 1, "zombie")).is_empty());
+        assert_eq!(process(&mut filter, batch(10, "g")), vec![10]);
+        assert!(process(&mut filter, transactional(11, 2, 2, "h")).is_empty());
+
+        // marker of fenced producer doesn't end transaction of new instance
+        assert!(process(&mut filter, marker(12, 2, 1, ControlType::Abort)).is_empty());
+        assert!(process(&mut filter, batch(13, "i")).is_empty());
+        assert_eq!(
+            process(&mut filter, marker(14, 2, 2, ControlType::Commit)),
+            vec![11, 13]
+        );
+    }
+
+    #[test]
+    fn test_held_bytes_limit() {
+        let size = {
+            use dataplane::core::Encoder;
+            transactional(0, 1, 0, "a").write_size(0)
+        };
+        let mut filter = ReadCommitted::new(size * 2);
+
+        assert!(process(&mut filter, transactional(0, 1, 0, "a")).is_empty());
+        assert!(process(&mut filter, batch(1, "b")).is_empty());
+        assert!(filter.process(batch(2, "c")).is_err());
+
+        // aborted batches are released from limit
+        assert_eq!(
+            process(&mut filter, marker(3, 1, 0, ControlType::Abort)),
+            vec![1]
+        );
+        assert!(process(&mut filter, transactional(4, 1, 0, "d")).is_empty());
+        assert!(process(&mut filter, transactional(5, 1, 0, "e")).is_empty());
+        assert_eq!(
+            process(&mut filter, marker(6, 1, 0, ControlType::Commit)),
+            vec![4, 5]
+        );
+    }
+}
@@ -0,0 +1,347 @@
+//!
+//! # Stream control
+//!
+//! Seek, pause and resume of active partition stream, sent to stream fetch
+//! session on partition leader through `StreamControl` request.
+//!
+use std::pin::Pin;
+use std::sync::Arc;
+use std::task::{Context, Poll};
+
+use futures_util::stream::{BoxStream, Stream};
+use once_cell::sync::OnceCell;
+use tracing::debug;
+
+use dataplane::ErrorCode;
+use dataplane::ReplicaKey;
+use fluvio_protocol::api::Request;
+use fluvio_sc_schema::ApiError;
+use fluvio_spu_schema::server::stream_control::{StreamControl, StreamControlRequest};
+use fluvio_spu_schema::server::stream_fetch::{DefaultStreamFetchRequest, STREAM_CONTROL_API};
+use fluvio_types::event::StickyEvent;
+
+use crate::FluvioError;
+use crate::offset::{Offset, fetch_offsets};
+use crate::sockets::VersionedSerialSocket;
+
+use super::Record;
+
+/// id of stream fetch session, known after first response from leader
+pub(crate) struct SessionId {
+    id: OnceCell<u32>,
+    started: Arc<StickyEvent>,
+}
+
+impl SessionId {
+    pub(crate) fn shared() -> Arc<Self> {
+        Arc::new(Self {
+            id: OnceCell::new(),
+            started: StickyEvent::shared(),
+        })
+    }
+
+    pub(crate) fn set(&self, id: u32) {
+        let _ = self.id.set(id);
+        self.started.notify();
+    }
+
+    /// stream ended before session was started
+    pub(crate) fn end(&self) {
+        self.started.notify();
+    }
+
+    async fn wait(&self) -> Option<u32> {
+        self.started.listen().await;
+        self.id.get().copied()
+    }
+}
+
+/// Partition stream which can be moved to other offset, paused and resumed
+///
+/// Records are consumed like any other stream, while [`StreamHandle`]
+/// obtained from [`handle`] controls the stream from other tasks.
+///
+/// [`StreamHandle`]: struct.StreamHandle.html
+/// [`handle`]: struct.ControlledStream.html#method.handle
+pub struct ControlledStream {
+    stream: BoxStream<'static, Result<Record, FluvioError>>,
+    handle: StreamHandle,
+}
+
+impl ControlledStream {
+    pub(crate) fn new(
+        stream: BoxStream<'static, Result<Record, FluvioError>>,
+        handle: StreamHandle,
+    ) -> Self {
+        Self { stream, handle }
+    }
+
+    /// Handle which controls this stream
+    pub fn handle(&self) -> StreamHandle {
+        self.handle.clone()
+    }
+
+    /// Continue stream from offset, see [`StreamHandle::seek`]
+    ///
+    /// [`StreamHandle::seek`]: struct.StreamHandle.html#method.seek
+    pub async fn seek(&self, offset: Offset) -> Result<(), FluvioError> {
+        self.handle.seek(offset).await
+    }
+
+    /// Stop receiving records until resumed
+    pub async fn pause(&self) -> Result<(), FluvioError> {
+        self.handle.pause().await
+    }
+
+    /// Continue receiving records after pause
+    pub async fn resume(&self) -> Result<(), FluvioError> {
+        self.handle.resume().await
+    }
+}
+
+impl Stream for ControlledStream {
+    type Item = Result<Record, FluvioError>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.stream.as_mut().poll_next(cx)
+    }
+}
+
+/// Controls stream fetch session of [`ControlledStream`]
+///
+/// Records which were already sent by the leader when control is applied
+/// are still delivered, so after `seek` the stream may return records
+/// from previous position before records from the new offset.
+///
+/// [`ControlledStream`]: struct.ControlledStream.html
+#[derive(Clone)]
+pub struct StreamHandle {
+    socket: Arc<VersionedSerialSocket>,
+    replica: ReplicaKey,
+    session: Arc<SessionId>,
+}
+
+impl StreamHandle {
+    pub(crate) fn new(
+        socket: VersionedSerialSocket,
+        replica: ReplicaKey,
+        session: Arc<SessionId>,
+    ) -> Result<Self, FluvioError> {
+        let stream_fetch_version = socket
+            .versions()
+            .lookup_version(DefaultStreamFetchRequest::API_KEY)
+            .unwrap_or_default();
+        if stream_fetch_version < STREAM_CONTROL_API {
+            return Err(FluvioError::Other(
+                "SPU does not support stream control".to_owned(),
+            ));
+        }
+
+        Ok(Self {
+            socket: Arc::new(socket),
+            replica,
+            session,
+        })
+    }
+
+    /// Continue stream from offset
+    ///
+    /// Offset is resolved against current offsets of partition,
+    /// offsets outside of partition are rejected by the leader.
+    pub async fn seek(&self, offset: Offset) -> Result<(), FluvioError> {
+        let offsets = fetch_offsets(&self.socket, &self.replica).await?;
+        let offset = offset.resolve(&offsets).await?;
+        self.send(StreamControl::Seek(offset)).await
+    }
+
+    /// Stop receiving records until resumed
+    ///
+    /// Position of consumer is kept by the leader while paused.
+    pub async fn pause(&self) -> Result<(), FluvioError> {
+        self.send(StreamControl::Pause).await
+    }
+
+    /// Continue receiving records after pause
+    pub async fn resume(&self) -> Result<(), FluvioError> {
+        self.send(StreamControl::Resume).await
+    }
+
+    async fn send(&self, control: StreamControl) -> Result<(), FluvioError> {
+        let session_id = self.session.wait().await.ok_or_else(|| {
+            FluvioError::AdminApi(ApiError::Code(ErrorCode::FetchSessionNotFoud, None))
+        })?;
+        debug!(session_id, ?control, replica = %self.replica, "sending stream control");
+
+        let response = self
+            .socket
+            .send_receive(StreamControlRequest::new(session_id, control))
+            .await?;
+        match response.error {
+            ErrorCode::None => Ok(()),
+            code => Err(FluvioError::AdminApi(ApiError::Code(code, None))),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use std::io::Cursor;
+    use std::time::Duration;
+
+    use async_channel::Sender;
+    use futures_util::stream::StreamExt;
+
+    use dataplane::api::{RequestHeader, RequestMessage};
+    use dataplane::core::Decoder;
+    use dataplane::versions::{ApiVersionKey, ApiVersionsResponse};
+    use fluvio_future::net::{TcpListener, TcpStream};
+    use fluvio_future::task::spawn;
+    use fluvio_future::timer::sleep;
+    use fluvio_socket::{FluvioSocket, MultiplexerSocket};
+    use fluvio_spu_schema::server::fetch_offset::{
+        FetchOffsetsRequest, FetchOffsetsResponse, FetchOffsetTopicResponse,
+        FetchOffsetPartitionResponse,
+    };
+    use fluvio_spu_schema::server::stream_control::StreamControlResponse;
+
+    use crate::sockets::{ClientConfig, Versions};
+
+    use super::*;
+
+    /// SPU with 10 records in partition, received controls are forwarded to sender
+    async fn fake_spu(listener: TcpListener, controls: Sender<(u32, StreamControl)>) {
+        let mut incoming = listener.incoming();
+        let stream = incoming.next().await.expect("next").expect("stream");
+        let socket: FluvioSocket = stream.into();
+        let (mut sink, mut stream) = socket.split();
+        while let Some(Ok(bytes)) = stream.get_mut_tcp_stream().next().await {
+            let header = RequestHeader::decode_from(&mut Cursor::new(&bytes), 0).expect("header");
+            let version = header.api_version();
+            if header.api_key() == FetchOffsetsRequest::API_KEY {
+                let request: RequestMessage<FetchOffsetsRequest> =
+                    RequestMessage::decode_from(&mut Cursor::new(&bytes), 0).expect("decode");
+                let topic = &request.request.topics[0];
+                let response = FetchOffsetsResponse {
+                    topics: vec![FetchOffsetTopicResponse {
+                        name: topic.name.clone(),
+                        partitions: vec![FetchOffsetPartitionResponse {
+                            partition_index: topic.partitions[0].partition_index,
+                            start_offset: 0,
+                            last_stable_offset: 10,
+                            leo: 10,
+                            ..Default::default()
+                        }],
+                    }],
+                };
+                sink.send_response(&request.new_response(response), version)
+                    .await
+                    .expect("send");
+            } else {
+                let request: RequestMessage<StreamControlRequest> =
+                    RequestMessage::decode_from(&mut Cursor::new(&bytes), 0).expect("decode");
+                let session_id = request.request.session_id;
+                controls
+                    .send((session_id, request.request.control.clone()))
+                    .await
+                    .expect("control");
+                let response = StreamControlResponse {
+                    session_id,
+                    error: ErrorCode::None,
+                };
+                sink.send_response(&request.new_response(response), version)
+                    .await
+                    .expect("send");
+            }
+        }
+    }
+
+    async fn spu_socket(addr: &str, stream_fetch_version: i16) -> VersionedSerialSocket {
+        let stream = TcpStream::connect(addr).await.expect("connect");
+        let versions = Versions::new(ApiVersionsResponse {
+            api_keys: vec![ApiVersionKey {
+                api_key: DefaultStreamFetchRequest::API_KEY as i16,
+                min_version: 0,
+                max_version: stream_fetch_version,
+            }],
+            ..Default::default()
+        });
+        VersionedSerialSocket::new(
+            MultiplexerSocket::shared(stream.into()),
+            Arc::new(ClientConfig::with_addr(addr.to_owned())),
+            versions,
+        )
+    }
+
+    #[fluvio_future::test]
+    async fn test_stream_handle_controls() {
+        let addr = "127.0.0.1:6020";
+        let (sender, controls) = async_channel::unbounded();
+        let listener = TcpListener::bind(addr).await.expect("bind");
+        spawn(fake_spu(listener, sender));
+
+        let session = SessionId::shared();
+        let handle = StreamHandle::new(
+            spu_socket(addr, STREAM_CONTROL_API).await,
+            ReplicaKey::new("test", 0),
+            session.clone(),
+        )
+        .expect("handle");
+
+        // control waits until session is started by leader
+        let seek_handle = handle.clone();
+        let seek = spawn(async move { seek_handle.seek(Offset::from_end(2)).await });
+        sleep(Duration::from_millis(50)).await;
+        assert!(controls.is_empty());
+
+        session.set(7);
+        seek.await.expect("seek");
+        assert_eq!(
+            controls.recv().await.expect("control"),
+            (7, StreamControl::Seek(8))
+        );
+
+        handle.pause().await.expect("pause");
+        handle.resume().await.expect("resume");
+        assert_eq!(
+            controls.recv().await.expect("control"),
+            (7, StreamControl::Pause)
+        );
+        assert_eq!(
+            controls.recv().await.expect("control"),
+            (7, StreamControl::Resume)
+        );
+    }
+
+    #[fluvio_future::test]
+    async fn test_stream_handle_without_session() {
+        let addr = "127.0.0.1:6021";
+        let (sender, controls) = async_channel::unbounded();
+        let listener = TcpListener::bind(addr).await.expect("bind");
+        spawn(fake_spu(listener, sender));
+
+        // old SPU doesn't support stream control
+        assert!(StreamHandle::new(
+            spu_socket(addr, STREAM_CONTROL_API - 1).await,
+            ReplicaKey::new("test", 0),
+            SessionId::shared(),
+        )
+        .is_err());
+
+        let session = SessionId::shared();
+        let handle = StreamHandle::new(
+            spu_socket(addr, STREAM_CONTROL_API).await,
+            ReplicaKey::new("test", 0),
+            session.clone(),
+        )
+        .expect("handle");
+
+        // stream ended before leader started session
+        session.end();
+        match handle.pause().await {
+            Err(FluvioError::AdminApi(ApiError::Code(ErrorCode::FetchSessionNotFoud, _))) => {}
+            other => panic!("expected missing session, got: {:?}", other.err()),
+        }
+        assert!(controls.is_empty());
+    }
+}
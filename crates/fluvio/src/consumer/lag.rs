@@ -0,0 +1,215 @@
+//!
+//! # Consumer lag
+//!
+//! Offsets of partitions and positions of active stream sessions,
+//! reported by partition leaders through `FetchOffsets` request.
+//!
+use std::collections::BTreeMap;
+
+use serde::Serialize;
+use tracing::debug;
+
+use dataplane::ErrorCode;
+use dataplane::ReplicaKey;
+use fluvio_protocol::api::Request;
+use fluvio_sc_schema::ApiError;
+use fluvio_spu_schema::server::fetch_offset::{
+    FetchOffsetsRequest, FetchOffsetPartitionResponse, StreamSessionOffset, STREAM_SESSIONS_API,
+};
+use fluvio_types::{PartitionId, SpuId};
+
+use crate::FluvioError;
+use crate::spu::SpuPool;
+
+/// Offsets of a partition and lag of its consumers
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct PartitionLag {
+    pub topic: String,
+    pub partition: PartitionId,
+    pub leader: SpuId,
+    /// First readable offset
+    pub log_start_offset: i64,
+    pub high_watermark: i64,
+    /// Log end offset
+    pub leo: i64,
+    /// Active stream sessions on leader
+    pub sessions: Vec<SessionLag>,
+}
+
+/// Position and lag of a consumer stream session
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct SessionLag {
+    pub session_id: u32,
+    /// Client id of consumer
+    pub client_id: String,
+    /// Offset after last record sent to consumer
+    pub delivered_offset: Option<i64>,
+    /// Offset acknowledged by consumer
+    pub committed_offset: Option<i64>,
+    /// Records behind high watermark from committed offset,
+    /// or from delivered offset if consumer has not acknowledged yet
+    pub lag: Option<i64>,
+}
+
+impl SessionLag {
+    fn new(session: StreamSessionOffset, high_watermark: i64) -> Self {
+        let delivered_offset = valid_offset(session.delivered_offset);
+        let committed_offset = valid_offset(session.committed_offset);
+        let lag = committed_offset
+            .or(delivered_offset)
+            .map(|offset| (high_watermark - offset).max(0));
+        Self {
+            session_id: session.session_id,
+            client_id: session.client_id,
+            delivered_offset,
+            committed_offset,
+            lag,
+        }
+    }
+}
+
+/// offsets are -1 until known
+fn valid_offset(offset: i64) -> Option<i64> {
+    if offset < 0 {
+        None
+    } else {
+        Some(offset)
+    }
+}
+
+impl PartitionLag {
+    fn new(topic: &str, leader: SpuId, response: FetchOffsetPartitionResponse) -> Self {
+        let high_watermark = response.last_stable_offset;
+        Self {
+            topic: topic.to_owned(),
+            partition: response.partition_index,
+            leader,
+            log_start_offset: response.start_offset,
+            high_watermark,
+            leo: response.leo,
+            sessions: response
+                .sessions
+                .into_iter()
+                .map(|session| SessionLag::new(session, high_watermark))
+                .collect(),
+        }
+    }
+
+    /// Largest lag of sessions, None if there are no sessions with known position
+    pub fn max_lag(&self) -> Option<i64> {
+        self.sessions.iter().filter_map(|session| session.lag).max()
+    }
+}
+
+/// query leaders of all partitions of topic
+pub(crate) async fn consumer_lag(
+    pool: &SpuPool,
+    topic: &str,
+) -> Result<Vec<PartitionLag>, FluvioError> {
+    let topic_spec = pool
+        .metadata
+        .topics()
+        .lookup_by_key(topic)
+        .await?
+        .ok_or_else(|| FluvioError::TopicNotFound(topic.to_owned()))?
+        .spec;
+
+    let mut partitions_by_leader: BTreeMap<SpuId, Vec<PartitionId>> = BTreeMap::new();
+    for partition in 0..topic_spec.partitions() {
+        let replica = ReplicaKey::new(topic, partition);
+        let leader = pool
+            .metadata
+            .partitions()
+            .lookup_by_key(&replica)
+            .await?
+            .ok_or_else(|| FluvioError::PartitionNotFound(topic.to_owned(), partition))?
+            .spec
+            .leader;
+        partitions_by_leader
+            .entry(leader)
+            .or_default()
+            .push(partition);
+    }
+
+    let mut lags = vec![];
+    for (leader, partitions) in partitions_by_leader {
+        debug!(leader, ?partitions, "fetching consumer lag");
+        let socket = pool.create_serial_socket_from_leader(leader).await?;
+        let version = socket
+            .versions()
+            .lookup_version(FetchOffsetsRequest::API_KEY)
+            .unwrap_or_default();
+        if version < STREAM_SESSIONS_API {
+            return Err(FluvioError::Other(format!(
+                "SPU {} does not support consumer lag",
+                leader
+            )));
+        }
+
+        let request = FetchOffsetsRequest::with_sessions(topic.to_owned(), partitions);
+        let response = socket.send_receive(request).await?;
+        for topic_response in response.topics {
+            for partition in topic_response.partitions {
+                if partition.error_code != ErrorCode::None {
+                    return Err(FluvioError::AdminApi(ApiError::Code(
+                        partition.error_code,
+                        None,
+                    )));
+                }
+                lags.push(PartitionLag::new(topic, leader, partition));
+            }
+        }
+    }
+
+    lags.sort_by_key(|lag| lag.partition);
+    Ok(lags)
+}
+
+#[cfg(test)]
+mod tests {
+
+    use fluvio_spu_schema::server::fetch_offset::{FetchOffsetPartitionResponse, StreamSessionOffset};
+
+    use super::PartitionLag;
+
+    #[test]
+    fn test_partition_lag() {
+        let response = FetchOffsetPartitionResponse {
+            partition_index: 1,
+            start_offset: 0,
+            last_stable_offset: 100,
+            leo: 105,
+            sessions: vec![
+                StreamSessionOffset {
+                    session_id: 0,
+                    client_id: "committed".to_owned(),
+                    delivered_offset: 90,
+                    committed_offset: 80,
+                },
+                StreamSessionOffset {
+                    session_id: 1,
+                    client_id: "delivered".to_owned(),
+                    delivered_offset: 95,
+                    committed_offset: -1,
+                },
+                StreamSessionOffset {
+                    session_id: 2,
+                    client_id: "new".to_owned(),
+                    delivered_offset: -1,
+                    committed_offset: -1,
+                },
+            ],
+            ..Default::default()
+        };
+
+        let lag = PartitionLag::new("test", 5001, response);
+        assert_eq!(lag.partition, 1);
+        assert_eq!(lag.high_watermark, 100);
+        assert_eq!(lag.leo, 105);
+        assert_eq!(lag.sessions[0].lag, Some(20));
+        assert_eq!(lag.sessions[1].committed_offset, None);
+        assert_eq!(lag.sessions[1].lag, Some(5));
+        assert_eq!(lag.sessions[2].lag, None);
+        assert_eq!(lag.max_lag(), Some(20));
+    }
+}
@@ -1,9 +1,18 @@
+mod committed;
+mod control;
+pub(crate) mod lag;
+mod resume;
+mod subscription;
+mod typed;
+
 use std::sync::Arc;
+use std::time::Duration;
 
 use futures_util::stream::{Stream, select_all};
 use tracing::{debug, error, trace, instrument};
 use once_cell::sync::Lazy;
-use futures_util::future::{Either, err, join_all};
+use instant::Instant;
+use futures_util::future::{Either, err, join_all, ready};
 use futures_util::stream::{StreamExt, once, iter};
 use futures_util::FutureExt;
 
@@ -26,8 +35,16 @@ use fluvio_types::event::offsets::OffsetPublisher;
 use crate::FluvioError;
 use crate::offset::{Offset, fetch_offsets};
 use crate::spu::SpuPool;
+use self::committed::ReadCommitted;
+use self::control::SessionId;
+use self::subscription::TopicFilter;
 use derive_builder::Builder;
 
+pub use control::{ControlledStream, StreamHandle};
+pub use lag::{PartitionLag, SessionLag};
+pub use typed::{TypedRecord, TypedStream, TypedStreamExt};
+pub use regex::Regex;
+
 /// An interface for consuming events from a particular partition
 ///
 /// There are two ways to consume events: by "fetching" events
@@ -170,8 +187,8 @@ impl PartitionConsumer {
             offset, &replica,
         );
 
-        let mut leader = self.pool.create_serial_socket(&replica).await?;
-        let offsets = fetch_offsets(&mut leader, &replica).await?;
+        let leader = self.pool.create_serial_socket(&replica).await?;
+        let offsets = fetch_offsets(&leader, &replica).await?;
         debug!("found spu leader {}", leader);
         let offset = offset.resolve(&offsets).await?;
 
@@ -194,7 +211,9 @@ impl PartitionConsumer {
             ..Default::default()
         };
 
+        let start = Instant::now();
         let response = leader.send_receive(fetch_request).await?;
+        self.pool.metrics.record_fetch_latency(start.elapsed());
 
         debug!("received fetch logs for {}", &replica);
 
@@ -302,16 +321,28 @@ impl PartitionConsumer {
         offset: Offset,
         config: ConsumerConfig,
     ) -> Result<impl Stream<Item = Result<Record, FluvioError>>, FluvioError> {
-        let stream = self.stream_batches_with_config(offset, config).await?;
+        self.stream_records(offset, config, None).await
+    }
+
+    async fn stream_records(
+        &self,
+        offset: Offset,
+        config: ConsumerConfig,
+        session: Option<Arc<SessionId>>,
+    ) -> Result<impl Stream<Item = Result<Record, FluvioError>>, FluvioError> {
+        let stream = self.stream_batches(offset, config, session).await?;
+        let topic: Arc<str> = Arc::from(self.topic.as_str());
         let partition = self.partition;
         let flattened =
             stream.flat_map(move |result: Result<Batch, _>| match result {
                 Err(e) => Either::Right(once(err(e))),
                 Ok(batch) => {
                     let base_offset = batch.base_offset;
+                    let topic = topic.clone();
                     let records = batch.own_records().into_iter().enumerate().map(
                         move |(relative, record)| {
                             Ok(Record {
+                                topic: topic.clone(),
                                 partition,
                                 offset: base_offset + relative as i64,
                                 record,
@@ -325,6 +356,97 @@ impl PartitionConsumer {
         Ok(flattened)
     }
 
+    /// Continuously streams events, resuming the stream after connection or leader failures
+    ///
+    /// Unlike [`stream_with_config`], this stream does not end when the connection
+    /// to the SPU is lost or when the partition leader moves to another SPU.
+    /// The offset of the last delivered record is tracked, and the stream is
+    /// re-created against the current leader with exponential backoff, starting
+    /// at the next offset. Only errors which can't be retried, or errors after
+    /// [`max_retries`] attempts in a row, are returned by the stream.
+    ///
+    /// SmartStream aggregates are restarted with the initial accumulator when resumed.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use fluvio::{PartitionConsumer, FluvioError};
+    /// # use fluvio::{Offset, ConsumerConfig};
+    /// # mod futures {
+    /// #     pub use futures_util::stream::StreamExt;
+    /// # }
+    /// # async fn example(consumer: &PartitionConsumer) -> Result<(), FluvioError> {
+    /// use futures::StreamExt;
+    /// let config = ConsumerConfig::builder().max_retries(10).build()?;
+    /// let mut stream = consumer.resumable_stream(Offset::beginning(), config);
+    /// while let Some(Ok(record)) = stream.next().await {
+    ///     let value = String::from_utf8_lossy(record.value());
+    ///     println!("Got record at {}: {}", record.offset(), value);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`stream_with_config`]: struct.PartitionConsumer.html#method.stream_with_config
+    /// [`max_retries`]: struct.ConsumerConfigBuilder.html#method.max_retries
+    pub fn resumable_stream(
+        &self,
+        offset: Offset,
+        config: ConsumerConfig,
+    ) -> impl Stream<Item = Result<Record, FluvioError>> {
+        let consumer =
+            PartitionConsumer::new(self.topic.clone(), self.partition, self.pool.clone());
+        resume::resumable_stream(consumer, offset, config)
+    }
+
+    /// Continuously streams events, which can be moved to other offset, paused and resumed
+    ///
+    /// The returned [`ControlledStream`] yields records like [`stream_with_config`].
+    /// Its [`StreamHandle`] sends control to the stream session on the partition leader,
+    /// so the stream is not re-created. Records already sent by the leader are still
+    /// delivered before records from the new position. Control waits until the
+    /// stream has received its first response, so the stream must be polled.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use fluvio::{PartitionConsumer, FluvioError};
+    /// # use fluvio::{Offset, ConsumerConfig};
+    /// # mod futures {
+    /// #     pub use futures_util::stream::StreamExt;
+    /// # }
+    /// # async fn example(consumer: &PartitionConsumer) -> Result<(), FluvioError> {
+    /// use futures::StreamExt;
+    /// let config = ConsumerConfig::builder().build()?;
+    /// let mut stream = consumer.controlled_stream(Offset::beginning(), config).await?;
+    /// while let Some(Ok(record)) = stream.next().await {
+    ///     println!("Got record at {}", record.offset());
+    ///     if record.offset() == 100 {
+    ///         // skip to the latest records
+    ///         stream.seek(Offset::end()).await?;
+    ///     }
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`ControlledStream`]: struct.ControlledStream.html
+    /// [`StreamHandle`]: struct.StreamHandle.html
+    /// [`stream_with_config`]: struct.PartitionConsumer.html#method.stream_with_config
+    #[instrument(skip(self, offset, config))]
+    pub async fn controlled_stream(
+        &self,
+        offset: Offset,
+        config: ConsumerConfig,
+    ) -> Result<ControlledStream, FluvioError> {
+        let replica = ReplicaKey::new(&self.topic, self.partition);
+        let socket = self.pool.create_serial_socket(&replica).await?;
+        let session = SessionId::shared();
+        let handle = StreamHandle::new(socket, replica, session.clone())?;
+        let stream = self.stream_records(offset, config, Some(session)).await?;
+        Ok(ControlledStream::new(stream.boxed(), handle))
+    }
+
     /// Continuously streams batches of messages, starting an offset in the consumer's partition
     ///
     /// ```
@@ -356,46 +478,86 @@ impl PartitionConsumer {
         offset: Offset,
         config: ConsumerConfig,
     ) -> Result<impl Stream<Item = Result<Batch, FluvioError>>, FluvioError> {
-        let stream = self.request_stream(offset, config).await?;
-        let flattened = stream.flat_map(|batch_result: Result<DefaultStreamFetchResponse, _>| {
-            let response = match batch_result {
-                Ok(response) => response,
-                Err(e) => return Either::Right(once(err(e))),
-            };
-
-            // If we ever get an error_code AND batches of records, we want to first send
-            // the records down the consumer stream, THEN an Err with the error inside.
-            // This way the consumer always gets to read all records that were properly
-            // processed before hitting an error, so that the error does not obscure those records.
-            let batches = response.partition.records.batches.into_iter().map(Ok);
-            let error = {
-                let code = response.partition.error_code;
-                match code {
-                    ErrorCode::None => None,
-                    ErrorCode::SmartStreamError(error) => {
-                        Some(Err(FluvioError::SmartStream(error)))
+        self.stream_batches(offset, config, None).await
+    }
+
+    async fn stream_batches(
+        &self,
+        offset: Offset,
+        config: ConsumerConfig,
+        session: Option<Arc<SessionId>>,
+    ) -> Result<impl Stream<Item = Result<Batch, FluvioError>>, FluvioError> {
+        let transactional = config.transactional;
+        if transactional && config.wasm_module.is_some() {
+            // batches processed by SmartStream don't carry transaction of records
+            return Err(FluvioError::ConsumerConfig(
+                "transactional consumer can't be used with SmartStreams".to_owned(),
+            ));
+        }
+        let max_held_bytes = config.max_held_bytes;
+
+        let stream = self.request_stream(offset, config, session).await?;
+        let metrics = self.pool.metrics.clone();
+        let replica = ReplicaKey::new(&self.topic, self.partition);
+        let flattened =
+            stream.flat_map(move |batch_result: Result<DefaultStreamFetchResponse, _>| {
+                let response = match batch_result {
+                    Ok(response) => response,
+                    Err(e) => return Either::Right(once(err(e))),
+                };
+                metrics.record_consumed(&replica, &response.partition);
+
+                // If we ever get an error_code AND batches of records, we want to first send
+                // the records down the consumer stream, THEN an Err with the error inside.
+                // This way the consumer always gets to read all records that were properly
+                // processed before hitting an error, so that the error does not obscure those records.
+                let batches = response.partition.records.batches.into_iter().map(Ok);
+                let error = {
+                    let code = response.partition.error_code;
+                    match code {
+                        ErrorCode::None => None,
+                        ErrorCode::SmartStreamError(error) => {
+                            Some(Err(FluvioError::SmartStream(error)))
+                        }
+                        _ => Some(Err(FluvioError::AdminApi(
+                            fluvio_sc_schema::ApiError::Code(code, None),
+                        ))),
                     }
-                    _ => Some(Err(FluvioError::AdminApi(
-                        fluvio_sc_schema::ApiError::Code(code, None),
-                    ))),
-                }
-            };
+                };
 
-            let items = batches.chain(error.into_iter());
-            Either::Left(iter(items))
-        });
+                let items = batches.chain(error.into_iter());
+                Either::Left(iter(items))
+            });
 
-        Ok(flattened)
+        if !transactional {
+            // transaction markers are not delivered
+            let batches = flattened.filter(|result| {
+                ready(!matches!(result, Ok(batch) if batch.get_header().is_control()))
+            });
+            return Ok(Either::Left(batches));
+        }
+
+        let mut committed = ReadCommitted::new(max_held_bytes);
+        let committed_batches = flattened.flat_map(move |result| {
+            let items: Vec<Result<Batch, FluvioError>> =
+                match result.and_then(|batch| committed.process(batch)) {
+                    Ok(batches) => batches.into_iter().map(Ok).collect(),
+                    Err(e) => vec![Err(e)],
+                };
+            iter(items)
+        });
+        Ok(Either::Right(committed_batches))
     }
 
     /// Creates a stream of `DefaultStreamFetchResponse` for older consumers who rely
     /// on the internal structure of the fetch response. New clients should use the
     /// `stream` and `stream_with_config` methods.
-    #[instrument(skip(self, config))]
+    #[instrument(skip(self, config, session))]
     async fn request_stream(
         &self,
         offset: Offset,
         config: ConsumerConfig,
+        session: Option<Arc<SessionId>>,
     ) -> Result<impl Stream<Item = Result<DefaultStreamFetchResponse, FluvioError>>, FluvioError>
     {
         use fluvio_future::task::spawn;
@@ -404,20 +566,29 @@ impl PartitionConsumer {
         use fluvio_protocol::api::Request;
 
         let replica = ReplicaKey::new(&self.topic, self.partition);
-        let mut serial_socket = self.pool.create_serial_socket(&replica).await?;
-        let offsets = fetch_offsets(&mut serial_socket, &replica).await?;
+        let serial_socket = self.pool.create_serial_socket(&replica).await?;
+        let offsets = fetch_offsets(&serial_socket, &replica).await?;
 
         let start_absolute_offset = offset.resolve(&offsets).await?;
         let end_absolute_offset = offsets.last_stable_offset;
         let record_count = end_absolute_offset - start_absolute_offset;
+        self.pool
+            .metrics
+            .record_offsets(&replica, start_absolute_offset, end_absolute_offset);
 
         debug!(start_absolute_offset, end_absolute_offset, record_count);
 
+        // records of transaction are committed once they are replicated
+        let isolation = if config.transactional {
+            Isolation::ReadCommitted
+        } else {
+            config.isolation
+        };
         let mut stream_request = DefaultStreamFetchRequest {
             topic: self.topic.to_owned(),
             partition: self.partition,
             fetch_offset: start_absolute_offset,
-            isolation: config.isolation,
+            isolation,
             max_bytes: config.max_bytes,
             ..Default::default()
         };
@@ -458,6 +629,9 @@ impl PartitionConsumer {
         let ft_stream = async move {
             if let Some(Ok(response)) = stream.next().await {
                 let stream_id = response.stream_id;
+                if let Some(session) = &session {
+                    session.set(stream_id);
+                }
 
                 trace!("first stream response: {:#?}", response);
                 debug!(
@@ -524,6 +698,9 @@ impl PartitionConsumer {
                         .chain(publish_stream::EndPublishSt::new(update_stream, publisher)),
                 )
             } else {
+                if let Some(session) = &session {
+                    session.end();
+                }
                 Either::Right(empty())
             }
         };
@@ -668,6 +845,9 @@ static MAX_FETCH_BYTES: Lazy<i32> = Lazy::new(|| {
     max_bytes
 });
 
+/// default limit of bytes held by transactional consumer
+const DEFAULT_MAX_HELD_BYTES: usize = 64 * 1024 * 1024;
+
 /// Configures the behavior of consumer fetching and streaming
 #[derive(Debug, Builder, Clone)]
 #[builder(build_fn(private, name = "build_impl"))]
@@ -678,8 +858,27 @@ pub struct ConsumerConfig {
     pub(crate) max_bytes: i32,
     #[builder(default)]
     pub(crate) isolation: Isolation,
+    /// read records of committed transactions only, records of aborted transactions are skipped
+    /// and records after open transaction are held until it is ended.
+    /// Read committed isolation is used, can't be used with SmartStreams.
+    ///
+    /// Records are filtered by consumer, SPU still sends records of open and aborted transactions
+    /// and stream fails if held records exceed `max_held_bytes`
+    #[builder(default)]
+    pub(crate) transactional: bool,
+    /// limit of bytes held until transactions are ended, stream returns error if it is exceeded
+    #[builder(default = "DEFAULT_MAX_HELD_BYTES")]
+    pub(crate) max_held_bytes: usize,
     #[builder(private, default, setter(into, strip_option))]
     pub(crate) wasm_module: Option<SmartStreamPayload>,
+    /// max attempts in a row to resume stream, unlimited if not set
+    #[builder(default, setter(strip_option))]
+    pub(crate) max_retries: Option<u32>,
+    /// wait before first attempt to resume stream, doubled after each failed attempt
+    #[builder(default = "Duration::from_millis(100)")]
+    pub(crate) min_retry_backoff: Duration,
+    #[builder(default = "Duration::from_secs(10)")]
+    pub(crate) max_retry_backoff: Duration,
 }
 
 impl ConsumerConfig {
@@ -731,14 +930,32 @@ impl ConsumerConfigBuilder {
 }
 
 /// Strategy used to select which partitions and from which topics should be streamed by the [`MultiplePartitionConsumer`]
+#[derive(Debug, Clone)]
 pub enum PartitionSelectionStrategy {
     /// Consume from all the partitions of a given topic
     All(String),
     /// Consume from a given list of topics and partitions
     Multiple(Vec<(String, i32)>),
+    /// Consume from all the partitions of given topics.
+    /// Topics which don't exist yet and partitions added later are consumed once they are provisioned
+    Topics(Vec<String>),
+    /// Consume from all the partitions of topics with name matching regex, including topics created later.
+    /// Pattern is not anchored, use `^` and `$` to match whole name
+    Pattern(Regex),
 }
 
 impl PartitionSelectionStrategy {
+    /// filter of topics watched by stream, none if partitions are selected once
+    fn topic_filter(&self) -> Option<TopicFilter> {
+        match self {
+            PartitionSelectionStrategy::Topics(topics) => Some(TopicFilter::Topics(topics.clone())),
+            PartitionSelectionStrategy::Pattern(pattern) => {
+                Some(TopicFilter::Pattern(pattern.clone()))
+            }
+            _ => None,
+        }
+    }
+
     async fn selection(&self, spu_pool: Arc<SpuPool>) -> Result<Vec<(String, i32)>, FluvioError> {
         let pairs = match self {
             PartitionSelectionStrategy::All(topic) => {
@@ -754,6 +971,10 @@ impl PartitionSelectionStrategy {
                     .collect::<Vec<_>>()
             }
             PartitionSelectionStrategy::Multiple(topic_partition) => topic_partition.to_owned(),
+            // partitions are selected as topics are provisioned
+            PartitionSelectionStrategy::Topics(_) | PartitionSelectionStrategy::Pattern(_) => {
+                vec![]
+            }
         };
         Ok(pairs)
     }
@@ -857,6 +1078,11 @@ impl MultiplePartitionConsumer {
         offset: Offset,
         config: ConsumerConfig,
     ) -> Result<impl Stream<Item = Result<Record, FluvioError>>, FluvioError> {
+        if let Some(filter) = self.strategy.topic_filter() {
+            let stream = subscription::subscribe(filter, self.pool.clone(), offset, config);
+            return Ok(Either::Right(stream));
+        }
+
         let consumers = self
             .strategy
             .selection(self.pool.clone())
@@ -873,12 +1099,14 @@ impl MultiplePartitionConsumer {
 
         let streams = streams_result.into_iter().collect::<Result<Vec<_>, _>>()?;
 
-        Ok(select_all(streams))
+        Ok(Either::Left(select_all(streams)))
     }
 }
 
 /// The individual record for a given stream.
 pub struct Record {
+    /// The topic where this Record is stored
+    topic: Arc<str>,
     /// The offset of this Record into its partition
     offset: i64,
     /// The partition where this Record is stored
@@ -888,6 +1116,11 @@ pub struct Record {
 }
 
 impl Record {
+    /// The topic where this Record is stored.
+    pub fn topic(&self) -> &str {
+        &self.topic
+    }
+
     /// The offset from the initial offset for a given stream.
     pub fn offset(&self) -> i64 {
         self.offset
@@ -0,0 +1,565 @@
+//!
+//! # Resumable consumer stream
+//!
+//! Wraps partition stream and tracks offset of last delivered record.
+//! When connection to leader is lost or partition leader is changed,
+//! stream is recreated to current leader and resumed from next offset.
+//!
+use std::io::ErrorKind;
+use std::time::Duration;
+
+use futures_util::future::BoxFuture;
+use futures_util::stream::{BoxStream, Stream, StreamExt, unfold};
+use tracing::{debug, warn};
+
+use dataplane::ErrorCode;
+use dataplane::ReplicaKey;
+use fluvio_future::timer::sleep;
+use fluvio_sc_schema::ApiError;
+use fluvio_socket::SocketError;
+use fluvio_types::SpuId;
+
+use crate::FluvioError;
+use crate::metadata::partition::PartitionSpec;
+use crate::metadata::store::ChangeListener;
+use crate::offset::{Offset, fetch_offsets};
+use crate::sync::AlwaysNewContext;
+
+use super::{ConsumerConfig, PartitionConsumer, Record};
+
+/// stream of records which is resumed after retryable errors,
+/// only non retryable errors or errors after exceeding max retries are returned
+pub(crate) fn resumable_stream(
+    consumer: PartitionConsumer,
+    offset: Offset,
+    config: ConsumerConfig,
+) -> impl Stream<Item = Result<Record, FluvioError>> {
+    let listener = consumer
+        .pool
+        .metadata
+        .partitions()
+        .store()
+        .change_listener();
+    let source = ConsumerSource {
+        consumer,
+        config: config.clone(),
+        listener,
+    };
+    resume(source, offset, config)
+}
+
+fn resume<S>(
+    source: S,
+    offset: Offset,
+    config: ConsumerConfig,
+) -> impl Stream<Item = Result<Record, FluvioError>>
+where
+    S: ResumeSource + Send + 'static,
+{
+    let backoff = Backoff::new(config.min_retry_backoff, config.max_retry_backoff);
+    let state = ResumeState {
+        source,
+        config,
+        start: offset,
+        next_offset: None,
+        current: None,
+        backoff,
+        retries: 0,
+        done: false,
+    };
+
+    unfold(state, |mut state| async move {
+        let item = state.next_record().await?;
+        Some((item, state))
+    })
+}
+
+type RecordStream = BoxStream<'static, Result<Record, FluvioError>>;
+
+/// partition which stream is resumed from
+trait ResumeSource {
+    fn replica(&self) -> ReplicaKey;
+
+    /// current leader of partition, none if partition is deleted.
+    /// partition changes until now are considered seen
+    fn leader(&mut self) -> BoxFuture<'_, Result<Option<SpuId>, FluvioError>>;
+
+    /// absolute offset of start offset
+    fn resolve(&mut self, offset: Offset) -> BoxFuture<'_, Result<i64, FluvioError>>;
+
+    /// stream from leader starting at absolute offset
+    fn stream(&mut self, offset: i64) -> BoxFuture<'_, Result<RecordStream, FluvioError>>;
+
+    /// wait until partition is changed
+    fn changed(&mut self) -> BoxFuture<'_, ()>;
+}
+
+struct ConsumerSource {
+    consumer: PartitionConsumer,
+    config: ConsumerConfig,
+    listener: ChangeListener<PartitionSpec, AlwaysNewContext>,
+}
+
+impl ResumeSource for ConsumerSource {
+    fn replica(&self) -> ReplicaKey {
+        ReplicaKey::new(&self.consumer.topic, self.consumer.partition)
+    }
+
+    fn leader(&mut self) -> BoxFuture<'_, Result<Option<SpuId>, FluvioError>> {
+        Box::pin(async move {
+            self.listener.load_last();
+            let partition = self
+                .consumer
+                .pool
+                .metadata
+                .partitions()
+                .lookup_by_key(&self.replica())
+                .await?;
+            Ok(partition.map(|partition| partition.spec.leader))
+        })
+    }
+
+    fn resolve(&mut self, offset: Offset) -> BoxFuture<'_, Result<i64, FluvioError>> {
+        Box::pin(async move {
+            let replica = self.replica();
+            let socket = self.consumer.pool.create_serial_socket(&replica).await?;
+            let offsets = fetch_offsets(&socket, &replica).await?;
+            offset.resolve(&offsets).await
+        })
+    }
+
+    fn stream(&mut self, offset: i64) -> BoxFuture<'_, Result<RecordStream, FluvioError>> {
+        Box::pin(async move {
+            let stream = self
+                .consumer
+                .stream_with_config(Offset::absolute(offset)?, self.config.clone())
+                .await?;
+            Ok(stream.boxed())
+        })
+    }
+
+    fn changed(&mut self) -> BoxFuture<'_, ()> {
+        Box::pin(self.listener.listen())
+    }
+}
+
+struct LeaderStream {
+    leader: SpuId,
+    stream: RecordStream,
+}
+
+struct ResumeState<S> {
+    source: S,
+    config: ConsumerConfig,
+    /// used until it is resolved to absolute offset
+    start: Offset,
+    /// offset of next record to deliver
+    next_offset: Option<i64>,
+    current: Option<LeaderStream>,
+    backoff: Backoff,
+    retries: u32,
+    done: bool,
+}
+
+impl<S: ResumeSource> ResumeState<S> {
+    async fn next_record(&mut self) -> Option<Result<Record, FluvioError>> {
+        use tokio::select;
+
+        if self.done {
+            return None;
+        }
+
+        loop {
+            if self.current.is_none() {
+                match self.connect().await {
+                    Ok(current) => self.current = Some(current),
+                    Err(err) => {
+                        if let Some(err) = self.retry(err).await {
+                            return self.fail(err);
+                        }
+                        continue;
+                    }
+                }
+            }
+
+            let current = self.current.as_mut().expect("connected");
+            let leader = current.leader;
+
+            let next = select! {
+                next = current.stream.next() => Some(next),
+                _ = self.source.changed() => None,
+            };
+
+            match next {
+                Some(Some(Ok(record))) => {
+                    self.next_offset = Some(record.offset() + 1);
+                    self.retries = 0;
+                    self.backoff.reset();
+                    return Some(Ok(record));
+                }
+                Some(Some(Err(err))) => {
+                    self.current = None;
+                    if let Some(err) = self.retry(err).await {
+                        return self.fail(err);
+                    }
+                }
+                Some(None) => {
+                    if self.config.disable_continuous {
+                        self.done = true;
+                        return None;
+                    }
+                    self.current = None;
+                    let err = FluvioError::Socket(SocketError::SocketClosed);
+                    if let Some(err) = self.retry(err).await {
+                        return self.fail(err);
+                    }
+                }
+                None => {
+                    if self.leader_changed(leader).await {
+                        debug!(
+                            old_leader = leader,
+                            next_offset = ?self.next_offset,
+                            "partition leader changed, resuming stream"
+                        );
+                        self.current = None;
+                    }
+                }
+            }
+        }
+    }
+
+    async fn connect(&mut self) -> Result<LeaderStream, FluvioError> {
+        // only changes after this point can move leader of new stream
+        let leader = self.source.leader().await?.ok_or_else(|| {
+            let replica = self.source.replica();
+            FluvioError::PartitionNotFound(replica.topic, replica.partition)
+        })?;
+
+        // relative offset is resolved only once, otherwise records produced
+        // before first record is delivered would be skipped on retry
+        let offset = match self.next_offset {
+            Some(next_offset) => next_offset,
+            None => {
+                let start = self.source.resolve(self.start.clone()).await?;
+                self.next_offset = Some(start);
+                start
+            }
+        };
+        debug!(leader, offset, "creating stream");
+
+        let stream = self.source.stream(offset).await?;
+        Ok(LeaderStream { leader, stream })
+    }
+
+    async fn leader_changed(&mut self, leader: SpuId) -> bool {
+        match self.source.leader().await {
+            Ok(Some(current)) => current != leader,
+            // partition is deleted, new stream will report it
+            Ok(None) => true,
+            Err(_) => false,
+        }
+    }
+
+    /// wait before next attempt, returns error back if it can't be retried
+    async fn retry(&mut self, err: FluvioError) -> Option<FluvioError> {
+        if !is_retryable(&err) {
+            return Some(err);
+        }
+        if let Some(max_retries) = self.config.max_retries {
+            if self.retries >= max_retries {
+                warn!(max_retries, "exceeded max retries of stream");
+                return Some(err);
+            }
+        }
+        self.retries += 1;
+        let wait = self.backoff.next_wait();
+        warn!(
+            retries = self.retries,
+            wait_ms = wait.as_millis() as u64,
+            "stream error: {}, resuming",
+            err
+        );
+        sleep(wait).await;
+        None
+    }
+
+    fn fail(&mut self, err: FluvioError) -> Option<Result<Record, FluvioError>> {
+        self.done = true;
+        Some(Err(err))
+    }
+}
+
+/// errors caused by lost connection or leader movement
+fn is_retryable(err: &FluvioError) -> bool {
+    match err {
+        FluvioError::Socket(_) => true,
+        FluvioError::SPUNotFound(_) => true,
+        FluvioError::Io(err) => matches!(
+            err.kind(),
+            ErrorKind::ConnectionRefused
+                | ErrorKind::ConnectionReset
+                | ErrorKind::ConnectionAborted
+                | ErrorKind::NotConnected
+                | ErrorKind::BrokenPipe
+                | ErrorKind::TimedOut
+                | ErrorKind::UnexpectedEof
+                | ErrorKind::Interrupted
+        ),
+        FluvioError::AdminApi(ApiError::Code(code, _)) => matches!(
+            code,
+            ErrorCode::NotLeaderForPartition
+                | ErrorCode::PartitionNotLeader
+                | ErrorCode::SpuOffline
+                | ErrorCode::SpuNotFound
+                | ErrorCode::FetchSessionNotFoud
+        ),
+        _ => false,
+    }
+}
+
+/// exponential backoff between attempts
+pub(super) struct Backoff {
+    min: Duration,
+    max: Duration,
+    current: Duration,
+}
+
+impl Backoff {
+    pub(super) fn new(min: Duration, max: Duration) -> Self {
+        Self {
+            min,
+            max,
+            current: min,
+        }
+    }
+
+    pub(super) fn next_wait(&mut self) -> Duration {
+        let wait = self.current;
+        self.current = (self.current * 2).min(self.max);
+        wait
+    }
+
+    fn reset(&mut self) {
+        self.current = self.min;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use std::collections::VecDeque;
+    use std::io::{Error as IoError, ErrorKind};
+    use std::sync::{Arc, Mutex};
+    use std::time::Duration;
+
+    use async_channel::{Receiver, Sender};
+    use futures_util::future::{BoxFuture, pending};
+    use futures_util::stream::{self, StreamExt};
+
+    use dataplane::ErrorCode;
+    use dataplane::ReplicaKey;
+    use dataplane::record::Record as DefaultRecord;
+    use fluvio_sc_schema::ApiError;
+    use fluvio_socket::SocketError;
+    use fluvio_types::SpuId;
+
+    use crate::FluvioError;
+    use crate::consumer::{ConsumerConfig, Record};
+    use crate::offset::Offset;
+
+    use super::{Backoff, RecordStream, ResumeSource, is_retryable, resume};
+
+    #[derive(Default)]
+    struct MockState {
+        leader: SpuId,
+        /// end offset, moved by each resolve as if records were produced
+        end: i64,
+        resolved: usize,
+        /// offsets streams were opened at
+        opened: Vec<i64>,
+        /// offsets of records or error of each stream
+        streams: VecDeque<Vec<Result<i64, FluvioError>>>,
+    }
+
+    struct MockSource {
+        state: Arc<Mutex<MockState>>,
+        changes: Receiver<()>,
+    }
+
+    impl ResumeSource for MockSource {
+        fn replica(&self) -> ReplicaKey {
+            ReplicaKey::new("test", 0)
+        }
+
+        fn leader(&mut self) -> BoxFuture<'_, Result<Option<SpuId>, FluvioError>> {
+            let leader = self.state.lock().unwrap().leader;
+            Box::pin(async move { Ok(Some(leader)) })
+        }
+
+        fn resolve(&mut self, _offset: Offset) -> BoxFuture<'_, Result<i64, FluvioError>> {
+            let mut state = self.state.lock().unwrap();
+            state.resolved += 1;
+            let end = state.end;
+            state.end += 3;
+            Box::pin(async move { Ok(end) })
+        }
+
+        fn stream(&mut self, offset: i64) -> BoxFuture<'_, Result<RecordStream, FluvioError>> {
+            let mut state = self.state.lock().unwrap();
+            state.opened.push(offset);
+            let items: Vec<_> = state
+                .streams
+                .pop_front()
+                .unwrap_or_default()
+                .into_iter()
+                .map(|item| item.map(record))
+                .collect();
+            // stream stays open after scripted items
+            let stream = stream::iter(items).chain(stream::pending()).boxed();
+            Box::pin(async move { Ok(stream) })
+        }
+
+        fn changed(&mut self) -> BoxFuture<'_, ()> {
+            Box::pin(async move {
+                if self.changes.recv().await.is_err() {
+                    pending::<()>().await;
+                }
+            })
+        }
+    }
+
+    fn record(offset: i64) -> Record {
+        Record {
+            topic: "test".into(),
+            partition: 0,
+            offset,
+            record: DefaultRecord::new(vec![]),
+        }
+    }
+
+    fn closed() -> FluvioError {
+        FluvioError::Socket(SocketError::SocketClosed)
+    }
+
+    fn mock(state: MockState) -> (MockSource, Arc<Mutex<MockState>>, Sender<()>) {
+        let state = Arc::new(Mutex::new(state));
+        let (sender, changes) = async_channel::unbounded();
+        let source = MockSource {
+            state: state.clone(),
+            changes,
+        };
+        (source, state, sender)
+    }
+
+    fn config() -> ConsumerConfig {
+        ConsumerConfig::builder()
+            .min_retry_backoff(Duration::from_millis(1))
+            .max_retry_backoff(Duration::from_millis(1))
+            .build()
+            .expect("config")
+    }
+
+    #[fluvio_future::test]
+    async fn test_resume_from_last_offset() {
+        let (source, state, _changes) = mock(MockState {
+            leader: 1,
+            end: 5,
+            streams: vec![
+                vec![Err(closed())],
+                vec![Ok(5), Ok(6), Err(closed())],
+                vec![Ok(7)],
+            ]
+            .into(),
+            ..Default::default()
+        });
+
+        let mut stream = resume(source, Offset::end(), config()).boxed();
+        for expected in 5..8 {
+            let record = stream.next().await.expect("record").expect("ok");
+            assert_eq!(record.offset(), expected);
+        }
+
+        let state = state.lock().unwrap();
+        // relative offset is resolved once, so error before first record doesn't skip records
+        assert_eq!(state.resolved, 1);
+        assert_eq!(state.opened, vec![5, 5, 7]);
+    }
+
+    #[fluvio_future::test]
+    async fn test_resume_after_leader_change() {
+        let (source, state, changes) = mock(MockState {
+            leader: 1,
+            streams: vec![vec![Ok(0)], vec![Ok(1)]].into(),
+            ..Default::default()
+        });
+
+        let mut stream = resume(source, Offset::beginning(), config()).boxed();
+        let record = stream.next().await.expect("record").expect("ok");
+        assert_eq!(record.offset(), 0);
+
+        state.lock().unwrap().leader = 2;
+        changes.send(()).await.expect("send");
+        let record = stream.next().await.expect("record").expect("ok");
+        assert_eq!(record.offset(), 1);
+
+        assert_eq!(state.lock().unwrap().opened, vec![0, 1]);
+    }
+
+    #[fluvio_future::test]
+    async fn test_resume_fails_after_max_retries() {
+        let (source, state, _changes) = mock(MockState {
+            leader: 1,
+            streams: vec![vec![Err(closed())], vec![Err(closed())]].into(),
+            ..Default::default()
+        });
+
+        let mut config = config();
+        config.max_retries = Some(1);
+        let mut stream = resume(source, Offset::beginning(), config).boxed();
+        assert!(matches!(
+            stream.next().await,
+            Some(Err(FluvioError::Socket(SocketError::SocketClosed)))
+        ));
+        assert!(stream.next().await.is_none());
+        assert_eq!(state.lock().unwrap().opened.len(), 2);
+    }
+
+    #[test]
+    fn test_retryable_errors() {
+        assert!(is_retryable(&FluvioError::Socket(
+            SocketError::SocketClosed
+        )));
+        assert!(is_retryable(&FluvioError::Io(IoError::new(
+            ErrorKind::ConnectionReset,
+            "reset"
+        ))));
+        assert!(is_retryable(&FluvioError::AdminApi(ApiError::Code(
+            ErrorCode::NotLeaderForPartition,
+            None
+        ))));
+
+        assert!(!is_retryable(&FluvioError::AdminApi(ApiError::Code(
+            ErrorCode::OffsetOutOfRange,
+            None
+        ))));
+        assert!(!is_retryable(&FluvioError::PartitionNotFound(
+            "test".to_owned(),
+            0
+        )));
+        assert!(!is_retryable(&FluvioError::Io(IoError::new(
+            ErrorKind::PermissionDenied,
+            "denied"
+        ))));
+    }
+
+    #[test]
+    fn test_backoff() {
+        let mut backoff = Backoff::new(Duration::from_millis(100), Duration::from_millis(300));
+        assert_eq!(backoff.next_wait(), Duration::from_millis(100));
+        assert_eq!(backoff.next_wait(), Duration::from_millis(200));
+        assert_eq!(backoff.next_wait(), Duration::from_millis(300));
+        assert_eq!(backoff.next_wait(), Duration::from_millis(300));
+        backoff.reset();
+        assert_eq!(backoff.next_wait(), Duration::from_millis(100));
+    }
+}
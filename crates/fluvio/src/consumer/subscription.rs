@@ -0,0 +1,541 @@
+//!
+//! # Topic subscription
+//!
+//! Streams records from all partitions of topics selected by name or pattern.
+//! Topic store is watched, so partitions of topics which are provisioned
+//! after stream is created, or partitions added to selected topics, are streamed as well.
+//!
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+use std::time::Instant;
+
+use futures_util::future::BoxFuture;
+use futures_util::stream::{BoxStream, SelectAll, Stream, StreamExt, unfold};
+use regex::Regex;
+use tracing::{debug, warn};
+
+use fluvio_future::timer::sleep;
+
+use crate::FluvioError;
+use crate::metadata::store::ChangeListener;
+use crate::metadata::topic::{TopicResolution, TopicSpec};
+use crate::offset::Offset;
+use crate::spu::SpuPool;
+use crate::sync::AlwaysNewContext;
+
+use super::resume::Backoff;
+use super::{ConsumerConfig, PartitionConsumer, Record};
+
+/// topics selected by subscription
+#[derive(Debug, Clone)]
+pub(crate) enum TopicFilter {
+    Topics(Vec<String>),
+    Pattern(Regex),
+}
+
+impl TopicFilter {
+    fn matches(&self, topic: &str) -> bool {
+        match self {
+            Self::Topics(topics) => topics.iter().any(|name| name == topic),
+            Self::Pattern(pattern) => pattern.is_match(topic),
+        }
+    }
+}
+
+/// stream of records from partitions of selected topics, including topics created later.
+/// Stream never ends unless continuous streaming is disabled, then only partitions
+/// which exist when stream is created are streamed until their end
+pub(crate) fn subscribe(
+    filter: TopicFilter,
+    pool: Arc<SpuPool>,
+    offset: Offset,
+    config: ConsumerConfig,
+) -> impl Stream<Item = Result<Record, FluvioError>> {
+    let listener = pool.metadata.topics().store().change_listener();
+    let source = PoolSource {
+        pool,
+        offset,
+        config: config.clone(),
+        listener,
+    };
+    subscribe_source(filter, source, config)
+}
+
+fn subscribe_source<S>(
+    filter: TopicFilter,
+    source: S,
+    config: ConsumerConfig,
+) -> impl Stream<Item = Result<Record, FluvioError>>
+where
+    S: PartitionSource + Send + 'static,
+{
+    let state = SubscriptionState {
+        filter,
+        source,
+        config,
+        streams: SelectAll::new(),
+        started: HashSet::new(),
+        retries: HashMap::new(),
+        loaded: false,
+    };
+
+    unfold(state, |mut state| async move {
+        let item = state.next_record().await?;
+        Some((item, state))
+    })
+}
+
+type RecordStream = BoxStream<'static, Result<Record, FluvioError>>;
+
+/// partitions which are subscribed to
+trait PartitionSource {
+    /// provisioned partitions of all topics, topic changes until now are considered seen
+    fn partitions(&mut self) -> BoxFuture<'_, Vec<(String, i32)>>;
+
+    /// stream of partition
+    fn stream(
+        &mut self,
+        topic: String,
+        partition: i32,
+    ) -> BoxFuture<'_, Result<RecordStream, FluvioError>>;
+
+    /// wait until topics are changed
+    fn changed(&mut self) -> BoxFuture<'_, ()>;
+}
+
+struct PoolSource {
+    pool: Arc<SpuPool>,
+    offset: Offset,
+    config: ConsumerConfig,
+    listener: ChangeListener<TopicSpec, AlwaysNewContext>,
+}
+
+impl PartitionSource for PoolSource {
+    fn partitions(&mut self) -> BoxFuture<'_, Vec<(String, i32)>> {
+        Box::pin(async move {
+            self.listener.load_last();
+            let topics = self.pool.metadata.topics().store().read().await;
+            topics
+                .values()
+                .filter(|topic| topic.status.resolution == TopicResolution::Provisioned)
+                .flat_map(|topic| {
+                    (0..topic.spec.partitions())
+                        .map(move |partition| (topic.key().clone(), partition))
+                })
+                .collect()
+        })
+    }
+
+    fn stream(
+        &mut self,
+        topic: String,
+        partition: i32,
+    ) -> BoxFuture<'_, Result<RecordStream, FluvioError>> {
+        Box::pin(async move {
+            let consumer = PartitionConsumer::new(topic, partition, self.pool.clone());
+            let stream = consumer
+                .stream_with_config(self.offset.clone(), self.config.clone())
+                .await?;
+            Ok(stream.boxed())
+        })
+    }
+
+    fn changed(&mut self) -> BoxFuture<'_, ()> {
+        Box::pin(self.listener.listen())
+    }
+}
+
+/// partition which failed to start
+struct PartitionRetry {
+    attempts: u32,
+    backoff: Backoff,
+    next_attempt: Instant,
+}
+
+struct SubscriptionState<S> {
+    filter: TopicFilter,
+    source: S,
+    config: ConsumerConfig,
+    streams: SelectAll<RecordStream>,
+    /// partitions which are being streamed
+    started: HashSet<(String, i32)>,
+    /// partitions which are waiting for next attempt to start
+    retries: HashMap<(String, i32), PartitionRetry>,
+    /// partitions existing when stream was created are started
+    loaded: bool,
+}
+
+impl<S: PartitionSource> SubscriptionState<S> {
+    async fn next_record(&mut self) -> Option<Result<Record, FluvioError>> {
+        use tokio::select;
+
+        if !self.loaded {
+            let failed = self.start_new_partitions().await;
+            self.loaded = true;
+            if let Some(err) = failed {
+                return Some(Err(err));
+            }
+        }
+
+        loop {
+            if self.config.disable_continuous && self.streams.is_empty() && self.retries.is_empty()
+            {
+                return None;
+            }
+
+            let next_retry = self
+                .retries
+                .values()
+                .map(|retry| retry.next_attempt)
+                .min()
+                .map(|next_attempt| next_attempt.saturating_duration_since(Instant::now()));
+
+            select! {
+                Some(next) = self.streams.next(), if !self.streams.is_empty() => {
+                    return Some(next);
+                },
+                _ = self.source.changed(), if !self.config.disable_continuous => {
+                    if let Some(err) = self.start_new_partitions().await {
+                        return Some(Err(err));
+                    }
+                },
+                _ = sleep(next_retry.unwrap_or_default()), if next_retry.is_some() => {
+                    if let Some(err) = self.start_new_partitions().await {
+                        return Some(Err(err));
+                    }
+                }
+            }
+        }
+    }
+
+    /// start streams for partitions of selected topics which are not streamed yet.
+    /// Partition which fails to start is retried with backoff independently of others,
+    /// error is returned when partition exceeds max retries
+    async fn start_new_partitions(&mut self) -> Option<FluvioError> {
+        let filter = &self.filter;
+        let mut partitions: Vec<(String, i32)> = self
+            .source
+            .partitions()
+            .await
+            .into_iter()
+            .filter(|(topic, _)| filter.matches(topic))
+            .collect();
+        if self.config.disable_continuous && self.loaded {
+            // only partitions selected when stream was created
+            partitions.retain(|partition| self.retries.contains_key(partition));
+        }
+
+        // partitions of deleted topics are not retried
+        self.retries
+            .retain(|partition, _| partitions.contains(partition));
+
+        let now = Instant::now();
+        let mut failed = None;
+        for (topic, partition) in partitions {
+            let key = (topic, partition);
+            if self.started.contains(&key) {
+                continue;
+            }
+            if let Some(retry) = self.retries.get(&key) {
+                if retry.next_attempt > now {
+                    continue;
+                }
+            }
+
+            debug!(topic = %key.0, partition, "subscribing to partition");
+            match self.source.stream(key.0.clone(), partition).await {
+                Ok(stream) => {
+                    self.streams.push(stream);
+                    self.retries.remove(&key);
+                    self.started.insert(key);
+                }
+                Err(err) => {
+                    if let Some(err) = self.retry(key, err) {
+                        failed = Some(err);
+                    }
+                }
+            }
+        }
+        failed
+    }
+
+    /// schedule next attempt of partition, returns error back if max retries are exceeded
+    fn retry(&mut self, key: (String, i32), err: FluvioError) -> Option<FluvioError> {
+        let config = &self.config;
+        let retry = self
+            .retries
+            .entry(key.clone())
+            .or_insert_with(|| PartitionRetry {
+                attempts: 0,
+                backoff: Backoff::new(config.min_retry_backoff, config.max_retry_backoff),
+                next_attempt: Instant::now(),
+            });
+
+        if let Some(max_retries) = config.max_retries {
+            if retry.attempts >= max_retries {
+                warn!(
+                    topic = %key.0,
+                    partition = key.1,
+                    max_retries,
+                    "exceeded max retries of partition"
+                );
+                if config.disable_continuous {
+                    self.retries.remove(&key);
+                } else {
+                    // continuous subscription keeps trying after reporting error
+                    retry.attempts = 0;
+                    retry.next_attempt = Instant::now() + config.max_retry_backoff;
+                }
+                return Some(err);
+            }
+        }
+
+        retry.attempts += 1;
+        let wait = retry.backoff.next_wait();
+        retry.next_attempt = Instant::now() + wait;
+        warn!(
+            topic = %key.0,
+            partition = key.1,
+            attempts = retry.attempts,
+            wait_ms = wait.as_millis() as u64,
+            "partition failed to start: {}, retrying",
+            err
+        );
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use std::collections::HashMap;
+    use std::sync::{Arc, Mutex};
+    use std::time::Duration;
+
+    use async_channel::{Receiver, Sender};
+    use futures_util::future::{BoxFuture, pending};
+    use futures_util::stream::{self, StreamExt};
+    use regex::Regex;
+
+    use dataplane::record::Record as DefaultRecord;
+    use fluvio_socket::SocketError;
+
+    use crate::FluvioError;
+    use crate::consumer::{ConsumerConfig, Record};
+
+    use super::{PartitionSource, RecordStream, TopicFilter, subscribe_source};
+
+    #[derive(Default)]
+    struct MockState {
+        partitions: Vec<(String, i32)>,
+        /// failed attempts left before partition starts
+        failures: HashMap<(String, i32), u32>,
+        /// attempts to start partitions
+        attempts: Vec<(String, i32)>,
+        /// streams end after first record
+        finite: bool,
+    }
+
+    struct MockSource {
+        state: Arc<Mutex<MockState>>,
+        changes: Receiver<()>,
+    }
+
+    impl PartitionSource for MockSource {
+        fn partitions(&mut self) -> BoxFuture<'_, Vec<(String, i32)>> {
+            let partitions = self.state.lock().unwrap().partitions.clone();
+            Box::pin(async move { partitions })
+        }
+
+        fn stream(
+            &mut self,
+            topic: String,
+            partition: i32,
+        ) -> BoxFuture<'_, Result<RecordStream, FluvioError>> {
+            let mut state = self.state.lock().unwrap();
+            let key = (topic.clone(), partition);
+            state.attempts.push(key.clone());
+            if let Some(failures) = state.failures.get_mut(&key).filter(|left| **left > 0) {
+                *failures -= 1;
+                return Box::pin(async { Err(FluvioError::Socket(SocketError::SocketClosed)) });
+            }
+
+            let record = Record {
+                topic: topic.into(),
+                partition,
+                offset: 0,
+                record: DefaultRecord::new(vec![]),
+            };
+            let records = stream::iter(vec![Ok(record)]);
+            let stream = if state.finite {
+                records.boxed()
+            } else {
+                records.chain(stream::pending()).boxed()
+            };
+            Box::pin(async move { Ok(stream) })
+        }
+
+        fn changed(&mut self) -> BoxFuture<'_, ()> {
+            Box::pin(async move {
+                if self.changes.recv().await.is_err() {
+                    pending::<()>().await;
+                }
+            })
+        }
+    }
+
+    fn mock(state: MockState) -> (MockSource, Arc<Mutex<MockState>>, Sender<()>) {
+        let state = Arc::new(Mutex::new(state));
+        let (sender, changes) = async_channel::unbounded();
+        let source = MockSource {
+            state: state.clone(),
+            changes,
+        };
+        (source, state, sender)
+    }
+
+    fn partitions(names: &[(&str, i32)]) -> Vec<(String, i32)> {
+        names
+            .iter()
+            .map(|(topic, partition)| (topic.to_string(), *partition))
+            .collect()
+    }
+
+    fn config() -> ConsumerConfig {
+        ConsumerConfig::builder()
+            .min_retry_backoff(Duration::from_millis(1))
+            .max_retry_backoff(Duration::from_millis(1))
+            .build()
+            .expect("config")
+    }
+
+    fn orders() -> TopicFilter {
+        TopicFilter::Pattern(Regex::new("^orders-.*$").expect("regex"))
+    }
+
+    #[test]
+    fn test_topic_filter() {
+        let topics = TopicFilter::Topics(vec!["orders".to_owned(), "payments".to_owned()]);
+        assert!(topics.matches("orders"));
+        assert!(!topics.matches("orders-eu"));
+
+        let pattern = TopicFilter::Pattern(Regex::new("^orders-.*$").expect("regex"));
+        assert!(pattern.matches("orders-eu"));
+        assert!(!pattern.matches("orders"));
+        assert!(!pattern.matches("eu-orders-eu2"));
+    }
+
+    #[fluvio_future::test]
+    async fn test_pattern_subscription_with_new_topic() {
+        let (source, state, changes) = mock(MockState {
+            partitions: partitions(&[("orders-eu", 0), ("payments", 0)]),
+            ..Default::default()
+        });
+
+        let mut stream = subscribe_source(orders(), source, config()).boxed();
+        let record = stream.next().await.expect("record").expect("ok");
+        assert_eq!(record.topic(), "orders-eu");
+
+        // new topic matching pattern is provisioned
+        state.lock().unwrap().partitions.extend(partitions(&[
+            ("orders-us", 0),
+            ("orders-us", 1),
+            ("users", 0),
+        ]));
+        changes.send(()).await.expect("send");
+
+        let mut added = vec![];
+        for _ in 0..2 {
+            let record = stream.next().await.expect("record").expect("ok");
+            added.push((record.topic().to_owned(), record.partition()));
+        }
+        added.sort();
+        assert_eq!(added, partitions(&[("orders-us", 0), ("orders-us", 1)]));
+        assert_eq!(
+            state.lock().unwrap().attempts,
+            partitions(&[("orders-eu", 0), ("orders-us", 0), ("orders-us", 1)])
+        );
+    }
+
+    #[fluvio_future::test]
+    async fn test_failed_partition_retried() {
+        let mut failures = HashMap::new();
+        failures.insert(("orders-eu".to_owned(), 0), 2);
+        let (source, state, _changes) = mock(MockState {
+            partitions: partitions(&[("orders-eu", 0), ("orders-eu", 1)]),
+            failures,
+            ..Default::default()
+        });
+
+        // failing partition doesn't stop other partitions and is retried without topic change
+        let mut stream = subscribe_source(orders(), source, config()).boxed();
+        let mut started = vec![];
+        for _ in 0..2 {
+            let record = stream.next().await.expect("record").expect("ok");
+            started.push(record.partition());
+        }
+        assert_eq!(started, vec![1, 0]);
+
+        let state = state.lock().unwrap();
+        let attempts = |partition| {
+            state
+                .attempts
+                .iter()
+                .filter(|attempt| attempt.1 == partition)
+                .count()
+        };
+        assert_eq!(attempts(0), 3);
+        assert_eq!(attempts(1), 1);
+    }
+
+    #[fluvio_future::test]
+    async fn test_subscription_without_continuous() {
+        let mut failures = HashMap::new();
+        failures.insert(("orders-us".to_owned(), 0), u32::MAX);
+        let (source, state, changes) = mock(MockState {
+            partitions: partitions(&[("orders-eu", 0), ("orders-us", 0)]),
+            failures,
+            finite: true,
+            ..Default::default()
+        });
+
+        let config = ConsumerConfig::builder()
+            .disable_continuous(true)
+            .max_retries(1)
+            .min_retry_backoff(Duration::from_millis(1))
+            .max_retry_backoff(Duration::from_millis(1))
+            .build()
+            .expect("config");
+        let mut stream = subscribe_source(orders(), source, config.clone()).boxed();
+
+        let record = stream.next().await.expect("record").expect("ok");
+        assert_eq!(record.topic(), "orders-eu");
+
+        // topics created later are not streamed
+        state
+            .lock()
+            .unwrap()
+            .partitions
+            .extend(partitions(&[("orders-asia", 0)]));
+        changes.send(()).await.expect("send");
+
+        assert!(matches!(
+            stream.next().await,
+            Some(Err(FluvioError::Socket(SocketError::SocketClosed)))
+        ));
+        assert!(stream.next().await.is_none());
+        assert!(!state
+            .lock()
+            .unwrap()
+            .attempts
+            .contains(&("orders-asia".to_owned(), 0)));
+
+        // no matching topics
+        let (source, _, _changes) = mock(MockState {
+            finite: true,
+            ..Default::default()
+        });
+        let mut stream = subscribe_source(orders(), source, config).boxed();
+        assert!(stream.next().await.is_none());
+    }
+}
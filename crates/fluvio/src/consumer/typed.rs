@@ -0,0 +1,210 @@
+//!
+//! # Typed record streams
+//!
+//! Records of consumer streams decoded by deserializers.
+//! Record which can't be decoded is returned as `FluvioError::Deserialize`
+//! error and the stream continues with next record.
+//!
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+use futures_util::stream::Stream;
+use pin_project_lite::pin_project;
+
+use crate::FluvioError;
+use crate::serializer::Deserializer;
+
+use super::Record;
+
+/// Record with decoded key and value
+pub struct TypedRecord<K, V> {
+    key: Option<K>,
+    value: V,
+    record: Record,
+}
+
+impl<K, V> TypedRecord<K, V> {
+    /// Decoded key, None if record has no key
+    pub fn key(&self) -> Option<&K> {
+        self.key.as_ref()
+    }
+
+    /// Decoded value
+    pub fn value(&self) -> &V {
+        &self.value
+    }
+
+    pub fn offset(&self) -> i64 {
+        self.record.offset()
+    }
+
+    pub fn topic(&self) -> &str {
+        self.record.topic()
+    }
+
+    pub fn partition(&self) -> i32 {
+        self.record.partition()
+    }
+
+    /// Record which was decoded
+    pub fn raw(&self) -> &Record {
+        &self.record
+    }
+
+    /// Decoded key and value
+    pub fn into_parts(self) -> (Option<K>, V) {
+        (self.key, self.value)
+    }
+}
+
+struct Deserializers<K, V> {
+    key: Box<dyn Deserializer<K>>,
+    value: Box<dyn Deserializer<V>>,
+}
+
+impl<K, V> Deserializers<K, V> {
+    fn decode(&self, record: Record) -> Result<TypedRecord<K, V>, FluvioError> {
+        let error = |error| FluvioError::Deserialize {
+            partition: record.partition(),
+            offset: record.offset(),
+            error,
+        };
+        let key = match record.key() {
+            Some(key) => Some(self.key.deserialize(key).map_err(error)?),
+            None => None,
+        };
+        let value = self.value.deserialize(record.value()).map_err(error)?;
+        Ok(TypedRecord { key, value, record })
+    }
+}
+
+pin_project! {
+    /// Stream of records decoded by deserializers
+    pub struct TypedStream<S, K, V> {
+        #[pin]
+        stream: S,
+        deserializers: Deserializers<K, V>,
+    }
+}
+
+impl<S, K, V> Stream for TypedStream<S, K, V>
+where
+    S: Stream<Item = Result<Record, FluvioError>>,
+{
+    type Item = Result<TypedRecord<K, V>, FluvioError>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.project();
+        match this.stream.poll_next(cx) {
+            Poll::Ready(Some(Ok(record))) => Poll::Ready(Some(this.deserializers.decode(record))),
+            Poll::Ready(Some(Err(err))) => Poll::Ready(Some(Err(err))),
+            Poll::Ready(None) => Poll::Ready(None),
+            Poll::Pending => Poll::Pending,
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.stream.size_hint()
+    }
+}
+
+/// Decodes records of any consumer stream
+///
+/// # Example
+///
+/// ```
+/// # use fluvio::{PartitionConsumer, FluvioError};
+/// # use fluvio::{Offset, ConsumerConfig};
+/// # use fluvio::consumer::TypedStreamExt;
+/// # use fluvio::serializer::Utf8;
+/// # mod futures {
+/// #     pub use futures_util::stream::StreamExt;
+/// # }
+/// # async fn example(consumer: &PartitionConsumer) -> Result<(), FluvioError> {
+/// use futures::StreamExt;
+/// let stream = consumer.stream(Offset::beginning()).await?;
+/// let mut stream = stream.deserialize::<String, String, _, _>(Utf8, Utf8);
+/// while let Some(result) = stream.next().await {
+///     match result {
+///         Ok(record) => println!("Got record: {}", record.value()),
+///         Err(FluvioError::Deserialize { offset, error, .. }) => {
+///             println!("Skipping record at {}: {}", offset, error);
+///         }
+///         Err(err) => return Err(err),
+///     }
+/// }
+/// # Ok(())
+/// # }
+/// ```
+pub trait TypedStreamExt: Stream<Item = Result<Record, FluvioError>> + Sized {
+    fn deserialize<K, V, KD, VD>(
+        self,
+        key_deserializer: KD,
+        value_deserializer: VD,
+    ) -> TypedStream<Self, K, V>
+    where
+        KD: Deserializer<K> + 'static,
+        VD: Deserializer<V> + 'static,
+    {
+        TypedStream {
+            stream: self,
+            deserializers: Deserializers {
+                key: Box::new(key_deserializer),
+                value: Box::new(value_deserializer),
+            },
+        }
+    }
+}
+
+impl<S> TypedStreamExt for S where S: Stream<Item = Result<Record, FluvioError>> + Sized {}
+
+#[cfg(test)]
+mod tests {
+
+    use futures_util::stream::{iter, StreamExt};
+
+    use dataplane::record::Record as DefaultRecord;
+
+    use crate::FluvioError;
+    use crate::serializer::Utf8;
+
+    use super::{Record, TypedStreamExt};
+
+    fn record(offset: i64, key: Option<&[u8]>, value: &[u8]) -> Result<Record, FluvioError> {
+        let record = match key {
+            Some(key) => DefaultRecord::new_key_value(key.to_vec(), value.to_vec()),
+            None => DefaultRecord::new(value.to_vec()),
+        };
+        Ok(Record {
+            topic: "test".into(),
+            partition: 0,
+            offset,
+            record,
+        })
+    }
+
+    #[fluvio_future::test]
+    async fn test_deserialize_errors_per_record() {
+        let records = vec![
+            record(0, Some(&b"a"[..]), b"one"),
+            record(1, None, &[0xff]),
+            record(2, None, b"three"),
+        ];
+        let decoded: Vec<_> = iter(records)
+            .deserialize::<String, String, _, _>(Utf8, Utf8)
+            .collect()
+            .await;
+        assert_eq!(decoded.len(), 3);
+
+        let first = decoded[0].as_ref().expect("decoded");
+        assert_eq!(first.key().map(|key| key.as_str()), Some("a"));
+        assert_eq!(first.value(), "one");
+        assert!(matches!(
+            decoded[1],
+            Err(FluvioError::Deserialize { offset: 1, .. })
+        ));
+        let third = decoded[2].as_ref().expect("decoded");
+        assert_eq!(third.key(), None);
+        assert_eq!(third.value(), "three");
+    }
+}
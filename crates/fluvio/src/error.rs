@@ -3,6 +3,7 @@ use std::io::Error as IoError;
 use fluvio_socket::SocketError;
 use fluvio_sc_schema::ApiError;
 use crate::config::ConfigError;
+use crate::serializer::SerdeError;
 use semver::Version;
 use dataplane::smartstream::SmartStreamRuntimeError;
 use dataplane::SmartStreamError;
@@ -42,6 +43,8 @@ To interact with this cluster, please install the matching CLI version using the
         cluster_version: Version,
         client_maximum_version: Version,
     },
+    #[error("Authentication failed: {0}")]
+    Authentication(String),
     #[error("Consumer config error: {0}")]
     ConsumerConfig(String),
     #[deprecated(since = "0.9.8", note = "use 'FluvioError::SmartStream' instead")]
@@ -49,6 +52,16 @@ To interact with this cluster, please install the matching CLI version using the
     SmartStreamRuntime(#[from] SmartStreamRuntimeError),
     #[error("SmartStream error")]
     SmartStream(#[from] SmartStreamError),
+    #[error("Failed to serialize record: {0}")]
+    Serialize(SerdeError),
+    #[error("Failed to deserialize record {partition}:{offset}: {error}")]
+    Deserialize {
+        partition: i32,
+        offset: i64,
+        error: SerdeError,
+    },
+    #[error("Transaction error: {0}")]
+    Transaction(String),
     #[error("Unknown error: {0}")]
     Other(String),
 }
@@ -1,34 +1,59 @@
 use std::convert::TryFrom;
-use std::sync::Arc;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
 
-use tracing::{debug};
-use tokio::sync::OnceCell;
+use async_channel::{Sender, Receiver};
+use futures_util::stream::Stream;
+use tracing::{debug, error, info, warn};
+use tokio::sync::{OnceCell, RwLock};
 
-use fluvio_socket::{SharedMultiplexerSocket, MultiplexerSocket};
+use fluvio_socket::SharedMultiplexerSocket;
 use fluvio_future::net::DomainConnector;
+use fluvio_future::timer::sleep;
+use fluvio_types::event::StickyEvent;
 use semver::Version;
 
 use crate::config::ConfigFile;
 use crate::admin::FluvioAdmin;
 use crate::TopicProducer;
+use crate::TransactionalProducer;
 use crate::PartitionConsumer;
 
 use crate::FluvioError;
 use crate::FluvioConfig;
 use crate::consumer::MultiplePartitionConsumer;
 use crate::consumer::PartitionSelectionStrategy;
+use crate::consumer::PartitionLag;
 use crate::spu::SpuPool;
 use crate::sockets::{ClientConfig, Versions, VersionedSerialSocket};
 use crate::sync::MetadataStores;
+use crate::metrics::ClientMetrics;
+
+/// first wait before reconnecting to SC
+const MIN_RECONNECT_BACKOFF: Duration = Duration::from_millis(100);
+/// max wait between attempts to reconnect to SC
+const MAX_RECONNECT_BACKOFF: Duration = Duration::from_secs(10);
+
+/// Change of connection to SC
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConnectionEvent {
+    /// Connection is lost, requests to SC fail until connection is re-established
+    Disconnected,
+    /// Connection is re-established and metadata is synchronized again
+    Reconnected,
+}
 
 /// An interface for interacting with Fluvio streaming
 pub struct Fluvio {
-    socket: SharedMultiplexerSocket,
+    socket: Arc<RwLock<SharedMultiplexerSocket>>,
     config: Arc<ClientConfig>,
     versions: Versions,
-    spu_pool: OnceCell<Arc<SpuPool>>,
+    spu_pool: Arc<OnceCell<Arc<SpuPool>>>,
     metadata: MetadataStores,
     watch_version: i16,
+    events: Arc<ConnectionEvents>,
+    metrics: Arc<ClientMetrics>,
+    shutdown: Arc<StickyEvent>,
 }
 
 impl Fluvio {
@@ -79,7 +104,10 @@ impl Fluvio {
         use fluvio_sc_schema::objects::WatchRequest;
         use fluvio_protocol::api::Request;
 
-        let config = ClientConfig::new(&config.endpoint, connector, config.use_spu_local_address);
+        let config = ClientConfig::new(&config.endpoint, connector, config.use_spu_local_address)
+            .set_credentials(config.credentials.clone())
+            .set_request_timeout(config.request_timeout())
+            .set_heartbeat_interval(config.heartbeat_interval());
         let inner_client = config.connect().await?;
         debug!("connected to cluster");
 
@@ -90,18 +118,22 @@ impl Fluvio {
             debug!(platform = %versions.platform_version(),"checking platform version");
             check_platform_compatible(versions.platform_version())?;
 
-            let socket = MultiplexerSocket::shared(socket);
+            let socket = config.create_multiplexer(socket);
             let metadata = MetadataStores::start(socket.clone(), watch_version).await?;
 
-            let spu_pool = OnceCell::new();
-            Ok(Self {
-                socket,
+            let fluvio = Self {
+                socket: Arc::new(RwLock::new(socket)),
                 config,
                 versions,
-                spu_pool,
+                spu_pool: Arc::new(OnceCell::new()),
                 metadata,
                 watch_version,
-            })
+                events: Arc::new(ConnectionEvents::default()),
+                metrics: Arc::new(ClientMetrics::default()),
+                shutdown: StickyEvent::shared(),
+            };
+            fluvio.start_reconnect();
+            Ok(fluvio)
         } else {
             Err(FluvioError::Other("WatchApi versio not found".to_string()))
         }
@@ -111,9 +143,9 @@ impl Fluvio {
     async fn spu_pool(&self) -> Result<Arc<SpuPool>, FluvioError> {
         self.spu_pool
             .get_or_try_init(|| async {
-                let metadata =
-                    MetadataStores::start(self.socket.clone(), self.watch_version).await?;
-                let pool = SpuPool::start(self.config.clone(), metadata);
+                let socket = self.socket.read().await.clone();
+                let metadata = MetadataStores::start(socket, self.watch_version).await?;
+                let pool = SpuPool::start(self.config.clone(), metadata, self.metrics.clone());
                 Ok(Arc::new(pool?))
             })
             .await
@@ -151,6 +183,38 @@ impl Fluvio {
         Ok(TopicProducer::new(topic, spu_pool))
     }
 
+    /// Creates a new `TransactionalProducer` with the given transactional id
+    ///
+    /// Previous producer with same transactional id is fenced, its unfinished
+    /// transaction is completed or aborted before new producer is returned.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use fluvio::{Fluvio, FluvioError};
+    /// # async fn do_produce_in_transaction(fluvio: &Fluvio) -> Result<(), FluvioError> {
+    /// let mut producer = fluvio.transactional_producer("my-processor").await?;
+    /// producer.begin()?;
+    /// producer.send("my-topic", 0, "key", "value").await?;
+    /// producer.commit().await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn transactional_producer<S: Into<String>>(
+        &self,
+        transactional_id: S,
+    ) -> Result<TransactionalProducer, FluvioError> {
+        let transactional_id = transactional_id.into();
+        debug!(
+            transactional_id = &*transactional_id,
+            "Creating transactional producer"
+        );
+
+        let spu_pool = self.spu_pool().await?;
+        let socket = self.create_serial_client().await;
+        TransactionalProducer::new(transactional_id, socket, spu_pool).await
+    }
+
     /// Creates a new `PartitionConsumer` for the given topic and partition
     ///
     /// If you have a topic with multiple partitions, then in order to receive
@@ -193,6 +257,9 @@ impl Fluvio {
     ///
     /// Records across different partitions are not guaranteed to be ordered.
     ///
+    /// With `Topics` and `Pattern` strategies, topics created after the stream
+    /// is started are consumed as well. Use [`Record::topic`] to tell them apart.
+    ///
     /// # Example
     ///
     /// ```no_run
@@ -203,6 +270,26 @@ impl Fluvio {
     /// # Ok(())
     /// # }
     /// ```
+    ///
+    /// ```no_run
+    /// # use fluvio::{Fluvio, Offset, FluvioError, PartitionSelectionStrategy};
+    /// # use fluvio::consumer::Regex;
+    /// # mod futures {
+    /// #     pub use futures_util::stream::StreamExt;
+    /// # }
+    /// # async fn do_consume_from_pattern(fluvio: &Fluvio) -> Result<(), FluvioError> {
+    /// use futures::StreamExt;
+    /// let pattern = Regex::new("^orders-").expect("valid pattern");
+    /// let consumer = fluvio.consumer(PartitionSelectionStrategy::Pattern(pattern)).await?;
+    /// let mut stream = consumer.stream(Offset::end()).await?;
+    /// while let Some(Ok(record)) = stream.next().await {
+    ///     println!("{}/{}: {}", record.topic(), record.partition(), record.offset());
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`Record::topic`]: consumer/struct.Record.html#method.topic
     pub async fn consumer(
         &self,
         strategy: PartitionSelectionStrategy,
@@ -213,6 +300,32 @@ impl Fluvio {
         ))
     }
 
+    /// Reports offsets of each partition of topic and lag of active consumer streams
+    ///
+    /// Lag of a stream is measured from the offset its consumer has acknowledged
+    /// to the leader, or from the last offset sent to it if there is no acknowledgement yet.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use fluvio::{Fluvio, FluvioError};
+    /// # async fn do_consumer_lag(fluvio: &Fluvio) -> Result<(), FluvioError> {
+    /// for partition in fluvio.consumer_lag("my-topic").await? {
+    ///     println!("partition {}: max lag {:?}", partition.partition, partition.max_lag());
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn consumer_lag<S: Into<String>>(
+        &self,
+        topic: S,
+    ) -> Result<Vec<PartitionLag>, FluvioError> {
+        let topic = topic.into();
+        debug!(topic = &*topic, "fetching consumer lag");
+        let spu_pool = self.spu_pool().await?;
+        crate::consumer::lag::consumer_lag(&spu_pool, &topic).await
+    }
+
     /// Provides an interface for managing a Fluvio cluster
     ///
     /// # Example
@@ -240,14 +353,164 @@ impl Fluvio {
         self.versions.platform_version()
     }
 
-    /// create serial connection
+    /// Stream of changes of connection to SC
+    ///
+    /// When connection is lost, client reconnects in background and
+    /// metadata is synchronized again. Producers, consumers and `FluvioAdmin`
+    /// keep working after reconnect.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use fluvio::{Fluvio, FluvioError, ConnectionEvent};
+    /// # use futures_util::StreamExt;
+    /// # async fn do_watch_connection(fluvio: &Fluvio) -> Result<(), FluvioError> {
+    /// let mut events = fluvio.connection_events();
+    /// while let Some(event) = events.next().await {
+    ///     if event == ConnectionEvent::Reconnected {
+    ///         println!("reconnected to cluster");
+    ///     }
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn connection_events(&self) -> impl Stream<Item = ConnectionEvent> {
+        self.events.subscribe()
+    }
+
+    /// Metrics of producers, consumers and connections of this client
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use fluvio::{Fluvio, FluvioError};
+    /// # async fn do_read_metrics(fluvio: &Fluvio) -> Result<(), FluvioError> {
+    /// let snapshot = fluvio.metrics().snapshot();
+    /// for (topic, producer) in &snapshot.producers {
+    ///     println!("{}: {} records sent", topic, producer.records_sent);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn metrics(&self) -> Arc<ClientMetrics> {
+        self.metrics.clone()
+    }
+
+    /// create serial connection, which switches to new connection after reconnect
     async fn create_serial_client(&self) -> VersionedSerialSocket {
-        VersionedSerialSocket::new(
+        VersionedSerialSocket::shared(
             self.socket.clone(),
             self.config.clone(),
             self.versions.clone(),
         )
     }
+
+    fn start_reconnect(&self) {
+        use fluvio_future::task::spawn;
+
+        let controller = ReconnectController {
+            socket: self.socket.clone(),
+            config: self.config.clone(),
+            metadata: self.metadata.clone(),
+            spu_pool: self.spu_pool.clone(),
+            events: self.events.clone(),
+            metrics: self.metrics.clone(),
+            shutdown: self.shutdown.clone(),
+        };
+        spawn(controller.dispatch_loop());
+    }
+}
+
+impl Drop for Fluvio {
+    fn drop(&mut self) {
+        self.shutdown.notify();
+    }
+}
+
+/// subscribers of connection events, closed subscribers are removed on publish
+#[derive(Default)]
+struct ConnectionEvents {
+    senders: Mutex<Vec<Sender<ConnectionEvent>>>,
+}
+
+impl ConnectionEvents {
+    fn subscribe(&self) -> Receiver<ConnectionEvent> {
+        let (sender, receiver) = async_channel::unbounded();
+        self.senders.lock().unwrap().push(sender);
+        receiver
+    }
+
+    fn publish(&self, event: ConnectionEvent) {
+        self.senders
+            .lock()
+            .unwrap()
+            .retain(|sender| sender.try_send(event).is_ok());
+    }
+}
+
+/// waits until SC connection is stale, then reconnects and re-syncs metadata
+struct ReconnectController {
+    socket: Arc<RwLock<SharedMultiplexerSocket>>,
+    config: Arc<ClientConfig>,
+    metadata: MetadataStores,
+    spu_pool: Arc<OnceCell<Arc<SpuPool>>>,
+    events: Arc<ConnectionEvents>,
+    metrics: Arc<ClientMetrics>,
+    shutdown: Arc<StickyEvent>,
+}
+
+impl ReconnectController {
+    async fn dispatch_loop(self) {
+        use tokio::select;
+
+        loop {
+            let socket = self.socket.read().await.clone();
+            select! {
+                _ = socket.wait_stale() => {},
+                _ = self.shutdown.listen() => {
+                    debug!("shutdown, stopping reconnect");
+                    break;
+                }
+            }
+
+            warn!("connection to SC is lost, reconnecting");
+            socket.close_stale();
+            drop(socket);
+            self.metrics.record_sc_disconnect();
+            self.events.publish(ConnectionEvent::Disconnected);
+
+            let mut backoff = MIN_RECONNECT_BACKOFF;
+            let socket = loop {
+                match self.reconnect().await {
+                    Ok(socket) => break socket,
+                    Err(err) => {
+                        error!("reconnecting to SC: {}", err);
+                        select! {
+                            _ = sleep(backoff) => {},
+                            _ = self.shutdown.listen() => return,
+                        }
+                        backoff = (backoff * 2).min(MAX_RECONNECT_BACKOFF);
+                    }
+                }
+            };
+
+            *self.socket.write().await = socket;
+            info!("reconnected to SC");
+            self.metrics.record_sc_reconnect();
+            self.events.publish(ConnectionEvent::Reconnected);
+        }
+    }
+
+    /// connect with version handshake, then restart metadata watches on new connection
+    async fn reconnect(&self) -> Result<SharedMultiplexerSocket, FluvioError> {
+        let (socket, config, _versions) = self.config.duplicate().connect().await?.split();
+        let socket = config.create_multiplexer(socket);
+        self.metadata.resync(socket.clone()).await?;
+        if let Some(pool) = self.spu_pool.get() {
+            pool.metadata.resync(socket.clone()).await?;
+        }
+        Ok(socket)
+    }
 }
 
 /// The remote cluster is compatible with this client if its
@@ -85,25 +85,33 @@ mod error;
 mod sockets;
 mod admin;
 mod fluvio;
+#[cfg(not(target_arch = "wasm32"))]
+pub mod blocking;
 pub mod consumer;
+pub mod metrics;
 mod producer;
+pub mod serializer;
 mod offset;
 mod sync;
 mod spu;
+mod sasl;
 
 pub mod config;
 
 use tracing::instrument;
 pub use error::FluvioError;
 pub use config::FluvioConfig;
-pub use producer::{TopicProducer, RecordKey};
+pub use producer::{TopicProducer, TypedProducer, TransactionalProducer, RecordKey};
+pub use producer::committed_offsets;
 pub use consumer::{
     PartitionConsumer, ConsumerConfig, MultiplePartitionConsumer, PartitionSelectionStrategy,
+    ControlledStream, StreamHandle,
 };
 pub use offset::Offset;
+pub use metrics::{ClientMetrics, MetricsSnapshot};
 
 pub use crate::admin::FluvioAdmin;
-pub use crate::fluvio::Fluvio;
+pub use crate::fluvio::{Fluvio, ConnectionEvent};
 
 /// The minimum VERSION of the Fluvio Platform that this client is compatible with.
 const MINIMUM_PLATFORM_VERSION: &str = "0.9.0";
@@ -220,6 +228,18 @@ pub mod metadata {
         pub use fluvio_sc_schema::partition::*;
     }
 
+    pub mod acl {
+        pub use fluvio_sc_schema::acl::*;
+    }
+
+    pub mod quota {
+        pub use fluvio_sc_schema::quota::*;
+    }
+
+    pub mod schema {
+        pub use fluvio_sc_schema::schema::*;
+    }
+
     pub mod objects {
         pub use fluvio_sc_schema::objects::*;
     }
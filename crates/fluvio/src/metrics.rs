@@ -0,0 +1,804 @@
+//!
+//! # Client metrics
+//!
+//! Counters and latency histograms of producers, consumers and connections
+//! of a [`Fluvio`] client. Current values are read with [`ClientMetrics::snapshot`].
+//! With `prometheus` feature, snapshot can be rendered as Prometheus text,
+//! with `otel` feature, metrics can be registered to OpenTelemetry meter.
+//!
+//! [`Fluvio`]: crate::Fluvio
+//!
+use std::collections::BTreeMap;
+use std::sync::Mutex;
+use std::time::Duration;
+
+use dataplane::ReplicaKey;
+use dataplane::fetch::FetchablePartitionResponse;
+use dataplane::record::{Record, RecordSet};
+use fluvio_types::PartitionId;
+
+/// upper bounds of latency buckets in milliseconds
+const LATENCY_BUCKETS_MS: [u64; 12] = [1, 2, 5, 10, 25, 50, 100, 250, 500, 1000, 2500, 5000];
+
+/// Registry of client metrics, shared by producers and consumers of same client
+#[derive(Debug, Default)]
+pub struct ClientMetrics {
+    inner: Mutex<MetricsSnapshot>,
+}
+
+impl ClientMetrics {
+    /// Copy of current values of all metrics
+    pub fn snapshot(&self) -> MetricsSnapshot {
+        self.inner.lock().unwrap().clone()
+    }
+
+    /// record produce request to leader of topic partitions
+    pub(crate) fn record_produce(
+        &self,
+        topic: &str,
+        records: u64,
+        bytes: u64,
+        latency: Duration,
+        success: bool,
+    ) {
+        let mut inner = self.inner.lock().unwrap();
+        let producer = inner.producers.entry(topic.to_owned()).or_default();
+        producer.requests += 1;
+        producer.latency.record(latency);
+        if success {
+            producer.records_sent += records;
+            producer.bytes_sent += bytes;
+        } else {
+            producer.errors += 1;
+        }
+    }
+
+    pub(crate) fn record_fetch_latency(&self, latency: Duration) {
+        self.inner.lock().unwrap().fetch_latency.record(latency);
+    }
+
+    /// record offsets of partition from FetchOffsets response
+    pub(crate) fn record_offsets(
+        &self,
+        replica: &ReplicaKey,
+        next_offset: i64,
+        high_watermark: i64,
+    ) {
+        let mut inner = self.inner.lock().unwrap();
+        let consumer = inner.consumer(replica);
+        consumer.next_offset = next_offset;
+        consumer.set_high_watermark(high_watermark);
+    }
+
+    /// record records received by consumer of partition
+    pub(crate) fn record_consumed(
+        &self,
+        replica: &ReplicaKey,
+        partition: &FetchablePartitionResponse<RecordSet>,
+    ) {
+        let mut records = 0;
+        let mut bytes = 0;
+        for batch in &partition.records.batches {
+            records += batch.records().len() as u64;
+            bytes += batch.records().iter().map(payload_size).sum::<u64>();
+        }
+
+        let mut inner = self.inner.lock().unwrap();
+        let consumer = inner.consumer(replica);
+        consumer.records_received += records;
+        consumer.bytes_received += bytes;
+        if let Some(next_offset) = partition.next_offset_for_fetch() {
+            consumer.next_offset = next_offset;
+        }
+        consumer.set_high_watermark(partition.high_watermark);
+    }
+
+    pub(crate) fn record_sc_disconnect(&self) {
+        self.inner.lock().unwrap().connection.sc_disconnects += 1;
+    }
+
+    pub(crate) fn record_sc_reconnect(&self) {
+        self.inner.lock().unwrap().connection.sc_reconnects += 1;
+    }
+
+    pub(crate) fn record_spu_connect(&self) {
+        self.inner.lock().unwrap().connection.spu_connects += 1;
+    }
+
+    /// stale connection to SPU is replaced
+    pub(crate) fn record_spu_reconnect(&self) {
+        self.inner.lock().unwrap().connection.spu_reconnects += 1;
+    }
+}
+
+/// size of key and value of record
+pub(crate) fn payload_size(record: &Record) -> u64 {
+    let key = record.key.as_ref().map(|key| key.len()).unwrap_or(0);
+    (key + record.value.len()) as u64
+}
+
+/// Values of client metrics at a point in time
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct MetricsSnapshot {
+    /// Producer metrics by topic
+    pub producers: BTreeMap<String, ProducerMetrics>,
+    /// Consumer metrics by topic and partition
+    pub consumers: BTreeMap<(String, PartitionId), ConsumerMetrics>,
+    /// Latency of fetch requests
+    pub fetch_latency: Histogram,
+    pub connection: ConnectionMetrics,
+}
+
+impl MetricsSnapshot {
+    fn consumer(&mut self, replica: &ReplicaKey) -> &mut ConsumerMetrics {
+        self.consumers
+            .entry((replica.topic.clone(), replica.partition))
+            .or_default()
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ProducerMetrics {
+    /// Records acknowledged by leaders
+    pub records_sent: u64,
+    /// Bytes of keys and values acknowledged by leaders
+    pub bytes_sent: u64,
+    /// Produce requests, one request is sent to each leader
+    pub requests: u64,
+    /// Failed produce requests
+    pub errors: u64,
+    /// Latency of produce requests
+    pub latency: Histogram,
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ConsumerMetrics {
+    pub records_received: u64,
+    /// Bytes of keys and values received
+    pub bytes_received: u64,
+    /// Offset of next record to be received
+    pub next_offset: i64,
+    /// Last high watermark reported by leader
+    pub high_watermark: i64,
+    /// Records behind high watermark
+    pub lag: i64,
+}
+
+impl ConsumerMetrics {
+    fn set_high_watermark(&mut self, high_watermark: i64) {
+        self.high_watermark = high_watermark;
+        self.lag = (high_watermark - self.next_offset).max(0);
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ConnectionMetrics {
+    /// Lost connections to SC
+    pub sc_disconnects: u64,
+    /// Successful reconnects to SC
+    pub sc_reconnects: u64,
+    /// Connections opened to SPUs
+    pub spu_connects: u64,
+    /// Stale SPU connections which were replaced
+    pub spu_reconnects: u64,
+}
+
+/// Latency histogram with fixed buckets
+#[derive(Debug, Clone, PartialEq)]
+pub struct Histogram {
+    /// counts of each bucket, last one is for values above largest bound
+    counts: Vec<u64>,
+    count: u64,
+    sum: Duration,
+}
+
+impl Default for Histogram {
+    fn default() -> Self {
+        Self {
+            counts: vec![0; LATENCY_BUCKETS_MS.len() + 1],
+            count: 0,
+            sum: Duration::ZERO,
+        }
+    }
+}
+
+impl Histogram {
+    pub(crate) fn record(&mut self, value: Duration) {
+        let millis = value.as_secs_f64() * 1000.0;
+        let index = LATENCY_BUCKETS_MS
+            .iter()
+            .position(|bound| millis <= *bound as f64)
+            .unwrap_or(LATENCY_BUCKETS_MS.len());
+        self.counts[index] += 1;
+        self.count += 1;
+        self.sum += value;
+    }
+
+    /// Number of observations
+    pub fn count(&self) -> u64 {
+        self.count
+    }
+
+    /// Sum of observations
+    pub fn sum(&self) -> Duration {
+        self.sum
+    }
+
+    pub fn mean(&self) -> Option<Duration> {
+        if self.count == 0 {
+            None
+        } else {
+            // divide in nanos, count doesn't fit into u32 divisor of Duration
+            let mean = self.sum.as_nanos() / self.count as u128;
+            Some(Duration::from_nanos(mean as u64))
+        }
+    }
+
+    /// Upper bound of each bucket with count of observations less or equal to it
+    pub fn buckets(&self) -> Vec<(Duration, u64)> {
+        let mut cumulative = 0;
+        LATENCY_BUCKETS_MS
+            .iter()
+            .zip(self.counts.iter())
+            .map(|(bound, count)| {
+                cumulative += count;
+                (Duration::from_millis(*bound), cumulative)
+            })
+            .collect()
+    }
+
+    /// Upper bound of bucket containing quantile, None if there are no observations
+    /// or quantile is above largest bucket
+    pub fn quantile(&self, quantile: f64) -> Option<Duration> {
+        if self.count == 0 {
+            return None;
+        }
+        let rank = (quantile.clamp(0.0, 1.0) * self.count as f64).ceil() as u64;
+        self.buckets()
+            .into_iter()
+            .find(|(_, cumulative)| *cumulative >= rank.max(1))
+            .map(|(bound, _)| bound)
+    }
+}
+
+#[cfg(feature = "prometheus")]
+mod prometheus {
+    use std::fmt::Write;
+
+    use super::{Histogram, MetricsSnapshot};
+
+    impl MetricsSnapshot {
+        /// Render metrics in Prometheus text exposition format
+        pub fn to_prometheus_text(&self) -> String {
+            let mut out = String::new();
+
+            header(
+                &mut out,
+                "fluvio_producer_records_sent_total",
+                "counter",
+                "Records acknowledged by leaders",
+            );
+            for (topic, producer) in &self.producers {
+                sample(
+                    &mut out,
+                    "fluvio_producer_records_sent_total",
+                    &topic_labels(topic),
+                    producer.records_sent,
+                );
+            }
+            header(
+                &mut out,
+                "fluvio_producer_bytes_sent_total",
+                "counter",
+                "Bytes of keys and values acknowledged by leaders",
+            );
+            for (topic, producer) in &self.producers {
+                sample(
+                    &mut out,
+                    "fluvio_producer_bytes_sent_total",
+                    &topic_labels(topic),
+                    producer.bytes_sent,
+                );
+            }
+            header(
+                &mut out,
+                "fluvio_producer_requests_total",
+                "counter",
+                "Produce requests",
+            );
+            for (topic, producer) in &self.producers {
+                sample(
+                    &mut out,
+                    "fluvio_producer_requests_total",
+                    &topic_labels(topic),
+                    producer.requests,
+                );
+            }
+            header(
+                &mut out,
+                "fluvio_producer_errors_total",
+                "counter",
+                "Failed produce requests",
+            );
+            for (topic, producer) in &self.producers {
+                sample(
+                    &mut out,
+                    "fluvio_producer_errors_total",
+                    &topic_labels(topic),
+                    producer.errors,
+                );
+            }
+            header(
+                &mut out,
+                "fluvio_producer_latency_seconds",
+                "histogram",
+                "Latency of produce requests",
+            );
+            for (topic, producer) in &self.producers {
+                histogram(
+                    &mut out,
+                    "fluvio_producer_latency_seconds",
+                    &topic_labels(topic),
+                    &producer.latency,
+                );
+            }
+
+            header(
+                &mut out,
+                "fluvio_consumer_records_received_total",
+                "counter",
+                "Records received by consumers",
+            );
+            for ((topic, partition), consumer) in &self.consumers {
+                sample(
+                    &mut out,
+                    "fluvio_consumer_records_received_total",
+                    &partition_labels(topic, *partition),
+                    consumer.records_received,
+                );
+            }
+            header(
+                &mut out,
+                "fluvio_consumer_bytes_received_total",
+                "counter",
+                "Bytes of keys and values received by consumers",
+            );
+            for ((topic, partition), consumer) in &self.consumers {
+                sample(
+                    &mut out,
+                    "fluvio_consumer_bytes_received_total",
+                    &partition_labels(topic, *partition),
+                    consumer.bytes_received,
+                );
+            }
+            header(
+                &mut out,
+                "fluvio_consumer_lag",
+                "gauge",
+                "Records behind high watermark",
+            );
+            for ((topic, partition), consumer) in &self.consumers {
+                sample(
+                    &mut out,
+                    "fluvio_consumer_lag",
+                    &partition_labels(topic, *partition),
+                    consumer.lag,
+                );
+            }
+
+            header(
+                &mut out,
+                "fluvio_fetch_latency_seconds",
+                "histogram",
+                "Latency of fetch requests",
+            );
+            histogram(
+                &mut out,
+                "fluvio_fetch_latency_seconds",
+                "",
+                &self.fetch_latency,
+            );
+
+            let connection = &self.connection;
+            header(
+                &mut out,
+                "fluvio_sc_disconnects_total",
+                "counter",
+                "Lost connections to SC",
+            );
+            sample(
+                &mut out,
+                "fluvio_sc_disconnects_total",
+                "",
+                connection.sc_disconnects,
+            );
+            header(
+                &mut out,
+                "fluvio_sc_reconnects_total",
+                "counter",
+                "Reconnects to SC",
+            );
+            sample(
+                &mut out,
+                "fluvio_sc_reconnects_total",
+                "",
+                connection.sc_reconnects,
+            );
+            header(
+                &mut out,
+                "fluvio_spu_connects_total",
+                "counter",
+                "Connections opened to SPUs",
+            );
+            sample(
+                &mut out,
+                "fluvio_spu_connects_total",
+                "",
+                connection.spu_connects,
+            );
+            header(
+                &mut out,
+                "fluvio_spu_reconnects_total",
+                "counter",
+                "Stale SPU connections which were replaced",
+            );
+            sample(
+                &mut out,
+                "fluvio_spu_reconnects_total",
+                "",
+                connection.spu_reconnects,
+            );
+
+            out
+        }
+    }
+
+    fn header(out: &mut String, name: &str, kind: &str, help: &str) {
+        let _ = writeln!(out, "# HELP {} {}", name, help);
+        let _ = writeln!(out, "# TYPE {} {}", name, kind);
+    }
+
+    fn sample<V: std::fmt::Display>(out: &mut String, name: &str, labels: &str, value: V) {
+        if labels.is_empty() {
+            let _ = writeln!(out, "{} {}", name, value);
+        } else {
+            let _ = writeln!(out, "{}{{{}}} {}", name, labels, value);
+        }
+    }
+
+    fn histogram(out: &mut String, name: &str, labels: &str, histogram: &Histogram) {
+        let separator = if labels.is_empty() { "" } else { "," };
+        for (bound, count) in histogram.buckets() {
+            let _ = writeln!(
+                out,
+                "{}_bucket{{{}{}le=\"{}\"}} {}",
+                name,
+                labels,
+                separator,
+                bound.as_secs_f64(),
+                count
+            );
+        }
+        let _ = writeln!(
+            out,
+            "{}_bucket{{{}{}le=\"+Inf\"}} {}",
+            name,
+            labels,
+            separator,
+            histogram.count()
+        );
+        sample(
+            out,
+            &format!("{}_sum", name),
+            labels,
+            histogram.sum().as_secs_f64(),
+        );
+        sample(out, &format!("{}_count", name), labels, histogram.count());
+    }
+
+    fn topic_labels(topic: &str) -> String {
+        format!("topic=\"{}\"", escape(topic))
+    }
+
+    fn partition_labels(topic: &str, partition: i32) -> String {
+        format!("topic=\"{}\",partition=\"{}\"", escape(topic), partition)
+    }
+
+    fn escape(value: &str) -> String {
+        value
+            .replace('\\', "\\\\")
+            .replace('"', "\\\"")
+            .replace('\n', "\\n")
+    }
+}
+
+#[cfg(feature = "otel")]
+mod otel {
+    use std::sync::Arc;
+
+    use opentelemetry::KeyValue;
+    use opentelemetry::metrics::{Meter, ObserverResult};
+
+    use super::{ClientMetrics, MetricsSnapshot};
+
+    impl ClientMetrics {
+        /// Register observers of client metrics to OpenTelemetry meter,
+        /// values are read from registry on each collection
+        pub fn register_opentelemetry(self: &Arc<Self>, meter: &Meter) {
+            self.u64_counter(meter, "fluvio.producer.records_sent", |snapshot, result| {
+                for (topic, producer) in &snapshot.producers {
+                    result.observe(producer.records_sent, &[topic_label(topic)]);
+                }
+            });
+            self.u64_counter(meter, "fluvio.producer.bytes_sent", |snapshot, result| {
+                for (topic, producer) in &snapshot.producers {
+                    result.observe(producer.bytes_sent, &[topic_label(topic)]);
+                }
+            });
+            self.u64_counter(meter, "fluvio.producer.requests", |snapshot, result| {
+                for (topic, producer) in &snapshot.producers {
+                    result.observe(producer.requests, &[topic_label(topic)]);
+                }
+            });
+            self.u64_counter(meter, "fluvio.producer.errors", |snapshot, result| {
+                for (topic, producer) in &snapshot.producers {
+                    result.observe(producer.errors, &[topic_label(topic)]);
+                }
+            });
+
+            let metrics = self.clone();
+            meter
+                .f64_sum_observer("fluvio.producer.latency.sum", move |result| {
+                    for (topic, producer) in &metrics.snapshot().producers {
+                        result.observe(producer.latency.sum().as_secs_f64(), &[topic_label(topic)]);
+                    }
+                })
+                .with_description("Sum of produce latency in seconds")
+                .init();
+
+            self.u64_counter(
+                meter,
+                "fluvio.consumer.records_received",
+                |snapshot, result| {
+                    for ((topic, partition), consumer) in &snapshot.consumers {
+                        result.observe(
+                            consumer.records_received,
+                            &partition_labels(topic, *partition),
+                        );
+                    }
+                },
+            );
+            self.u64_counter(
+                meter,
+                "fluvio.consumer.bytes_received",
+                |snapshot, result| {
+                    for ((topic, partition), consumer) in &snapshot.consumers {
+                        result.observe(
+                            consumer.bytes_received,
+                            &partition_labels(topic, *partition),
+                        );
+                    }
+                },
+            );
+
+            let metrics = self.clone();
+            meter
+                .i64_value_observer("fluvio.consumer.lag", move |result| {
+                    for ((topic, partition), consumer) in &metrics.snapshot().consumers {
+                        result.observe(consumer.lag, &partition_labels(topic, *partition));
+                    }
+                })
+                .with_description("Records behind high watermark")
+                .init();
+
+            self.u64_counter(meter, "fluvio.sc.disconnects", |snapshot, result| {
+                result.observe(snapshot.connection.sc_disconnects, &[]);
+            });
+            self.u64_counter(meter, "fluvio.sc.reconnects", |snapshot, result| {
+                result.observe(snapshot.connection.sc_reconnects, &[]);
+            });
+            self.u64_counter(meter, "fluvio.spu.connects", |snapshot, result| {
+                result.observe(snapshot.connection.spu_connects, &[]);
+            });
+            self.u64_counter(meter, "fluvio.spu.reconnects", |snapshot, result| {
+                result.observe(snapshot.connection.spu_reconnects, &[]);
+            });
+        }
+
+        fn u64_counter<F>(self: &Arc<Self>, meter: &Meter, name: &str, observe: F)
+        where
+            F: Fn(&MetricsSnapshot, &ObserverResult<u64>) + Send + Sync + 'static,
+        {
+            let metrics = self.clone();
+            meter
+                .u64_sum_observer(name, move |result| observe(&metrics.snapshot(), &result))
+                .init();
+        }
+    }
+
+    fn topic_label(topic: &str) -> KeyValue {
+        KeyValue::new("topic", topic.to_owned())
+    }
+
+    fn partition_labels(topic: &str, partition: i32) -> [KeyValue; 2] {
+        [
+            topic_label(topic),
+            KeyValue::new("partition", partition as i64),
+        ]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use std::time::Duration;
+
+    use dataplane::ReplicaKey;
+    use dataplane::batch::Batch;
+    use dataplane::fetch::FetchablePartitionResponse;
+    use dataplane::record::{Record, RecordSet};
+
+    use super::{ClientMetrics, Histogram};
+
+    #[test]
+    fn test_histogram() {
+        let mut histogram = Histogram::default();
+        assert_eq!(histogram.mean(), None);
+        assert_eq!(histogram.quantile(0.5), None);
+
+        histogram.record(Duration::from_micros(500));
+        histogram.record(Duration::from_millis(3));
+        histogram.record(Duration::from_millis(40));
+        histogram.record(Duration::from_secs(10));
+
+        assert_eq!(histogram.count(), 4);
+        let buckets = histogram.buckets();
+        assert_eq!(buckets[0], (Duration::from_millis(1), 1));
+        assert_eq!(buckets[2], (Duration::from_millis(5), 2));
+        assert_eq!(buckets[5], (Duration::from_millis(50), 3));
+        assert_eq!(buckets.last().unwrap().1, 3);
+        assert_eq!(histogram.quantile(0.5), Some(Duration::from_millis(5)));
+        assert_eq!(histogram.quantile(0.75), Some(Duration::from_millis(50)));
+        assert_eq!(histogram.quantile(1.0), None);
+    }
+
+    #[test]
+    fn test_producer_metrics() {
+        let metrics = ClientMetrics::default();
+        metrics.record_produce("test", 2, 10, Duration::from_millis(4), true);
+        metrics.record_produce("test", 3, 15, Duration::from_millis(8), false);
+
+        let snapshot = metrics.snapshot();
+        let producer = snapshot.producers.get("test").unwrap();
+        assert_eq!(producer.records_sent, 2);
+        assert_eq!(producer.bytes_sent, 10);
+        assert_eq!(producer.requests, 2);
+        assert_eq!(producer.errors, 1);
+        assert_eq!(producer.latency.mean(), Some(Duration::from_millis(6)));
+    }
+
+    #[test]
+    fn test_consumer_lag() {
+        let metrics = ClientMetrics::default();
+        let replica = ReplicaKey::new("test", 0);
+        metrics.record_offsets(&replica, 0, 10);
+
+        let mut batch = Batch::default();
+        batch.add_record(Record::new("abc"));
+        batch.add_record(Record::new("de"));
+        let mut partition = FetchablePartitionResponse::<RecordSet> {
+            high_watermark: 12,
+            ..Default::default()
+        };
+        partition.records.batches.push(batch);
+        metrics.record_consumed(&replica, &partition);
+
+        let snapshot = metrics.snapshot();
+        let consumer = snapshot.consumers.get(&("test".to_owned(), 0)).unwrap();
+        assert_eq!(consumer.records_received, 2);
+        assert_eq!(consumer.bytes_received, 5);
+        assert_eq!(consumer.next_offset, 2);
+        assert_eq!(consumer.high_watermark, 12);
+        assert_eq!(consumer.lag, 10);
+    }
+
+    #[test]
+    fn test_histogram_mean() {
+        let mut histogram = Histogram::default();
+        histogram.record(Duration::from_millis(1));
+        histogram.record(Duration::from_millis(2));
+        assert_eq!(histogram.mean(), Some(Duration::from_micros(1500)));
+
+        // more observations than u32 can count
+        histogram.count = u32::MAX as u64 * 2;
+        histogram.sum = Duration::from_secs(u32::MAX as u64 * 4);
+        assert_eq!(histogram.mean(), Some(Duration::from_secs(2)));
+    }
+
+    #[cfg(feature = "prometheus")]
+    #[test]
+    fn test_prometheus_text() {
+        let metrics = ClientMetrics::default();
+        metrics.record_produce("te\"st", 2, 10, Duration::from_millis(500), true);
+        metrics.record_produce("te\"st", 3, 15, Duration::from_secs(20), true);
+        metrics.record_sc_disconnect();
+
+        let text = metrics.snapshot().to_prometheus_text();
+        let lines: Vec<&str> = text.lines().collect();
+        for expected in &[
+            "# TYPE fluvio_producer_records_sent_total counter",
+            "fluvio_producer_records_sent_total{topic=\"te\\\"st\"} 5",
+            "fluvio_producer_bytes_sent_total{topic=\"te\\\"st\"} 25",
+            "fluvio_producer_latency_seconds_bucket{topic=\"te\\\"st\",le=\"0.5\"} 1",
+            "fluvio_producer_latency_seconds_bucket{topic=\"te\\\"st\",le=\"+Inf\"} 2",
+            "fluvio_producer_latency_seconds_sum{topic=\"te\\\"st\"} 20.5",
+            "fluvio_producer_latency_seconds_count{topic=\"te\\\"st\"} 2",
+            "fluvio_fetch_latency_seconds_bucket{le=\"+Inf\"} 0",
+            "fluvio_sc_disconnects_total 1",
+        ] {
+            assert!(lines.contains(expected), "missing: {}", expected);
+        }
+    }
+
+    #[cfg(feature = "otel")]
+    #[test]
+    fn test_otel_export() {
+        use std::collections::BTreeMap;
+        use std::sync::Arc;
+
+        use opentelemetry::metrics::MeterProvider;
+        use opentelemetry::sdk::export::metrics::{CheckpointSet, ExportKindSelector, Sum};
+        use opentelemetry::sdk::metrics::aggregators::SumAggregator;
+        use opentelemetry::sdk::metrics::{controllers, selectors};
+
+        let metrics = Arc::new(ClientMetrics::default());
+        metrics.record_produce("test", 2, 10, Duration::from_millis(4), true);
+        metrics.record_produce("test", 3, 15, Duration::from_millis(6), false);
+        metrics.record_sc_disconnect();
+
+        let mut controller = controllers::pull(
+            Box::new(selectors::simple::Selector::Exact),
+            Box::new(ExportKindSelector::Cumulative),
+        )
+        .with_memory(true)
+        .build();
+        metrics.register_opentelemetry(&controller.provider().meter("fluvio", None));
+        controller.collect().expect("collect");
+
+        let mut sums = BTreeMap::new();
+        controller
+            .try_for_each(&ExportKindSelector::Cumulative, &mut |record| {
+                let sum = record
+                    .aggregator()
+                    .and_then(|agg| agg.as_any().downcast_ref::<SumAggregator>());
+                if let Some(sum) = sum {
+                    let labels: Vec<String> = record
+                        .labels()
+                        .iter()
+                        .map(|(key, value)| format!("{}={}", key.as_str(), value.as_str()))
+                        .collect();
+                    let value = sum.sum()?.to_f64(record.descriptor().number_kind());
+                    sums.insert(
+                        (record.descriptor().name().to_owned(), labels.join(",")),
+                        value,
+                    );
+                }
+                Ok(())
+            })
+            .expect("export");
+
+        let sum = |name: &str, labels: &str| sums.get(&(name.to_owned(), labels.to_owned()));
+        assert_eq!(
+            sum("fluvio.producer.records_sent", "topic=test"),
+            Some(&2.0)
+        );
+        assert_eq!(sum("fluvio.producer.bytes_sent", "topic=test"), Some(&10.0));
+        assert_eq!(sum("fluvio.producer.requests", "topic=test"), Some(&2.0));
+        assert_eq!(sum("fluvio.producer.errors", "topic=test"), Some(&1.0));
+        assert_eq!(
+            sum("fluvio.producer.latency.sum", "topic=test"),
+            Some(&0.01)
+        );
+        assert_eq!(sum("fluvio.sc.disconnects", ""), Some(&1.0));
+    }
+}
@@ -301,7 +301,7 @@ impl Offset {
 }
 
 pub(crate) async fn fetch_offsets(
-    client: &mut VersionedSerialSocket,
+    client: &VersionedSerialSocket,
     replica: &ReplicaKey,
 ) -> Result<FetchOffsetPartitionResponse, FluvioError> {
     debug!("fetching offset for replica: {}", replica);
@@ -343,6 +343,7 @@ mod tests {
             partition_index: 0,
             start_offset: 0,
             last_stable_offset: 10,
+            ..Default::default()
         };
 
         let offset_inner = OffsetInner::FromBeginning(3);
@@ -357,6 +358,7 @@ mod tests {
             partition_index: 0,
             start_offset: 5,
             last_stable_offset: 10,
+            ..Default::default()
         };
 
         let offset_inner = OffsetInner::FromBeginning(3);
@@ -371,6 +373,7 @@ mod tests {
             partition_index: 0,
             start_offset: 0,
             last_stable_offset: 10,
+            ..Default::default()
         };
 
         let offset_inner = OffsetInner::FromBeginning(15);
@@ -385,6 +388,7 @@ mod tests {
             partition_index: 0,
             start_offset: 5,
             last_stable_offset: 10,
+            ..Default::default()
         };
 
         let offset_inner = OffsetInner::FromBeginning(15);
@@ -399,6 +403,7 @@ mod tests {
             partition_index: 0,
             start_offset: 0,
             last_stable_offset: 10,
+            ..Default::default()
         };
 
         let offset_inner = OffsetInner::FromEnd(3);
@@ -413,6 +418,7 @@ mod tests {
             partition_index: 0,
             start_offset: 6,
             last_stable_offset: 10,
+            ..Default::default()
         };
 
         let offset_inner = OffsetInner::FromEnd(6);
@@ -427,6 +433,7 @@ mod tests {
             partition_index: 0,
             start_offset: 0,
             last_stable_offset: 10,
+            ..Default::default()
         };
 
         let offset_inner = OffsetInner::FromEnd(100);
@@ -1,8 +1,11 @@
 use std::sync::Arc;
 use std::collections::HashMap;
 use tracing::instrument;
+use instant::Instant;
 
 mod partitioning;
+mod transaction;
+mod typed;
 
 use fluvio_types::{SpuId, PartitionId};
 use dataplane::ReplicaKey;
@@ -12,10 +15,13 @@ use dataplane::produce::DefaultTopicRequest;
 use dataplane::batch::{Batch, MemoryRecords};
 use dataplane::record::Record;
 pub use dataplane::record::{RecordKey, RecordData};
+pub use typed::TypedProducer;
+pub use transaction::{TransactionalProducer, committed_offsets};
 
 use crate::FluvioError;
 use crate::spu::SpuPool;
 use crate::sync::StoreContext;
+use crate::metrics::payload_size;
 use crate::metadata::partition::PartitionSpec;
 use crate::producer::partitioning::{Partitioner, SiphashRoundRobinPartitioner, PartitionerConfig};
 
@@ -119,12 +125,44 @@ impl TopicProducer {
         let requests = assemble_requests(&self.topic, partitions_by_spu);
 
         for (leader, request) in requests {
-            let spu_client = self.pool.create_serial_socket_from_leader(leader).await?;
-            spu_client.send_receive(request).await?;
+            let (records, bytes) = request_size(&request);
+            let start = Instant::now();
+            let result = self.send_request(leader, request).await;
+            self.pool.metrics.record_produce(
+                &self.topic,
+                records,
+                bytes,
+                start.elapsed(),
+                result.is_ok(),
+            );
+            result?;
         }
 
         Ok(())
     }
+
+    async fn send_request(
+        &self,
+        leader: SpuId,
+        request: DefaultProduceRequest,
+    ) -> Result<(), FluvioError> {
+        let spu_client = self.pool.create_serial_socket_from_leader(leader).await?;
+        spu_client.send_receive(request).await?;
+        Ok(())
+    }
+}
+
+/// number of records and their bytes in request
+fn request_size(request: &DefaultProduceRequest) -> (u64, u64) {
+    let records = request
+        .topics
+        .iter()
+        .flat_map(|topic| topic.partitions.iter())
+        .flat_map(|partition| partition.records.batches.iter())
+        .flat_map(|batch| batch.records().iter());
+    records.fold((0, 0), |(count, bytes), record| {
+        (count + 1, bytes + payload_size(record))
+    })
 }
 
 async fn group_by_spu(
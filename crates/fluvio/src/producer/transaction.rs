@@ -0,0 +1,419 @@
+//!
+//! # Transactional producer
+//!
+//! Records sent to partitions of many topics within transaction are read by
+//! transactional consumers only if transaction is committed.
+//! Transaction is coordinated by SC, which assigns producer id and epoch
+//! to transactional id and writes markers of ended transaction to its partitions.
+//! Creating producer with same transactional id fences previous instance and
+//! waits until its unfinished transaction is completed or aborted.
+//!
+//! Consumer offsets are stored as records of offsets topic, so they can be sent in same
+//! transaction as records produced from consumed records.
+//!
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+use std::time::Duration;
+
+use futures_util::stream::StreamExt;
+use tracing::{debug, trace, instrument};
+use instant::Instant;
+
+use fluvio_future::timer::sleep;
+
+use dataplane::ReplicaKey;
+use dataplane::batch::Batch;
+use dataplane::produce::{DefaultPartitionRequest, DefaultProduceRequest, DefaultTopicRequest};
+use dataplane::record::{Record, RecordData, RecordKey};
+use fluvio_sc_schema::ApiError;
+use fluvio_sc_schema::transaction::{
+    AddPartitionsToTxnRequest, EndTxnPhase, EndTxnRequest, InitProducerIdRequest,
+};
+
+use crate::{ConsumerConfig, FluvioError, Offset, PartitionConsumer};
+use crate::sockets::VersionedSerialSocket;
+use crate::spu::SpuPool;
+
+/// wait before checking again if markers are written, doubled up to max wait
+const MIN_COMPLETE_WAIT: Duration = Duration::from_millis(20);
+const MAX_COMPLETE_WAIT: Duration = Duration::from_secs(1);
+/// transaction is not complete if markers are not written within timeout
+const COMPLETE_TIMEOUT: Duration = Duration::from_secs(60);
+
+/// An interface for producing records to many partitions atomically
+///
+/// Transaction is started with [`begin`], records are sent to partitions with [`send`]
+/// and offsets of consumed records with [`send_offsets`], then transaction is ended
+/// with [`commit`] or [`abort`].
+///
+/// Consumers must be built with `transactional` option of [`ConsumerConfig`] to skip
+/// records of aborted transactions.
+/// Records after open transaction are not delivered until transaction is ended.
+///
+/// # Example
+///
+/// ```no_run
+/// # use fluvio::{Fluvio, FluvioError};
+/// # async fn example(fluvio: &Fluvio) -> Result<(), FluvioError> {
+/// let mut producer = fluvio.transactional_producer("word-counter").await?;
+/// producer.begin()?;
+/// producer.send("counts", 0, "hello", "1").await?;
+/// producer.send_offsets("offsets", "word-counter", vec![(("words", 0).into(), 11)]).await?;
+/// producer.commit().await?;
+/// # Ok(())
+/// # }
+/// ```
+///
+/// [`begin`]: TransactionalProducer::begin
+/// [`send`]: TransactionalProducer::send
+/// [`send_offsets`]: TransactionalProducer::send_offsets
+/// [`commit`]: TransactionalProducer::commit
+/// [`abort`]: TransactionalProducer::abort
+pub struct TransactionalProducer {
+    transactional_id: String,
+    producer_id: i64,
+    producer_epoch: i16,
+    socket: VersionedSerialSocket,
+    pool: Arc<SpuPool>,
+    /// partitions of current transaction, none if transaction is not started
+    partitions: Option<HashSet<ReplicaKey>>,
+}
+
+impl TransactionalProducer {
+    pub(crate) async fn new(
+        transactional_id: String,
+        socket: VersionedSerialSocket,
+        pool: Arc<SpuPool>,
+    ) -> Result<Self, FluvioError> {
+        let response = socket
+            .send_receive(InitProducerIdRequest::new(transactional_id.clone()))
+            .await?;
+        response.status.as_result()?;
+
+        let producer = Self {
+            transactional_id,
+            producer_id: response.producer_id,
+            producer_epoch: response.producer_epoch,
+            socket,
+            pool,
+            partitions: None,
+        };
+
+        if let Some(pending) = response.pending {
+            debug!(
+                transactional_id = %producer.transactional_id,
+                commit = pending.commit,
+                partitions = pending.partitions.len(),
+                "waiting for transaction of previous producer"
+            );
+            producer.complete(pending.commit).await?;
+        }
+        Ok(producer)
+    }
+
+    pub fn transactional_id(&self) -> &str {
+        &self.transactional_id
+    }
+
+    /// producer id assigned by coordinator, written to batches of transaction
+    pub fn producer_id(&self) -> i64 {
+        self.producer_id
+    }
+
+    pub fn producer_epoch(&self) -> i16 {
+        self.producer_epoch
+    }
+
+    /// start new transaction
+    pub fn begin(&mut self) -> Result<(), FluvioError> {
+        if self.partitions.is_some() {
+            return Err(FluvioError::Transaction(
+                "transaction is already started".to_owned(),
+            ));
+        }
+        self.partitions = Some(HashSet::new());
+        Ok(())
+    }
+
+    /// send key/value record to partition of topic within transaction
+    pub async fn send<K, V>(
+        &mut self,
+        topic: &str,
+        partition: i32,
+        key: K,
+        value: V,
+    ) -> Result<(), FluvioError>
+    where
+        K: Into<RecordKey>,
+        V: Into<RecordData>,
+    {
+        self.send_all(topic, partition, Some((key, value))).await
+    }
+
+    /// send key/value records to partition of topic within transaction
+    #[instrument(
+        skip(self, records),
+        fields(transactional_id = %self.transactional_id),
+    )]
+    pub async fn send_all<K, V, I>(
+        &mut self,
+        topic: &str,
+        partition: i32,
+        records: I,
+    ) -> Result<(), FluvioError>
+    where
+        K: Into<RecordKey>,
+        V: Into<RecordData>,
+        I: IntoIterator<Item = (K, V)>,
+    {
+        let replica = ReplicaKey::new(topic, partition);
+        self.add_partition(&replica).await?;
+
+        let records: Vec<Record> = records
+            .into_iter()
+            .map::<(RecordKey, RecordData), _>(|(k, v)| (k.into(), v.into()))
+            .map(Record::from)
+            .collect();
+        if records.is_empty() {
+            return Ok(());
+        }
+        let mut batch = Batch::from(records);
+        batch
+            .get_mut_header()
+            .set_transactional(self.producer_id, self.producer_epoch);
+        self.produce(&replica, batch).await
+    }
+
+    /// send offsets of consumer group within transaction.
+    /// Offset is the next offset to be consumed from partition.
+    /// Offsets are written to partition 0 of offsets topic
+    pub async fn send_offsets<I>(
+        &mut self,
+        offsets_topic: &str,
+        group: &str,
+        offsets: I,
+    ) -> Result<(), FluvioError>
+    where
+        I: IntoIterator<Item = (ReplicaKey, i64)>,
+    {
+        let records = offsets.into_iter().map(|(replica, offset)| {
+            (offset_key(group, &replica), offset.to_string().into_bytes())
+        });
+        self.send_all(offsets_topic, 0, records).await
+    }
+
+    /// commit current transaction
+    pub async fn commit(&mut self) -> Result<(), FluvioError> {
+        self.end(true).await
+    }
+
+    /// abort current transaction, records sent within it are not read by
+    /// transactional consumers
+    pub async fn abort(&mut self) -> Result<(), FluvioError> {
+        self.end(false).await
+    }
+
+    async fn add_partition(&mut self, replica: &ReplicaKey) -> Result<(), FluvioError> {
+        let partitions = self
+            .partitions
+            .as_mut()
+            .ok_or_else(|| FluvioError::Transaction("transaction is not started".to_owned()))?;
+        if partitions.contains(replica) {
+            return Ok(());
+        }
+
+        let request = AddPartitionsToTxnRequest {
+            transactional_id: self.transactional_id.clone(),
+            producer_id: self.producer_id,
+            producer_epoch: self.producer_epoch,
+            partitions: vec![replica.clone()],
+        };
+        self.socket
+            .send_receive(request)
+            .await?
+            .status
+            .as_result()?;
+        partitions.insert(replica.clone());
+        Ok(())
+    }
+
+    /// prepare decision, then wait until markers are written by SPUs.
+    /// If it fails, decision can be retried with same producer
+    #[instrument(skip(self), fields(transactional_id = %self.transactional_id))]
+    async fn end(&mut self, commit: bool) -> Result<(), FluvioError> {
+        if self.partitions.is_none() {
+            return Err(FluvioError::Transaction(
+                "transaction is not started".to_owned(),
+            ));
+        }
+
+        let request = EndTxnRequest {
+            transactional_id: self.transactional_id.clone(),
+            producer_id: self.producer_id,
+            producer_epoch: self.producer_epoch,
+            commit,
+            phase: EndTxnPhase::Prepare,
+        };
+        let response = self.socket.send_receive(request).await?;
+        response.status.as_result()?;
+
+        if !response.partitions.is_empty() {
+            self.complete(commit).await?;
+        }
+        self.partitions = None;
+        Ok(())
+    }
+
+    /// wait until markers of prepared transaction are written to all partitions
+    async fn complete(&self, commit: bool) -> Result<(), FluvioError> {
+        let started = Instant::now();
+        let mut wait = MIN_COMPLETE_WAIT;
+        loop {
+            let request = EndTxnRequest {
+                transactional_id: self.transactional_id.clone(),
+                producer_id: self.producer_id,
+                producer_epoch: self.producer_epoch,
+                commit,
+                phase: EndTxnPhase::Complete,
+            };
+            let response = self.socket.send_receive(request).await?;
+            response.status.as_result()?;
+            if response.partitions.is_empty() {
+                debug!(commit, "transaction completed");
+                return Ok(());
+            }
+
+            if started.elapsed() >= COMPLETE_TIMEOUT {
+                return Err(FluvioError::Transaction(format!(
+                    "markers are not written to {} partitions",
+                    response.partitions.len()
+                )));
+            }
+            trace!(
+                partitions = response.partitions.len(),
+                ?wait,
+                "waiting for markers"
+            );
+            sleep(wait).await;
+            wait = (wait * 2).min(MAX_COMPLETE_WAIT);
+        }
+    }
+
+    async fn produce(&self, replica: &ReplicaKey, batch: Batch) -> Result<(), FluvioError> {
+        let mut partition_request = DefaultPartitionRequest {
+            partition_index: replica.partition,
+            ..Default::default()
+        };
+        partition_request.records.batches.push(batch);
+
+        let topic_request = DefaultTopicRequest {
+            name: replica.topic.clone(),
+            partitions: vec![partition_request],
+            ..Default::default()
+        };
+
+        let request = DefaultProduceRequest {
+            transactional_id: Some(self.transactional_id.clone()),
+            acks: 1,
+            timeout_ms: 1500,
+            topics: vec![topic_request],
+            ..Default::default()
+        };
+
+        let spu_client = self.pool.create_serial_socket(replica).await?;
+        let response = spu_client.send_receive(request).await?;
+        if let Some(partition) = response.find_partition_response(&replica.topic, replica.partition)
+        {
+            if partition.error_code.is_error() {
+                return Err(FluvioError::AdminApi(ApiError::Code(
+                    partition.error_code.clone(),
+                    None,
+                )));
+            }
+        }
+        Ok(())
+    }
+}
+
+/// key of offset record, topic names can't contain separator
+fn offset_key(group: &str, replica: &ReplicaKey) -> String {
+    format!("{}/{}/{}", replica.topic, replica.partition, group)
+}
+
+fn parse_offset_key(key: &[u8]) -> Option<(&str, ReplicaKey)> {
+    let key = std::str::from_utf8(key).ok()?;
+    let mut parts = key.splitn(3, '/');
+    let topic = parts.next()?;
+    let partition = parts.next()?.parse().ok()?;
+    let group = parts.next()?;
+    Some((group, ReplicaKey::new(topic, partition)))
+}
+
+/// last committed offsets of consumer group, read from partition 0 of offsets topic
+///
+/// # Example
+///
+/// ```no_run
+/// # use fluvio::{Fluvio, FluvioError};
+/// # async fn example(fluvio: &Fluvio) -> Result<(), FluvioError> {
+/// let offsets = fluvio.partition_consumer("offsets", 0).await?;
+/// let committed = fluvio::committed_offsets(&offsets, "word-counter").await?;
+/// # Ok(())
+/// # }
+/// ```
+pub async fn committed_offsets(
+    offsets: &PartitionConsumer,
+    group: &str,
+) -> Result<HashMap<ReplicaKey, i64>, FluvioError> {
+    let config = ConsumerConfig::builder()
+        .transactional(true)
+        .disable_continuous(true)
+        .build()?;
+    let mut stream = Box::pin(
+        offsets
+            .stream_with_config(Offset::beginning(), config)
+            .await?,
+    );
+
+    let mut committed = HashMap::new();
+    while let Some(record) = stream.next().await {
+        let record = record?;
+        let key = match record.key() {
+            Some(key) => key,
+            None => continue,
+        };
+        if let Some((record_group, replica)) = parse_offset_key(key) {
+            if record_group != group {
+                continue;
+            }
+            if let Some(offset) = std::str::from_utf8(record.value())
+                .ok()
+                .and_then(|value| value.parse().ok())
+            {
+                committed.insert(replica, offset);
+            }
+        }
+    }
+    Ok(committed)
+}
+
+#[cfg(test)]
+mod tests {
+
+    use dataplane::ReplicaKey;
+
+    use super::{offset_key, parse_offset_key};
+
+    #[test]
+    fn test_offset_key() {
+        let replica = ReplicaKey::new("words", 2);
+        let key = offset_key("counter/v2", &replica);
+        assert_eq!(key, "words/2/counter/v2");
+        assert_eq!(
+            parse_offset_key(key.as_bytes()),
+            Some(("counter/v2", replica))
+        );
+        assert_eq!(parse_offset_key(b"words/x/counter"), None);
+        assert_eq!(parse_offset_key(b"words"), None);
+    }
+}
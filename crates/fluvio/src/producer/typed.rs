@@ -0,0 +1,93 @@
+use std::marker::PhantomData;
+
+use crate::FluvioError;
+use crate::serializer::Serializer;
+
+use super::{TopicProducer, RecordKey};
+
+/// Producer of typed keys and values
+///
+/// Keys and values are encoded by serializers before they are sent
+/// by the inner [`TopicProducer`].
+///
+/// # Example
+///
+/// ```
+/// # use fluvio::{TopicProducer, TypedProducer, FluvioError};
+/// # use fluvio::serializer::Utf8;
+/// # async fn example(producer: TopicProducer) -> Result<(), FluvioError> {
+/// let producer = TypedProducer::new(producer, Utf8, Utf8);
+/// producer.send(&"key".to_owned(), &"value".to_owned()).await?;
+/// # Ok(())
+/// # }
+/// ```
+///
+/// [`TopicProducer`]: struct.TopicProducer.html
+pub struct TypedProducer<K, V> {
+    producer: TopicProducer,
+    key_serializer: Box<dyn Serializer<K>>,
+    value_serializer: Box<dyn Serializer<V>>,
+    data: PhantomData<fn(&K, &V)>,
+}
+
+impl<K, V> TypedProducer<K, V> {
+    pub fn new<KS, VS>(producer: TopicProducer, key_serializer: KS, value_serializer: VS) -> Self
+    where
+        KS: Serializer<K> + 'static,
+        VS: Serializer<V> + 'static,
+    {
+        Self {
+            producer,
+            key_serializer: Box::new(key_serializer),
+            value_serializer: Box::new(value_serializer),
+            data: PhantomData,
+        }
+    }
+
+    /// Producer of raw records
+    pub fn inner(&self) -> &TopicProducer {
+        &self.producer
+    }
+
+    /// Sends key/value record, partition is derived from encoded key
+    pub async fn send(&self, key: &K, value: &V) -> Result<(), FluvioError> {
+        let record = self.serialize(Some(key), value)?;
+        self.producer.send_all(Some(record)).await
+    }
+
+    /// Sends record without key
+    pub async fn send_value(&self, value: &V) -> Result<(), FluvioError> {
+        let record = self.serialize(None, value)?;
+        self.producer.send_all(Some(record)).await
+    }
+
+    /// Sends key/value records, nothing is sent if any of them can't be serialized
+    pub async fn send_all<'a, I>(&self, records: I) -> Result<(), FluvioError>
+    where
+        K: 'a,
+        V: 'a,
+        I: IntoIterator<Item = (&'a K, &'a V)>,
+    {
+        let records = records
+            .into_iter()
+            .map(|(key, value)| self.serialize(Some(key), value))
+            .collect::<Result<Vec<_>, _>>()?;
+        self.producer.send_all(records).await
+    }
+
+    fn serialize(&self, key: Option<&K>, value: &V) -> Result<(RecordKey, Vec<u8>), FluvioError> {
+        let key = match key {
+            Some(key) => self
+                .key_serializer
+                .serialize(key)
+                .map_err(FluvioError::Serialize)?
+                .into(),
+            None => RecordKey::NULL,
+        };
+        let value = self
+            .value_serializer
+            .serialize(value)
+            .map_err(FluvioError::Serialize)?;
+        Ok((key, value))
+    }
+}
@@ -0,0 +1,241 @@
+//!
+//! # SASL Handshake
+//!
+//! Client side of SASL authentication, performed as first exchange of connection
+//! when credentials are configured.
+//!
+use tracing::{debug, instrument};
+use rand::Rng;
+
+use dataplane::ErrorCode;
+use dataplane::api::RequestMessage;
+use dataplane::sasl::{
+    SaslAuthenticateRequest, SaslAuthenticateResponse, PLAIN_MECHANISM, SCRAM_SHA_256_MECHANISM,
+    OAUTHBEARER_MECHANISM,
+};
+use dataplane::sasl::scram::{self, CLIENT_KEY, SERVER_KEY};
+use fluvio_socket::FluvioSocket;
+
+use crate::FluvioError;
+use crate::config::Credentials;
+
+const NONCE_LEN: usize = 18;
+/// base64 of gs2 header "n,,", no channel binding
+const CHANNEL_BINDING: &str = "biws";
+
+/// authenticate connection with credentials
+#[instrument(skip(socket, credentials))]
+pub(crate) async fn authenticate(
+    socket: &mut FluvioSocket,
+    credentials: &Credentials,
+    client_id: &str,
+) -> Result<(), FluvioError> {
+    match credentials {
+        Credentials::Plain { username, password } => {
+            let message = format!("\0{}\0{}", username, password);
+            let response = send(socket, client_id, PLAIN_MECHANISM, message).await?;
+            expect_complete(&response)
+        }
+        Credentials::ScramSha256 { username, password } => {
+            let client = ScramClient::new(username, password);
+            let response = send(
+                socket,
+                client_id,
+                SCRAM_SHA_256_MECHANISM,
+                client.client_first(),
+            )
+            .await?;
+            let (client_final, server_signature) = client.client_final(&response.auth_bytes)?;
+            let response = send(socket, client_id, SCRAM_SHA_256_MECHANISM, client_final).await?;
+            expect_complete(&response)?;
+            ScramClient::verify_server_final(&response.auth_bytes, &server_signature)
+        }
+        Credentials::Token { token } => {
+            let message = format!("n,,\x01auth=Bearer {}\x01\x01", token);
+            let response = send(socket, client_id, OAUTHBEARER_MECHANISM, message).await?;
+            expect_complete(&response)
+        }
+    }
+}
+
+async fn send(
+    socket: &mut FluvioSocket,
+    client_id: &str,
+    mechanism: &str,
+    message: String,
+) -> Result<SaslAuthenticateResponse, FluvioError> {
+    let mut req_msg = RequestMessage::new_request(SaslAuthenticateRequest::new(
+        mechanism,
+        message.into_bytes(),
+    ));
+    req_msg.get_mut_header().set_client_id(client_id);
+
+    debug!(%mechanism, "sending sasl authenticate");
+    let response = socket.send(&req_msg).await?.response;
+    if response.error_code != ErrorCode::None {
+        return Err(FluvioError::Authentication(
+            response
+                .error_message
+                .unwrap_or_else(|| response.error_code.to_sentence()),
+        ));
+    }
+    Ok(response)
+}
+
+fn expect_complete(response: &SaslAuthenticateResponse) -> Result<(), FluvioError> {
+    if response.complete {
+        Ok(())
+    } else {
+        Err(authentication_error(
+            "server did not complete authentication",
+        ))
+    }
+}
+
+fn authentication_error(msg: &str) -> FluvioError {
+    FluvioError::Authentication(msg.to_owned())
+}
+
+/// Client side of SCRAM-SHA-256, RFC 5802
+struct ScramClient<'a> {
+    password: &'a str,
+    client_first_bare: String,
+    nonce: String,
+}
+
+impl<'a> ScramClient<'a> {
+    fn new(username: &str, password: &'a str) -> Self {
+        let nonce: [u8; NONCE_LEN] = rand::thread_rng().gen();
+        let nonce = base64::encode(nonce);
+        let username = username.replace('=', "=3D").replace(',', "=2C");
+        Self {
+            password,
+            client_first_bare: format!("n={},r={}", username, nonce),
+            nonce,
+        }
+    }
+
+    fn client_first(&self) -> String {
+        format!("n,,{}", self.client_first_bare)
+    }
+
+    /// process server first message, returns client final message and expected server signature
+    fn client_final(&self, server_first: &[u8]) -> Result<(String, Vec<u8>), FluvioError> {
+        let server_first = std::str::from_utf8(server_first)
+            .map_err(|_| authentication_error("invalid scram server message"))?;
+
+        let mut nonce = None;
+        let mut salt = None;
+        let mut iterations = None;
+        for attribute in server_first.split(',') {
+            if let Some(value) = attribute.strip_prefix("r=") {
+                nonce = Some(value);
+            } else if let Some(value) = attribute.strip_prefix("s=") {
+                salt = base64::decode(value).ok();
+            } else if let Some(value) = attribute.strip_prefix("i=") {
+                iterations = value.parse::<u32>().ok();
+            }
+        }
+
+        let nonce = nonce
+            .filter(|nonce| nonce.starts_with(&self.nonce))
+            .ok_or_else(|| authentication_error("invalid scram server nonce"))?;
+        let salt = salt.ok_or_else(|| authentication_error("invalid scram salt"))?;
+        let iterations = iterations
+            .ok_or_else(|| "invalid scram iterations".to_owned())
+            .and_then(scram::validate_iterations)
+            .map_err(|msg| authentication_error(&msg))?;
+
+        let salted_password = scram::hi(self.password.as_bytes(), &salt, iterations);
+        let client_key = scram::hmac(&salted_password, CLIENT_KEY);
+        let stored_key = scram::h(&client_key);
+
+        let without_proof = format!("c={},r={}", CHANNEL_BINDING, nonce);
+        let auth_message = format!(
+            "{},{},{}",
+            self.client_first_bare, server_first, without_proof
+        );
+        let client_signature = scram::hmac(&stored_key, auth_message.as_bytes());
+        let proof = scram::xor(&client_key, &client_signature);
+
+        let server_key = scram::hmac(&salted_password, SERVER_KEY);
+        let server_signature = scram::hmac(&server_key, auth_message.as_bytes());
+
+        Ok((
+            format!("{},p={}", without_proof, base64::encode(proof)),
+            server_signature,
+        ))
+    }
+
+    /// server must prove it knows the credential as well
+    fn verify_server_final(server_final: &[u8], expected: &[u8]) -> Result<(), FluvioError> {
+        let signature = std::str::from_utf8(server_final)
+            .ok()
+            .and_then(|message| message.strip_prefix("v="))
+            .and_then(|value| base64::decode(value).ok())
+            .ok_or_else(|| authentication_error("invalid scram server final message"))?;
+        if scram::constant_time_eq(&signature, expected) {
+            Ok(())
+        } else {
+            Err(authentication_error("invalid scram server signature"))
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    use super::*;
+
+    /// test vector from RFC 7677
+    #[test]
+    fn test_scram_client() {
+        let client = ScramClient {
+            password: "pencil",
+            client_first_bare: "n=user,r=rOprNGfwEbeRWgbNEkqO".to_owned(),
+            nonce: "rOprNGfwEbeRWgbNEkqO".to_owned(),
+        };
+        assert_eq!(client.client_first(), "n,,n=user,r=rOprNGfwEbeRWgbNEkqO");
+
+        let server_first = "r=rOprNGfwEbeRWgbNEkqO%hvYDpWUa2RaTCAfuxFIlj)hNlF$k0,s=W22ZaJ0SNY7soEsUEjb6gQ==,i=4096";
+        let (client_final, server_signature) = client
+            .client_final(server_first.as_bytes())
+            .expect("client final");
+        assert_eq!(
+            client_final,
+            "c=biws,r=rOprNGfwEbeRWgbNEkqO%hvYDpWUa2RaTCAfuxFIlj)hNlF$k0,p=dHzbZapWIk4jUhN+Ute9ytag9zjfMHgsqmmiz7AndVQ="
+        );
+        assert!(ScramClient::verify_server_final(
+            b"v=6rriTRBi23WpRR/wtup+mMhUZUn/dB5nLTJRsjl95G4=",
+            &server_signature
+        )
+        .is_ok());
+        assert!(ScramClient::verify_server_final(b"v=AAAA", &server_signature).is_err());
+
+        // server nonce must extend client nonce
+        assert!(client
+            .client_final(b"r=other,s=W22ZaJ0SNY7soEsUEjb6gQ==,i=4096")
+            .is_err());
+    }
+
+    #[test]
+    fn test_scram_client_rejects_iterations() {
+        let client = ScramClient::new("user", "pencil");
+        let server_first = |iterations: u32| {
+            format!(
+                "r={}server,s=W22ZaJ0SNY7soEsUEjb6gQ==,i={}",
+                client.nonce, iterations
+            )
+        };
+
+        assert!(client.client_final(server_first(4096).as_bytes()).is_ok());
+        // weak key derivation
+        assert!(client.client_final(server_first(1).as_bytes()).is_err());
+        // server must not make client spin
+        assert!(client
+            .client_final(server_first(u32::MAX).as_bytes())
+            .is_err());
+        let missing = format!("r={}server,s=W22ZaJ0SNY7soEsUEjb6gQ==", client.nonce);
+        assert!(client.client_final(missing.as_bytes()).is_err());
+    }
+}
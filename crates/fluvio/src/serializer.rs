@@ -0,0 +1,193 @@
+//!
+//! # Record serializers
+//!
+//! Conversion between typed keys and values and bytes of records,
+//! used by [`TypedProducer`] and typed record streams.
+//!
+//! Built-in formats are enabled by cargo features:
+//!
+//! | Format       | Feature    | Types                              |
+//! |--------------|------------|------------------------------------|
+//! | [`Utf8`]     |            | `String`                           |
+//! | [`Json`]     | `json`     | `serde::Serialize`, `Deserialize`  |
+//! | [`Bincode`]  | `bincode`  | `serde::Serialize`, `Deserialize`  |
+//! | [`Protobuf`] | `protobuf` | `prost::Message`                   |
+//!
+//! Values of topics with registered schema are framed with schema id by [`WithSchemaId`].
+//!
+//! [`TypedProducer`]: ../struct.TypedProducer.html
+//!
+
+use fluvio_sc_schema::schema::{encode_schema_id, decode_schema_id};
+
+/// Error returned by serializer
+pub type SerdeError = Box<dyn std::error::Error + Send + Sync>;
+
+/// Encodes value of type `T` as bytes of record key or value
+pub trait Serializer<T>: Send + Sync {
+    fn serialize(&self, value: &T) -> Result<Vec<u8>, SerdeError>;
+}
+
+/// Decodes value of type `T` from bytes of record key or value
+pub trait Deserializer<T>: Send + Sync {
+    fn deserialize(&self, bytes: &[u8]) -> Result<T, SerdeError>;
+}
+
+/// UTF-8 text
+#[derive(Debug, Default, Clone, Copy)]
+pub struct Utf8;
+
+impl Serializer<String> for Utf8 {
+    fn serialize(&self, value: &String) -> Result<Vec<u8>, SerdeError> {
+        Ok(value.as_bytes().to_vec())
+    }
+}
+
+impl Deserializer<String> for Utf8 {
+    fn deserialize(&self, bytes: &[u8]) -> Result<String, SerdeError> {
+        Ok(String::from_utf8(bytes.to_vec())?)
+    }
+}
+
+/// JSON encoding with `serde_json`
+#[cfg(feature = "json")]
+#[derive(Debug, Default, Clone, Copy)]
+pub struct Json;
+
+#[cfg(feature = "json")]
+impl<T: serde::Serialize> Serializer<T> for Json {
+    fn serialize(&self, value: &T) -> Result<Vec<u8>, SerdeError> {
+        Ok(serde_json::to_vec(value)?)
+    }
+}
+
+#[cfg(feature = "json")]
+impl<T: serde::de::DeserializeOwned> Deserializer<T> for Json {
+    fn deserialize(&self, bytes: &[u8]) -> Result<T, SerdeError> {
+        Ok(serde_json::from_slice(bytes)?)
+    }
+}
+
+/// Binary encoding with `bincode`
+#[cfg(feature = "bincode")]
+#[derive(Debug, Default, Clone, Copy)]
+pub struct Bincode;
+
+#[cfg(feature = "bincode")]
+impl<T: serde::Serialize> Serializer<T> for Bincode {
+    fn serialize(&self, value: &T) -> Result<Vec<u8>, SerdeError> {
+        Ok(bincode::serialize(value)?)
+    }
+}
+
+#[cfg(feature = "bincode")]
+impl<T: serde::de::DeserializeOwned> Deserializer<T> for Bincode {
+    fn deserialize(&self, bytes: &[u8]) -> Result<T, SerdeError> {
+        Ok(bincode::deserialize(bytes)?)
+    }
+}
+
+/// Protocol buffers encoding with `prost`
+#[cfg(feature = "protobuf")]
+#[derive(Debug, Default, Clone, Copy)]
+pub struct Protobuf;
+
+#[cfg(feature = "protobuf")]
+impl<T: prost::Message> Serializer<T> for Protobuf {
+    fn serialize(&self, value: &T) -> Result<Vec<u8>, SerdeError> {
+        Ok(value.encode_to_vec())
+    }
+}
+
+#[cfg(feature = "protobuf")]
+impl<T: prost::Message + Default> Deserializer<T> for Protobuf {
+    fn deserialize(&self, bytes: &[u8]) -> Result<T, SerdeError> {
+        Ok(T::decode(bytes)?)
+    }
+}
+
+/// Frames bytes of inner format with schema id, as expected by SPU schema validation.
+/// Deserializer accepts any registered schema id
+#[derive(Debug, Clone, Copy)]
+pub struct WithSchemaId<S> {
+    id: u32,
+    inner: S,
+}
+
+impl<S> WithSchemaId<S> {
+    /// id returned when schema was registered
+    pub fn new(id: u32, inner: S) -> Self {
+        Self { id, inner }
+    }
+
+    pub fn id(&self) -> u32 {
+        self.id
+    }
+}
+
+impl<T, S: Serializer<T>> Serializer<T> for WithSchemaId<S> {
+    fn serialize(&self, value: &T) -> Result<Vec<u8>, SerdeError> {
+        Ok(encode_schema_id(self.id, &self.inner.serialize(value)?))
+    }
+}
+
+impl<T, S: Deserializer<T>> Deserializer<T> for WithSchemaId<S> {
+    fn deserialize(&self, bytes: &[u8]) -> Result<T, SerdeError> {
+        let (_, payload) = decode_schema_id(bytes).ok_or("value is not framed with schema id")?;
+        self.inner.deserialize(payload)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::{Serializer, Deserializer, Utf8, WithSchemaId};
+
+    #[test]
+    fn test_utf8() {
+        let bytes = Utf8.serialize(&"hello".to_owned()).expect("serialize");
+        assert_eq!(bytes, b"hello");
+        let value: String = Utf8.deserialize(&bytes).expect("deserialize");
+        assert_eq!(value, "hello");
+        assert!(Deserializer::<String>::deserialize(&Utf8, &[0xff, 0xfe]).is_err());
+    }
+
+    #[test]
+    fn test_with_schema_id() {
+        let serializer = WithSchemaId::new(3, Utf8);
+        let bytes = serializer
+            .serialize(&"hello".to_owned())
+            .expect("serialize");
+        assert_eq!(bytes, b"\0\0\0\0\x03hello");
+        let value: String = serializer.deserialize(&bytes).expect("deserialize");
+        assert_eq!(value, "hello");
+        assert!(Deserializer::<String>::deserialize(&serializer, b"hello").is_err());
+    }
+
+    #[cfg(feature = "json")]
+    #[test]
+    fn test_json() {
+        use std::collections::BTreeMap;
+
+        use super::Json;
+
+        let mut value = BTreeMap::new();
+        value.insert("a".to_owned(), 1);
+        let bytes = Json.serialize(&value).expect("serialize");
+        assert_eq!(bytes, br#"{"a":1}"#);
+        let decoded: BTreeMap<String, i32> = Json.deserialize(&bytes).expect("deserialize");
+        assert_eq!(decoded, value);
+        assert!(Deserializer::<BTreeMap<String, i32>>::deserialize(&Json, b"[1]").is_err());
+    }
+
+    #[cfg(feature = "bincode")]
+    #[test]
+    fn test_bincode() {
+        use super::Bincode;
+
+        let value = (5u32, "five".to_owned());
+        let bytes = Bincode.serialize(&value).expect("serialize");
+        let decoded: (u32, String) = Bincode.deserialize(&bytes).expect("deserialize");
+        assert_eq!(decoded, value);
+    }
+}
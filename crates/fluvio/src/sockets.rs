@@ -2,17 +2,23 @@ use std::default::Default;
 use std::fmt;
 use std::fmt::Display;
 use std::sync::Arc;
+use std::time::Duration;
 
+use tokio::sync::RwLock;
 use tracing::{debug, instrument};
 
 use dataplane::api::RequestMessage;
 use dataplane::api::Request;
 use dataplane::versions::{ApiVersions, ApiVersionsRequest, ApiVersionsResponse};
 use fluvio_socket::SocketError;
-use fluvio_socket::{FluvioSocket, SharedMultiplexerSocket};
+use fluvio_socket::{FluvioSocket, MultiplexerSocket, SharedMultiplexerSocket};
 use fluvio_future::net::{DomainConnector, DefaultDomainConnector};
 
 use crate::FluvioError;
+use crate::config::Credentials;
+
+/// longest time to wait for response of heartbeat
+const MAX_HEARTBEAT_TIMEOUT: Duration = Duration::from_secs(10);
 
 /// Frame with request and response
 pub(crate) trait SerialFrame: Display {
@@ -77,6 +83,9 @@ pub struct ClientConfig {
     client_id: String,
     connector: DomainConnector,
     pub(crate) use_spu_local_address: bool,
+    credentials: Option<Credentials>,
+    request_timeout: Option<Duration>,
+    heartbeat_interval: Option<Duration>,
 }
 
 impl fmt::Display for ClientConfig {
@@ -102,6 +111,9 @@ impl ClientConfig {
             client_id: "fluvio".to_owned(),
             connector,
             use_spu_local_address,
+            credentials: None,
+            request_timeout: None,
+            heartbeat_interval: None,
         }
     }
 
@@ -123,6 +135,24 @@ impl ClientConfig {
         self
     }
 
+    /// set credentials to authenticate connection with SASL handshake
+    pub fn set_credentials(mut self, credentials: Option<Credentials>) -> Self {
+        self.credentials = credentials;
+        self
+    }
+
+    /// set time to wait for response, default is used if not set
+    pub fn set_request_timeout(mut self, timeout: Option<Duration>) -> Self {
+        self.request_timeout = timeout;
+        self
+    }
+
+    /// set interval of ApiVersions heartbeats, heartbeats are disabled if not set
+    pub fn set_heartbeat_interval(mut self, interval: Option<Duration>) -> Self {
+        self.heartbeat_interval = interval;
+        self
+    }
+
     pub fn set_addr(&mut self, domain: String) {
         self.addr = domain
     }
@@ -130,24 +160,62 @@ impl ClientConfig {
     #[instrument(skip(self))]
     pub(crate) async fn connect(self) -> Result<VersionedSocket, FluvioError> {
         debug!(add = %self.addr, "Connection to");
-        let socket =
+        let mut socket =
             FluvioSocket::connect_with_connector(&self.addr, self.connector.as_ref()).await?;
+        if let Some(credentials) = &self.credentials {
+            debug!(add = %self.addr, "authenticating");
+            crate::sasl::authenticate(&mut socket, credentials, &self.client_id).await?;
+        }
         debug!(add = %self.addr, "creating version socket");
         VersionedSocket::connect(socket, Arc::new(self)).await
     }
 
+    /// create multiplexer with request timeout and heartbeat of this config
+    pub(crate) fn create_multiplexer(&self, socket: FluvioSocket) -> SharedMultiplexerSocket {
+        let socket = MultiplexerSocket::shared(socket);
+        if let Some(timeout) = self.request_timeout {
+            socket.set_request_timeout(timeout);
+        }
+        if let Some(interval) = self.heartbeat_interval {
+            debug!(
+                interval_ms = interval.as_millis() as u64,
+                "starting heartbeat"
+            );
+            let client_id = self.client_id.clone();
+            let timeout = interval.min(MAX_HEARTBEAT_TIMEOUT);
+            socket.start_heartbeat(interval, timeout, move || {
+                let mut req_msg = RequestMessage::new_request(ApiVersionsRequest::default());
+                req_msg.get_mut_header().set_client_id(&client_id);
+                req_msg
+            });
+        }
+        socket
+    }
+
     /// create new config with prefix add to domain, this is useful for SNI
     #[instrument(skip(self))]
     pub fn with_prefix_sni_domain(&self, prefix: &str) -> Self {
         let new_domain = format!("{}.{}", prefix, self.connector.domain());
         debug!(sni_domain = %new_domain);
-        let connector = self.connector.new_domain(new_domain);
+        self.with_domain(new_domain)
+    }
+
+    /// copy of this config, used to reconnect
+    pub(crate) fn duplicate(&self) -> Self {
+        self.with_domain(self.connector.domain().to_owned())
+    }
+
+    fn with_domain(&self, domain: String) -> Self {
+        let connector = self.connector.new_domain(domain);
 
         Self {
             addr: self.addr.clone(),
             client_id: self.client_id.clone(),
             connector,
             use_spu_local_address: self.use_spu_local_address,
+            credentials: self.credentials.clone(),
+            request_timeout: self.request_timeout,
+            heartbeat_interval: self.heartbeat_interval,
         }
     }
 }
@@ -188,7 +256,7 @@ impl Versions {
 
 /// Connection that perform request/response
 pub struct VersionedSerialSocket {
-    socket: SharedMultiplexerSocket,
+    socket: Arc<RwLock<SharedMultiplexerSocket>>,
     config: Arc<ClientConfig>,
     versions: Versions,
 }
@@ -205,6 +273,15 @@ impl VersionedSerialSocket {
         socket: SharedMultiplexerSocket,
         config: Arc<ClientConfig>,
         versions: Versions,
+    ) -> Self {
+        Self::shared(Arc::new(RwLock::new(socket)), config, versions)
+    }
+
+    /// socket which follows reconnects, `socket` is replaced when connection is re-established
+    pub(crate) fn shared(
+        socket: Arc<RwLock<SharedMultiplexerSocket>>,
+        config: Arc<ClientConfig>,
+        versions: Versions,
     ) -> Self {
         Self {
             socket,
@@ -226,7 +303,8 @@ impl VersionedSerialSocket {
         let req_msg = self.new_request(request, self.versions.lookup_version(R::API_KEY));
 
         // send request & save response
-        self.socket.send_and_receive(req_msg).await
+        let socket = self.socket.read().await.clone();
+        socket.send_and_receive(req_msg).await
     }
 
     /// create new request based on version
@@ -8,10 +8,11 @@ use dataplane::ReplicaKey;
 use dataplane::api::Request;
 use dataplane::api::RequestMessage;
 use fluvio_types::SpuId;
-use fluvio_socket::{MultiplexerSocket, SharedMultiplexerSocket, SocketError, AsyncResponse};
+use fluvio_socket::{SharedMultiplexerSocket, SocketError, AsyncResponse};
 use crate::FluvioError;
 use crate::sockets::ClientConfig;
 use crate::sync::MetadataStores;
+use crate::metrics::ClientMetrics;
 use crate::sockets::VersionedSerialSocket;
 use crate::sockets::Versions;
 
@@ -54,6 +55,7 @@ impl SpuSocket {
 pub struct SpuPool {
     config: Arc<ClientConfig>,
     pub(crate) metadata: MetadataStores,
+    pub(crate) metrics: Arc<ClientMetrics>,
     spu_clients: Arc<Mutex<HashMap<SpuId, SpuSocket>>>,
 }
 
@@ -66,10 +68,15 @@ impl Drop for SpuPool {
 
 impl SpuPool {
     /// start synchronize based on pool
-    pub fn start(config: Arc<ClientConfig>, metadata: MetadataStores) -> Result<Self, SocketError> {
+    pub fn start(
+        config: Arc<ClientConfig>,
+        metadata: MetadataStores,
+        metrics: Arc<ClientMetrics>,
+    ) -> Result<Self, SocketError> {
         debug!("starting spu pool");
         Ok(Self {
             metadata,
+            metrics,
             config,
             spu_clients: Arc::new(Mutex::new(HashMap::new())),
         })
@@ -96,8 +103,9 @@ impl SpuPool {
         client_config.set_addr(spu_addr);
         let versioned_socket = client_config.connect().await?;
         let (socket, config, versions) = versioned_socket.split();
+        self.metrics.record_spu_connect();
         Ok(SpuSocket {
-            socket: MultiplexerSocket::shared(socket),
+            socket: config.create_multiplexer(socket),
             config,
             versions,
         })
@@ -141,6 +149,7 @@ impl SpuPool {
                 return Ok(spu_socket.create_serial_socket().await);
             } else {
                 client_lock.remove(&leader_id);
+                self.metrics.record_spu_reconnect();
             }
         }
 
@@ -176,9 +185,14 @@ impl SpuPool {
         let mut client_lock = self.spu_clients.lock().await;
 
         if let Some(spu_socket) = client_lock.get_mut(&leader_id) {
-            return spu_socket
-                .create_stream_with_version(request, version)
-                .await;
+            if !spu_socket.is_stale() {
+                return spu_socket
+                    .create_stream_with_version(request, version)
+                    .await;
+            } else {
+                client_lock.remove(&leader_id);
+                self.metrics.record_spu_reconnect();
+            }
         }
 
         let mut spu_socket = self.connect_to_leader(leader_id).await?;
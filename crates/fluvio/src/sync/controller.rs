@@ -62,8 +62,8 @@ where
 
 impl<S> MetadataSyncController<S>
 where
-    S: Spec + Encoder + Decoder + Sync + Send + 'static,
-    <S as Spec>::Status: Sync + Send + Encoder + Decoder,
+    S: Spec + Encoder + Decoder + Default + Sync + Send + 'static,
+    <S as Spec>::Status: Sync + Send + Encoder + Decoder + Default,
     <S as Spec>::IndexKey: Sync + Send,
     S::IndexKey: Display,
     WatchResponse: TryInto<MetadataUpdate<S>> + Send,
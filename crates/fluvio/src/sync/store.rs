@@ -64,6 +64,20 @@ impl MetadataStores {
         self.shutdown.notify();
     }
 
+    /// restart synchronization of same stores with new connection to SC,
+    /// first response of each watch replaces content of stores
+    #[instrument(skip(self, socket))]
+    pub async fn resync(&self, socket: SharedMultiplexerSocket) -> Result<(), SocketError> {
+        let store = Self {
+            socket,
+            ..self.clone()
+        };
+        store.start_watch_for_spu().await?;
+        store.start_watch_for_partition().await?;
+        store.start_watch_for_topic().await?;
+        Ok(())
+    }
+
     /// start watch for spu
     #[instrument(skip(self))]
     pub async fn start_watch_for_spu(&self) -> Result<(), SocketError> {
@@ -123,3 +137,87 @@ impl MetadataStores {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+
+    use std::io::Cursor;
+    use std::time::Duration;
+
+    use futures_util::stream::StreamExt;
+
+    use dataplane::api::RequestMessage;
+    use dataplane::core::Decoder;
+    use fluvio_future::net::{TcpListener, TcpStream};
+    use fluvio_future::task::spawn;
+    use fluvio_future::timer::sleep;
+    use fluvio_sc_schema::objects::{Metadata, MetadataUpdate, WatchRequest, WatchResponse};
+    use fluvio_socket::{FluvioSocket, MultiplexerSocket, SharedMultiplexerSocket};
+
+    use crate::metadata::topic::{TopicSpec, TopicStatus};
+
+    use super::MetadataStores;
+
+    /// SC which answers each watch with full sync containing single topic
+    async fn fake_sc(listener: TcpListener, topic: &'static str) {
+        let mut incoming = listener.incoming();
+        let stream = incoming.next().await.expect("next").expect("stream");
+        let socket: FluvioSocket = stream.into();
+        let (mut sink, mut stream) = socket.split();
+        while let Some(Ok(bytes)) = stream.get_mut_tcp_stream().next().await {
+            let request: RequestMessage<WatchRequest> =
+                RequestMessage::decode_from(&mut Cursor::new(&bytes), 0).expect("decode");
+            let version = request.header.api_version();
+            let response = match request.request {
+                WatchRequest::Topic(_) => WatchResponse::Topic(MetadataUpdate::with_all(
+                    1,
+                    vec![Metadata {
+                        name: topic.to_owned(),
+                        spec: TopicSpec::default(),
+                        status: TopicStatus::default(),
+                    }],
+                )),
+                WatchRequest::Spu(_) => WatchResponse::Spu(MetadataUpdate::with_all(1, vec![])),
+                WatchRequest::Partition(_) => {
+                    WatchResponse::Partition(MetadataUpdate::with_all(1, vec![]))
+                }
+            };
+            sink.send_response(&request.new_response(response), version)
+                .await
+                .expect("send");
+        }
+    }
+
+    async fn connect_sc(addr: &str, topic: &'static str) -> SharedMultiplexerSocket {
+        let listener = TcpListener::bind(addr).await.expect("bind");
+        spawn(fake_sc(listener, topic));
+        let stream = TcpStream::connect(addr).await.expect("connect");
+        MultiplexerSocket::shared(stream.into())
+    }
+
+    async fn wait_for_topics(stores: &MetadataStores, expected: &[&str]) {
+        for _ in 0..50 {
+            let topics = stores.topics().store().clone_keys().await;
+            if topics == expected {
+                return;
+            }
+            sleep(Duration::from_millis(20)).await;
+        }
+        panic!("topics were not synced to: {:?}", expected);
+    }
+
+    #[fluvio_future::test]
+    async fn test_resync_replaces_stores() {
+        let first = connect_sc("127.0.0.1:6010", "first").await;
+        let stores = MetadataStores::start(first.clone(), 0)
+            .await
+            .expect("start");
+        wait_for_topics(&stores, &["first"]).await;
+
+        // connection is lost, stores are kept and synced from new connection
+        first.close_stale();
+        let second = connect_sc("127.0.0.1:6011", "second").await;
+        stores.resync(second).await.expect("resync");
+        wait_for_topics(&stores, &["second"]).await;
+    }
+}